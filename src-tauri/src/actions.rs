@@ -0,0 +1,94 @@
+// src-tauri/src/actions.rs
+// Deduplicación de acciones sobre procesos (terminar, cambiar prioridad...):
+// una repetición mientras la primera sigue en curso espera su resultado, y las
+// repeticiones dentro de la ventana de rebote reciben el mismo resultado
+// sin volver a ejecutar la acción. Cada ejecución real queda en la auditoría
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::audit::AuditOrigin;
+use crate::AppState;
+
+const DEBOUNCE: Duration = Duration::from_millis(750);
+
+// Clave: PID y acción con sus argumentos ("kill:false", "gpu_priority:High"...)
+pub type ActionMap = HashMap<(u32, String), Arc<PendingAction>>;
+
+#[derive(Default)]
+pub struct PendingAction {
+    finished: Mutex<Option<(Instant, Box<dyn Any + Send>)>>,
+    done: Condvar,
+}
+
+impl PendingAction {
+    fn is_expired(&self) -> bool {
+        matches!(&*self.finished.lock().unwrap(), Some((at, _)) if at.elapsed() >= DEBOUNCE)
+    }
+
+    // Espera a que termine la ejecución original y devuelve una copia de su resultado
+    fn wait<T: Clone + 'static>(&self) -> Result<T, String> {
+        let mut finished = self.finished.lock().unwrap();
+        while finished.is_none() {
+            finished = self.done.wait(finished).unwrap();
+        }
+        let (_, result) = finished.as_ref().unwrap();
+        result
+            .downcast_ref::<Result<T, String>>()
+            .cloned()
+            .unwrap_or_else(|| Err("Acción duplicada con un tipo de resultado distinto".to_string()))
+    }
+}
+
+// Si `run` entra en pánico, los que esperan reciben un error y la clave se
+// retira para que la siguiente petición vuelva a ejecutarse
+struct PanicGuard<'a, T: Send + 'static> {
+    state: &'a AppState,
+    key: Option<(u32, String)>,
+    pending: Arc<PendingAction>,
+    _result: std::marker::PhantomData<T>,
+}
+
+impl<T: Send + 'static> Drop for PanicGuard<'_, T> {
+    fn drop(&mut self) {
+        let Some(key) = self.key.take() else { return };
+        let result: Result<T, String> = Err("La acción falló de forma inesperada".to_string());
+        *self.pending.finished.lock().unwrap_or_else(|e| e.into_inner()) = Some((Instant::now(), Box::new(result)));
+        self.pending.done.notify_all();
+        let mut actions = self.state.pending_actions.lock().unwrap_or_else(|e| e.into_inner());
+        if actions.get(&key).is_some_and(|current| Arc::ptr_eq(current, &self.pending)) {
+            actions.remove(&key);
+        }
+    }
+}
+
+// Ejecuta `run` salvo que ya haya una ejecución idéntica en curso o reciente
+pub fn run_once<T, F>(state: &AppState, pid: u32, action: &str, run: F) -> Result<T, String>
+where
+    T: Clone + Send + 'static,
+    F: FnOnce() -> Result<T, String>,
+{
+    let key = (pid, action.to_string());
+    let pending = {
+        let mut actions = state.pending_actions.lock().unwrap();
+        actions.retain(|_, pending| !pending.is_expired());
+        if let Some(existing) = actions.get(&key).cloned() {
+            drop(actions);
+            return existing.wait();
+        }
+        let pending = Arc::new(PendingAction::default());
+        actions.insert(key.clone(), pending.clone());
+        pending
+    };
+
+    let mut guard = PanicGuard::<T> { state, key: Some(key), pending, _result: std::marker::PhantomData };
+    let target = crate::audit::process_name(state, pid);
+    let result = run();
+    guard.key = None;
+    crate::audit::record(state, AuditOrigin::User, action, &target, Some(pid), None, &result);
+    *guard.pending.finished.lock().unwrap() = Some((Instant::now(), Box::new(result.clone())));
+    guard.pending.done.notify_all();
+    result
+}
@@ -0,0 +1,173 @@
+// src-tauri/src/alertlog.rs
+// Historial persistente de alertas disparadas, con su estado de confirmación,
+// para que una notificación que no se vio (o que se omitió en horas de
+// silencio) no se pierda. Cada alerta se añade al momento a alert_log.jsonl;
+// al confirmarlas, o cuando el archivo dobla la capacidad, se reescribe con lo
+// que hay en memoria
+
+use serde::{Serialize, Deserialize};
+use std::collections::VecDeque;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Emitter, State};
+
+use crate::alerts::{AlertEvent, AlertTarget};
+use crate::AppState;
+
+const CAPACITY: usize = 2000;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LoggedAlert {
+    pub id: u64, // Correlativo, se mantiene entre sesiones
+    #[serde(flatten)]
+    pub event: AlertEvent,
+    pub acknowledged: bool,
+    pub acknowledged_at: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct AlertFilter {
+    pub since: Option<u64>, // Milisegundos desde UNIX_EPOCH
+    pub until: Option<u64>,
+    pub acknowledged: Option<bool>, // false: solo las pendientes
+    pub rule_id: Option<u64>,
+    pub process: Option<String>, // Ejecutable del objetivo
+    pub limit: Option<usize>,    // Las más recientes
+}
+
+impl AlertFilter {
+    fn matches(&self, alert: &LoggedAlert) -> bool {
+        let timestamp = alert.event.timestamp;
+        self.since.is_none_or(|since| timestamp >= since)
+            && self.until.is_none_or(|until| timestamp <= until)
+            && self.acknowledged.is_none_or(|acknowledged| alert.acknowledged == acknowledged)
+            && self.rule_id.is_none_or(|rule_id| alert.event.rule_id == rule_id)
+            && self.process.as_ref().is_none_or(|process| {
+                matches!(&alert.event.target, AlertTarget::Process(name) if crate::text::same_name(name, process))
+            })
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct AlertLog {
+    alerts: VecDeque<LoggedAlert>,
+    next_id: u64,
+    path: Option<PathBuf>,
+    file_lines: usize, // Alertas escritas en el archivo, incluidas las ya descartadas
+}
+
+impl AlertLog {
+    // Las líneas corruptas se ignoran; solo se cargan las más recientes
+    pub fn load(path: &Path) -> Self {
+        let mut log = AlertLog { path: Some(path.to_path_buf()), ..Default::default() };
+        if let Ok(content) = fs::read_to_string(path) {
+            log.file_lines = content.lines().count();
+            for alert in content.lines().filter_map(|line| serde_json::from_str::<LoggedAlert>(line).ok()) {
+                log.next_id = log.next_id.max(alert.id + 1);
+                log.insert(alert);
+            }
+        }
+        log
+    }
+
+    fn insert(&mut self, alert: LoggedAlert) {
+        if self.alerts.len() == CAPACITY {
+            self.alerts.pop_front();
+        }
+        self.alerts.push_back(alert);
+    }
+
+    fn write_all(&mut self) -> Result<(), String> {
+        let Some(path) = &self.path else { return Ok(()) };
+        let content: String = self
+            .alerts
+            .iter()
+            .filter_map(|alert| serde_json::to_string(alert).ok())
+            .map(|line| line + "\n")
+            .collect();
+        fs::write(path, content).map_err(|e| format!("No se pudo guardar {}: {}", path.display(), e))?;
+        self.file_lines = self.alerts.len();
+        Ok(())
+    }
+
+    // Anota la alerta y la devuelve con su identificador; un fallo al
+    // escribir el archivo no impide avisar
+    pub fn push(&mut self, event: AlertEvent) -> LoggedAlert {
+        let alert = LoggedAlert { id: self.next_id, event, acknowledged: false, acknowledged_at: None };
+        self.next_id += 1;
+        self.insert(alert.clone());
+        if let Some(path) = &self.path {
+            if let Some(dir) = path.parent() {
+                let _ = fs::create_dir_all(dir);
+            }
+            // Se compacta de vez en cuando para no crecer sin límite
+            if self.file_lines >= 2 * CAPACITY {
+                let _ = self.write_all();
+            } else if let Ok(line) = serde_json::to_string(&alert) {
+                let written = OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(path)
+                    .and_then(|mut file| writeln!(file, "{}", line));
+                if written.is_ok() {
+                    self.file_lines += 1;
+                }
+            }
+        }
+        alert
+    }
+
+    // Confirma las alertas indicadas (todas las pendientes con None) y
+    // devuelve las que han cambiado
+    fn acknowledge(&mut self, id: Option<u64>) -> Result<Vec<LoggedAlert>, String> {
+        let now = crate::stats::now_millis();
+        let mut changed = Vec::new();
+        for alert in self.alerts.iter_mut().filter(|alert| id.is_none_or(|id| alert.id == id)) {
+            if !alert.acknowledged {
+                alert.acknowledged = true;
+                alert.acknowledged_at = Some(now);
+                changed.push(alert.clone());
+            }
+        }
+        if !changed.is_empty() {
+            self.write_all()?;
+        }
+        Ok(changed)
+    }
+}
+
+// De la más antigua a la más reciente
+#[tauri::command]
+pub fn get_alerts(state: State<AppState>, filter: Option<AlertFilter>) -> Vec<LoggedAlert> {
+    let filter = filter.unwrap_or_default();
+    let log = state.alert_log.lock().unwrap();
+    let alerts: Vec<&LoggedAlert> = log.alerts.iter().filter(|alert| filter.matches(alert)).collect();
+    let skip = filter.limit.map_or(0, |limit| alerts.len().saturating_sub(limit));
+    alerts.into_iter().skip(skip).cloned().collect()
+}
+
+#[tauri::command]
+pub fn ack_alert(app: AppHandle, state: State<AppState>, id: u64) -> Result<LoggedAlert, String> {
+    let mut log = state.alert_log.lock().unwrap();
+    if let Some(alert) = log.acknowledge(Some(id))?.pop() {
+        let _ = app.emit("alerts-acknowledged", vec![id]);
+        return Ok(alert);
+    }
+    log.alerts
+        .iter()
+        .find(|alert| alert.id == id)
+        .cloned()
+        .ok_or_else(|| format!("No existe la alerta {} en el historial", id))
+}
+
+// Devuelve cuántas estaban pendientes
+#[tauri::command]
+pub fn ack_all_alerts(app: AppHandle, state: State<AppState>) -> Result<usize, String> {
+    let changed = state.alert_log.lock().unwrap().acknowledge(None)?;
+    if !changed.is_empty() {
+        let _ = app.emit("alerts-acknowledged", changed.iter().map(|alert| alert.id).collect::<Vec<_>>());
+    }
+    Ok(changed.len())
+}
@@ -0,0 +1,278 @@
+// src-tauri/src/alerts.rs
+// Alertas de recursos: un proceso (todas sus instancias), el sistema o una
+// expresión de vigilancia por encima de un umbral durante un tiempo mínimo. Al cumplirse se emite
+// "alert-triggered" y una notificación del sistema (salvo en horas de
+// silencio); se vuelve a armar cuando el valor baja del umbral
+
+use serde::{Serialize, Deserialize};
+use std::collections::{HashMap, HashSet};
+use tauri::{AppHandle, Emitter, Manager, State};
+use tauri_plugin_notification::NotificationExt;
+
+use crate::expressions::{Expr, MetricContext};
+use crate::format::{format_bytes, FormatOptions};
+use crate::provider::SystemSnapshot;
+use crate::quiethours::SuppressedNotification;
+use crate::{AppState, ProcessInfo, ProcessTotals};
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertTarget {
+    System,
+    Process(String), // Ejecutable, p. ej. "chrome.exe"
+    // Fórmula de las expresiones de vigilancia, con la métrica Expression;
+    // una condición (`a > b`) vale 1 mientras se cumple
+    Expression(String),
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertMetric {
+    CpuUsage,    // Porcentaje
+    MemoryUsage, // Bytes
+    GpuUsage,    // Porcentaje
+    DiskRate,    // Lectura más escritura, bytes/s
+    NetworkRate, // Envío más recepción, bytes/s
+    ExitCode,    // Salida de un proceso vigilado con notify_on_exit; no vale para reglas
+    Expression,  // Valor de la expresión del objetivo
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AlertRule {
+    #[serde(default)]
+    pub id: u64, // Lo asigna add_alert_rule
+    pub target: AlertTarget,
+    pub metric: AlertMetric,
+    pub threshold: f64,
+    #[serde(default)]
+    pub duration_secs: u64, // Tiempo seguido por encima del umbral
+    #[serde(default = "default_true")]
+    pub notify: bool, // Notificación del sistema además del evento
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AlertEvent {
+    pub rule_id: u64,
+    pub target: AlertTarget,
+    pub metric: AlertMetric,
+    pub value: f64,
+    pub threshold: f64,
+    pub duration_secs: u64,
+    pub pids: Vec<u32>, // Instancias del proceso; vacío para el sistema
+    pub timestamp: u64,
+    #[serde(default)]
+    pub notification_suppressed: bool, // Por las horas de silencio
+}
+
+// Valores globales del refresco. El tiempo seguido por encima del umbral se
+// mide con el timestamp de la instantánea, como en una reproducción
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemReading {
+    pub timestamp: u64, // Milisegundos
+    pub cpu_usage: f32,
+    pub used_memory: u64,
+}
+
+impl SystemReading {
+    pub fn of(snapshot: &SystemSnapshot) -> Self {
+        let (timestamp, cpu_usage, used_memory) = (snapshot.timestamp, snapshot.cpu_usage, snapshot.used_memory);
+        SystemReading { timestamp, cpu_usage, used_memory }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct AlertEngine {
+    above_since: HashMap<u64, u64>, // Timestamp de la instantánea
+    fired: HashSet<u64>, // Disparadas y aún por encima del umbral
+}
+
+pub fn metric(process: &ProcessInfo, metric: AlertMetric) -> f64 {
+    match metric {
+        AlertMetric::CpuUsage => process.cpu_usage as f64,
+        AlertMetric::MemoryUsage => process.memory_usage as f64,
+        AlertMetric::GpuUsage => process.gpu_usage as f64,
+        AlertMetric::DiskRate => process.disk_read_rate + process.disk_write_rate,
+        AlertMetric::NetworkRate => process.network_send_rate + process.network_receive_rate,
+        AlertMetric::ExitCode | AlertMetric::Expression => 0.0,
+    }
+}
+
+fn system_metric(totals: &ProcessTotals, system: &SystemReading, metric: AlertMetric) -> f64 {
+    match metric {
+        AlertMetric::CpuUsage => system.cpu_usage as f64,
+        AlertMetric::MemoryUsage => system.used_memory as f64,
+        AlertMetric::GpuUsage => totals.gpu_usage as f64,
+        AlertMetric::DiskRate => totals.disk_read_rate + totals.disk_write_rate,
+        AlertMetric::NetworkRate => totals.network_receive_rate + totals.network_send_rate,
+        AlertMetric::ExitCode | AlertMetric::Expression => 0.0,
+    }
+}
+
+impl AlertEngine {
+    // Devuelve las alertas que se cumplen por primera vez en este refresco.
+    // Sin contexto, o si la expresión falla, una alerta de expresión cuenta
+    // como por debajo del umbral
+    pub fn evaluate(
+        &mut self,
+        rules: &[AlertRule],
+        processes: &[ProcessInfo],
+        totals: &ProcessTotals,
+        system: &SystemReading,
+        context: Option<&MetricContext>,
+    ) -> Vec<AlertEvent> {
+        let ids: HashSet<u64> = rules.iter().map(|rule| rule.id).collect();
+        self.above_since.retain(|id, _| ids.contains(id));
+        self.fired.retain(|id| ids.contains(id));
+
+        let now = system.timestamp;
+        let mut events = Vec::new();
+        for rule in rules {
+            let (value, pids) = match &rule.target {
+                AlertTarget::System => (system_metric(totals, system, rule.metric), Vec::new()),
+                AlertTarget::Process(name) => {
+                    let instances: Vec<&ProcessInfo> =
+                        processes.iter().filter(|process| crate::text::same_name(&process.name, name)).collect();
+                    (
+                        instances.iter().map(|process| metric(process, rule.metric)).sum(),
+                        instances.iter().map(|process| process.pid).collect(),
+                    )
+                }
+                AlertTarget::Expression(formula) => {
+                    let value = context.and_then(|context| {
+                        Expr::parse(formula).and_then(|expr| expr.eval(&|name| context.get(name))).ok()
+                    });
+                    (value.unwrap_or(f64::NEG_INFINITY), Vec::new())
+                }
+            };
+            if value < rule.threshold {
+                self.above_since.remove(&rule.id);
+                self.fired.remove(&rule.id);
+                continue;
+            }
+            // Un timestamp anterior (se pasó a una reproducción) vuelve a contar
+            let since = *self.above_since.entry(rule.id).and_modify(|since| *since = (*since).min(now)).or_insert(now);
+            if self.fired.contains(&rule.id) || now.saturating_sub(since) < rule.duration_secs * 1000 {
+                continue;
+            }
+            self.fired.insert(rule.id);
+            events.push(AlertEvent {
+                rule_id: rule.id,
+                target: rule.target.clone(),
+                metric: rule.metric,
+                value,
+                threshold: rule.threshold,
+                duration_secs: rule.duration_secs,
+                pids,
+                timestamp: crate::stats::now_millis(),
+                notification_suppressed: false,
+            });
+        }
+        events
+    }
+}
+
+fn describe(event: &AlertEvent, options: &FormatOptions) -> (String, String) {
+    let target = match &event.target {
+        AlertTarget::System => "El sistema".to_string(),
+        AlertTarget::Process(name) => name.clone(),
+        AlertTarget::Expression(formula) => format!("La expresión {}", formula),
+    };
+    let number = |value: f64| options.locale().number(value, options.decimals);
+    let percent = |value: f64| format!("{} %", options.locale().number(value, 0));
+    let bytes = |value: f64| format_bytes(value, options);
+    let rate = |value: f64| format_bytes(value, options) + "/s";
+    let (metric, value, threshold) = match event.metric {
+        AlertMetric::CpuUsage => ("CPU", percent(event.value), percent(event.threshold)),
+        AlertMetric::GpuUsage => ("GPU", percent(event.value), percent(event.threshold)),
+        AlertMetric::MemoryUsage => ("memoria", bytes(event.value), bytes(event.threshold)),
+        AlertMetric::DiskRate => ("disco", rate(event.value), rate(event.threshold)),
+        AlertMetric::NetworkRate => ("red", rate(event.value), rate(event.threshold)),
+        AlertMetric::ExitCode => ("salida", format!("{}", event.value), format!("{}", event.threshold)),
+        AlertMetric::Expression => ("expresión", number(event.value), number(event.threshold)),
+    };
+    let mut body = format!("{}: {} de {} (umbral {})", target, value, metric, threshold);
+    if event.duration_secs > 0 {
+        body += &format!(" durante {} s", event.duration_secs);
+    }
+    (format!("Alerta de {}", metric), body)
+}
+
+// Evento para la interfaz y, si la regla lo pide, notificación del sistema.
+// Todas quedan en el historial de alertas; el evento lleva su identificador
+// para confirmarla
+pub fn dispatch(app: &AppHandle, rules: &[AlertRule], event: AlertEvent) {
+    let notify = rules.iter().any(|rule| rule.id == event.rule_id && rule.notify);
+    let options = app.state::<AppState>().settings.read().unwrap().format;
+    let notification = notify.then(|| describe(&event, &options));
+    raise(app, event, notification);
+}
+
+// Camino común de todos los avisos: en horas de silencio la notificación
+// solo se anota, y el evento se guarda en el historial antes de emitirse
+pub fn raise(app: &AppHandle, mut event: AlertEvent, notification: Option<(String, String)>) {
+    let state = app.state::<AppState>();
+    if let Some((title, body)) = notification {
+        if state.settings.read().unwrap().quiet_hours.is_active() {
+            event.notification_suppressed = true;
+            let notification =
+                SuppressedNotification { timestamp: event.timestamp, rule_id: event.rule_id, title, body };
+            state.quiet_hours.lock().unwrap().push(notification);
+        } else {
+            let _ = app.notification().builder().title(title).body(body).show();
+        }
+    }
+    let logged = state.alert_log.lock().unwrap().push(event);
+    let _ = app.emit("alert-triggered", logged);
+}
+
+// Comprobaciones comunes a todas las vías por las que llega una regla:
+// add_alert_rule, la configuración (también importada) y las listas de
+// vigilancia. Con umbral 0 una métrica nativa se cumpliría en cada refresco
+pub fn validate_rule(rule: &AlertRule) -> Result<(), String> {
+    if !rule.threshold.is_finite() {
+        return Err("El umbral debe ser un número".to_string());
+    }
+    match (&rule.target, rule.metric) {
+        (_, AlertMetric::ExitCode) => Err("Para avisar al terminar un proceso usa notify_on_exit".to_string()),
+        (AlertTarget::Expression(formula), AlertMetric::Expression) => Expr::parse(formula).map(|_| ()),
+        (AlertTarget::Expression(_), _) | (_, AlertMetric::Expression) => {
+            Err("Las alertas de expresión usan el objetivo y la métrica expression".to_string())
+        }
+        (AlertTarget::Process(name), _) if name.trim().is_empty() => Err("Falta el nombre del proceso".to_string()),
+        _ if rule.threshold <= 0.0 => Err("El umbral debe ser mayor que cero".to_string()),
+        _ => Ok(()),
+    }
+}
+
+#[tauri::command]
+pub fn add_alert_rule(app: AppHandle, state: State<AppState>, rule: AlertRule) -> Result<AlertRule, String> {
+    validate_rule(&rule)?;
+    let mut settings = state.settings.write().unwrap();
+    // Las configuraciones anteriores al contador parten del mayor existente
+    let last = settings.alert_rules.iter().map(|rule| rule.id).max().unwrap_or(0).max(settings.last_alert_rule_id);
+    settings.last_alert_rule_id = last + 1;
+    let rule = AlertRule { id: last + 1, ..rule };
+    settings.alert_rules.push(rule.clone());
+    crate::settings::save(&app, &settings)?;
+    Ok(rule)
+}
+
+#[tauri::command]
+pub fn list_alert_rules(state: State<AppState>) -> Vec<AlertRule> {
+    state.settings.read().unwrap().alert_rules.clone()
+}
+
+#[tauri::command]
+pub fn remove_alert_rule(app: AppHandle, state: State<AppState>, id: u64) -> Result<(), String> {
+    let mut settings = state.settings.write().unwrap();
+    let count = settings.alert_rules.len();
+    settings.alert_rules.retain(|rule| rule.id != id);
+    if settings.alert_rules.len() == count {
+        return Err(format!("No existe la alerta {}", id));
+    }
+    crate::settings::save(&app, &settings)
+}
@@ -0,0 +1,207 @@
+// src-tauri/src/anomaly.rs
+// Detección de anomalías: cada ejecutable se compara con su propia línea base
+// (media y desviación móviles por métrica y hora del día). Si se aleja más de
+// `anomaly_sigma` desviaciones se emite "anomaly-detected". Las líneas base se
+// guardan en anomaly_baselines.json porque tardan días en formarse
+
+use serde::{Serialize, Deserialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use tauri::State;
+
+use crate::alerts::AlertMetric;
+use crate::{AppState, ProcessInfo};
+
+const METRICS: [AlertMetric; 5] = [
+    AlertMetric::CpuUsage,
+    AlertMetric::MemoryUsage,
+    AlertMetric::GpuUsage,
+    AlertMetric::DiskRate,
+    AlertMetric::NetworkRate,
+];
+// La línea base se actualiza cada SAMPLE_INTERVAL y olvida poco a poco lo
+// anterior a WINDOW muestras (unos 5 días de una misma hora)
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(10);
+const WINDOW: f64 = 2000.0;
+const MIN_SAMPLES: u64 = 30; // Antes no hay base suficiente para comparar
+const SAVE_INTERVAL: Duration = Duration::from_secs(300);
+const RECENT_CAPACITY: usize = 100;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default)]
+struct Baseline {
+    count: u64,
+    mean: f64,
+    variance: f64,
+}
+
+impl Baseline {
+    // Media y varianza de Welford hasta WINDOW muestras; después, exponenciales
+    fn update(&mut self, value: f64) {
+        self.count += 1;
+        let weight = 1.0 / (self.count as f64).min(WINDOW);
+        let delta = value - self.mean;
+        self.mean += weight * delta;
+        self.variance = (1.0 - weight) * (self.variance + weight * delta * delta);
+    }
+}
+
+// Desviación mínima por métrica: un proceso que siempre usa 0 % no es
+// anómalo por usar un 1 %
+pub fn min_std_dev(metric: AlertMetric) -> f64 {
+    match metric {
+        AlertMetric::CpuUsage | AlertMetric::GpuUsage => 2.0,
+        AlertMetric::MemoryUsage => 32.0 * 1024.0 * 1024.0,
+        AlertMetric::DiskRate | AlertMetric::NetworkRate => 256.0 * 1024.0,
+        AlertMetric::ExitCode | AlertMetric::Expression => 1.0,
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct AnomalyEvent {
+    pub name: String,
+    pub metric: AlertMetric,
+    pub value: f64,
+    pub mean: f64,
+    pub std_dev: f64,
+    pub sigma: f64, // Desviaciones por encima de la media
+    pub hour: u32,  // Hora local de la línea base usada
+    pub pids: Vec<u32>,
+    pub timestamp: u64,
+    pub message: String,
+}
+
+impl AnomalyEvent {
+    fn describe(&self) -> String {
+        let metric = match self.metric {
+            AlertMetric::CpuUsage => "CPU",
+            AlertMetric::MemoryUsage => "memoria",
+            AlertMetric::GpuUsage => "GPU",
+            AlertMetric::DiskRate => "disco",
+            AlertMetric::NetworkRate => "red",
+            AlertMetric::ExitCode => "código de salida",
+            AlertMetric::Expression => "expresión",
+        };
+        format!(
+            "{}: {} está {:.1}σ por encima de lo normal a las {:02}:00",
+            self.name, metric, self.sigma, self.hour
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct AnomalyDetector {
+    // Ejecutable (en minúsculas) -> métrica -> una línea base por hora
+    baselines: HashMap<String, HashMap<AlertMetric, Vec<Baseline>>>,
+    flagged: HashSet<(String, AlertMetric)>, // Ya notificadas y aún anómalas
+    recent: VecDeque<AnomalyEvent>,
+    path: Option<PathBuf>,
+    last_sample: Option<Instant>,
+    last_save: Option<Instant>,
+}
+
+impl AnomalyDetector {
+    // Un archivo ausente o corrupto empieza sin líneas base
+    pub fn load(path: &Path) -> Self {
+        let baselines = fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        AnomalyDetector { baselines, path: Some(path.to_path_buf()), ..Default::default() }
+    }
+
+    pub fn save(&mut self) -> Result<(), String> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("No se pudo crear {}: {}", dir.display(), e))?;
+        }
+        let content = serde_json::to_string(&self.baselines)
+            .map_err(|e| format!("Error al serializar las líneas base: {}", e))?;
+        fs::write(path, content).map_err(|e| format!("No se pudo guardar {}: {}", path.display(), e))?;
+        self.last_save = Some(Instant::now());
+        Ok(())
+    }
+
+    // Punto de partida de la calibración inicial para las horas que aún no
+    // tienen muestras suficientes; el aprendizaje normal lo va corrigiendo
+    pub fn seed(&mut self, name: &str, metric: AlertMetric, mean: f64, variance: f64) {
+        let hours = self.baselines.entry(name.to_string()).or_default().entry(metric).or_default();
+        hours.resize(24, Baseline::default());
+        for baseline in hours.iter_mut().filter(|baseline| baseline.count < MIN_SAMPLES) {
+            *baseline = Baseline { count: MIN_SAMPLES, mean, variance };
+        }
+    }
+
+    // Compara cada ejecutable (todas sus instancias sumadas) con la línea base
+    // de esta hora y devuelve las anomalías nuevas de este refresco
+    pub fn evaluate(&mut self, processes: &[ProcessInfo], sigma: f64) -> Vec<AnomalyEvent> {
+        let timestamp = crate::stats::now_millis();
+        let hour = crate::locale::local_hour(timestamp) as usize;
+        let now = Instant::now();
+        let learn = self.last_sample.is_none_or(|at| now.duration_since(at) >= SAMPLE_INTERVAL);
+        if learn {
+            self.last_sample = Some(now);
+        }
+
+        let mut instances: HashMap<String, Vec<&ProcessInfo>> = HashMap::new();
+        for process in processes {
+            instances.entry(process.name.to_lowercase()).or_default().push(process);
+        }
+
+        let mut events = Vec::new();
+        for (name, group) in &instances {
+            let metrics = self.baselines.entry(name.clone()).or_default();
+            for metric in METRICS {
+                let value: f64 = group.iter().map(|process| crate::alerts::metric(process, metric)).sum();
+                let baseline = &mut metrics.entry(metric).or_insert_with(|| vec![Baseline::default(); 24])[hour];
+                let key = (name.clone(), metric);
+                if baseline.count >= MIN_SAMPLES {
+                    let std_dev = baseline.variance.sqrt().max(min_std_dev(metric));
+                    let deviation = (value - baseline.mean) / std_dev;
+                    if deviation < sigma {
+                        self.flagged.remove(&key);
+                    } else if self.flagged.insert(key) {
+                        let mut event = AnomalyEvent {
+                            name: group[0].name.clone(),
+                            metric,
+                            value,
+                            mean: baseline.mean,
+                            std_dev,
+                            sigma: deviation,
+                            hour: hour as u32,
+                            pids: group.iter().map(|process| process.pid).collect(),
+                            timestamp,
+                            message: String::new(),
+                        };
+                        event.message = event.describe();
+                        events.push(event);
+                    }
+                }
+                if learn {
+                    baseline.update(value);
+                }
+            }
+        }
+        self.flagged.retain(|(name, _)| instances.contains_key(name));
+
+        for event in &events {
+            if self.recent.len() == RECENT_CAPACITY {
+                self.recent.pop_front();
+            }
+            self.recent.push_back(event.clone());
+        }
+        if self.last_save.is_none_or(|at| at.elapsed() >= SAVE_INTERVAL) {
+            let _ = self.save();
+        }
+        events
+    }
+}
+
+// Anomalías detectadas en esta sesión, de la más reciente a la más antigua
+#[tauri::command]
+pub fn get_recent_anomalies(state: State<AppState>) -> Vec<AnomalyEvent> {
+    state.anomalies.lock().unwrap().recent.iter().rev().cloned().collect()
+}
@@ -0,0 +1,106 @@
+// src-tauri/src/arch.rs
+// Arquitectura de CPU del sistema y de cada proceso: en Windows on ARM un
+// proceso x64 o x86 se ejecuta emulado, y en Windows x64 uno x86 bajo WOW64
+
+use serde::Serialize;
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Architecture {
+    X86,
+    X64,
+    Arm,
+    Arm64,
+    Unknown,
+}
+
+#[derive(Debug, Serialize, Clone, Copy)]
+pub struct ProcessArchitecture {
+    pub architecture: Architecture,
+    pub emulated: bool, // Distinta de la nativa (emulación x64/x86 en ARM64 o WOW64)
+}
+
+#[cfg(target_os = "windows")]
+fn from_machine(machine: windows::Win32::System::SystemInformation::IMAGE_FILE_MACHINE) -> Architecture {
+    use windows::Win32::System::SystemInformation::{
+        IMAGE_FILE_MACHINE_AMD64, IMAGE_FILE_MACHINE_ARM, IMAGE_FILE_MACHINE_ARM64, IMAGE_FILE_MACHINE_ARMNT,
+        IMAGE_FILE_MACHINE_I386,
+    };
+
+    match machine {
+        IMAGE_FILE_MACHINE_I386 => Architecture::X86,
+        IMAGE_FILE_MACHINE_AMD64 => Architecture::X64,
+        IMAGE_FILE_MACHINE_ARM | IMAGE_FILE_MACHINE_ARMNT => Architecture::Arm,
+        IMAGE_FILE_MACHINE_ARM64 => Architecture::Arm64,
+        _ => Architecture::Unknown,
+    }
+}
+
+// Arquitectura del sistema operativo, aunque este proceso se ejecute emulado
+pub fn native() -> Architecture {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::System::SystemInformation::IMAGE_FILE_MACHINE;
+        use windows::Win32::System::Threading::{GetCurrentProcess, IsWow64Process2};
+
+        let (mut process, mut native) = (IMAGE_FILE_MACHINE::default(), IMAGE_FILE_MACHINE::default());
+        if unsafe { IsWow64Process2(GetCurrentProcess(), &mut process, Some(&mut native)) }.as_bool() {
+            return from_machine(native);
+        }
+        Architecture::Unknown
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        match std::env::consts::ARCH {
+            "x86" => Architecture::X86,
+            "x86_64" => Architecture::X64,
+            "arm" => Architecture::Arm,
+            "aarch64" => Architecture::Arm64,
+            _ => Architecture::Unknown,
+        }
+    }
+}
+
+// None si no hay acceso al proceso o fuera de Windows
+pub fn query(pid: u32) -> Option<ProcessArchitecture> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::System::SystemInformation::{IMAGE_FILE_MACHINE, IMAGE_FILE_MACHINE_UNKNOWN};
+        use windows::Win32::System::Threading::{
+            GetProcessInformation, IsWow64Process2, ProcessMachineTypeInfo, PROCESS_MACHINE_INFORMATION,
+            PROCESS_QUERY_LIMITED_INFORMATION,
+        };
+
+        let process = crate::win::open_process(pid, PROCESS_QUERY_LIMITED_INFORMATION).ok()?;
+        let native = native();
+        // ProcessMachineTypeInfo (Windows 11) es la única que distingue la emulación
+        // x64 en ARM64; IsWow64Process2 solo conoce WOW64
+        let mut info = PROCESS_MACHINE_INFORMATION::default();
+        let has_machine_info = unsafe {
+            GetProcessInformation(
+                process.0,
+                ProcessMachineTypeInfo,
+                &mut info as *mut _ as *mut _,
+                std::mem::size_of::<PROCESS_MACHINE_INFORMATION>() as u32,
+            )
+        }
+        .as_bool();
+        let machine = if has_machine_info {
+            from_machine(info.ProcessMachine)
+        } else {
+            let mut machine = IMAGE_FILE_MACHINE::default();
+            if !unsafe { IsWow64Process2(process.0, &mut machine, None) }.as_bool() {
+                return None;
+            }
+            if machine == IMAGE_FILE_MACHINE_UNKNOWN { native } else { from_machine(machine) }
+        };
+        Some(ProcessArchitecture { architecture: machine, emulated: machine != native })
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = pid;
+        None
+    }
+}
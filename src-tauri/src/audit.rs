@@ -0,0 +1,192 @@
+// src-tauri/src/audit.rs
+// Registro de auditoría de las acciones destructivas (terminar, suspender,
+// cambiar prioridad, detener servicios...) con fecha, usuario y resultado.
+// Cada entrada se añade al momento a audit_log.jsonl, una por línea
+
+use serde::{Serialize, Deserialize};
+use std::collections::VecDeque;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+use crate::paging::{paginate, Page};
+use crate::stats::now_millis;
+use crate::AppState;
+
+// Entradas que se conservan en memoria y al compactar el archivo
+const CAPACITY: usize = 5000;
+const MAX_FILE_BYTES: u64 = 4 * 1024 * 1024;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditOrigin {
+    User,       // Pedida desde la interfaz
+    Automation, // Reglas y límites diarios
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AuditEntry {
+    pub timestamp: u64,
+    pub user: String,
+    pub origin: AuditOrigin,
+    pub action: String, // Con sus argumentos: "kill:true", "stop_service"...
+    pub target: String, // Proceso, servicio o elemento de inicio
+    pub pid: Option<u32>,
+    #[serde(default)]
+    pub detail: Option<String>, // Regla que la disparó, etc.
+    pub success: bool,
+    pub error: Option<String>,
+    #[serde(default)]
+    pub exit_code: Option<u32>, // Si la acción terminó el proceso y se pudo leer
+}
+
+#[derive(Debug, Default)]
+pub struct AuditLog {
+    entries: VecDeque<AuditEntry>,
+    path: Option<PathBuf>,
+}
+
+impl AuditLog {
+    // Las líneas corruptas se ignoran; solo se cargan las más recientes
+    pub fn load(path: &Path) -> Self {
+        let mut entries = VecDeque::new();
+        if let Ok(content) = fs::read_to_string(path) {
+            for entry in content.lines().filter_map(|line| serde_json::from_str::<AuditEntry>(line).ok()) {
+                if entries.len() == CAPACITY {
+                    entries.pop_front();
+                }
+                entries.push_back(entry);
+            }
+        }
+        AuditLog { entries, path: Some(path.to_path_buf()) }
+    }
+
+    fn push(&mut self, entry: AuditEntry) -> Result<(), String> {
+        if self.entries.len() == CAPACITY {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry.clone());
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("No se pudo crear {}: {}", dir.display(), e))?;
+        }
+        // Al pasar del tamaño máximo se reescribe solo con lo que hay en memoria
+        if fs::metadata(path).is_ok_and(|meta| meta.len() >= MAX_FILE_BYTES) {
+            return self.rewrite();
+        }
+        let line = serde_json::to_string(&entry)
+            .map_err(|e| format!("Error al serializar la entrada de auditoría: {}", e))?;
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut file| writeln!(file, "{}", line))
+            .map_err(|e| format!("No se pudo guardar {}: {}", path.display(), e))
+    }
+
+    fn rewrite(&self) -> Result<(), String> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let content: String = self
+            .entries
+            .iter()
+            .filter_map(|entry| serde_json::to_string(entry).ok())
+            .map(|line| line + "\n")
+            .collect();
+        fs::write(path, content).map_err(|e| format!("No se pudo guardar {}: {}", path.display(), e))
+    }
+
+    // Completa la entrada de una terminación cuyo código llegó después de
+    // anotarla (el proceso tardó en salir). Solo la más reciente del PID desde
+    // `since`; el archivo se reescribe con lo que hay en memoria
+    pub fn set_exit_code(&mut self, pid: u32, since: u64, exit_code: u32) -> Result<(), String> {
+        let entry = self
+            .entries
+            .iter_mut()
+            .rev()
+            .take_while(|entry| entry.timestamp >= since)
+            .find(|entry| entry.pid == Some(pid) && entry.success);
+        match entry {
+            Some(entry) if entry.exit_code.is_none() => {
+                entry.exit_code = Some(exit_code);
+                self.rewrite()
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+// Usuario de la sesión que ejecuta el administrador (DOMINIO\usuario en Windows)
+fn current_user() -> String {
+    let user = std::env::var("USERNAME").or_else(|_| std::env::var("USER")).unwrap_or_default();
+    match std::env::var("USERDOMAIN") {
+        Ok(domain) if cfg!(target_os = "windows") && !domain.is_empty() => format!("{}\\{}", domain, user),
+        _ => user,
+    }
+}
+
+// Anota una acción ya ejecutada; un fallo al escribir el archivo no la deshace
+pub fn record<T>(
+    state: &AppState,
+    origin: AuditOrigin,
+    action: &str,
+    target: &str,
+    pid: Option<u32>,
+    detail: Option<String>,
+    result: &Result<T, String>,
+) {
+    // Con el registro bloqueado: capture anota aquí los códigos que llegan tarde
+    let mut audit = state.audit.lock().unwrap();
+    let entry = AuditEntry {
+        timestamp: now_millis(),
+        user: current_user(),
+        origin,
+        action: action.to_string(),
+        target: target.to_string(),
+        pid,
+        detail,
+        success: result.is_ok(),
+        error: result.as_ref().err().cloned(),
+        exit_code: pid.filter(|_| result.is_ok()).and_then(|pid| state.exit_codes.lock().unwrap().just_captured(pid)),
+    };
+    let _ = audit.push(entry);
+}
+
+// Nombre del proceso según el último árbol publicado, para anotarlo antes de terminarlo
+pub fn process_name(state: &AppState, pid: u32) -> String {
+    state
+        .processes
+        .read()
+        .unwrap()
+        .processes
+        .iter()
+        .find(|process| process.pid == pid)
+        .map_or_else(|| format!("PID {}", pid), |process| process.name.clone())
+}
+
+// Del más antiguo al más reciente; `since` en milisegundos desde UNIX_EPOCH
+#[tauri::command]
+pub fn get_action_log(state: State<AppState>, since: Option<u64>, limit: Option<usize>) -> Vec<AuditEntry> {
+    let audit = state.audit.lock().unwrap();
+    let entries: Vec<&AuditEntry> =
+        audit.entries.iter().filter(|entry| entry.timestamp >= since.unwrap_or(0)).collect();
+    let skip = limit.map_or(0, |limit| entries.len().saturating_sub(limit));
+    entries.into_iter().skip(skip).cloned().collect()
+}
+
+// Igual que get_action_log pero por páginas, desde la más antigua
+#[tauri::command]
+pub fn get_action_log_page(
+    state: State<AppState>,
+    since: Option<u64>,
+    cursor: Option<String>,
+    limit: Option<usize>,
+) -> Result<Page<AuditEntry>, String> {
+    let audit = state.audit.lock().unwrap();
+    let entries = audit.entries.iter().filter(|entry| entry.timestamp >= since.unwrap_or(0));
+    paginate(entries, |entry| entry.timestamp, cursor.as_deref(), limit)
+}
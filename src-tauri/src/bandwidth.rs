@@ -0,0 +1,167 @@
+// src-tauri/src/bandwidth.rs
+// Tráfico de red por proceso, acumulado durante la sesión a partir de los
+// eventos TcpIp/UdpIp de ETW, con un historial corto para sparklines
+
+use serde::{Serialize, Deserialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::net::IpAddr;
+use std::time::Instant;
+use tauri::State;
+
+use crate::{AppState, ProcessInfo};
+
+// Muestras de historial por proceso (un minuto a una muestra por segundo)
+const HISTORY_LEN: usize = 60;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+pub struct NetworkSample {
+    pub timestamp: u64,
+    pub send_rate: f64,    // Bytes por segundo
+    pub receive_rate: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProcessNetworkUsage {
+    pub pid: u32,
+    pub sent_bytes: u64,     // Total de la sesión
+    pub received_bytes: u64,
+    pub send_rate: f64,
+    pub receive_rate: f64,
+    pub history: Vec<NetworkSample>,
+}
+
+#[derive(Debug, Default)]
+struct Counters {
+    sent: u64,
+    received: u64,
+    sampled_sent: u64,
+    sampled_received: u64,
+    send_rate: f64,
+    receive_rate: f64,
+    history: VecDeque<NetworkSample>,
+    hosts: HashMap<IpAddr, (u64, u64)>, // Bytes (enviados, recibidos) por extremo remoto
+}
+
+#[derive(Debug, Default)]
+pub struct BandwidthCollector {
+    counters: HashMap<u32, Counters>,
+    last_sample: Option<Instant>,
+    pub error: Option<String>, // Motivo por el que el colector no está activo
+}
+
+impl BandwidthCollector {
+    pub fn record(&mut self, pid: u32, sent: bool, bytes: u64, remote: Option<IpAddr>) {
+        let counters = self.counters.entry(pid).or_default();
+        let host = remote.map(|remote| counters.hosts.entry(remote).or_insert((0, 0)));
+        if sent {
+            counters.sent += bytes;
+            if let Some(host) = host {
+                host.0 += bytes;
+            }
+        } else {
+            counters.received += bytes;
+            if let Some(host) = host {
+                host.1 += bytes;
+            }
+        }
+    }
+
+    // Tráfico de la sesión de un proceso por dirección remota
+    pub fn hosts(&self, pid: u32) -> Option<&HashMap<IpAddr, (u64, u64)>> {
+        self.counters.get(&pid).map(|counters| &counters.hosts)
+    }
+
+    // Calcula las tasas desde la muestra anterior y descarta los procesos
+    // terminados; devuelve los bytes (enviados, recibidos) de cada PID en el intervalo
+    pub fn sample(&mut self, alive: &HashSet<u32>) -> HashMap<u32, (u64, u64)> {
+        let now = Instant::now();
+        let elapsed = self
+            .last_sample
+            .map(|last| now.duration_since(last).as_secs_f64())
+            .unwrap_or(0.0);
+        self.last_sample = Some(now);
+        let timestamp = crate::stats::now_millis();
+
+        self.counters.retain(|pid, _| alive.contains(pid));
+        let mut deltas = HashMap::new();
+        for (pid, counters) in self.counters.iter_mut() {
+            let delta = (counters.sent - counters.sampled_sent, counters.received - counters.sampled_received);
+            if delta != (0, 0) {
+                deltas.insert(*pid, delta);
+            }
+            if elapsed > 0.0 {
+                counters.send_rate = (counters.sent - counters.sampled_sent) as f64 / elapsed;
+                counters.receive_rate = (counters.received - counters.sampled_received) as f64 / elapsed;
+            }
+            counters.sampled_sent = counters.sent;
+            counters.sampled_received = counters.received;
+
+            if counters.history.len() == HISTORY_LEN {
+                counters.history.pop_front();
+            }
+            counters.history.push_back(NetworkSample {
+                timestamp,
+                send_rate: counters.send_rate,
+                receive_rate: counters.receive_rate,
+            });
+        }
+        deltas
+    }
+
+    pub fn annotate(&self, processes: &mut [ProcessInfo]) {
+        for process in processes {
+            if let Some(counters) = self.counters.get(&process.pid) {
+                process.network_send_rate = counters.send_rate;
+                process.network_receive_rate = counters.receive_rate;
+            }
+        }
+    }
+
+    pub fn usage(&self) -> Vec<ProcessNetworkUsage> {
+        self.counters
+            .iter()
+            .map(|(pid, counters)| ProcessNetworkUsage {
+                pid: *pid,
+                sent_bytes: counters.sent,
+                received_bytes: counters.received,
+                send_rate: counters.send_rate,
+                receive_rate: counters.receive_rate,
+                history: counters.history.iter().copied().collect(),
+            })
+            .collect()
+    }
+
+    // Eventos TcpIp/UdpIp: opcodes 10/26 envío y 11/27 recepción (IPv4/IPv6);
+    // la carga empieza por PID (u32), tamaño (u32) y daddr, que en ambos
+    // sentidos es el extremo remoto (4 o 16 bytes)
+    #[cfg(target_os = "windows")]
+    pub fn on_event(&mut self, event: &crate::etw::KernelEvent) {
+        if event.provider != crate::etw::TCPIP_GUID && event.provider != crate::etw::UDPIP_GUID {
+            return;
+        }
+        let (sent, ipv6) = match event.opcode {
+            10 => (true, false),
+            11 => (false, false),
+            26 => (true, true),
+            27 => (false, true),
+            _ => return,
+        };
+        let remote = if ipv6 {
+            event.data.get(8..24).map(|bytes| IpAddr::from(<[u8; 16]>::try_from(bytes).unwrap()))
+        } else {
+            event.data.get(8..12).map(|bytes| IpAddr::from(<[u8; 4]>::try_from(bytes).unwrap()))
+        };
+        if let (Some(pid), Some(size)) = (event.u32_at(0), event.u32_at(4)) {
+            self.record(pid, sent, size as u64, remote);
+        }
+    }
+}
+
+#[tauri::command]
+pub fn get_network_usage(state: State<AppState>) -> Result<Vec<ProcessNetworkUsage>, String> {
+    let bandwidth = state.bandwidth.lock().unwrap();
+    if let Some(error) = &bandwidth.error {
+        return Err(error.clone());
+    }
+    Ok(bandwidth.usage())
+}
@@ -0,0 +1,249 @@
+// src-tauri/src/baselines.rs
+// Líneas base con nombre ("arranque limpio"): una instantánea del sistema y de
+// cada ejecutable para comparar más tarde el estado actual y ver qué cambió
+// tras instalar un controlador o un programa. Se guardan en baselines.json
+
+use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use sysinfo::{CpuExt, SystemExt};
+use tauri::{AppHandle, State};
+
+use crate::{AppState, ProcessInfo};
+
+const MAX_BASELINES: usize = 50;
+
+// Todas las instancias de un ejecutable sumadas
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq)]
+pub struct AppMetrics {
+    pub instances: u32,
+    pub cpu_usage: f32,
+    pub memory_usage: u64,
+    pub gpu_usage: f32,
+    pub disk_rate: f64,    // Lectura más escritura, bytes/s
+    pub network_rate: f64, // Envío más recepción, bytes/s
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default)]
+pub struct SystemMetrics {
+    pub cpu_usage: f32,
+    pub used_memory: u64,
+    pub total_memory: u64,
+    pub process_count: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NamedBaseline {
+    pub name: String,
+    pub captured_at: u64,
+    pub system: SystemMetrics,
+    pub apps: BTreeMap<String, AppMetrics>, // Ejecutable en minúsculas
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct BaselineInfo {
+    pub name: String,
+    pub captured_at: u64,
+    pub process_count: usize,
+    pub app_count: usize,
+}
+
+// Diferencias con signo (actual menos línea base)
+#[derive(Debug, Serialize, Clone, Copy, Default)]
+pub struct MetricDelta {
+    pub instances: i64,
+    pub cpu_usage: f64,
+    pub memory_usage: i64,
+    pub gpu_usage: f64,
+    pub disk_rate: f64,
+    pub network_rate: f64,
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    Added,   // No se ejecutaba al capturar la línea base
+    Removed, // Ya no se ejecuta
+    Changed,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct AppChange {
+    pub name: String,
+    pub kind: ChangeKind,
+    pub before: Option<AppMetrics>,
+    pub after: Option<AppMetrics>,
+    pub delta: MetricDelta,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct BaselineComparison {
+    pub baseline: String,
+    pub captured_at: u64,
+    pub before: SystemMetrics,
+    pub after: SystemMetrics,
+    pub cpu_usage_delta: f64,
+    pub used_memory_delta: i64,
+    pub process_count_delta: i64,
+    pub changes: Vec<AppChange>, // De mayor a menor diferencia de memoria
+}
+
+#[derive(Debug, Default)]
+pub struct BaselineStore {
+    baselines: BTreeMap<String, NamedBaseline>,
+    path: Option<PathBuf>,
+}
+
+impl BaselineStore {
+    // Un archivo ausente o corrupto empieza sin líneas base
+    pub fn load(path: &Path) -> Self {
+        let baselines = fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        BaselineStore { baselines, path: Some(path.to_path_buf()) }
+    }
+
+    fn save(&self) -> Result<(), String> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("No se pudo crear {}: {}", dir.display(), e))?;
+        }
+        let content = serde_json::to_string(&self.baselines)
+            .map_err(|e| format!("Error al serializar las líneas base: {}", e))?;
+        fs::write(path, content).map_err(|e| format!("No se pudo guardar {}: {}", path.display(), e))
+    }
+}
+
+fn aggregate(processes: &[ProcessInfo]) -> BTreeMap<String, AppMetrics> {
+    let mut apps: BTreeMap<String, AppMetrics> = BTreeMap::new();
+    for process in processes {
+        let app = apps.entry(process.name.to_lowercase()).or_default();
+        app.instances += 1;
+        app.cpu_usage += process.cpu_usage;
+        app.memory_usage += process.memory_usage;
+        app.gpu_usage += process.gpu_usage;
+        app.disk_rate += process.disk_read_rate + process.disk_write_rate;
+        app.network_rate += process.network_send_rate + process.network_receive_rate;
+    }
+    apps
+}
+
+fn delta(before: &AppMetrics, after: &AppMetrics) -> MetricDelta {
+    MetricDelta {
+        instances: after.instances as i64 - before.instances as i64,
+        cpu_usage: after.cpu_usage as f64 - before.cpu_usage as f64,
+        memory_usage: after.memory_usage as i64 - before.memory_usage as i64,
+        gpu_usage: after.gpu_usage as f64 - before.gpu_usage as f64,
+        disk_rate: after.disk_rate - before.disk_rate,
+        network_rate: after.network_rate - before.network_rate,
+    }
+}
+
+// Estado actual con los datos del último refresco completo
+fn current(app: AppHandle, state: &State<AppState>, name: String) -> NamedBaseline {
+    let tree = crate::get_processes(app, state.clone());
+    let system = {
+        let system = state.system.read().unwrap();
+        SystemMetrics {
+            cpu_usage: system.global_cpu_info().cpu_usage(),
+            used_memory: system.used_memory(),
+            total_memory: system.total_memory(),
+            process_count: tree.processes.len(),
+        }
+    };
+    NamedBaseline { name, captured_at: crate::stats::now_millis(), system, apps: aggregate(&tree.processes) }
+}
+
+fn info(baseline: &NamedBaseline) -> BaselineInfo {
+    BaselineInfo {
+        name: baseline.name.clone(),
+        captured_at: baseline.captured_at,
+        process_count: baseline.system.process_count,
+        app_count: baseline.apps.len(),
+    }
+}
+
+// Con un nombre ya existente se sustituye la línea base anterior
+#[tauri::command]
+pub fn capture_baseline(app: AppHandle, state: State<AppState>, name: String) -> Result<BaselineInfo, String> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err("La línea base necesita un nombre".to_string());
+    }
+    let baseline = current(app, &state, name.clone());
+    let mut store = state.baselines.lock().unwrap();
+    if !store.baselines.contains_key(&name) && store.baselines.len() >= MAX_BASELINES {
+        return Err(format!("Se admiten como máximo {} líneas base", MAX_BASELINES));
+    }
+    let summary = info(&baseline);
+    store.baselines.insert(name, baseline);
+    store.save()?;
+    Ok(summary)
+}
+
+#[tauri::command]
+pub fn list_baselines(state: State<AppState>) -> Vec<BaselineInfo> {
+    let store = state.baselines.lock().unwrap();
+    let mut baselines: Vec<BaselineInfo> = store.baselines.values().map(info).collect();
+    baselines.sort_by_key(|baseline| std::cmp::Reverse(baseline.captured_at));
+    baselines
+}
+
+#[tauri::command]
+pub fn delete_baseline(state: State<AppState>, name: String) -> Result<(), String> {
+    let mut store = state.baselines.lock().unwrap();
+    if store.baselines.remove(&name).is_none() {
+        return Err(format!("No existe la línea base {}", name));
+    }
+    store.save()
+}
+
+#[tauri::command]
+pub fn compare_to_baseline(app: AppHandle, state: State<AppState>, name: String) -> Result<BaselineComparison, String> {
+    let baseline = state
+        .baselines
+        .lock()
+        .unwrap()
+        .baselines
+        .get(&name)
+        .cloned()
+        .ok_or_else(|| format!("No existe la línea base {}", name))?;
+    let now = current(app, &state, name);
+
+    let names: HashSet<&String> = baseline.apps.keys().chain(now.apps.keys()).collect();
+    let mut changes: Vec<AppChange> = names
+        .into_iter()
+        .filter_map(|app| {
+            let (before, after) = (baseline.apps.get(app).copied(), now.apps.get(app).copied());
+            let kind = match (before, after) {
+                (None, _) => ChangeKind::Added,
+                (_, None) => ChangeKind::Removed,
+                (Some(before), Some(after)) if before == after => return None,
+                _ => ChangeKind::Changed,
+            };
+            Some(AppChange {
+                name: app.clone(),
+                kind,
+                delta: delta(&before.unwrap_or_default(), &after.unwrap_or_default()),
+                before,
+                after,
+            })
+        })
+        .collect();
+    changes.sort_by_key(|change| std::cmp::Reverse(change.delta.memory_usage.unsigned_abs()));
+
+    Ok(BaselineComparison {
+        baseline: baseline.name,
+        captured_at: baseline.captured_at,
+        before: baseline.system,
+        after: now.system,
+        cpu_usage_delta: now.system.cpu_usage as f64 - baseline.system.cpu_usage as f64,
+        used_memory_delta: now.system.used_memory as i64 - baseline.system.used_memory as i64,
+        process_count_delta: now.system.process_count as i64 - baseline.system.process_count as i64,
+        changes,
+    })
+}
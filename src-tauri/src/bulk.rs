@@ -0,0 +1,151 @@
+// src-tauri/src/bulk.rs
+// Operaciones sobre una selección múltiple en una sola llamada IPC: la misma
+// acción se aplica a cada PID en paralelo (con un número limitado de hilos) y
+// se devuelve un informe con el resultado de cada uno. Cada proceso pasa por
+// actions::run_once como desde su menú, así que queda en la auditoría y no se
+// repite si ya hay una ejecución idéntica en curso
+
+use serde::{Serialize, Deserialize};
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::Instant;
+use tauri::State;
+
+use crate::cores::AffinityPreset;
+use crate::priority::PriorityClass;
+use crate::AppState;
+
+const WORKERS: usize = 8;
+const MAX_PIDS: usize = 1000;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkAction {
+    Suspend,
+    Resume,
+    SetPriority,       // Requiere `priority`
+    SetAffinity,       // Requiere `affinity`
+    SetEfficiencyMode, // Requiere `enabled`
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct BulkParams {
+    pub priority: Option<PriorityClass>,
+    pub affinity: Option<AffinityPreset>,
+    pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct BulkResult {
+    pub pid: u32,
+    pub name: String,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct BulkReport {
+    pub action: BulkAction,
+    pub succeeded: usize,
+    pub failed: usize,
+    pub results: Vec<BulkResult>, // En el orden de `pids`
+    pub duration_ms: u64,
+}
+
+// Operación sobre un PID ya validada, con la clave de run_once de su comando
+enum Operation {
+    Suspend(bool),
+    Priority(PriorityClass),
+    Affinity(AffinityPreset),
+    EfficiencyMode(bool),
+}
+
+impl Operation {
+    fn from_request(action: BulkAction, params: &BulkParams) -> Result<Self, String> {
+        let missing = |param: &str| format!("La acción {:?} requiere el parámetro {}", action, param);
+        Ok(match action {
+            BulkAction::Suspend => Operation::Suspend(true),
+            BulkAction::Resume => Operation::Suspend(false),
+            BulkAction::SetPriority => Operation::Priority(params.priority.ok_or_else(|| missing("priority"))?),
+            BulkAction::SetAffinity => Operation::Affinity(params.affinity.ok_or_else(|| missing("affinity"))?),
+            BulkAction::SetEfficiencyMode => {
+                Operation::EfficiencyMode(params.enabled.ok_or_else(|| missing("enabled"))?)
+            }
+        })
+    }
+
+    // Misma clave y mismo tipo de resultado que el comando de un solo proceso,
+    // para que una repetición de cualquiera de los dos reciba el resultado
+    fn run(&self, state: &AppState, pid: u32) -> Result<(), String> {
+        use crate::actions::run_once;
+
+        match self {
+            Operation::Suspend(suspended) => {
+                let key = if *suspended { "suspend:false" } else { "resume:false" };
+                run_once(state, pid, key, || {
+                    crate::suspend::set_suspended(state, pid, *suspended).map(|()| vec![pid])
+                })
+                .map(|_| ())
+            }
+            Operation::Priority(priority) => {
+                run_once(state, pid, &format!("priority:{:?}", priority), || crate::priority::apply(pid, *priority))
+            }
+            Operation::Affinity(preset) => {
+                run_once(state, pid, &format!("affinity:{:?}", preset), || crate::cores::apply_affinity(pid, *preset))
+                    .map(|_| ())
+            }
+            Operation::EfficiencyMode(enabled) => run_once(state, pid, &format!("efficiency_mode:{}", enabled), || {
+                crate::priority::apply_efficiency_mode(pid, *enabled)
+            }),
+        }
+    }
+}
+
+#[tauri::command]
+pub fn bulk_action(
+    state: State<AppState>,
+    action: BulkAction,
+    pids: Vec<u32>,
+    params: Option<BulkParams>,
+) -> Result<BulkReport, String> {
+    if pids.len() > MAX_PIDS {
+        return Err(format!("Demasiados procesos en una sola operación (máximo {})", MAX_PIDS));
+    }
+    let operation = Operation::from_request(action, &params.unwrap_or_default())?;
+    let started = Instant::now();
+    let mut pids = pids;
+    let mut seen = HashSet::new();
+    pids.retain(|pid| seen.insert(*pid));
+
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<BulkResult>>> = Mutex::new(vec![None; pids.len()]);
+    std::thread::scope(|scope| {
+        for _ in 0..WORKERS.min(pids.len()) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(&pid) = pids.get(index) else { break };
+                let name = crate::audit::process_name(&state, pid);
+                // "Seleccionar todo" incluye el propio administrador y el proceso inactivo
+                let result = if pid == 0 || pid == std::process::id() {
+                    Err("No se aplica al propio administrador ni al proceso inactivo del sistema".to_string())
+                } else {
+                    operation.run(&state, pid)
+                };
+                results.lock().unwrap()[index] =
+                    Some(BulkResult { pid, name, success: result.is_ok(), error: result.err() });
+            });
+        }
+    });
+
+    let results: Vec<BulkResult> = results.into_inner().unwrap().into_iter().flatten().collect();
+    let succeeded = results.iter().filter(|result| result.success).count();
+    Ok(BulkReport {
+        action,
+        succeeded,
+        failed: results.len() - succeeded,
+        results,
+        duration_ms: started.elapsed().as_millis() as u64,
+    })
+}
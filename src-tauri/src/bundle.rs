@@ -0,0 +1,240 @@
+// src-tauri/src/bundle.rs
+// Paquete de diagnóstico: un único .zip con la instantánea de procesos, los
+// registros recientes (acciones, reglas, servicios reiniciados, sesiones), el
+// informe de capacidades y la información del sistema, para adjuntarlo a un
+// informe de errores o enviarlo a soporte técnico. La configuración no se
+// incluye: puede contener rutas y scripts del usuario
+//
+// Las entradas se comprimen con Deflate (flate2) en un zip sencillo escrito
+// aquí, sin zip64 ni cifrado, que se abre en cualquier sistema sin
+// herramientas adicionales. Si la tarea falla o se cancela se borra el archivo
+// a medio escribir
+
+use flate2::write::DeflateEncoder;
+use flate2::{Compression, Crc};
+use serde::Serialize;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use sysinfo::{CpuExt, SystemExt};
+use tauri::{AppHandle, Manager};
+
+use crate::jobs::JobReporter;
+use crate::AppState;
+
+const BUNDLE_VERSION: u32 = 1;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct BundleSystemInfo {
+    pub bundle_version: u32,
+    pub created_at: u64,
+    pub app_version: String,
+    pub host_name: Option<String>,
+    pub os_version: Option<String>,
+    pub kernel_version: Option<String>,
+    pub cpu_brand: String,
+    pub cpu_count: usize,
+    pub total_memory: u64, // Bytes
+    pub uptime_secs: u64,
+    pub boot_time: u64, // Segundos desde UNIX_EPOCH
+}
+
+fn system_info(app: &AppHandle, state: &AppState) -> BundleSystemInfo {
+    let system = state.system.read().unwrap();
+    BundleSystemInfo {
+        bundle_version: BUNDLE_VERSION,
+        created_at: crate::stats::now_millis(),
+        app_version: app.package_info().version.to_string(),
+        host_name: system.host_name(),
+        os_version: system.long_os_version(),
+        kernel_version: system.kernel_version(),
+        cpu_brand: system.global_cpu_info().brand().trim().to_string(),
+        cpu_count: system.cpus().len(),
+        total_memory: system.total_memory(),
+        uptime_secs: system.uptime(),
+        boot_time: system.boot_time(),
+    }
+}
+
+// Entrada ya escrita, para el directorio central
+struct ZipEntry {
+    name: String,
+    crc: u32,
+    compressed_size: u32,
+    size: u32,
+    offset: u32, // De su cabecera local
+}
+
+struct ZipArchive {
+    file: BufWriter<File>,
+    entries: Vec<ZipEntry>,
+    offset: u64,
+    modified: (u16, u16), // Hora y fecha MS-DOS, las mismas para todas las entradas
+}
+
+fn zip_size(value: u64) -> Result<u32, String> {
+    u32::try_from(value).map_err(|_| "El paquete supera los 4 GB que admite el formato zip".to_string())
+}
+
+impl ZipArchive {
+    fn create(path: &str) -> Result<Self, String> {
+        let file = File::create(path).map_err(|e| format!("No se pudo crear {}: {}", path, e))?;
+        let now = crate::locale::local_time(crate::stats::now_millis());
+        let time = (now.hour << 11) | (now.minute << 5) | (now.second / 2);
+        let date = (((now.year - 1980).max(0) as u32) << 9) | (now.month << 5) | now.day;
+        let modified = (time as u16, date as u16);
+        Ok(ZipArchive { file: BufWriter::new(file), entries: Vec::new(), offset: 0, modified })
+    }
+
+    fn write(&mut self, bytes: &[u8]) -> std::io::Result<()> {
+        self.file.write_all(bytes)?;
+        self.offset += bytes.len() as u64;
+        Ok(())
+    }
+
+    // Versión necesaria (2.0), nombre en UTF-8, Deflate, fecha, CRC, tamaños y
+    // longitud del nombre: lo que comparten la cabecera local y la central
+    fn common_header(&self, entry: &ZipEntry) -> Vec<u8> {
+        let mut header = Vec::with_capacity(26);
+        header.extend(20u16.to_le_bytes());
+        header.extend(0x0800u16.to_le_bytes());
+        header.extend(8u16.to_le_bytes());
+        header.extend(self.modified.0.to_le_bytes());
+        header.extend(self.modified.1.to_le_bytes());
+        header.extend(entry.crc.to_le_bytes());
+        header.extend(entry.compressed_size.to_le_bytes());
+        header.extend(entry.size.to_le_bytes());
+        header.extend((entry.name.len() as u16).to_le_bytes());
+        header
+    }
+
+    fn add(&mut self, name: &str, content: &[u8]) -> Result<(), String> {
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+        let compressed = encoder
+            .write_all(content)
+            .and_then(|_| encoder.finish())
+            .map_err(|e| format!("No se pudo comprimir {}: {}", name, e))?;
+        let mut crc = Crc::new();
+        crc.update(content);
+        let entry = ZipEntry {
+            name: name.to_string(),
+            crc: crc.sum(),
+            compressed_size: zip_size(compressed.len() as u64)?,
+            size: zip_size(content.len() as u64)?,
+            offset: zip_size(self.offset)?,
+        };
+
+        let mut header = 0x04034b50u32.to_le_bytes().to_vec();
+        header.extend(self.common_header(&entry));
+        header.extend(0u16.to_le_bytes()); // Sin campos extra
+        header.extend(name.as_bytes());
+        self.write(&header)
+            .and_then(|_| self.write(&compressed))
+            .map_err(|e| format!("No se pudo escribir {} en el paquete: {}", name, e))?;
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    // Directorio central y registro de fin
+    fn finish(mut self) -> Result<(), String> {
+        let start = zip_size(self.offset)?;
+        let mut directory = Vec::new();
+        for entry in &self.entries {
+            directory.extend(0x02014b50u32.to_le_bytes());
+            directory.extend(20u16.to_le_bytes()); // Creado con la versión 2.0
+            directory.extend(self.common_header(entry));
+            directory.extend([0u8; 12]); // Extra, comentario, disco y atributos
+            directory.extend(entry.offset.to_le_bytes());
+            directory.extend(entry.name.as_bytes());
+        }
+        let count = u16::try_from(self.entries.len()).map_err(|_| "Demasiadas entradas para el paquete".to_string())?;
+        let mut end = 0x06054b50u32.to_le_bytes().to_vec();
+        end.extend([0u8; 4]); // Un único disco
+        end.extend(count.to_le_bytes());
+        end.extend(count.to_le_bytes());
+        end.extend(zip_size(directory.len() as u64)?.to_le_bytes());
+        end.extend(start.to_le_bytes());
+        end.extend(0u16.to_le_bytes()); // Sin comentario
+        self.write(&directory)
+            .and_then(|_| self.write(&end))
+            .and_then(|_| self.file.flush())
+            .map_err(|e| format!("No se pudo guardar el paquete: {}", e))
+    }
+}
+
+struct Bundle {
+    archive: ZipArchive,
+    errors: Vec<String>, // Entradas que no se pudieron generar; van en errors.txt
+}
+
+impl Bundle {
+    fn add(&mut self, name: &str, content: &[u8]) -> Result<(), String> {
+        self.archive.add(name, content)
+    }
+
+    fn add_json<T: Serialize>(&mut self, name: &str, value: &T) -> Result<(), String> {
+        let content = serde_json::to_vec_pretty(value).map_err(|e| format!("Error al serializar {}: {}", name, e))?;
+        self.add(name, &content)
+    }
+
+    // Una fuente que falla no impide generar el resto del paquete
+    fn add_result<T: Serialize>(&mut self, name: &str, value: Result<T, String>) -> Result<(), String> {
+        match value {
+            Ok(value) => self.add_json(name, &value),
+            Err(e) => {
+                self.errors.push(format!("{}: {}", name, e));
+                Ok(())
+            }
+        }
+    }
+}
+
+fn write_bundle(app: &AppHandle, job: &JobReporter, path: &str) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let mut bundle = Bundle { archive: ZipArchive::create(path)?, errors: Vec::new() };
+
+    job.progress(0.1, "Información del sistema");
+    bundle.add_json("system.json", &system_info(app, &state))?;
+    bundle.add("summary.txt", crate::summary::describe(&state).as_bytes())?;
+    bundle.add_json("capabilities.json", &crate::platform::get_capabilities(app.state()))?;
+    bundle.add_json("platform.json", &crate::platform::get_platform_capabilities())?;
+    bundle.add_json("self_metrics.json", &crate::selfmon::get_self_metrics(app.state()))?;
+    job.check_cancelled()?;
+
+    job.progress(0.3, "Instantánea de procesos");
+    let tree = state.processes.read().unwrap().clone();
+    bundle.add_json("snapshot.json", &tree)?;
+    bundle.add_result("services.json", crate::services::enumerate_services())?;
+    bundle.add_result("security.json", crate::defender::get_security_status(app.state()))?;
+    job.check_cancelled()?;
+
+    job.progress(0.6, "Registros recientes");
+    bundle.add_json("logs/actions.json", &crate::audit::get_action_log(app.state(), None, None))?;
+    bundle.add_json("logs/alerts.json", &crate::alertlog::get_alerts(app.state(), None))?;
+    bundle.add_json("logs/exit_codes.json", &crate::exitcodes::get_exit_codes(app.state(), None))?;
+    bundle.add_json("logs/rules.json", &crate::rules::get_rule_log(app.state()))?;
+    bundle.add_json("logs/service_restarts.json", &crate::watchdog::get_service_restart_log(app.state()))?;
+    bundle.add_json("logs/degraded_collectors.json", &state.collector_health.lock().unwrap().degraded())?;
+    bundle.add_result("logs/sessions.json", crate::session::get_session_summaries(app.clone(), None))?;
+    job.check_cancelled()?;
+
+    job.progress(0.9, "Guardando el paquete");
+    if !bundle.errors.is_empty() {
+        let errors = bundle.errors.join("\n");
+        bundle.add("errors.txt", errors.as_bytes())?;
+    }
+    bundle.archive.finish()
+}
+
+// Tarea en segundo plano como generate_report; el resultado ("job-finished")
+// es la ruta del paquete
+#[tauri::command]
+pub fn create_support_bundle(app: AppHandle, path: String) -> u64 {
+    crate::jobs::spawn(&app.clone(), "support_bundle", move |job| {
+        // El archivo ya está cerrado cuando write_bundle devuelve el error
+        if let Err(e) = write_bundle(&app, job, &path) {
+            let _ = fs::remove_file(&path);
+            return Err(e);
+        }
+        Ok(path)
+    })
+}
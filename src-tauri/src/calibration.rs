@@ -0,0 +1,232 @@
+// src-tauri/src/calibration.rs
+// Calibración inicial: en el primer arranque se observan durante 10 minutos la
+// CPU y la memoria de cada ejecutable (todas sus instancias sumadas) para
+// saber qué es lo habitual en este equipo. El resultado se guarda en
+// calibration.json, sirve de punto de partida a las líneas base del detector
+// de anomalías (que si no tardan días en formarse) y marca los procesos que
+// usan bastante más de lo habitual. start_calibration la repite
+//
+// Las muestras salen del refresco de procesos, así que los 10 minutos son de
+// datos reales: con la ventana oculta y sin muestreo la calibración espera
+
+use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, State};
+
+use crate::alerts::AlertMetric;
+use crate::{AppState, ProcessInfo};
+
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(10);
+const SAMPLES: u32 = 60; // 10 minutos de muestras
+const MIN_APP_SAMPLES: u32 = 6; // Un minuto en ejecución como mínimo
+const USUAL_SIGMA: f64 = 2.0; // Desviaciones sobre la media para "más de lo habitual"
+
+// Media y varianza de Welford del total de un ejecutable
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default)]
+pub struct UsualValue {
+    pub mean: f64,
+    pub variance: f64,
+}
+
+impl UsualValue {
+    fn update(&mut self, count: u32, value: f64) {
+        let delta = value - self.mean;
+        self.mean += delta / count as f64;
+        self.variance += (delta * (value - self.mean) - self.variance) / count as f64;
+    }
+
+    // Umbral de la insignia, con la desviación mínima del detector
+    fn threshold(&self, metric: AlertMetric) -> f64 {
+        self.mean + USUAL_SIGMA * self.variance.sqrt().max(crate::anomaly::min_std_dev(metric))
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default)]
+pub struct UsualMetrics {
+    pub samples: u32, // Muestras en las que se estaba ejecutando
+    pub cpu_usage: UsualValue,
+    pub memory_usage: UsualValue, // Bytes
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct CalibrationProfile {
+    pub started_at: u64,
+    pub completed_at: u64,
+    pub samples: u32,
+    pub apps: BTreeMap<String, UsualMetrics>, // Ejecutable en minúsculas
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct CalibrationStatus {
+    pub running: bool,
+    pub started_at: Option<u64>, // De la calibración en curso
+    pub progress: f32,           // 0.0 - 1.0
+    pub completed_at: Option<u64>, // De la última completada; None si nunca se calibró
+    pub app_count: usize,
+}
+
+// Ejecutable en minúsculas -> (CPU, memoria) de todas sus instancias
+fn totals(processes: &[ProcessInfo]) -> HashMap<String, (f64, f64)> {
+    let mut totals: HashMap<String, (f64, f64)> = HashMap::new();
+    for process in processes {
+        let total = totals.entry(process.name.to_lowercase()).or_default();
+        total.0 += process.cpu_usage as f64;
+        total.1 += process.memory_usage as f64;
+    }
+    totals
+}
+
+#[derive(Debug)]
+struct Run {
+    started_at: u64,
+    samples: u32,
+    apps: HashMap<String, UsualMetrics>,
+    last_sample: Option<Instant>,
+}
+
+#[derive(Debug, Default)]
+pub struct Calibration {
+    profile: Option<CalibrationProfile>,
+    run: Option<Run>,
+    path: Option<PathBuf>,
+}
+
+impl Calibration {
+    // Sin archivo (primer arranque, o se cerró a medias) empieza a calibrar
+    pub fn load(path: &Path) -> Self {
+        let profile: Option<CalibrationProfile> =
+            fs::read_to_string(path).ok().and_then(|content| serde_json::from_str(&content).ok());
+        let mut calibration = Calibration { profile, run: None, path: Some(path.to_path_buf()) };
+        if calibration.profile.is_none() {
+            calibration.start();
+        }
+        calibration
+    }
+
+    fn save(&self) -> Result<(), String> {
+        let (Some(path), Some(profile)) = (&self.path, &self.profile) else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("No se pudo crear {}: {}", dir.display(), e))?;
+        }
+        let content =
+            serde_json::to_string(profile).map_err(|e| format!("Error al serializar la calibración: {}", e))?;
+        fs::write(path, content).map_err(|e| format!("No se pudo guardar {}: {}", path.display(), e))
+    }
+
+    fn start(&mut self) {
+        self.run = Some(Run {
+            started_at: crate::stats::now_millis(),
+            samples: 0,
+            apps: HashMap::new(),
+            last_sample: None,
+        });
+    }
+
+    pub fn status(&self) -> CalibrationStatus {
+        CalibrationStatus {
+            running: self.run.is_some(),
+            started_at: self.run.as_ref().map(|run| run.started_at),
+            progress: self.run.as_ref().map_or(1.0, |run| run.samples as f32 / SAMPLES as f32),
+            completed_at: self.profile.as_ref().map(|profile| profile.completed_at),
+            app_count: self.profile.as_ref().map_or(0, |profile| profile.apps.len()),
+        }
+    }
+
+    // Anota una muestra de la calibración en curso; devuelve el perfil cuando
+    // se completa. Solo con datos en vivo, no de una grabación ni simulados
+    pub fn sample(&mut self, processes: &[ProcessInfo], live: bool) -> Option<CalibrationProfile> {
+        let run = self.run.as_mut().filter(|_| live)?;
+        if run.last_sample.is_some_and(|at| at.elapsed() < SAMPLE_INTERVAL) {
+            return None;
+        }
+        run.last_sample = Some(Instant::now());
+        run.samples += 1;
+
+        for (name, (cpu_usage, memory_usage)) in totals(processes) {
+            let app = run.apps.entry(name).or_default();
+            app.samples += 1;
+            app.cpu_usage.update(app.samples, cpu_usage);
+            app.memory_usage.update(app.samples, memory_usage);
+        }
+        if run.samples < SAMPLES {
+            return None;
+        }
+
+        let run = self.run.take()?;
+        let profile = CalibrationProfile {
+            started_at: run.started_at,
+            completed_at: crate::stats::now_millis(),
+            samples: run.samples,
+            apps: run.apps.into_iter().filter(|(_, app)| app.samples >= MIN_APP_SAMPLES).collect(),
+        };
+        self.profile = Some(profile.clone());
+        let _ = self.save();
+        Some(profile)
+    }
+
+    // Métricas en las que el ejecutable de cada proceso supera lo habitual
+    pub fn annotate(&self, processes: &mut [ProcessInfo]) {
+        let Some(profile) = &self.profile else {
+            return;
+        };
+        let totals = totals(processes);
+        for process in processes {
+            let name = process.name.to_lowercase();
+            let (Some(usual), Some(&(cpu_usage, memory_usage))) = (profile.apps.get(&name), totals.get(&name)) else {
+                continue;
+            };
+            if cpu_usage > usual.cpu_usage.threshold(AlertMetric::CpuUsage) {
+                process.above_usual.push(AlertMetric::CpuUsage);
+            }
+            if memory_usage > usual.memory_usage.threshold(AlertMetric::MemoryUsage) {
+                process.above_usual.push(AlertMetric::MemoryUsage);
+            }
+        }
+    }
+}
+
+// Lo llama el refresco de procesos: avanza la calibración y, al terminar,
+// siembra las líneas base del detector y emite "calibration-finished"
+pub fn record(app: &AppHandle, state: &AppState, processes: &[ProcessInfo], live: bool) {
+    let finished = state.calibration.lock().unwrap().sample(processes, live);
+    if let Some(profile) = finished {
+        let mut anomalies = state.anomalies.lock().unwrap();
+        for (name, usual) in &profile.apps {
+            anomalies.seed(name, AlertMetric::CpuUsage, usual.cpu_usage.mean, usual.cpu_usage.variance);
+            anomalies.seed(name, AlertMetric::MemoryUsage, usual.memory_usage.mean, usual.memory_usage.variance);
+        }
+        let _ = anomalies.save();
+        drop(anomalies);
+        let _ = app.emit("calibration-finished", state.calibration.lock().unwrap().status());
+    }
+}
+
+#[tauri::command]
+pub fn get_calibration(state: State<AppState>) -> CalibrationStatus {
+    state.calibration.lock().unwrap().status()
+}
+
+// Vuelve a calibrar; el perfil anterior se sigue usando hasta que termine
+#[tauri::command]
+pub fn start_calibration(state: State<AppState>) -> Result<CalibrationStatus, String> {
+    let mut calibration = state.calibration.lock().unwrap();
+    if calibration.run.is_some() {
+        return Err("Ya hay una calibración en curso".to_string());
+    }
+    calibration.start();
+    Ok(calibration.status())
+}
+
+#[tauri::command]
+pub fn cancel_calibration(state: State<AppState>) -> Result<(), String> {
+    let mut calibration = state.calibration.lock().unwrap();
+    if calibration.run.take().is_none() {
+        return Err("No hay ninguna calibración en curso".to_string());
+    }
+    Ok(())
+}
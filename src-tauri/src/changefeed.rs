@@ -0,0 +1,200 @@
+// src-tauri/src/changefeed.rs
+// Flujo de cambios para clientes ligeros (CLI, paneles remotos): en cada
+// refresco se compara el árbol con el anterior y se anotan los procesos que
+// arrancan o terminan y los cambios significativos de CPU, memoria o estado.
+// Cada cambio lleva un número correlativo; el cliente pide lo posterior al
+// cursor que recibió en vez de descargar la instantánea completa. El cursor
+// ("sesión:secuencia", con el formato de paging) incluye el arranque de la
+// aplicación, para que uno de una sesión anterior no se confunda con uno de
+// esta al volver a contar desde cero.
+//
+// Un cambio de métrica se mide respecto al último valor anunciado, no al del
+// refresco anterior, para que una subida lenta también acabe apareciendo
+
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use tauri::State;
+
+use crate::paging::{clamp_limit, parse_cursor};
+use crate::status::ProcessStatus;
+use crate::{AppState, ProcessInfo};
+
+const CAPACITY: usize = 10_000;
+const CPU_CHANGE: f32 = 10.0; // Puntos porcentuales
+const MEMORY_CHANGE_RATIO: f64 = 0.2;
+const MEMORY_CHANGE_MIN: u64 = 16 * 1024 * 1024;
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ChangeKind {
+    Started { parent_pid: Option<u32>, cpu_usage: f32, memory_usage: u64 },
+    Stopped,
+    Cpu { previous: f32, current: f32 },
+    Memory { previous: u64, current: u64 }, // Bytes
+    Status { previous: ProcessStatus, current: ProcessStatus },
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ProcessChange {
+    pub sequence: u64, // Correlativo dentro de la sesión
+    pub timestamp: u64,
+    pub pid: u32,
+    pub name: String,
+    #[serde(flatten)]
+    pub change: ChangeKind,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ChangePage {
+    pub changes: Vec<ProcessChange>,
+    pub cursor: String, // Para la siguiente llamada
+    pub more: bool,  // Quedan cambios por encima del límite
+    pub gap: bool,   // Faltan cambios desde since_cursor: recargar la instantánea y seguir desde `cursor`
+}
+
+// Último valor anunciado de cada proceso
+#[derive(Debug, Clone)]
+struct Reported {
+    name: String,
+    cpu_usage: f32,
+    memory_usage: u64,
+    status: ProcessStatus,
+}
+
+impl Reported {
+    fn of(process: &ProcessInfo) -> Self {
+        Reported {
+            name: process.name.clone(),
+            cpu_usage: process.cpu_usage,
+            memory_usage: process.memory_usage,
+            status: process.status,
+        }
+    }
+}
+
+fn memory_changed(previous: u64, current: u64) -> bool {
+    let difference = previous.abs_diff(current);
+    difference >= MEMORY_CHANGE_MIN && difference as f64 >= previous as f64 * MEMORY_CHANGE_RATIO
+}
+
+#[derive(Debug)]
+pub struct ChangeFeed {
+    session: u64, // Arranque de la aplicación, en milisegundos
+    changes: VecDeque<ProcessChange>,
+    last_sequence: u64,
+    reported: Option<HashMap<u32, Reported>>, // None hasta el primer refresco
+}
+
+impl Default for ChangeFeed {
+    fn default() -> Self {
+        ChangeFeed {
+            session: crate::stats::now_millis(),
+            changes: VecDeque::new(),
+            last_sequence: 0,
+            reported: None,
+        }
+    }
+}
+
+impl ChangeFeed {
+    fn cursor(&self, sequence: u64) -> String {
+        format!("{}:{}", self.session, sequence)
+    }
+
+    fn push(&mut self, timestamp: u64, pid: u32, name: &str, change: ChangeKind) {
+        self.last_sequence += 1;
+        if self.changes.len() == CAPACITY {
+            self.changes.pop_front();
+        }
+        let name = name.to_string();
+        self.changes.push_back(ProcessChange { sequence: self.last_sequence, timestamp, pid, name, change });
+    }
+
+    // El primer refresco solo fija la base: sus procesos ya están en la
+    // instantánea con la que empieza el cliente
+    pub fn record(&mut self, processes: &[ProcessInfo]) {
+        let Some(mut previous) = self.reported.take() else {
+            self.reported = Some(processes.iter().map(|process| (process.pid, Reported::of(process))).collect());
+            return;
+        };
+        let timestamp = crate::stats::now_millis();
+        let mut reported = HashMap::with_capacity(processes.len());
+        for process in processes {
+            let (pid, name) = (process.pid, process.name.as_str());
+            let mut before = previous.remove(&pid);
+            // Mismo PID con otro ejecutable: el anterior terminó
+            if let Some(reused) = before.take_if(|before| before.name != process.name) {
+                self.push(timestamp, pid, &reused.name, ChangeKind::Stopped);
+            }
+            let Some(mut before) = before else {
+                let change = ChangeKind::Started {
+                    parent_pid: process.parent_pid,
+                    cpu_usage: process.cpu_usage,
+                    memory_usage: process.memory_usage,
+                };
+                self.push(timestamp, pid, name, change);
+                reported.insert(pid, Reported::of(process));
+                continue;
+            };
+            if (process.cpu_usage - before.cpu_usage).abs() >= CPU_CHANGE {
+                let change = ChangeKind::Cpu { previous: before.cpu_usage, current: process.cpu_usage };
+                self.push(timestamp, pid, name, change);
+                before.cpu_usage = process.cpu_usage;
+            }
+            if memory_changed(before.memory_usage, process.memory_usage) {
+                let change = ChangeKind::Memory { previous: before.memory_usage, current: process.memory_usage };
+                self.push(timestamp, pid, name, change);
+                before.memory_usage = process.memory_usage;
+            }
+            if before.status != process.status {
+                let change = ChangeKind::Status { previous: before.status, current: process.status };
+                self.push(timestamp, pid, name, change);
+                before.status = process.status;
+            }
+            reported.insert(pid, before);
+        }
+        let mut stopped: Vec<(u32, Reported)> = previous.into_iter().collect();
+        stopped.sort_by_key(|(pid, _)| *pid);
+        for (pid, before) in stopped {
+            self.push(timestamp, pid, &before.name, ChangeKind::Stopped);
+        }
+        self.reported = Some(reported);
+    }
+
+    fn current(&self) -> ChangePage {
+        ChangePage { changes: Vec::new(), cursor: self.cursor(self.last_sequence), more: false, gap: false }
+    }
+
+    fn since(&self, since_cursor: &str, limit: usize) -> Result<ChangePage, String> {
+        let (session, since) = parse_cursor(since_cursor)?;
+        let since = since as u64;
+        // Otra sesión, o el primer cambio conservado no es el siguiente al del
+        // cliente
+        let gap = session != self.session
+            || since > self.last_sequence
+            || self.changes.front().is_some_and(|first| first.sequence > since + 1);
+        if gap {
+            return Ok(ChangePage { gap, ..self.current() });
+        }
+        let mut pending = self.changes.iter().filter(|change| change.sequence > since);
+        let changes: Vec<ProcessChange> = pending.by_ref().take(limit).cloned().collect();
+        let more = pending.next().is_some();
+        let cursor = self.cursor(changes.last().map_or(since, |change| change.sequence));
+        Ok(ChangePage { changes, cursor, more, gap })
+    }
+}
+
+// `since_cursor` es el cursor de la respuesta anterior; sin él se devuelve el
+// cursor actual sin cambios, para empezar a seguir el flujo desde ahora
+#[tauri::command]
+pub fn get_changes(
+    state: State<AppState>,
+    since_cursor: Option<String>,
+    limit: Option<usize>,
+) -> Result<ChangePage, String> {
+    let feed = state.change_feed.lock().unwrap();
+    match since_cursor.filter(|cursor| !cursor.is_empty()) {
+        Some(since_cursor) => feed.since(&since_cursor, clamp_limit(limit)),
+        None => Ok(feed.current()),
+    }
+}
@@ -0,0 +1,216 @@
+// src-tauri/src/cleanup.rs
+// Limpieza de archivos prescindibles (temporales del usuario, volcados de
+// error antiguos y caché de miniaturas), con una estimación previa del espacio
+// que se liberaría. Solo se borran archivos que cumplen la antigüedad mínima;
+// los que están en uso o sin permiso se saltan
+
+use serde::{Serialize, Deserialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use tauri::{AppHandle, Manager};
+
+use crate::audit::AuditOrigin;
+use crate::AppState;
+
+const DAY: Duration = Duration::from_secs(86_400);
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CleanupTarget {
+    Temp,           // %TEMP% del usuario, más de un día sin modificarse
+    CrashDumps,     // Volcados y informes de WER de más de una semana
+    ThumbnailCache, // thumbcache_*.db; el Explorador los vuelve a generar
+}
+
+const TARGETS: [CleanupTarget; 3] = [CleanupTarget::Temp, CleanupTarget::CrashDumps, CleanupTarget::ThumbnailCache];
+
+#[derive(Debug, Serialize, Clone)]
+pub struct CleanupEstimate {
+    pub target: CleanupTarget,
+    pub locations: Vec<String>,
+    pub size: u64,
+    pub file_count: u64,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct CleanupResult {
+    pub target: CleanupTarget,
+    pub freed_bytes: u64,
+    pub deleted_files: u64,
+    pub skipped_files: u64, // En uso o sin permiso
+}
+
+struct Location {
+    dir: PathBuf,
+    recursive: bool,
+    min_age: Duration,
+    prefix: Option<&'static str>, // Solo archivos cuyo nombre empieza así
+    extension: Option<&'static str>,
+}
+
+fn env_dir(name: &str) -> Option<PathBuf> {
+    std::env::var_os(name).map(PathBuf::from).filter(|dir| dir.is_dir())
+}
+
+fn locations(target: CleanupTarget) -> Vec<Location> {
+    let location = |dir: PathBuf, recursive: bool, min_age: Duration| Location {
+        dir,
+        recursive,
+        min_age,
+        prefix: None,
+        extension: None,
+    };
+    match target {
+        CleanupTarget::Temp => vec![location(std::env::temp_dir(), true, DAY)],
+        CleanupTarget::CrashDumps => {
+            let mut dirs = Vec::new();
+            if let Some(local) = env_dir("LOCALAPPDATA") {
+                dirs.push(Location { extension: Some("dmp"), ..location(local.join("CrashDumps"), false, 7 * DAY) });
+                dirs.push(location(local.join("Microsoft\\Windows\\WER\\ReportArchive"), true, 7 * DAY));
+                dirs.push(location(local.join("Microsoft\\Windows\\WER\\ReportQueue"), true, 7 * DAY));
+            }
+            // Los de todo el equipo solo se pueden borrar con permisos de administrador
+            if let Some(program_data) = env_dir("ProgramData") {
+                dirs.push(location(program_data.join("Microsoft\\Windows\\WER\\ReportArchive"), true, 7 * DAY));
+                dirs.push(location(program_data.join("Microsoft\\Windows\\WER\\ReportQueue"), true, 7 * DAY));
+            }
+            dirs
+        }
+        CleanupTarget::ThumbnailCache => env_dir("LOCALAPPDATA")
+            .map(|local| Location {
+                prefix: Some("thumbcache_"),
+                extension: Some("db"),
+                ..location(local.join("Microsoft\\Windows\\Explorer"), false, Duration::ZERO)
+            })
+            .into_iter()
+            .collect(),
+    }
+    .into_iter()
+    .filter(|location| location.dir.is_dir())
+    .collect()
+}
+
+// Recorre los archivos que cumplen los criterios sin seguir enlaces ni uniones
+// Se detiene en cuanto `stop` devuelve true
+fn visit(location: &Location, dir: &Path, stop: &dyn Fn() -> bool, found: &mut dyn FnMut(&Path, u64)) {
+    let Ok(entries) = fs::read_dir(dir) else { return };
+    let now = SystemTime::now();
+    for entry in entries.flatten() {
+        if stop() {
+            return;
+        }
+        let Ok(kind) = entry.file_type() else { continue };
+        let path = entry.path();
+        if kind.is_dir() {
+            if location.recursive {
+                visit(location, &path, stop, found);
+            }
+            continue;
+        }
+        if !kind.is_file() {
+            continue;
+        }
+        let name = entry.file_name().to_string_lossy().to_lowercase();
+        if location.prefix.is_some_and(|prefix| !name.starts_with(prefix)) {
+            continue;
+        }
+        if location.extension.is_some_and(|extension| !name.ends_with(&format!(".{}", extension))) {
+            continue;
+        }
+        let Ok(metadata) = entry.metadata() else { continue };
+        let modified = metadata.modified().unwrap_or(now);
+        if now.duration_since(modified).unwrap_or_default() >= location.min_age {
+            found(&path, metadata.len());
+        }
+    }
+}
+
+// Quita las subcarpetas vacías con la antigüedad mínima (una recién creada
+// puede estar a punto de usarse), nunca la raíz
+fn remove_empty_dirs(dir: &Path, min_age: Duration) {
+    let Ok(entries) = fs::read_dir(dir) else { return };
+    for entry in entries.flatten() {
+        if entry.file_type().is_ok_and(|kind| kind.is_dir()) {
+            remove_empty_dirs(&entry.path(), min_age);
+            let old_enough = entry
+                .metadata()
+                .and_then(|metadata| metadata.modified())
+                .is_ok_and(|modified| modified.elapsed().unwrap_or_default() >= min_age);
+            if old_enough {
+                let _ = fs::remove_dir(entry.path());
+            }
+        }
+    }
+}
+
+fn label(target: CleanupTarget) -> &'static str {
+    match target {
+        CleanupTarget::Temp => "archivos temporales",
+        CleanupTarget::CrashDumps => "volcados de error",
+        CleanupTarget::ThumbnailCache => "caché de miniaturas",
+    }
+}
+
+#[tauri::command]
+pub fn estimate_cleanup() -> Vec<CleanupEstimate> {
+    TARGETS
+        .iter()
+        .map(|&target| {
+            let locations = locations(target);
+            let (mut size, mut file_count) = (0, 0);
+            for location in &locations {
+                visit(location, &location.dir, &|| false, &mut |_, len| {
+                    size += len;
+                    file_count += 1;
+                });
+            }
+            CleanupEstimate {
+                target,
+                locations: locations.iter().map(|location| location.dir.display().to_string()).collect(),
+                size,
+                file_count,
+            }
+        })
+        .collect()
+}
+
+// Tarea "cleanup"; el resultado es un CleanupResult por destino
+#[tauri::command]
+pub fn run_cleanup(app: AppHandle, targets: Vec<CleanupTarget>) -> Result<u64, String> {
+    if targets.is_empty() {
+        return Err("No se indicó qué limpiar".to_string());
+    }
+    let handle = app.clone();
+    Ok(crate::jobs::spawn(&app, "cleanup", move |job| {
+        let mut results = Vec::new();
+        for (index, &target) in targets.iter().enumerate() {
+            job.progress(index as f32 / targets.len() as f32, format!("Limpiando {}", label(target)));
+            let mut result = CleanupResult { target, freed_bytes: 0, deleted_files: 0, skipped_files: 0 };
+            for location in locations(target) {
+                let stop = || job.is_cancelled();
+                visit(&location, &location.dir, &stop, &mut |path, len| match fs::remove_file(path) {
+                    Ok(()) => {
+                        result.freed_bytes += len;
+                        result.deleted_files += 1;
+                    }
+                    Err(_) => result.skipped_files += 1,
+                });
+                if location.recursive && !job.is_cancelled() {
+                    remove_empty_dirs(&location.dir, location.min_age);
+                }
+            }
+            // Lo ya borrado se audita también si se canceló a medias
+            let state = handle.state::<AppState>();
+            let detail = format!("{} archivos, {} bytes", result.deleted_files, result.freed_bytes);
+            let done: Result<(), String> = Ok(());
+            crate::audit::record(&state, AuditOrigin::User, "cleanup", label(target), None, Some(detail), &done);
+            results.push(result);
+            if job.is_cancelled() {
+                let freed: u64 = results.iter().map(|result| result.freed_bytes).sum();
+                return Err(format!("Limpieza cancelada tras liberar {} bytes", freed));
+            }
+        }
+        Ok(results)
+    }))
+}
@@ -0,0 +1,148 @@
+// src-tauri/src/clipboard.rs
+// Monitor opcional del portapapeles: cada vez que cambia se emite
+// "clipboard-changed" con el proceso que lo escribió (GetClipboardOwner). Solo
+// se registra el escucha mientras el ajuste `clipboard_monitor` está activo
+
+use serde::Serialize;
+use tauri::{AppHandle, Manager, State};
+
+use crate::AppState;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ClipboardChange {
+    pub pid: Option<u32>, // None si el contenido no tiene ventana propietaria
+    pub process: Option<String>,
+    pub sequence: u32, // GetClipboardSequenceNumber
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct ClipboardMonitor {
+    #[cfg(target_os = "windows")]
+    listener: Option<crate::win::MessageLoop>, // Hilo de la ventana de mensajes
+    last_change: Option<ClipboardChange>,
+}
+
+#[cfg(target_os = "windows")]
+fn owner_change() -> ClipboardChange {
+    use windows::Win32::System::DataExchange::{GetClipboardOwner, GetClipboardSequenceNumber};
+    use windows::Win32::UI::WindowsAndMessaging::GetWindowThreadProcessId;
+
+    let owner = unsafe { GetClipboardOwner() };
+    let pid = (owner.0 != 0)
+        .then(|| {
+            let mut pid = 0u32;
+            unsafe { GetWindowThreadProcessId(owner, Some(&mut pid)) };
+            pid
+        })
+        .filter(|pid| *pid != 0);
+    ClipboardChange {
+        pid,
+        process: pid.and_then(crate::win::process_image_name),
+        sequence: unsafe { GetClipboardSequenceNumber() },
+        timestamp: crate::stats::now_millis(),
+    }
+}
+
+#[cfg(target_os = "windows")]
+unsafe extern "system" fn listener_proc(
+    hwnd: windows::Win32::Foundation::HWND,
+    msg: u32,
+    wparam: windows::Win32::Foundation::WPARAM,
+    lparam: windows::Win32::Foundation::LPARAM,
+) -> windows::Win32::Foundation::LRESULT {
+    use tauri::Emitter;
+    use windows::Win32::Foundation::LRESULT;
+    use windows::Win32::UI::WindowsAndMessaging::{DefWindowProcW, WM_CLIPBOARDUPDATE};
+
+    if msg != WM_CLIPBOARDUPDATE {
+        return DefWindowProcW(hwnd, msg, wparam, lparam);
+    }
+    if let Some(app) = crate::win::message_loop_app() {
+        let change = owner_change();
+        app.state::<AppState>().clipboard.lock().unwrap().last_change = Some(change.clone());
+        let _ = app.emit("clipboard-changed", change);
+    }
+    LRESULT(0)
+}
+
+// Ventana de solo mensajes en un hilo con su bucle; se destruye al pararlo
+#[cfg(target_os = "windows")]
+fn start_listener(app: &AppHandle) -> Result<crate::win::MessageLoop, String> {
+    use windows::core::PCWSTR;
+    use windows::Win32::System::DataExchange::{AddClipboardFormatListener, RemoveClipboardFormatListener};
+    use windows::Win32::UI::WindowsAndMessaging::{
+        CreateWindowExW, DestroyWindow, RegisterClassW, HWND_MESSAGE, MSG, WINDOW_EX_STYLE, WINDOW_STYLE, WNDCLASSW,
+    };
+
+    crate::win::MessageLoop::start(app, "clipboard", || {
+        let class_name = crate::win::to_wide("TaskManagerClipboardListener");
+        let class = WNDCLASSW {
+            lpfnWndProc: Some(listener_proc),
+            lpszClassName: PCWSTR(class_name.as_ptr()),
+            ..Default::default()
+        };
+        // Al reactivar el monitor la clase ya existe y RegisterClassW falla
+        unsafe { RegisterClassW(&class) };
+        let hwnd = unsafe {
+            CreateWindowExW(
+                WINDOW_EX_STYLE(0),
+                PCWSTR(class_name.as_ptr()),
+                PCWSTR(class_name.as_ptr()),
+                WINDOW_STYLE(0),
+                0,
+                0,
+                0,
+                0,
+                HWND_MESSAGE,
+                None,
+                None,
+                None,
+            )
+        };
+        if hwnd.0 == 0 {
+            return Err("No se pudo crear la ventana del monitor del portapapeles".to_string());
+        }
+        unsafe { AddClipboardFormatListener(hwnd) };
+        let cleanup = move || unsafe {
+            RemoveClipboardFormatListener(hwnd);
+            DestroyWindow(hwnd);
+        };
+        Ok((|_: &MSG| {}, cleanup))
+    })
+}
+
+// Arranca o detiene el escucha según el ajuste
+pub fn apply(app: &AppHandle, enabled: bool) {
+    let state = app.state::<AppState>();
+    let mut monitor = state.clipboard.lock().unwrap();
+
+    #[cfg(target_os = "windows")]
+    {
+        if enabled {
+            if monitor.listener.is_none() {
+                monitor.listener = start_listener(app).ok();
+            }
+            return;
+        }
+        // Sin el bloqueo: la ventana lo toma mientras el hilo termina
+        let listener = monitor.listener.take();
+        monitor.last_change = None;
+        drop(monitor);
+        if let Some(listener) = listener {
+            listener.stop();
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = enabled;
+        monitor.last_change = None;
+    }
+}
+
+// Último cambio visto desde que se activó el monitor
+#[tauri::command]
+pub fn get_clipboard_owner(state: State<AppState>) -> Option<ClipboardChange> {
+    state.clipboard.lock().unwrap().last_change.clone()
+}
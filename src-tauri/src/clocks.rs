@@ -0,0 +1,124 @@
+// src-tauri/src/clocks.rs
+// Historial de frecuencia por núcleo junto a su carga, para ver en
+// Rendimiento el comportamiento del turbo y la caída del reloj sostenido
+
+use serde::{Serialize, Deserialize};
+use std::collections::VecDeque;
+use sysinfo::System;
+use tauri::State;
+
+use crate::stats::{now_millis, HISTORY_CAPACITY};
+use crate::AppState;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CoreClock {
+    pub core: usize, // Procesador lógico
+    pub frequency_mhz: f64,
+    pub load: f64, // Porcentaje
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ClockSample {
+    pub timestamp: u64,
+    pub cores: Vec<CoreClock>,
+}
+
+#[derive(Default)]
+pub struct ClockCollector {
+    #[cfg(target_os = "windows")]
+    query: Option<crate::pdh::PdhQuery>,
+    samples: VecDeque<ClockSample>,
+    pub error: Option<String>,
+}
+
+// Instancia "grupo,núcleo" de Processor Information; se descartan los totales
+#[cfg(target_os = "windows")]
+fn parse_core_instance(instance: &str) -> Option<(u32, u32)> {
+    let (group, core) = instance.split_once(',')?;
+    Some((group.parse().ok()?, core.parse().ok()?))
+}
+
+impl ClockCollector {
+    // En Windows la frecuencia de sysinfo es la nominal: se usa PDH, escalando
+    // la frecuencia por el % de rendimiento para incluir el turbo
+    #[cfg(target_os = "windows")]
+    fn read_cores(&mut self, _system: &System) -> Result<Vec<CoreClock>, String> {
+        use std::collections::BTreeMap;
+
+        if self.query.is_none() {
+            let mut query = crate::pdh::PdhQuery::open()?;
+            query.add_counter(r"\Processor Information(*)\Processor Frequency")?;
+            query.add_counter(r"\Processor Information(*)\% Processor Performance")?;
+            query.add_counter(r"\Processor Information(*)\% Processor Utility")?;
+            query.collect()?;
+            self.query = Some(query);
+        }
+        let query = self.query.as_ref().unwrap();
+        query.collect()?;
+
+        let per_core = |index: usize| -> Result<BTreeMap<(u32, u32), f64>, String> {
+            Ok(query
+                .values(index)?
+                .into_iter()
+                .filter_map(|(instance, value)| parse_core_instance(&instance).map(|core| (core, value)))
+                .collect())
+        };
+        let (frequency, performance, utility) = (per_core(0)?, per_core(1)?, per_core(2)?);
+        Ok(frequency
+            .iter()
+            .enumerate()
+            .map(|(core, (instance, nominal))| CoreClock {
+                core,
+                frequency_mhz: nominal * performance.get(instance).copied().unwrap_or(100.0) / 100.0,
+                load: utility.get(instance).copied().unwrap_or(0.0).min(100.0),
+            })
+            .collect())
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    fn read_cores(&mut self, system: &System) -> Result<Vec<CoreClock>, String> {
+        use sysinfo::{CpuExt, SystemExt};
+
+        Ok(system
+            .cpus()
+            .iter()
+            .enumerate()
+            .map(|(core, cpu)| CoreClock {
+                core,
+                frequency_mhz: cpu.frequency() as f64,
+                load: cpu.cpu_usage() as f64,
+            })
+            .collect())
+    }
+
+    // Sin los contadores de frecuencia en este equipo, que no es un fallo
+    pub fn is_unavailable(&self) -> bool {
+        #[cfg(target_os = "windows")]
+        return self.query.is_none();
+        #[cfg(not(target_os = "windows"))]
+        false
+    }
+
+    pub fn sample(&mut self, system: &System) {
+        match self.read_cores(system) {
+            Ok(cores) => {
+                self.error = None;
+                if self.samples.len() == HISTORY_CAPACITY {
+                    self.samples.pop_front();
+                }
+                self.samples.push_back(ClockSample { timestamp: now_millis(), cores });
+            }
+            Err(e) => self.error = Some(e),
+        }
+    }
+}
+
+#[tauri::command]
+pub fn get_clock_history(state: State<AppState>, duration_secs: u64) -> Result<Vec<ClockSample>, String> {
+    let clocks = state.clocks.lock().unwrap();
+    if let (Some(error), true) = (&clocks.error, clocks.samples.is_empty()) {
+        return Err(error.clone());
+    }
+    let since = now_millis().saturating_sub(duration_secs * 1000);
+    Ok(clocks.samples.iter().filter(|sample| sample.timestamp >= since).cloned().collect())
+}
@@ -0,0 +1,190 @@
+// src-tauri/src/compare.rs
+// Historial corto de métricas por proceso: series para las gráficas de cada
+// proceso y para comparar de 2 a 4 procesos lado a lado (p. ej. dos
+// navegadores, o antes y después de un cambio)
+
+use serde::{Serialize, Deserialize};
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
+use tauri::State;
+
+use crate::paging::{paginate, Page};
+use crate::{AppState, ProcessInfo};
+
+// Límite de la ventana configurable: a un refresco por segundo, cada minuto
+// son 60 muestras por proceso
+pub const MAX_HISTORY_MINUTES: u64 = 30;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+pub struct ProcessSample {
+    pub cpu_usage: f32,
+    pub memory_usage: u64,
+    pub disk_read_bytes: u64,
+    pub disk_write_bytes: u64,
+    pub disk_read_rate: f64,
+    pub disk_write_rate: f64,
+    pub gpu_usage: f32,
+    pub network_send_rate: f64,
+    pub network_receive_rate: f64,
+}
+
+#[derive(Debug, Default)]
+pub struct ProcessHistory {
+    timestamps: VecDeque<u64>,
+    series: HashMap<u32, VecDeque<(u64, ProcessSample)>>,
+}
+
+impl ProcessHistory {
+    // Descarta las muestras más antiguas que `window`
+    pub fn record(&mut self, timestamp: u64, processes: &[ProcessInfo], window: Duration) {
+        let window = window.min(Duration::from_secs(MAX_HISTORY_MINUTES * 60)).as_millis() as u64;
+        self.timestamps.push_back(timestamp);
+        while self.timestamps.front().is_some_and(|at| *at + window < timestamp) {
+            self.timestamps.pop_front();
+        }
+        let oldest = self.timestamps.front().copied().unwrap_or(timestamp);
+
+        for process in processes {
+            let series = self.series.entry(process.pid).or_default();
+            series.push_back((
+                timestamp,
+                ProcessSample {
+                    cpu_usage: process.cpu_usage,
+                    memory_usage: process.memory_usage,
+                    disk_read_bytes: process.disk_read_bytes,
+                    disk_write_bytes: process.disk_write_bytes,
+                    disk_read_rate: process.disk_read_rate,
+                    disk_write_rate: process.disk_write_rate,
+                    gpu_usage: process.gpu_usage,
+                    network_send_rate: process.network_send_rate,
+                    network_receive_rate: process.network_receive_rate,
+                },
+            ));
+        }
+        // Los procesos terminados se conservan mientras quede alguna muestra en la ventana
+        self.series.retain(|_, series| {
+            while series.front().is_some_and(|(at, _)| *at < oldest) {
+                series.pop_front();
+            }
+            !series.is_empty()
+        });
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProcessSeries {
+    pub pid: u32,
+    pub name: Option<String>,                // None si ya terminó
+    pub samples: Vec<Option<ProcessSample>>, // Alineado con `timestamps`; None si no existía
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProcessComparison {
+    pub timestamps: Vec<u64>,
+    pub processes: Vec<ProcessSeries>,
+}
+
+#[tauri::command]
+pub fn compare_processes(state: State<AppState>, pids: Vec<u32>) -> Result<ProcessComparison, String> {
+    if !(2..=4).contains(&pids.len()) {
+        return Err("Se pueden comparar entre 2 y 4 procesos".to_string());
+    }
+    let names: HashMap<u32, String> = state
+        .processes
+        .read()
+        .unwrap()
+        .processes
+        .iter()
+        .filter(|process| pids.contains(&process.pid))
+        .map(|process| (process.pid, process.name.clone()))
+        .collect();
+
+    let history = state.process_history.read().unwrap();
+    let processes = pids
+        .iter()
+        .map(|pid| {
+            let by_time: HashMap<u64, ProcessSample> = history
+                .series
+                .get(pid)
+                .map(|series| series.iter().copied().collect())
+                .unwrap_or_default();
+            ProcessSeries {
+                pid: *pid,
+                name: names.get(pid).cloned(),
+                samples: history.timestamps.iter().map(|at| by_time.get(at).copied()).collect(),
+            }
+        })
+        .collect();
+    Ok(ProcessComparison {
+        timestamps: history.timestamps.iter().copied().collect(),
+        processes,
+    })
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct HistoryPoint {
+    pub timestamp: u64,
+    pub cpu_usage: f32,
+    pub memory_usage: u64,
+    pub disk_read_rate: f64, // Bytes/s
+    pub disk_write_rate: f64,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ProcessHistorySeries {
+    pub pid: u32,
+    pub name: Option<String>, // None si ya terminó
+    pub points: Vec<HistoryPoint>,
+}
+
+fn history_points(state: &AppState, pid: u32, duration_secs: Option<u64>) -> Result<Vec<HistoryPoint>, String> {
+    let history = state.process_history.read().unwrap();
+    let series = history.series.get(&pid).ok_or_else(|| format!("No hay historial del proceso {}", pid))?;
+    let newest = history.timestamps.back().copied().unwrap_or_default();
+    let since = duration_secs.map_or(0, |secs| newest.saturating_sub(secs * 1000));
+
+    Ok(series
+        .iter()
+        .filter(|(timestamp, _)| *timestamp >= since)
+        .map(|(timestamp, sample)| HistoryPoint {
+            timestamp: *timestamp,
+            cpu_usage: sample.cpu_usage,
+            memory_usage: sample.memory_usage,
+            disk_read_rate: sample.disk_read_rate,
+            disk_write_rate: sample.disk_write_rate,
+        })
+        .collect())
+}
+
+// Serie de un proceso para las gráficas en miniatura, de la más antigua a la
+// más reciente; sin `duration_secs` se devuelve toda la ventana guardada
+#[tauri::command]
+pub fn get_process_history(
+    state: State<AppState>,
+    pid: u32,
+    duration_secs: Option<u64>,
+) -> Result<ProcessHistorySeries, String> {
+    let name = state
+        .processes
+        .read()
+        .unwrap()
+        .processes
+        .iter()
+        .find(|process| process.pid == pid)
+        .map(|process| process.name.clone());
+    let points = history_points(&state, pid, duration_secs)?;
+    Ok(ProcessHistorySeries { pid, name, points })
+}
+
+// La misma serie por páginas, para ventanas de historial largas
+#[tauri::command]
+pub fn get_process_history_page(
+    state: State<AppState>,
+    pid: u32,
+    duration_secs: Option<u64>,
+    cursor: Option<String>,
+    limit: Option<usize>,
+) -> Result<Page<HistoryPoint>, String> {
+    let points = history_points(&state, pid, duration_secs)?;
+    paginate(&points, |point| point.timestamp, cursor.as_deref(), limit)
+}
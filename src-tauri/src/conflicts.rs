@@ -0,0 +1,129 @@
+// src-tauri/src/conflicts.rs
+// Software duplicado o incompatible en ejecución a la vez (varios antivirus,
+// varias superposiciones o capturadores, varios clientes de sincronización)
+// para el panel de avisos. Las reglas incluidas se pueden ampliar o sustituir
+// por nombre con <datos de la app>/conflicts.json
+
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
+use tauri::{AppHandle, Manager, State};
+
+use crate::AppState;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ConflictRule {
+    pub name: String,
+    pub category: String,       // antivirus, overlay, sync...
+    pub processes: Vec<String>, // Ejecutables; cada uno cuenta como un producto
+    #[serde(default = "default_min_running")]
+    pub min_running: usize, // Productos distintos a la vez para avisar
+    pub advice: String,
+}
+
+fn default_min_running() -> usize {
+    2
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct RunningProduct {
+    pub process: String,
+    pub pids: Vec<u32>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct Conflict {
+    pub rule: String,
+    pub category: String,
+    pub running: Vec<RunningProduct>,
+    pub advice: String,
+}
+
+fn rule(name: &str, category: &str, processes: &[&str], advice: &str) -> ConflictRule {
+    ConflictRule {
+        name: name.to_string(),
+        category: category.to_string(),
+        processes: processes.iter().map(|process| process.to_string()).collect(),
+        min_running: default_min_running(),
+        advice: advice.to_string(),
+    }
+}
+
+// Microsoft Defender (MsMpEng.exe) no se incluye: pasa a modo pasivo por su
+// cuenta cuando hay otro antivirus registrado
+fn builtin_rules() -> Vec<ConflictRule> {
+    vec![
+        rule(
+            "Varios antivirus",
+            "antivirus",
+            &[
+                "avp.exe", "AvastSvc.exe", "AVGSvc.exe", "bdservicehost.exe", "ekrn.exe", "mcshield.exe",
+                "NortonSecurity.exe", "SophosHealth.exe", "MBAMService.exe", "SavService.exe",
+            ],
+            "Dos motores con protección en tiempo real analizan cada archivo dos veces y pueden bloquearse \
+             entre sí. Deja uno solo activo.",
+        ),
+        rule(
+            "Varias superposiciones o capturadores",
+            "overlay",
+            &[
+                "RTSS.exe", "NVIDIA Share.exe", "obs64.exe", "XSplit.Core.exe", "bdcam.exe", "fraps.exe",
+                "Action.exe", "GameBar.exe",
+            ],
+            "Las superposiciones se inyectan en los juegos y, combinadas, causan cierres o caídas de \
+             rendimiento. Desactiva las que no uses.",
+        ),
+        rule(
+            "Varios clientes de sincronización",
+            "sync",
+            &[
+                "OneDrive.exe", "Dropbox.exe", "GoogleDriveFS.exe", "iCloudDrive.exe", "pCloud.exe",
+                "MEGAsync.exe", "nextcloud.exe", "owncloud.exe",
+            ],
+            "Si dos clientes sincronizan la misma carpeta se generan copias en conflicto o cambios que se \
+             repiten sin fin. Comprueba que cada uno use carpetas distintas.",
+        ),
+    ]
+}
+
+// Reglas incluidas más las del archivo; una regla del archivo con el mismo
+// nombre sustituye a la incluida
+fn load_rules(app: &AppHandle) -> Result<Vec<ConflictRule>, String> {
+    let mut rules = builtin_rules();
+    let Ok(dir) = app.path().app_data_dir() else { return Ok(rules) };
+    let Ok(content) = std::fs::read_to_string(dir.join("conflicts.json")) else { return Ok(rules) };
+    let custom: Vec<ConflictRule> =
+        serde_json::from_str(&content).map_err(|e| format!("conflicts.json no es válido: {}", e))?;
+    for custom in custom {
+        rules.retain(|rule| rule.name != custom.name);
+        rules.push(custom);
+    }
+    Ok(rules)
+}
+
+#[tauri::command]
+pub fn get_conflicts(app: AppHandle, state: State<AppState>) -> Result<Vec<Conflict>, String> {
+    let rules = load_rules(&app)?;
+    let tree = state.processes.read().unwrap();
+    let mut conflicts = Vec::new();
+    for rule in rules {
+        // Agrupado por el nombre de la regla para no contar dos veces un producto
+        let mut running: BTreeMap<&str, Vec<u32>> = BTreeMap::new();
+        for process in &tree.processes {
+            if let Some(name) = rule.processes.iter().find(|name| crate::text::same_name(name, &process.name)) {
+                running.entry(name.as_str()).or_default().push(process.pid);
+            }
+        }
+        if running.len() >= rule.min_running.max(1) {
+            conflicts.push(Conflict {
+                running: running
+                    .into_iter()
+                    .map(|(process, pids)| RunningProduct { process: process.to_string(), pids })
+                    .collect(),
+                rule: rule.name,
+                category: rule.category,
+                advice: rule.advice,
+            });
+        }
+    }
+    Ok(conflicts)
+}
@@ -0,0 +1,284 @@
+// src-tauri/src/connections.rs
+// Conexiones TCP y puertos UDP por proceso (tablas de IP Helper) y eventos de
+// apertura y cierre para los procesos vigilados, para las insignias de
+// actividad de la UI
+
+use serde::{Serialize, Deserialize};
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::AppState;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+pub struct Connection {
+    pub pid: u32,
+    pub protocol: String, // tcp, udp
+    pub local_address: String,
+    pub local_port: u16,
+    pub remote_address: String,
+    pub remote_port: u16,
+    pub state: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ConnectionEvent {
+    pub pid: u32,
+    pub remote_address: String,
+    pub remote_port: u16,
+    pub state: String,
+}
+
+// Extremos remotos conocidos de cada proceso vigilado
+#[derive(Debug, Default)]
+pub struct ConnectionWatcher {
+    known: HashMap<u32, HashSet<(String, u16)>>,
+}
+
+#[cfg(target_os = "windows")]
+fn tcp_state_name(state: u32) -> &'static str {
+    match state {
+        1 => "closed",
+        2 => "listen",
+        3 => "syn_sent",
+        4 => "syn_received",
+        5 => "established",
+        6 => "fin_wait1",
+        7 => "fin_wait2",
+        8 => "close_wait",
+        9 => "closing",
+        10 => "last_ack",
+        11 => "time_wait",
+        12 => "delete_tcb",
+        _ => "unknown",
+    }
+}
+
+// Tabla de una familia de direcciones (AF_INET = 2, AF_INET6 = 23); `read`
+// hace la llamada de IP Helper con el búfer (o sin él, para el tamaño)
+#[cfg(target_os = "windows")]
+fn read_table(name: &str, read: impl Fn(Option<*mut std::ffi::c_void>, &mut u32) -> u32) -> Result<Vec<u8>, String> {
+    use windows::Win32::Foundation::{ERROR_INSUFFICIENT_BUFFER, NO_ERROR, WIN32_ERROR};
+
+    let mut size = 0u32;
+    let mut buffer: Vec<u8> = Vec::new();
+    // La tabla puede crecer entre la consulta del tamaño y la lectura
+    for _ in 0..4 {
+        let status = read((!buffer.is_empty()).then_some(buffer.as_mut_ptr() as *mut _), &mut size);
+        if status == NO_ERROR.0 {
+            return Ok(buffer);
+        }
+        if status != ERROR_INSUFFICIENT_BUFFER.0 {
+            let error = windows::core::Error::from(WIN32_ERROR(status).to_hresult());
+            return Err(format!("No se pudo leer la tabla {}: {}", name, error));
+        }
+        buffer = vec![0u8; size as usize];
+    }
+    Err(format!("La tabla {} cambia demasiado rápido", name))
+}
+
+#[cfg(target_os = "windows")]
+fn tcp_table(family: u32) -> Result<Vec<u8>, String> {
+    use windows::Win32::NetworkManagement::IpHelper::{GetExtendedTcpTable, TCP_TABLE_OWNER_PID_ALL};
+
+    read_table("de conexiones TCP", |buffer, size| unsafe {
+        GetExtendedTcpTable(buffer, size, false, family, TCP_TABLE_OWNER_PID_ALL, 0)
+    })
+}
+
+#[cfg(target_os = "windows")]
+fn udp_table(family: u32) -> Result<Vec<u8>, String> {
+    use windows::Win32::NetworkManagement::IpHelper::{GetExtendedUdpTable, UDP_TABLE_OWNER_PID};
+
+    read_table("de puertos UDP", |buffer, size| unsafe {
+        GetExtendedUdpTable(buffer, size, false, family, UDP_TABLE_OWNER_PID, 0)
+    })
+}
+
+// Los puertos vienen en orden de red en los 16 bits bajos
+#[cfg(target_os = "windows")]
+fn port(value: u32) -> u16 {
+    u16::from_be(value as u16)
+}
+
+pub fn list_connections() -> Result<Vec<Connection>, String> {
+    #[cfg(target_os = "windows")]
+    {
+        use std::net::{Ipv4Addr, Ipv6Addr};
+        use windows::Win32::NetworkManagement::IpHelper::{MIB_TCP6TABLE_OWNER_PID, MIB_TCPTABLE_OWNER_PID};
+
+        let mut connections = Vec::new();
+
+        let buffer = tcp_table(2)?;
+        if !buffer.is_empty() {
+            let table = unsafe { &*(buffer.as_ptr() as *const MIB_TCPTABLE_OWNER_PID) };
+            let rows = unsafe { std::slice::from_raw_parts(table.table.as_ptr(), table.dwNumEntries as usize) };
+            connections.extend(rows.iter().map(|row| Connection {
+                pid: row.dwOwningPid,
+                protocol: "tcp".to_string(),
+                local_address: Ipv4Addr::from(row.dwLocalAddr.to_ne_bytes()).to_string(),
+                local_port: port(row.dwLocalPort),
+                remote_address: Ipv4Addr::from(row.dwRemoteAddr.to_ne_bytes()).to_string(),
+                remote_port: port(row.dwRemotePort),
+                state: tcp_state_name(row.dwState).to_string(),
+            }));
+        }
+
+        let buffer = tcp_table(23)?;
+        if !buffer.is_empty() {
+            let table = unsafe { &*(buffer.as_ptr() as *const MIB_TCP6TABLE_OWNER_PID) };
+            let rows = unsafe { std::slice::from_raw_parts(table.table.as_ptr(), table.dwNumEntries as usize) };
+            connections.extend(rows.iter().map(|row| Connection {
+                pid: row.dwOwningPid,
+                protocol: "tcp".to_string(),
+                local_address: Ipv6Addr::from(row.ucLocalAddr).to_string(),
+                local_port: port(row.dwLocalPort),
+                remote_address: Ipv6Addr::from(row.ucRemoteAddr).to_string(),
+                remote_port: port(row.dwRemotePort),
+                state: tcp_state_name(row.dwState).to_string(),
+            }));
+        }
+        Ok(connections)
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
+
+// Puertos UDP abiertos: sin conexión, así que no tienen extremo remoto ni
+// estado y esos campos quedan vacíos
+pub fn list_udp_endpoints() -> Result<Vec<Connection>, String> {
+    #[cfg(target_os = "windows")]
+    {
+        use std::net::{Ipv4Addr, Ipv6Addr};
+        use windows::Win32::NetworkManagement::IpHelper::{MIB_UDP6TABLE_OWNER_PID, MIB_UDPTABLE_OWNER_PID};
+
+        let endpoint = |pid: u32, local_address: String, local_port: u32| Connection {
+            pid,
+            protocol: "udp".to_string(),
+            local_address,
+            local_port: port(local_port),
+            remote_address: String::new(),
+            remote_port: 0,
+            state: String::new(),
+        };
+        let mut endpoints = Vec::new();
+
+        let buffer = udp_table(2)?;
+        if !buffer.is_empty() {
+            let table = unsafe { &*(buffer.as_ptr() as *const MIB_UDPTABLE_OWNER_PID) };
+            let rows = unsafe { std::slice::from_raw_parts(table.table.as_ptr(), table.dwNumEntries as usize) };
+            endpoints.extend(rows.iter().map(|row| {
+                endpoint(row.dwOwningPid, Ipv4Addr::from(row.dwLocalAddr.to_ne_bytes()).to_string(), row.dwLocalPort)
+            }));
+        }
+
+        let buffer = udp_table(23)?;
+        if !buffer.is_empty() {
+            let table = unsafe { &*(buffer.as_ptr() as *const MIB_UDP6TABLE_OWNER_PID) };
+            let rows = unsafe { std::slice::from_raw_parts(table.table.as_ptr(), table.dwNumEntries as usize) };
+            endpoints.extend(rows.iter().map(|row| {
+                endpoint(row.dwOwningPid, Ipv6Addr::from(row.ucLocalAddr).to_string(), row.dwLocalPort)
+            }));
+        }
+        Ok(endpoints)
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
+
+// Las conexiones en escucha no tienen extremo remoto
+fn remote_endpoints(connections: &[Connection], pid: u32) -> HashMap<(String, u16), &Connection> {
+    connections
+        .iter()
+        .filter(|connection| connection.pid == pid && connection.state != "listen")
+        .map(|connection| ((connection.remote_address.clone(), connection.remote_port), connection))
+        .collect()
+}
+
+impl ConnectionWatcher {
+    // Compara con la lectura anterior: devuelve (abiertas, cerradas)
+    fn poll(&mut self, connections: &[Connection]) -> (Vec<ConnectionEvent>, Vec<ConnectionEvent>) {
+        let (mut opened, mut closed) = (Vec::new(), Vec::new());
+        for (pid, known) in self.known.iter_mut() {
+            let current = remote_endpoints(connections, *pid);
+            for (endpoint, connection) in &current {
+                if !known.contains(endpoint) {
+                    opened.push(ConnectionEvent {
+                        pid: *pid,
+                        remote_address: endpoint.0.clone(),
+                        remote_port: endpoint.1,
+                        state: connection.state.clone(),
+                    });
+                }
+            }
+            for endpoint in known.iter().filter(|endpoint| !current.contains_key(*endpoint)) {
+                closed.push(ConnectionEvent {
+                    pid: *pid,
+                    remote_address: endpoint.0.clone(),
+                    remote_port: endpoint.1,
+                    state: "closed".to_string(),
+                });
+            }
+            *known = current.into_keys().collect();
+        }
+        (opened, closed)
+    }
+}
+
+// Hilo de sondeo: solo consulta la tabla mientras haya procesos vigilados
+pub fn start(app: AppHandle) {
+    std::thread::spawn(move || loop {
+        std::thread::sleep(POLL_INTERVAL);
+        let state = app.state::<AppState>();
+        if state.connections.lock().unwrap().known.is_empty() {
+            continue;
+        }
+        let Ok(connections) = list_connections() else { continue };
+        let (opened, closed) = state.connections.lock().unwrap().poll(&connections);
+        for event in opened {
+            let _ = app.emit("connection-opened", event);
+        }
+        for event in closed {
+            let _ = app.emit("connection-closed", event);
+        }
+    });
+}
+
+// Conexiones TCP y puertos UDP, como la pestaña Red del Monitor de recursos
+#[tauri::command]
+pub fn get_connections(pid: Option<u32>) -> Result<Vec<Connection>, String> {
+    let mut connections = list_connections()?;
+    connections.extend(list_udp_endpoints()?);
+    if let Some(pid) = pid {
+        connections.retain(|connection| connection.pid == pid);
+    }
+    Ok(connections)
+}
+
+// Nombre con el que se pidió el comando; equivale a get_connections
+#[tauri::command]
+pub fn get_network_connections(pid: Option<u32>) -> Result<Vec<Connection>, String> {
+    get_connections(pid)
+}
+
+// Las conexiones ya abiertas al empezar a vigilar no generan eventos
+#[tauri::command]
+pub fn watch_connections(state: State<AppState>, pid: u32) -> Result<(), String> {
+    let connections = list_connections()?;
+    let known = remote_endpoints(&connections, pid).into_keys().collect();
+    state.connections.lock().unwrap().known.insert(pid, known);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn unwatch_connections(state: State<AppState>, pid: u32) {
+    state.connections.lock().unwrap().known.remove(&pid);
+}
@@ -0,0 +1,287 @@
+// src-tauri/src/cores.rs
+// Tipos de núcleo en CPUs híbridas (P-cores y E-cores), reparto de cada
+// proceso entre ellos y afinidad por preajustes ("solo P-cores"...)
+
+use serde::{Serialize, Deserialize};
+use tauri::State;
+
+use crate::AppState;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CoreType {
+    Performance,
+    Efficiency,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct LogicalCpu {
+    pub group: u16,
+    pub number: u8,           // Índice dentro del grupo (bit de la máscara de afinidad)
+    pub core: u8,             // Núcleo físico; los hilos SMT lo comparten
+    pub efficiency_class: u8, // Mayor = más rendimiento
+    pub core_type: CoreType,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct CoreTopology {
+    pub hybrid: bool, // false si todos los núcleos son de la misma clase
+    pub cpus: Vec<LogicalCpu>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ThreadPlacement {
+    pub tid: u32,
+    pub group: u16, // Procesador ideal del hilo
+    pub number: u8,
+    pub cycles: u64, // Ciclos consumidos desde que empezó el hilo
+}
+
+// Reparto aproximado: Windows no expone en qué núcleo corrió cada hilo, así
+// que se usa su procesador ideal ponderado por los ciclos consumidos
+#[derive(Debug, Serialize, Clone)]
+pub struct CoreDistribution {
+    pub pid: u32,
+    pub threads: usize,
+    pub performance_share: f64, // Fracción de ciclos (0-1)
+    pub efficiency_share: f64,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ProcessAffinity {
+    pub process_mask: u64,
+    pub system_mask: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum AffinityPreset {
+    All,
+    PerformanceCores,
+    EfficiencyCores,
+    Custom(u64),
+}
+
+// Procesadores lógicos con su clase de eficiencia (GetSystemCpuSetInformation)
+pub fn topology() -> Result<CoreTopology, String> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::System::SystemInformation::{
+            GetSystemCpuSetInformation, CpuSetInformation, SYSTEM_CPU_SET_INFORMATION,
+        };
+        use windows::Win32::System::Threading::GetCurrentProcess;
+
+        let process = unsafe { GetCurrentProcess() };
+        let mut length = 0u32;
+        unsafe { GetSystemCpuSetInformation(None, 0, &mut length, process, 0) };
+        if length == 0 {
+            return Err(format!("No se pudo leer la topología de la CPU: {}", windows::core::Error::from_win32()));
+        }
+        let mut buffer = vec![0u64; (length as usize).div_ceil(8)];
+        let ok = unsafe {
+            GetSystemCpuSetInformation(
+                Some(buffer.as_mut_ptr() as *mut SYSTEM_CPU_SET_INFORMATION),
+                length,
+                &mut length,
+                process,
+                0,
+            )
+        };
+        if !ok.as_bool() {
+            return Err(format!("No se pudo leer la topología de la CPU: {}", windows::core::Error::from_win32()));
+        }
+
+        // Entradas de tamaño variable, encadenadas por su campo Size
+        let mut cpus = Vec::new();
+        let mut offset = 0usize;
+        let base = buffer.as_ptr() as *const u8;
+        while offset + std::mem::size_of::<SYSTEM_CPU_SET_INFORMATION>() <= length as usize {
+            let entry = unsafe { &*(base.add(offset) as *const SYSTEM_CPU_SET_INFORMATION) };
+            if entry.Size == 0 {
+                break;
+            }
+            offset += entry.Size as usize;
+            if entry.Type != CpuSetInformation {
+                continue;
+            }
+            let cpu_set = unsafe { entry.Anonymous.CpuSet };
+            cpus.push(LogicalCpu {
+                group: cpu_set.Group,
+                number: cpu_set.LogicalProcessorIndex,
+                core: cpu_set.CoreIndex,
+                efficiency_class: cpu_set.EfficiencyClass,
+                core_type: CoreType::Performance,
+            });
+        }
+
+        // Solo la clase más alta son P-cores; sin híbrido todos lo son
+        let highest = cpus.iter().map(|cpu| cpu.efficiency_class).max().unwrap_or(0);
+        let hybrid = cpus.iter().any(|cpu| cpu.efficiency_class != highest);
+        for cpu in &mut cpus {
+            if cpu.efficiency_class != highest {
+                cpu.core_type = CoreType::Efficiency;
+            }
+        }
+        cpus.sort_by_key(|cpu| (cpu.group, cpu.number));
+        Ok(CoreTopology { hybrid, cpus })
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
+
+// Procesador ideal y ciclos de cada hilo; se omiten los hilos sin acceso
+pub fn thread_placements(pid: u32) -> Result<Vec<ThreadPlacement>, String> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::System::Kernel::PROCESSOR_NUMBER;
+        use windows::Win32::System::Threading::{GetThreadIdealProcessorEx, THREAD_QUERY_INFORMATION};
+        use windows::Win32::System::WindowsProgramming::QueryThreadCycleTime;
+
+        let mut placements = Vec::new();
+        for tid in crate::win::thread_ids(pid)? {
+            // El procesador ideal requiere acceso completo de consulta
+            let Ok(thread) = crate::win::open_thread(tid, THREAD_QUERY_INFORMATION) else { continue };
+            let mut ideal = PROCESSOR_NUMBER::default();
+            if !unsafe { GetThreadIdealProcessorEx(thread.0, &mut ideal) }.as_bool() {
+                continue;
+            }
+            let mut cycles = 0u64;
+            unsafe { QueryThreadCycleTime(thread.0, &mut cycles) };
+            placements.push(ThreadPlacement { tid, group: ideal.Group, number: ideal.Number, cycles });
+        }
+        Ok(placements)
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = pid;
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
+
+// Máscara (grupo 0) de los procesadores de un tipo
+fn type_mask(topology: &CoreTopology, core_type: CoreType) -> u64 {
+    topology
+        .cpus
+        .iter()
+        .filter(|cpu| cpu.group == 0 && cpu.number < 64 && cpu.core_type == core_type)
+        .fold(0, |mask, cpu| mask | (1u64 << cpu.number))
+}
+
+#[tauri::command]
+pub fn get_core_topology() -> Result<CoreTopology, String> {
+    topology()
+}
+
+#[tauri::command]
+pub fn get_core_distribution(pid: u32) -> Result<CoreDistribution, String> {
+    let topology = topology()?;
+    let placements = thread_placements(pid)?;
+    let (mut performance, mut efficiency) = (0u64, 0u64);
+    for placement in &placements {
+        let core_type = topology
+            .cpus
+            .iter()
+            .find(|cpu| cpu.group == placement.group && cpu.number == placement.number)
+            .map_or(CoreType::Performance, |cpu| cpu.core_type);
+        match core_type {
+            CoreType::Performance => performance += placement.cycles,
+            CoreType::Efficiency => efficiency += placement.cycles,
+        }
+    }
+    let total = (performance + efficiency).max(1) as f64;
+    Ok(CoreDistribution {
+        pid,
+        threads: placements.len(),
+        performance_share: performance as f64 / total,
+        efficiency_share: efficiency as f64 / total,
+    })
+}
+
+// Máscaras del grupo de procesadores principal (hasta 64 procesadores lógicos)
+#[tauri::command]
+pub fn get_process_affinity(pid: u32) -> Result<ProcessAffinity, String> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::System::Threading::{GetProcessAffinityMask, PROCESS_QUERY_LIMITED_INFORMATION};
+
+        let process = crate::win::open_process(pid, PROCESS_QUERY_LIMITED_INFORMATION)?;
+        let (mut process_mask, mut system_mask) = (0usize, 0usize);
+        if !unsafe { GetProcessAffinityMask(process.0, &mut process_mask, &mut system_mask) }.as_bool() {
+            return Err(format!("No se pudo leer la afinidad: {}", windows::core::Error::from_win32()));
+        }
+        Ok(ProcessAffinity { process_mask: process_mask as u64, system_mask: system_mask as u64 })
+    }
+
+    // Linux: sched_getaffinity; la máscara del sistema son los procesadores en línea
+    #[cfg(target_os = "linux")]
+    {
+        let mut set: libc::cpu_set_t = unsafe { std::mem::zeroed() };
+        if unsafe { libc::sched_getaffinity(pid as libc::pid_t, std::mem::size_of::<libc::cpu_set_t>(), &mut set) } != 0 {
+            return Err(format!("No se pudo leer la afinidad: {}", std::io::Error::last_os_error()));
+        }
+        let process_mask = (0..64).filter(|&cpu| unsafe { libc::CPU_ISSET(cpu, &set) }).fold(0u64, |mask, cpu| mask | (1 << cpu));
+        let online = unsafe { libc::sysconf(libc::_SC_NPROCESSORS_ONLN) }.clamp(1, 64) as u32;
+        let system_mask = u64::MAX >> (64 - online);
+        Ok(ProcessAffinity { process_mask, system_mask })
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "linux")))]
+    {
+        let _ = pid;
+        Err("Esta función solo está disponible en Windows y Linux".to_string())
+    }
+}
+
+// Devuelve la máscara aplicada; con Custom se aplica una máscara arbitraria
+// (bit n = procesador lógico n)
+#[tauri::command]
+pub fn set_process_affinity(state: State<AppState>, pid: u32, preset: AffinityPreset) -> Result<u64, String> {
+    let action = format!("affinity:{:?}", preset);
+    crate::actions::run_once(&state, pid, &action, || apply_affinity(pid, preset))
+}
+
+pub fn apply_affinity(pid: u32, preset: AffinityPreset) -> Result<u64, String> {
+    let system_mask = get_process_affinity(pid)?.system_mask;
+    let mask = match preset {
+        AffinityPreset::All => system_mask,
+        AffinityPreset::PerformanceCores => type_mask(&topology()?, CoreType::Performance),
+        AffinityPreset::EfficiencyCores => type_mask(&topology()?, CoreType::Efficiency),
+        AffinityPreset::Custom(mask) => mask,
+    } & system_mask;
+    if mask == 0 {
+        return Err("La afinidad no incluye ningún procesador disponible".to_string());
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::System::Threading::{SetProcessAffinityMask, PROCESS_SET_INFORMATION};
+
+        let process = crate::win::open_process(pid, PROCESS_SET_INFORMATION)?;
+        if !unsafe { SetProcessAffinityMask(process.0, mask as usize) }.as_bool() {
+            return Err(format!("No se pudo cambiar la afinidad: {}", windows::core::Error::from_win32()));
+        }
+        Ok(mask)
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        let mut set: libc::cpu_set_t = unsafe { std::mem::zeroed() };
+        for cpu in (0..64).filter(|cpu| mask & (1 << cpu) != 0) {
+            unsafe { libc::CPU_SET(cpu, &mut set) };
+        }
+        if unsafe { libc::sched_setaffinity(pid as libc::pid_t, std::mem::size_of::<libc::cpu_set_t>(), &set) } != 0 {
+            return Err(format!("No se pudo cambiar la afinidad: {}", std::io::Error::last_os_error()));
+        }
+        Ok(mask)
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "linux")))]
+    {
+        let _ = mask;
+        Err("Esta función solo está disponible en Windows y Linux".to_string())
+    }
+}
@@ -0,0 +1,106 @@
+// src-tauri/src/datausage.rs
+// Consumo de datos por adaptador de red y mes, persistido en data_usage.json
+// (útil con conexiones medidas o compartidas desde el móvil)
+
+use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use tauri::State;
+
+use crate::provider::NetworkSample;
+use crate::AppState;
+
+const MONTHS_KEPT: usize = 24;
+const SAVE_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct AdapterTotals {
+    pub received_bytes: u64,
+    pub transmitted_bytes: u64,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct AdapterUsage {
+    pub name: String,
+    #[serde(flatten)]
+    pub totals: AdapterTotals,
+}
+
+#[derive(Debug, Default)]
+pub struct DataUsage {
+    months: BTreeMap<String, HashMap<String, AdapterTotals>>, // "AAAA-MM" -> adaptador
+    path: Option<PathBuf>,
+    last_save: Option<Instant>,
+}
+
+fn this_month() -> String {
+    crate::apphistory::today()[..7].to_string()
+}
+
+impl DataUsage {
+    // Un archivo ausente o corrupto empieza sin datos
+    pub fn load(path: &Path) -> Self {
+        let months = fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        DataUsage { months, path: Some(path.to_path_buf()), ..Default::default() }
+    }
+
+    pub fn save(&mut self) -> Result<(), String> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("No se pudo crear {}: {}", dir.display(), e))?;
+        }
+        let content = serde_json::to_string(&self.months)
+            .map_err(|e| format!("Error al serializar el consumo de datos: {}", e))?;
+        fs::write(path, content).map_err(|e| format!("No se pudo guardar {}: {}", path.display(), e))?;
+        self.last_save = Some(Instant::now());
+        Ok(())
+    }
+
+    // Los bytes son desde el refresco anterior, así que basta sumarlos
+    pub fn record(&mut self, networks: &[NetworkSample]) {
+        let month = self.months.entry(this_month()).or_default();
+        for network in networks {
+            if network.received == 0 && network.transmitted == 0 {
+                continue;
+            }
+            let totals = month.entry(network.name.clone()).or_default();
+            totals.received_bytes += network.received;
+            totals.transmitted_bytes += network.transmitted;
+        }
+
+        while self.months.len() > MONTHS_KEPT {
+            self.months.pop_first();
+        }
+        if self.last_save.is_none_or(|at| at.elapsed() >= SAVE_INTERVAL) {
+            let _ = self.save();
+        }
+    }
+}
+
+// Consumo por adaptador de un mes ("AAAA-MM", el actual si no se indica)
+#[tauri::command]
+pub fn get_data_usage(state: State<AppState>, month: Option<String>) -> Vec<AdapterUsage> {
+    let usage = state.data_usage.lock().unwrap();
+    let mut adapters: Vec<AdapterUsage> = usage
+        .months
+        .get(&month.unwrap_or_else(this_month))
+        .into_iter()
+        .flatten()
+        .map(|(name, totals)| AdapterUsage { name: name.clone(), totals: totals.clone() })
+        .collect();
+    adapters.sort_by_key(|adapter| std::cmp::Reverse(adapter.totals.received_bytes + adapter.totals.transmitted_bytes));
+    adapters
+}
+
+#[tauri::command]
+pub fn get_data_usage_months(state: State<AppState>) -> Vec<String> {
+    state.data_usage.lock().unwrap().months.keys().cloned().collect()
+}
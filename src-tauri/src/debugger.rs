@@ -0,0 +1,74 @@
+// src-tauri/src/debugger.rs
+// Adjuntar un depurador a un proceso: el comando configurado por el usuario
+// o el depurador JIT registrado en AeDebug
+
+use std::process::Command;
+use tauri::State;
+
+use crate::AppState;
+
+#[cfg(target_os = "windows")]
+const AEDEBUG_KEY: &str = r"SOFTWARE\Microsoft\Windows NT\CurrentVersion\AeDebug";
+
+// Separa el ejecutable (posiblemente entre comillas) del resto de argumentos
+pub fn split_program(command: &str) -> (String, String) {
+    let command = command.trim();
+    if let Some(rest) = command.strip_prefix('"') {
+        if let Some(end) = rest.find('"') {
+            return (rest[..end].to_string(), rest[end + 1..].trim().to_string());
+        }
+    }
+    match command.split_once(char::is_whitespace) {
+        Some((program, args)) => (program.to_string(), args.trim().to_string()),
+        None => (command.to_string(), String::new()),
+    }
+}
+
+// Sustituye el PID en la plantilla: `{pid}` en comandos de usuario y los `%ld`
+// de AeDebug (el segundo es el evento de depuración, que aquí no existe)
+fn substitute_pid(template: &str, pid: u32) -> String {
+    let command = template.replace("{pid}", &pid.to_string());
+    command.replacen("%ld", &pid.to_string(), 1).replace("%ld", "0")
+}
+
+fn registered_debugger() -> Option<String> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::System::Registry::HKEY_LOCAL_MACHINE;
+        crate::win::read_registry_string(HKEY_LOCAL_MACHINE, AEDEBUG_KEY, "Debugger")
+            .filter(|debugger| !debugger.trim().is_empty())
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        None
+    }
+}
+
+#[tauri::command]
+pub fn debug_process(state: State<AppState>, pid: u32) -> Result<(), String> {
+    let configured = state.settings.read().unwrap().debugger_command.clone();
+    let template = configured
+        .filter(|command| !command.trim().is_empty())
+        .or_else(registered_debugger)
+        .ok_or_else(|| "No hay ningún depurador configurado ni registrado en AeDebug".to_string())?;
+
+    let (program, args) = split_program(&substitute_pid(&template, pid));
+    let mut command = Command::new(&program);
+
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        command.raw_arg(&args);
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        command.args(args.split_whitespace());
+    }
+
+    command
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("Error al ejecutar el depurador {}: {}", program, e))
+}
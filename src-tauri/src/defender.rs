@@ -0,0 +1,196 @@
+// src-tauri/src/defender.rs
+// Estado del antivirus, el cortafuegos y la protección en tiempo real para la
+// información del sistema, leído del Centro de seguridad (WMI
+// root\SecurityCenter2) y de Microsoft Defender. También indica si el motor de
+// Defender (MsMpEng.exe) está analizando: un análisis programado suele ser el
+// "uso de CPU misterioso" que se viene a investigar
+
+use serde::Serialize;
+use tauri::State;
+
+use crate::AppState;
+
+// Uso de CPU del motor a partir del cual se considera que está analizando;
+// en reposo apenas llega al 1-2 %
+#[cfg(target_os = "windows")]
+const SCANNING_CPU: f32 = 15.0;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct SecurityProduct {
+    pub name: String,
+    pub enabled: bool,
+    pub up_to_date: Option<bool>, // Firmas al día; None en los cortafuegos
+    pub executable: Option<String>,
+}
+
+// Perfil del Firewall de Windows (dominio, privado, público)
+#[derive(Debug, Serialize, Clone)]
+pub struct FirewallProfile {
+    pub name: String,
+    pub enabled: bool,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct DefenderStatus {
+    pub service_enabled: bool,
+    pub antivirus_enabled: bool, // false en modo pasivo, con otro antivirus registrado
+    pub real_time_protection: bool,
+    pub signature_age_days: Option<u64>,
+    pub quick_scan_age_days: Option<u64>, // None si nunca se ha hecho
+    pub full_scan_age_days: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct DefenderEngine {
+    pub pid: u32,
+    pub cpu_usage: f32,
+    pub disk_read_rate: f64, // Bytes/s
+    pub scanning: bool,
+    pub reason: Option<String>, // Por qué se considera que está analizando
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct SecurityStatus {
+    pub antivirus: Vec<SecurityProduct>,
+    pub firewall: Vec<SecurityProduct>, // Cortafuegos de terceros registrados
+    pub firewall_profiles: Vec<FirewallProfile>,
+    pub real_time_protection: Option<bool>, // Algún antivirus activo; None si no se pudo leer
+    pub defender: Option<DefenderStatus>,
+    pub engine: Option<DefenderEngine>, // None si MsMpEng.exe no está en ejecución
+    pub errors: Vec<String>, // Fuentes que no se pudieron consultar (Windows Server no tiene Centro de seguridad)
+}
+
+// productState del Centro de seguridad: el bit 12 indica activo y el bit 4
+// firmas desactualizadas
+#[cfg(target_os = "windows")]
+fn decode_product_state(state: u64) -> (bool, bool) {
+    (state & 0x1000 != 0, state & 0x10 == 0)
+}
+
+#[cfg(target_os = "windows")]
+fn security_center(class: &str, signatures: bool) -> Result<Vec<SecurityProduct>, String> {
+    let rows = crate::wmi::query_namespace(
+        "ROOT\\SecurityCenter2",
+        &format!("SELECT displayName, productState, pathToSignedProductExe FROM {}", class),
+        &["displayName", "productState", "pathToSignedProductExe"],
+    )?;
+    Ok(rows
+        .into_iter()
+        .filter_map(|mut row| {
+            let (enabled, up_to_date) = decode_product_state(row[1].as_u64().unwrap_or(0));
+            let executable = row.swap_remove(2).into_text().filter(|path| !path.is_empty());
+            Some(SecurityProduct {
+                name: row.swap_remove(0).into_text()?,
+                enabled,
+                up_to_date: signatures.then_some(up_to_date),
+                executable,
+            })
+        })
+        .collect())
+}
+
+#[cfg(target_os = "windows")]
+fn firewall_profiles() -> Result<Vec<FirewallProfile>, String> {
+    // Enabled es un GpoBoolean: 1 activo, 0 desactivado, 2 sin configurar (activo)
+    let rows = crate::wmi::query_namespace(
+        "ROOT\\StandardCimv2",
+        "SELECT Name, Enabled FROM MSFT_NetFirewallProfile",
+        &["Name", "Enabled"],
+    )?;
+    Ok(rows
+        .into_iter()
+        .filter_map(|mut row| {
+            let enabled = row[1].as_u64().unwrap_or(1) != 0;
+            Some(FirewallProfile { name: row.swap_remove(0).into_text()?, enabled })
+        })
+        .collect())
+}
+
+#[cfg(target_os = "windows")]
+fn defender_status() -> Result<Option<DefenderStatus>, String> {
+    // Las antigüedades valen 4294967295 (u32::MAX) si nunca se han calculado
+    let age = |value: &crate::wmi::WmiValue| value.as_u64().filter(|days| *days < u32::MAX as u64);
+    let rows = crate::wmi::query_namespace(
+        "ROOT\\Microsoft\\Windows\\Defender",
+        "SELECT AMServiceEnabled, AntivirusEnabled, RealTimeProtectionEnabled, AntivirusSignatureAge, \
+         QuickScanAge, FullScanAge FROM MSFT_MpComputerStatus",
+        &[
+            "AMServiceEnabled",
+            "AntivirusEnabled",
+            "RealTimeProtectionEnabled",
+            "AntivirusSignatureAge",
+            "QuickScanAge",
+            "FullScanAge",
+        ],
+    )?;
+    Ok(rows.into_iter().next().map(|row| DefenderStatus {
+        service_enabled: row[0].as_bool().unwrap_or(false),
+        antivirus_enabled: row[1].as_bool().unwrap_or(false),
+        real_time_protection: row[2].as_bool().unwrap_or(false),
+        signature_age_days: age(&row[3]),
+        quick_scan_age_days: age(&row[4]),
+        full_scan_age_days: age(&row[5]),
+    }))
+}
+
+// Con el último árbol publicado: el motor analiza si consume CPU de forma
+// sostenida o si hay un MpCmdRun.exe (análisis programado o manual) en marcha
+#[cfg(target_os = "windows")]
+fn engine(state: &AppState) -> Option<DefenderEngine> {
+    use crate::text::same_name;
+
+    let tree = state.processes.read().unwrap();
+    let process = tree.processes.iter().find(|process| same_name(&process.name, "MsMpEng.exe"))?;
+    let scan_task = tree.processes.iter().find(|process| same_name(&process.name, "MpCmdRun.exe"));
+    let reason = match scan_task {
+        Some(task) => Some(format!("MpCmdRun.exe (PID {}) está lanzando un análisis", task.pid)),
+        None => (process.cpu_usage >= SCANNING_CPU)
+            .then(|| format!("El motor usa un {:.0} % de CPU", process.cpu_usage)),
+    };
+    Some(DefenderEngine {
+        pid: process.pid,
+        cpu_usage: process.cpu_usage,
+        disk_read_rate: process.disk_read_rate,
+        scanning: reason.is_some(),
+        reason,
+    })
+}
+
+#[tauri::command]
+pub fn get_security_status(state: State<AppState>) -> Result<SecurityStatus, String> {
+    #[cfg(target_os = "windows")]
+    {
+        // Cada fuente puede faltar por separado (Windows Server, Defender
+        // desinstalado...); lo que sí se lee se devuelve igualmente
+        fn read<T>(errors: &mut Vec<String>, result: Result<T, String>) -> Option<T> {
+            result.map_err(|e| errors.push(e)).ok()
+        }
+        let mut errors = Vec::new();
+        let antivirus = read(&mut errors, security_center("AntiVirusProduct", true));
+        let firewall = read(&mut errors, security_center("FirewallProduct", false)).unwrap_or_default();
+        let firewall_profiles = read(&mut errors, firewall_profiles()).unwrap_or_default();
+        let defender = read(&mut errors, defender_status()).flatten();
+
+        let defender_active =
+            defender.as_ref().map(|defender| defender.antivirus_enabled && defender.real_time_protection);
+        let real_time_protection = match &antivirus {
+            Some(antivirus) => Some(antivirus.iter().any(|product| product.enabled) || defender_active == Some(true)),
+            None => defender_active,
+        };
+        Ok(SecurityStatus {
+            antivirus: antivirus.unwrap_or_default(),
+            firewall,
+            firewall_profiles,
+            real_time_protection,
+            defender,
+            engine: engine(&state),
+            errors,
+        })
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = state;
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
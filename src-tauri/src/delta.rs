@@ -0,0 +1,102 @@
+// src-tauri/src/delta.rs
+// Protocolo de cambios para el árbol de procesos: cada refresco es una
+// generación y la UI pide solo lo añadido, quitado o cambiado desde la suya.
+// Las relaciones padre-hijo solo se envían si cambió la topología
+
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use tauri::State;
+
+use crate::{AppState, ProcessInfo, ProcessTotals, ProcessTree};
+
+// Generaciones recientes que se conservan; una más antigua recibe el árbol completo
+const GENERATION_CAPACITY: usize = 16;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ProcessDelta {
+    pub generation: u64,
+    pub full: bool, // Sin base conocida: `added` contiene todos los procesos
+    pub added: Vec<ProcessInfo>,
+    pub changed: Vec<ProcessInfo>,
+    pub removed: Vec<u32>,
+    pub process_relationships: Option<HashMap<u32, Vec<u32>>>,
+    pub totals: ProcessTotals,
+}
+
+struct Generation {
+    number: u64,
+    processes: HashMap<u32, ProcessInfo>,
+    relationships: HashMap<u32, Vec<u32>>,
+}
+
+#[derive(Default)]
+pub struct DeltaLog {
+    generations: VecDeque<Generation>,
+    totals: ProcessTotals,
+}
+
+impl DeltaLog {
+    pub fn record(&mut self, tree: &ProcessTree) {
+        let number = self.generations.back().map_or(1, |last| last.number + 1);
+        if self.generations.len() == GENERATION_CAPACITY {
+            self.generations.pop_front();
+        }
+        self.generations.push_back(Generation {
+            number,
+            processes: tree.processes.iter().map(|process| (process.pid, process.clone())).collect(),
+            relationships: tree.process_relationships.clone(),
+        });
+        self.totals = tree.totals.clone();
+    }
+
+    fn delta(&self, since: u64) -> ProcessDelta {
+        let Some(current) = self.generations.back() else {
+            return ProcessDelta {
+                generation: 0,
+                full: true,
+                added: Vec::new(),
+                changed: Vec::new(),
+                removed: Vec::new(),
+                process_relationships: Some(HashMap::new()),
+                totals: ProcessTotals::default(),
+            };
+        };
+        let Some(base) = self.generations.iter().find(|generation| generation.number == since) else {
+            return ProcessDelta {
+                generation: current.number,
+                full: true,
+                added: current.processes.values().cloned().collect(),
+                changed: Vec::new(),
+                removed: Vec::new(),
+                process_relationships: Some(current.relationships.clone()),
+                totals: self.totals.clone(),
+            };
+        };
+
+        let mut added = Vec::new();
+        let mut changed = Vec::new();
+        for (pid, process) in &current.processes {
+            match base.processes.get(pid) {
+                None => added.push(process.clone()),
+                Some(previous) if previous != process => changed.push(process.clone()),
+                Some(_) => {}
+            }
+        }
+        let removed = base.processes.keys().filter(|pid| !current.processes.contains_key(pid)).copied().collect();
+        ProcessDelta {
+            generation: current.number,
+            full: false,
+            added,
+            changed,
+            removed,
+            process_relationships: (base.relationships != current.relationships).then(|| current.relationships.clone()),
+            totals: self.totals.clone(),
+        }
+    }
+}
+
+// `generation` es la última que tiene la UI; 0 para pedir el árbol completo
+#[tauri::command]
+pub fn get_process_delta(state: State<AppState>, generation: u64) -> ProcessDelta {
+    state.process_deltas.lock().unwrap().delta(generation)
+}
@@ -0,0 +1,146 @@
+// src-tauri/src/dependents.rs
+// Qué depende de un proceso antes de terminarlo, para que el diálogo de
+// confirmación muestre las consecuencias reales: servicios que hospeda (y los
+// que dependen de ellos), procesos hijos y otros procesos conectados a él por
+// TCP local. Las tuberías con nombre y el RPC local (ALPC) no se pueden
+// atribuir a un cliente sin conectarse a ellas, así que no se incluyen
+
+use serde::Serialize;
+use std::collections::BTreeMap;
+use tauri::State;
+
+use crate::services::ServiceInfo;
+use crate::AppState;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct DependentProcess {
+    pub pid: u32,
+    pub name: String,
+}
+
+// Otro proceso con una conexión TCP local abierta con este
+#[derive(Debug, Serialize, Clone)]
+pub struct LocalClient {
+    pub pid: u32,
+    pub name: String,
+    pub ports: Vec<u16>, // Puertos de este proceso a los que está conectado
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct DependencyHints {
+    pub pid: u32,
+    pub services: Vec<ServiceInfo>,           // Hospedados en el proceso; se detienen con él
+    pub dependent_services: Vec<ServiceInfo>, // En ejecución y dependientes de los anteriores
+    pub children: Vec<DependentProcess>,      // Descendientes; siguen vivos, pero huérfanos
+    pub local_clients: Vec<LocalClient>,
+    pub consequences: Vec<String>, // Resumen para el diálogo, vacío si no hay nada que avisar
+    pub errors: Vec<String>,       // Fuentes que no se pudieron consultar
+}
+
+fn is_loopback(address: &str) -> bool {
+    address.parse::<std::net::IpAddr>().is_ok_and(|address| address.is_loopback())
+}
+
+// Cada conexión local aparece dos veces en la tabla, una por extremo: el
+// extremo del otro proceso tiene los puertos cruzados
+fn local_clients(pid: u32, names: &BTreeMap<u32, String>) -> Result<Vec<LocalClient>, String> {
+    let connections = crate::connections::list_connections()?;
+    let mut clients: BTreeMap<u32, Vec<u16>> = BTreeMap::new();
+    for own in connections.iter().filter(|connection| {
+        connection.pid == pid && connection.state == "established" && is_loopback(&connection.remote_address)
+    }) {
+        let peer = connections.iter().find(|peer| {
+            peer.pid != pid && peer.local_port == own.remote_port && peer.remote_port == own.local_port
+        });
+        if let Some(peer) = peer {
+            let ports = clients.entry(peer.pid).or_default();
+            if !ports.contains(&own.local_port) {
+                ports.push(own.local_port);
+            }
+        }
+    }
+    Ok(clients
+        .into_iter()
+        .map(|(pid, ports)| LocalClient { pid, name: names.get(&pid).cloned().unwrap_or_default(), ports })
+        .collect())
+}
+
+fn summarize(hints: &DependencyHints) -> Vec<String> {
+    let list = |names: Vec<&str>| names.join(", ");
+    let mut consequences = Vec::new();
+    if !hints.services.is_empty() {
+        let names = hints.services.iter().map(|service| service.display_name.as_str()).collect();
+        consequences.push(format!("Se detendrán {} servicio(s): {}", hints.services.len(), list(names)));
+    }
+    if !hints.dependent_services.is_empty() {
+        let names = hints.dependent_services.iter().map(|service| service.display_name.as_str()).collect();
+        consequences.push(format!("Pueden fallar los servicios que dependen de ellos: {}", list(names)));
+    }
+    if !hints.children.is_empty() {
+        consequences.push(format!(
+            "{} proceso(s) hijo(s) seguirán en ejecución sin su proceso padre",
+            hints.children.len()
+        ));
+    }
+    if !hints.local_clients.is_empty() {
+        let names = hints.local_clients.iter().map(|client| client.name.as_str()).collect();
+        consequences.push(format!("Se cortará la conexión local con: {}", list(names)));
+    }
+    consequences
+}
+
+#[tauri::command]
+pub fn get_dependency_hints(state: State<AppState>, pid: u32) -> Result<DependencyHints, String> {
+    let (children, names) = {
+        let tree = state.processes.read().unwrap();
+        if !tree.processes.iter().any(|process| process.pid == pid) {
+            return Err(format!("El proceso {} ya no está en ejecución", pid));
+        }
+        let names: BTreeMap<u32, String> =
+            tree.processes.iter().map(|process| (process.pid, process.name.clone())).collect();
+        // tree_order termina con el propio proceso
+        let children = crate::tree_order(&tree, pid)
+            .into_iter()
+            .filter(|child| *child != pid)
+            .map(|child| DependentProcess { pid: child, name: names.get(&child).cloned().unwrap_or_default() })
+            .collect();
+        (children, names)
+    };
+
+    let mut errors = Vec::new();
+    let services: Vec<ServiceInfo> = match crate::services::enumerate_services() {
+        Ok(services) => services.into_iter().filter(|service| service.pid == Some(pid)).collect(),
+        Err(e) => {
+            errors.push(e);
+            Vec::new()
+        }
+    };
+    let mut dependent_services: Vec<ServiceInfo> = Vec::new();
+    for service in &services {
+        match crate::services::active_dependents(&service.name) {
+            Ok(dependents) => dependent_services.extend(dependents),
+            Err(e) => errors.push(e),
+        }
+    }
+    // Los que ya se detienen por estar en el mismo proceso no se repiten
+    dependent_services.retain(|dependent| !services.iter().any(|service| service.name == dependent.name));
+    dependent_services.sort_by(|a, b| a.name.cmp(&b.name));
+    dependent_services.dedup_by(|a, b| a.name == b.name);
+
+    let local_clients = local_clients(pid, &names).unwrap_or_else(|e| {
+        errors.push(e);
+        Vec::new()
+    });
+
+    let mut hints = DependencyHints {
+        pid,
+        services,
+        dependent_services,
+        children,
+        local_clients,
+        consequences: Vec::new(),
+        errors,
+    };
+    hints.consequences = summarize(&hints);
+    Ok(hints)
+}
@@ -0,0 +1,95 @@
+// src-tauri/src/details.rs
+// Detalles de un proceso para el panel de inspección: línea de comandos,
+// rutas, usuario, tiempos, hilos, handles, integridad, prioridad, afinidad
+// y arquitectura
+
+use serde::Serialize;
+use sysinfo::{Pid, PidExt, ProcessExt, SystemExt};
+use tauri::State;
+
+use crate::arch::{self, ProcessArchitecture};
+use crate::cores::{self, ProcessAffinity};
+use crate::priority::{self, PriorityClass};
+use crate::AppState;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ProcessDetails {
+    pub pid: u32,
+    pub name: String,
+    pub command_line: Vec<String>,
+    pub executable_path: Option<String>,
+    pub working_directory: Option<String>,
+    pub user: Option<String>,     // DOMINIO\usuario o UID
+    pub user_sid: Option<String>, // Solo en Windows
+    pub start_time: u64,          // Segundos desde UNIX_EPOCH
+    pub run_time_secs: u64,
+    pub thread_count: Option<usize>,
+    pub handle_count: Option<u32>,
+    pub integrity_level: Option<String>, // untrusted, low, medium, high, system, protected
+    pub elevated: Option<bool>,
+    pub priority: Option<PriorityClass>, // None si no hay acceso al proceso
+    pub affinity: Option<ProcessAffinity>,
+    pub architecture: Option<ProcessArchitecture>, // Solo en Windows
+}
+
+fn non_empty(path: &std::path::Path) -> Option<String> {
+    let path = path.to_string_lossy();
+    (!path.is_empty()).then(|| path.to_string())
+}
+
+#[tauri::command]
+pub fn get_process_details(state: State<AppState>, pid: u32) -> Result<ProcessDetails, String> {
+    #[cfg_attr(target_os = "macos", allow(unused_mut))]
+    let mut details = {
+        let mut system = state.system.write().unwrap();
+        if !system.refresh_process(Pid::from_u32(pid)) {
+            return Err(format!("El proceso {} ya no está en ejecución", pid));
+        }
+        let process = system.process(Pid::from_u32(pid)).unwrap();
+        ProcessDetails {
+            pid,
+            name: process.name().to_string(),
+            command_line: process.cmd().to_vec(),
+            executable_path: non_empty(process.exe()),
+            working_directory: non_empty(process.cwd()),
+            user: process.user_id().map(|uid| uid.to_string()),
+            user_sid: None,
+            start_time: process.start_time(),
+            run_time_secs: process.run_time(),
+            thread_count: None,
+            handle_count: None,
+            integrity_level: None,
+            elevated: None,
+            priority: priority::query(pid).0,
+            affinity: cores::get_process_affinity(pid).ok(),
+            architecture: arch::query(pid),
+        }
+    };
+
+    // sysinfo no da la ruta de los procesos protegidos ni los datos del token
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::Security::TOKEN_QUERY;
+
+        if details.executable_path.is_none() {
+            details.executable_path = crate::win::process_image_path(pid);
+        }
+        details.thread_count = crate::win::thread_ids(pid).ok().map(|threads| threads.len());
+        details.handle_count = crate::win::process_handle_count(pid);
+        if let Ok(token) = crate::security::open_process_token(pid, TOKEN_QUERY) {
+            if let Ok((account, sid)) = crate::security::token_user(&token) {
+                details.user = account.or(details.user);
+                details.user_sid = Some(sid);
+            }
+            (details.integrity_level, details.elevated) = crate::security::token_integrity(&token);
+        }
+    }
+
+    // Linux: un subdirectorio por hilo en /proc/<pid>/task
+    #[cfg(target_os = "linux")]
+    {
+        details.thread_count = std::fs::read_dir(format!("/proc/{}/task", pid)).ok().map(|tasks| tasks.count());
+    }
+
+    Ok(details)
+}
@@ -0,0 +1,299 @@
+// src-tauri/src/disks.rs
+// Métricas de discos físicos, de sus particiones (espacio y transferencia) y
+// de la papelera de reciclaje de sus unidades
+
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+use sysinfo::{DiskExt, DiskType, SystemExt};
+use tauri::State;
+
+use crate::AppState;
+
+// Latencia y cola por disco físico: permite distinguir un disco lento de uno
+// saturado por volumen de transferencia
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DiskPerformance {
+    pub disk: String,              // Instancia PDH, p. ej. "0 C:"
+    pub read_latency_ms: f64,
+    pub write_latency_ms: f64,
+    pub queue_length: f64,         // Cola actual
+    pub avg_queue_length: f64,
+    pub active_time_percent: f64,
+    #[serde(default)]
+    pub recycle_bin: Vec<RecycleBinUsage>, // Una entrada por unidad del disco
+}
+
+// Una partición o volumen montado, para las gráficas de disco
+#[derive(Debug, Serialize, Clone)]
+pub struct DiskInfo {
+    pub name: String,
+    pub mount_point: String,
+    pub file_system: String,
+    pub kind: String, // "ssd", "hdd" o "unknown"
+    pub removable: bool,
+    pub total_space: u64,
+    pub available_space: u64,
+    pub read_rate: f64, // Bytes/s desde la consulta anterior
+    pub write_rate: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RecycleBinUsage {
+    pub drive: String, // "C:"
+    pub size: u64,
+    pub item_count: u64,
+}
+
+// Unidades con letra de una instancia PDH ("0 C: D:" -> ["C:", "D:"])
+#[cfg(target_os = "windows")]
+fn instance_drives(instance: &str) -> impl Iterator<Item = &str> {
+    instance.split_whitespace().filter(|part| part.len() == 2 && part.ends_with(':'))
+}
+
+// None si la unidad no tiene papelera (p. ej. extraíbles o de red)
+#[cfg(target_os = "windows")]
+fn recycle_bin_usage(drive: &str) -> Option<RecycleBinUsage> {
+    use windows::core::PCWSTR;
+    use windows::Win32::UI::Shell::{SHQueryRecycleBinW, SHQUERYRBINFO};
+
+    let root = crate::win::to_wide(&format!("{}\\", drive));
+    let mut info = SHQUERYRBINFO { cbSize: std::mem::size_of::<SHQUERYRBINFO>() as u32, ..Default::default() };
+    unsafe { SHQueryRecycleBinW(PCWSTR(root.as_ptr()), &mut info) }.ok()?;
+    Some(RecycleBinUsage {
+        drive: drive.to_string(),
+        size: info.i64Size.max(0) as u64,
+        item_count: info.i64NumItems.max(0) as u64,
+    })
+}
+
+#[cfg(target_os = "windows")]
+#[derive(Default)]
+pub struct DiskPerfCollector {
+    query: Option<crate::pdh::PdhQuery>,
+    volume_query: Option<crate::pdh::PdhQuery>, // Transferencia por volumen (LogicalDisk)
+}
+
+#[cfg(not(target_os = "windows"))]
+#[derive(Default)]
+pub struct DiskPerfCollector {
+    #[cfg(target_os = "linux")]
+    last_diskstats: Option<(std::time::Instant, HashMap<String, (u64, u64)>)>,
+}
+
+#[cfg(target_os = "windows")]
+const DISK_COUNTERS: [&str; 5] = [
+    r"\PhysicalDisk(*)\Avg. Disk sec/Read",
+    r"\PhysicalDisk(*)\Avg. Disk sec/Write",
+    r"\PhysicalDisk(*)\Current Disk Queue Length",
+    r"\PhysicalDisk(*)\Avg. Disk Queue Length",
+    r"\PhysicalDisk(*)\% Idle Time",
+];
+
+#[cfg(target_os = "windows")]
+impl DiskPerfCollector {
+    pub fn sample(&mut self) -> Result<Vec<DiskPerformance>, String> {
+        if self.query.is_none() {
+            let mut query = crate::pdh::PdhQuery::open()?;
+            for path in DISK_COUNTERS {
+                query.add_counter(path)?;
+            }
+            // Los contadores promediados necesitan dos muestras
+            query.collect()?;
+            std::thread::sleep(std::time::Duration::from_millis(250));
+            self.query = Some(query);
+        }
+        let query = self.query.as_ref().unwrap();
+        query.collect()?;
+
+        let mut values = Vec::new();
+        for index in 0..DISK_COUNTERS.len() {
+            values.push(query.values(index)?.into_iter().collect::<std::collections::HashMap<_, _>>());
+        }
+        let value = |index: usize, disk: &str| values[index].get(disk).copied().unwrap_or(0.0);
+
+        let mut disks: Vec<DiskPerformance> = values[0]
+            .keys()
+            .filter(|disk| *disk != "_Total")
+            .map(|disk| DiskPerformance {
+                disk: disk.clone(),
+                read_latency_ms: value(0, disk) * 1000.0,
+                write_latency_ms: value(1, disk) * 1000.0,
+                queue_length: value(2, disk),
+                avg_queue_length: value(3, disk),
+                active_time_percent: (100.0 - value(4, disk)).clamp(0.0, 100.0),
+                recycle_bin: instance_drives(disk).filter_map(recycle_bin_usage).collect(),
+            })
+            .collect();
+        disks.sort_by(|a, b| a.disk.cmp(&b.disk));
+        Ok(disks)
+    }
+
+    // Bytes/s leídos y escritos por unidad ("C:")
+    pub fn throughput(&mut self) -> Result<HashMap<String, (f64, f64)>, String> {
+        if self.volume_query.is_none() {
+            let mut query = crate::pdh::PdhQuery::open()?;
+            query.add_counter(r"\LogicalDisk(*)\Disk Read Bytes/sec")?;
+            query.add_counter(r"\LogicalDisk(*)\Disk Write Bytes/sec")?;
+            // Las tasas necesitan dos muestras
+            query.collect()?;
+            std::thread::sleep(std::time::Duration::from_millis(250));
+            self.volume_query = Some(query);
+        }
+        let query = self.volume_query.as_ref().unwrap();
+        query.collect()?;
+        let writes: HashMap<String, f64> = query.values(1)?.into_iter().collect();
+        Ok(query
+            .values(0)?
+            .into_iter()
+            .filter(|(volume, _)| volume != "_Total")
+            .map(|(volume, read)| {
+                let write = writes.get(&volume).copied().unwrap_or(0.0);
+                (volume, (read, write))
+            })
+            .collect())
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+impl DiskPerfCollector {
+    pub fn sample(&mut self) -> Result<Vec<DiskPerformance>, String> {
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+
+    // Bytes/s leídos y escritos por dispositivo ("sda1"), a partir de los
+    // sectores de /proc/diskstats (siempre de 512 bytes)
+    #[cfg(target_os = "linux")]
+    pub fn throughput(&mut self) -> Result<HashMap<String, (f64, f64)>, String> {
+        fn read() -> Result<HashMap<String, (u64, u64)>, String> {
+            let content = std::fs::read_to_string("/proc/diskstats")
+                .map_err(|e| format!("No se pudo leer /proc/diskstats: {}", e))?;
+            Ok(content
+                .lines()
+                .filter_map(|line| {
+                    // mayor menor nombre lecturas fusionadas sectores_leídos ms
+                    // escrituras fusionadas sectores_escritos ...
+                    let fields: Vec<&str> = line.split_whitespace().collect();
+                    let sectors = |index: usize| fields.get(index)?.parse::<u64>().ok();
+                    Some((fields.get(2)?.to_string(), (sectors(5)? * 512, sectors(9)? * 512)))
+                })
+                .collect())
+        }
+
+        if self.last_diskstats.is_none() {
+            self.last_diskstats = Some((std::time::Instant::now(), read()?));
+            std::thread::sleep(std::time::Duration::from_millis(250));
+        }
+        let now = std::time::Instant::now();
+        let current = read()?;
+        let (at, previous) = self.last_diskstats.replace((now, current.clone())).unwrap();
+        let seconds = (now - at).as_secs_f64().max(0.001);
+        Ok(current
+            .into_iter()
+            .map(|(device, (read, written))| {
+                let (last_read, last_written) = previous.get(&device).copied().unwrap_or((read, written));
+                let rate = |value: u64, last: u64| value.saturating_sub(last) as f64 / seconds;
+                (device, (rate(read, last_read), rate(written, last_written)))
+            })
+            .collect())
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn throughput(&mut self) -> Result<HashMap<String, (f64, f64)>, String> {
+        Ok(HashMap::new())
+    }
+}
+
+#[tauri::command]
+pub fn get_disk_performance(state: State<AppState>) -> Result<Vec<DiskPerformance>, String> {
+    state.disk_perf.lock().unwrap().sample()
+}
+
+// Clave con la que los contadores identifican la partición: la unidad ("C:")
+// en Windows, el dispositivo sin /dev/ en Linux
+fn throughput_key(name: &str, mount_point: &str) -> String {
+    if cfg!(target_os = "windows") {
+        mount_point.trim_end_matches('\\').to_uppercase()
+    } else {
+        name.trim_start_matches("/dev/").to_string()
+    }
+}
+
+#[tauri::command]
+pub fn get_disks(state: State<AppState>) -> Result<Vec<DiskInfo>, String> {
+    // Sin contadores se devuelve igualmente el espacio, con transferencia 0
+    let throughput = state.disk_perf.lock().unwrap().throughput().unwrap_or_default();
+    let mut system = state.system.write().unwrap();
+    system.refresh_disks();
+    let mut disks: Vec<DiskInfo> = system
+        .disks()
+        .iter()
+        .map(|disk| {
+            let name = disk.name().to_string_lossy().to_string();
+            let mount_point = disk.mount_point().display().to_string();
+            let key = throughput_key(&name, &mount_point);
+            let (read_rate, write_rate) = throughput.get(&key).copied().unwrap_or_default();
+            DiskInfo {
+                file_system: String::from_utf8_lossy(disk.file_system()).to_string(),
+                kind: match disk.type_() {
+                    DiskType::SSD => "ssd",
+                    DiskType::HDD => "hdd",
+                    DiskType::Unknown(_) => "unknown",
+                }
+                .to_string(),
+                removable: disk.is_removable(),
+                total_space: disk.total_space(),
+                available_space: disk.available_space(),
+                name,
+                mount_point,
+                read_rate,
+                write_rate,
+            }
+        })
+        .collect();
+    disks.sort_by(|a, b| a.mount_point.cmp(&b.mount_point));
+    Ok(disks)
+}
+
+// Vacía la papelera de una unidad ("C:") o, sin unidad, la de todas. La
+// confirmación la pide la UI, así que Windows no muestra diálogo ni progreso
+#[tauri::command]
+pub fn empty_recycle_bin(state: State<AppState>, drive: Option<String>) -> Result<(), String> {
+    #[cfg(target_os = "windows")]
+    {
+        use crate::audit::AuditOrigin;
+        use windows::core::PCWSTR;
+        use windows::Win32::Foundation::HWND;
+        use windows::Win32::UI::Shell::{
+            SHEmptyRecycleBinW, SHQueryRecycleBinW, SHERB_NOCONFIRMATION, SHERB_NOPROGRESSUI, SHERB_NOSOUND,
+            SHQUERYRBINFO,
+        };
+
+        let drive = drive.map(|drive| drive.trim().trim_end_matches(['\\', '/']).to_uppercase());
+        if drive.as_ref().is_some_and(|drive| drive.len() != 2 || !drive.ends_with(':')) {
+            return Err("La unidad debe indicarse como \"C:\"".to_string());
+        }
+        let root = drive.as_ref().map(|drive| crate::win::to_wide(&format!("{}\\", drive)));
+        let root = root.as_ref().map_or(PCWSTR::null(), |root| PCWSTR(root.as_ptr()));
+
+        let mut info = SHQUERYRBINFO { cbSize: std::mem::size_of::<SHQUERYRBINFO>() as u32, ..Default::default() };
+        unsafe { SHQueryRecycleBinW(root, &mut info) }
+            .map_err(|e| format!("No se pudo consultar la papelera: {}", e))?;
+        // Con la papelera ya vacía SHEmptyRecycleBinW devuelve error
+        if info.i64NumItems == 0 {
+            return Ok(());
+        }
+        let flags = SHERB_NOCONFIRMATION | SHERB_NOPROGRESSUI | SHERB_NOSOUND;
+        let result = unsafe { SHEmptyRecycleBinW(HWND(0), root, flags) }
+            .map_err(|e| format!("No se pudo vaciar la papelera: {}", e));
+        let target = drive.unwrap_or_else(|| "todas las unidades".to_string());
+        let detail = format!("{} elementos, {} bytes", info.i64NumItems, info.i64Size);
+        crate::audit::record(&state, AuditOrigin::User, "empty_recycle_bin", &target, None, Some(detail), &result);
+        result
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = (state, drive);
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
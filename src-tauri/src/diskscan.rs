@@ -0,0 +1,193 @@
+// src-tauri/src/diskscan.rs
+// Analizador de espacio en disco: tamaño de cada carpeta bajo una ruta, para
+// ver qué ocupa el disco tras un aviso de "disco casi lleno". Es una tarea
+// cancelable (jobs.rs) y los últimos resultados se guardan en caché por ruta y
+// profundidad
+
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
+
+use crate::format::FormatOptions;
+use crate::jobs::JobReporter;
+use crate::AppState;
+
+const DEFAULT_DEPTH: u32 = 2;
+const MAX_DEPTH: u32 = 10;
+const MAX_CHILDREN: usize = 100; // Por carpeta, las más grandes
+const CACHE_TTL: Duration = Duration::from_secs(600);
+const CACHE_CAPACITY: usize = 8; // Un análisis profundo puede ocupar bastante memoria
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
+
+#[derive(Debug, Serialize, Clone)]
+pub struct DirectoryUsage {
+    pub path: String,
+    pub name: String,
+    pub size: u64, // Bytes de todos los archivos que contiene, a cualquier profundidad
+    pub file_count: u64,
+    pub dir_count: u64,
+    pub children: Vec<DirectoryUsage>, // Hasta la profundidad pedida, de mayor a menor
+    pub omitted_children: usize,       // Subcarpetas fuera de las MAX_CHILDREN más grandes
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct DiskAnalysis {
+    pub root: DirectoryUsage,
+    pub depth: u32,
+    pub scanned_at: u64,
+    pub duration_ms: u64,
+    pub unreadable: u64, // Entradas sin permiso de lectura; su tamaño no se cuenta
+    pub cached: bool,
+}
+
+// (ruta, profundidad) -> (momento del análisis, resultado)
+#[derive(Debug, Default)]
+pub struct DiskScanCache {
+    entries: HashMap<(PathBuf, u32), (Instant, DiskAnalysis)>,
+}
+
+impl DiskScanCache {
+    fn get(&self, key: &(PathBuf, u32)) -> Option<&DiskAnalysis> {
+        self.entries.get(key).filter(|(at, _)| at.elapsed() < CACHE_TTL).map(|(_, analysis)| analysis)
+    }
+
+    // Descarta los caducados y, si sigue llena, el más antiguo
+    fn insert(&mut self, key: (PathBuf, u32), analysis: DiskAnalysis) {
+        self.entries.retain(|_, (at, _)| at.elapsed() < CACHE_TTL);
+        if !self.entries.contains_key(&key) && self.entries.len() >= CACHE_CAPACITY {
+            let oldest = self.entries.iter().min_by_key(|(_, (at, _))| *at).map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                self.entries.remove(&oldest);
+            }
+        }
+        self.entries.insert(key, (Instant::now(), analysis));
+    }
+}
+
+struct Scan<'a> {
+    job: &'a JobReporter,
+    options: &'a FormatOptions,
+    unreadable: u64,
+    bytes: u64,
+    last_progress: Instant,
+    progress: f32, // Fracción de las carpetas de primer nivel terminadas
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name().map_or_else(|| path.display().to_string(), |name| name.to_string_lossy().to_string())
+}
+
+impl Scan<'_> {
+    // Los enlaces simbólicos y las uniones no se siguen: no se cuenta dos
+    // veces lo mismo ni se entra en ciclos
+    fn directory(&mut self, path: &Path, depth: u32, top_level: bool) -> Result<DirectoryUsage, String> {
+        if self.job.is_cancelled() {
+            return Err("Análisis cancelado".to_string());
+        }
+        let mut usage = DirectoryUsage {
+            path: path.display().to_string(),
+            name: file_name(path),
+            size: 0,
+            file_count: 0,
+            dir_count: 0,
+            children: Vec::new(),
+            omitted_children: 0,
+        };
+        let Ok(entries) = fs::read_dir(path) else {
+            self.unreadable += 1;
+            return Ok(usage);
+        };
+        let (mut files, mut directories) = (Vec::new(), Vec::new());
+        for entry in entries {
+            let Ok(entry) = entry else {
+                self.unreadable += 1;
+                continue;
+            };
+            match entry.file_type() {
+                Ok(kind) if kind.is_dir() => directories.push(entry.path()),
+                Ok(kind) if kind.is_file() => files.push(entry),
+                Ok(_) => {}
+                Err(_) => self.unreadable += 1,
+            }
+        }
+        for file in files {
+            match file.metadata() {
+                Ok(metadata) => {
+                    usage.size += metadata.len();
+                    usage.file_count += 1;
+                }
+                Err(_) => self.unreadable += 1,
+            }
+        }
+        self.bytes += usage.size;
+
+        let count = directories.len();
+        let mut children = Vec::with_capacity(count);
+        for (index, directory) in directories.iter().enumerate() {
+            let child = self.directory(directory, depth.saturating_sub(1), false)?;
+            usage.size += child.size;
+            usage.file_count += child.file_count;
+            usage.dir_count += child.dir_count + 1;
+            children.push(child);
+            if top_level {
+                self.progress = (index + 1) as f32 / count as f32;
+            }
+            if self.last_progress.elapsed() >= PROGRESS_INTERVAL {
+                self.last_progress = Instant::now();
+                let counted = crate::format::format_bytes(self.bytes as f64, self.options);
+                self.job.progress(self.progress, format!("{} ({})", directory.display(), counted));
+            }
+        }
+        if depth > 0 {
+            children.sort_by_key(|child| std::cmp::Reverse(child.size));
+            usage.omitted_children = children.len().saturating_sub(MAX_CHILDREN);
+            children.truncate(MAX_CHILDREN);
+            usage.children = children;
+        }
+        Ok(usage)
+    }
+}
+
+fn analyze(job: &JobReporter, options: &FormatOptions, path: &Path, depth: u32) -> Result<DiskAnalysis, String> {
+    let started = Instant::now();
+    let mut scan = Scan { job, options, unreadable: 0, bytes: 0, last_progress: Instant::now(), progress: 0.0 };
+    let root = scan.directory(path, depth, true)?;
+    job.progress(1.0, format!("{} analizado", path.display()));
+    Ok(DiskAnalysis {
+        root,
+        depth,
+        scanned_at: crate::stats::now_millis(),
+        duration_ms: started.elapsed().as_millis() as u64,
+        unreadable: scan.unreadable,
+        cached: false,
+    })
+}
+
+// Tarea "analyze_disk"; el resultado es un DiskAnalysis. Con `refresh` se
+// ignora la caché aunque el análisis sea reciente
+#[tauri::command]
+pub fn analyze_disk(app: AppHandle, path: String, depth: Option<u32>, refresh: Option<bool>) -> Result<u64, String> {
+    let path = PathBuf::from(path.trim());
+    if !path.is_dir() {
+        return Err(format!("{} no es una carpeta", path.display()));
+    }
+    let depth = depth.unwrap_or(DEFAULT_DEPTH).min(MAX_DEPTH);
+    let refresh = refresh.unwrap_or(false);
+
+    Ok(crate::jobs::spawn(&app.clone(), "analyze_disk", move |job| {
+        let state = app.state::<AppState>();
+        let key = (path.clone(), depth);
+        if !refresh {
+            if let Some(analysis) = state.disk_scans.lock().unwrap().get(&key) {
+                return Ok(DiskAnalysis { cached: true, ..analysis.clone() });
+            }
+        }
+        let options = state.settings.read().unwrap().format;
+        let analysis = analyze(job, &options, &path, depth)?;
+        state.disk_scans.lock().unwrap().insert(key, analysis.clone());
+        Ok(analysis)
+    }))
+}
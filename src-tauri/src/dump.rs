@@ -0,0 +1,150 @@
+// src-tauri/src/dump.rs
+// Volcado de memoria de un proceso (minivolcado o completo) con
+// MiniDumpWriteDump, para abrirlo después en un depurador. Es una tarea de
+// jobs.rs: el progreso llega por eventos y se puede cancelar
+
+use std::path::PathBuf;
+use tauri::{AppHandle, State};
+
+use crate::AppState;
+
+// `output_path` puede ser el archivo .dmp o una carpeta; en ese caso el
+// nombre es <proceso>_<pid>_<marca de tiempo>.dmp
+fn dump_path(output_path: &str, name: &str, pid: u32) -> Result<PathBuf, String> {
+    let path = PathBuf::from(output_path.trim());
+    if path.as_os_str().is_empty() {
+        return Err("Indica dónde guardar el volcado".to_string());
+    }
+    if !path.is_dir() {
+        return Ok(path);
+    }
+    let stem = name.strip_suffix(".exe").unwrap_or(name);
+    Ok(path.join(format!("{}_{}_{}.dmp", stem, pid, crate::stats::now_millis())))
+}
+
+#[cfg(target_os = "windows")]
+mod minidump {
+    use std::os::windows::io::AsRawHandle;
+    use std::path::Path;
+    use std::time::{Duration, Instant};
+    use windows::Win32::Foundation::{BOOL, HANDLE};
+    use windows::Win32::System::Diagnostics::Debug::*;
+    use windows::Win32::System::Threading::{PROCESS_QUERY_INFORMATION, PROCESS_VM_READ};
+
+    use crate::jobs::JobReporter;
+
+    const PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
+
+    struct Progress<'a> {
+        job: &'a JobReporter,
+        total: usize, // Hilos más módulos, para estimar el avance
+        seen: usize,
+        last: Instant,
+    }
+
+    unsafe extern "system" fn callback(
+        param: *mut core::ffi::c_void,
+        input: *const MINIDUMP_CALLBACK_INPUT,
+        output: *mut MINIDUMP_CALLBACK_OUTPUT,
+    ) -> BOOL {
+        let progress = &mut *(param as *mut Progress);
+        let kind = MINIDUMP_CALLBACK_TYPE((*input).CallbackType as i32);
+        if kind == CancelCallback {
+            // Se pide que vuelva a preguntar mientras dure la escritura
+            (*output).Anonymous.Anonymous2.CheckCancel = BOOL(1);
+            (*output).Anonymous.Anonymous2.Cancel = BOOL::from(progress.job.is_cancelled());
+        } else if kind == ThreadCallback || kind == ThreadExCallback || kind == ModuleCallback {
+            progress.seen += 1;
+            if progress.last.elapsed() >= PROGRESS_INTERVAL {
+                progress.last = Instant::now();
+                let fraction = progress.seen as f32 / progress.total.max(1) as f32;
+                // La última parte (la memoria) no tiene avance medible
+                progress.job.progress(fraction * 0.9, format!("Leyendo hilos y módulos ({})", progress.seen));
+            }
+        }
+        BOOL(1)
+    }
+
+    pub fn write(job: &JobReporter, pid: u32, full: bool, path: &Path) -> Result<(), String> {
+        // Sin el privilegio aún se pueden volcar los procesos del mismo usuario
+        let _ = crate::security::enable_privilege("SeDebugPrivilege");
+        let process = crate::win::open_process(pid, PROCESS_QUERY_INFORMATION | PROCESS_VM_READ)?;
+
+        let threads = crate::win::thread_ids(pid).map(|ids| ids.len()).unwrap_or(0);
+        let modules = crate::modules::get_process_modules(pid).map(|modules| modules.len()).unwrap_or(0);
+        let mut progress = Progress { job, total: threads + modules, seen: 0, last: Instant::now() };
+        let information = MINIDUMP_CALLBACK_INFORMATION {
+            CallbackRoutine: Some(callback),
+            CallbackParam: &mut progress as *mut Progress as *mut _,
+        };
+
+        let dump_type = if full {
+            MINIDUMP_TYPE(
+                MiniDumpWithFullMemory.0
+                    | MiniDumpWithFullMemoryInfo.0
+                    | MiniDumpWithHandleData.0
+                    | MiniDumpWithThreadInfo.0
+                    | MiniDumpWithUnloadedModules.0
+                    | MiniDumpWithTokenInformation.0,
+            )
+        } else {
+            MINIDUMP_TYPE(
+                MiniDumpWithDataSegs.0
+                    | MiniDumpWithHandleData.0
+                    | MiniDumpWithThreadInfo.0
+                    | MiniDumpWithUnloadedModules.0
+                    | MiniDumpWithIndirectlyReferencedMemory.0,
+            )
+        };
+
+        let file = std::fs::File::create(path).map_err(|e| format!("No se pudo crear {}: {}", path.display(), e))?;
+        let file_handle = HANDLE(file.as_raw_handle() as isize);
+        let written =
+            unsafe { MiniDumpWriteDump(process.0, pid, file_handle, dump_type, None, None, Some(&information)) }.as_bool();
+        if !written {
+            let error = windows::core::Error::from_win32();
+            drop(file);
+            let _ = std::fs::remove_file(path);
+            if job.is_cancelled() {
+                return Err("Volcado cancelado".to_string());
+            }
+            return Err(format!("No se pudo escribir el volcado del proceso {}: {}", pid, error));
+        }
+        Ok(())
+    }
+}
+
+// Tarea "process_dump"; el resultado es la ruta del archivo escrito. `full`
+// incluye toda la memoria del proceso (el archivo ocupa lo que su memoria)
+#[tauri::command]
+pub fn create_process_dump(
+    app: AppHandle,
+    state: State<AppState>,
+    pid: u32,
+    full: bool,
+    output_path: String,
+) -> Result<u64, String> {
+    #[cfg(target_os = "windows")]
+    {
+        use crate::audit::AuditOrigin;
+        use tauri::Manager;
+
+        let name = crate::audit::process_name(&state, pid);
+        let path = dump_path(&output_path, &name, pid)?;
+        let handle = app.clone();
+        Ok(crate::jobs::spawn(&app, "process_dump", move |job| {
+            job.progress(0.0, format!("Volcando {} ({})", name, pid));
+            let result = minidump::write(job, pid, full, &path).map(|_| path.display().to_string());
+            let detail = format!("{} ({})", path.display(), if full { "completo" } else { "minivolcado" });
+            let state = handle.state::<AppState>();
+            crate::audit::record(&state, AuditOrigin::User, "process_dump", &name, Some(pid), Some(detail), &result);
+            result
+        }))
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = (app, state, pid, full, output_path, dump_path);
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
@@ -0,0 +1,222 @@
+// src-tauri/src/etw.rs
+// Sesión ETW del kernel ("NT Kernel Logger") en tiempo real. Requiere
+// privilegios de administrador; los eventos se reparten a un manejador.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use windows::core::{GUID, PWSTR};
+use windows::Win32::Foundation::ERROR_ALREADY_EXISTS;
+use windows::Win32::System::Diagnostics::Etw::{
+    CloseTrace, ControlTraceW, OpenTraceW, ProcessTrace, StartTraceW, CONTROLTRACE_HANDLE,
+    EVENT_HEADER_FLAG_32_BIT_HEADER, EVENT_RECORD, EVENT_TRACE_CONTROL_STOP, EVENT_TRACE_LOGFILEW,
+    EVENT_TRACE_PROPERTIES, EVENT_TRACE_REAL_TIME_MODE, KERNEL_LOGGER_NAMEW,
+    PROCESSTRACE_HANDLE, PROCESS_TRACE_MODE_EVENT_RECORD, PROCESS_TRACE_MODE_REAL_TIME,
+    SystemTraceControlGuid, WNODE_FLAG_TRACED_GUID,
+};
+
+pub use windows::Win32::System::Diagnostics::Etw::{
+    EVENT_TRACE_FLAG, EVENT_TRACE_FLAG_DISK_FILE_IO, EVENT_TRACE_FLAG_FILE_IO, EVENT_TRACE_FLAG_FILE_IO_INIT,
+    EVENT_TRACE_FLAG_NETWORK_TCPIP, EVENT_TRACE_FLAG_REGISTRY, EVENT_TRACE_FLAG_THREAD,
+};
+
+// Proveedores de eventos clásicos del kernel
+pub const TCPIP_GUID: GUID = GUID::from_u128(0x9a280ac0_c8e0_11d1_84e2_00c04fb998a2);
+pub const UDPIP_GUID: GUID = GUID::from_u128(0xbf3a50c5_a9c9_4988_a005_2df0b7c80f80);
+pub const FILEIO_GUID: GUID = GUID::from_u128(0x90cbdc39_4a3e_11d1_84f4_0000f80464e3);
+pub const REGISTRY_GUID: GUID = GUID::from_u128(0xae53722e_c863_11d2_8659_00c04fa321a1);
+pub const THREAD_GUID: GUID = GUID::from_u128(0x3d6fa8d1_fe05_11d0_9dda_00c04fd7ba7c);
+
+// Evento del kernel ya desempaquetado
+pub struct KernelEvent<'a> {
+    pub provider: GUID,
+    pub opcode: u8,
+    pub header_pid: u32,
+    pub pointer_size: usize,
+    pub data: &'a [u8],
+}
+
+impl KernelEvent<'_> {
+    pub fn u32_at(&self, offset: usize) -> Option<u32> {
+        self.data
+            .get(offset..offset + 4)
+            .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    pub fn u64_at(&self, offset: usize) -> Option<u64> {
+        self.data
+            .get(offset..offset + 8)
+            .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    // Campo de tamaño puntero (4 u 8 bytes según la cabecera del evento)
+    pub fn pointer_at(&self, offset: usize) -> Option<u64> {
+        if self.pointer_size == 4 {
+            self.u32_at(offset).map(u64::from)
+        } else {
+            self.u64_at(offset)
+        }
+    }
+
+    // Cadena UTF-16 terminada en nulo a partir de `offset`
+    pub fn wide_string_at(&self, offset: usize) -> Option<String> {
+        let bytes = self.data.get(offset..)?;
+        let units: Vec<u16> = bytes
+            .chunks_exact(2)
+            .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+            .take_while(|&unit| unit != 0)
+            .collect();
+        Some(String::from_utf16_lossy(&units))
+    }
+
+    // PID del hilo que emitió un evento cuya carga solo trae el TID
+    pub fn pid_for_thread(&self, thread_id: u32) -> u32 {
+        THREADS
+            .lock()
+            .unwrap()
+            .as_ref()
+            .and_then(|threads| threads.get(&thread_id).copied())
+            .unwrap_or(self.header_pid)
+    }
+}
+
+// Mapa TID -> PID alimentado por los eventos Thread (Start/DCStart/End/DCEnd)
+static THREADS: Mutex<Option<HashMap<u32, u32>>> = Mutex::new(None);
+
+fn track_thread(event: &KernelEvent) {
+    if event.provider != THREAD_GUID {
+        return;
+    }
+    if let (Some(pid), Some(tid)) = (event.u32_at(0), event.u32_at(4)) {
+        let mut threads = THREADS.lock().unwrap();
+        let threads = threads.get_or_insert_with(HashMap::new);
+        match event.opcode {
+            1 | 3 => {
+                threads.insert(tid, pid);
+            }
+            2 | 4 => {
+                threads.remove(&tid);
+            }
+            _ => {}
+        }
+    }
+}
+
+type Handler = Box<dyn Fn(&KernelEvent) + Send + Sync>;
+
+struct Session {
+    control: CONTROLTRACE_HANDLE,
+    trace: PROCESSTRACE_HANDLE,
+    thread: std::thread::JoinHandle<()>,
+    handler: *mut Handler,
+}
+
+// El puntero al manejador solo se libera en `stop`, tras terminar el hilo
+unsafe impl Send for Session {}
+
+static SESSION: Mutex<Option<Session>> = Mutex::new(None);
+
+unsafe extern "system" fn on_event(record: *mut EVENT_RECORD) {
+    let record = &*record;
+    if record.UserContext.is_null() {
+        return;
+    }
+    let handler = &*(record.UserContext as *const Handler);
+    let data = if record.UserData.is_null() {
+        &[][..]
+    } else {
+        std::slice::from_raw_parts(record.UserData as *const u8, record.UserDataLength as usize)
+    };
+    let header = &record.EventHeader;
+    let event = KernelEvent {
+        provider: header.ProviderId,
+        opcode: header.EventDescriptor.Opcode,
+        header_pid: header.ProcessId,
+        pointer_size: if header.Flags as u32 & EVENT_HEADER_FLAG_32_BIT_HEADER != 0 { 4 } else { 8 },
+        data,
+    };
+    track_thread(&event);
+    handler(&event);
+}
+
+// Búfer de EVENT_TRACE_PROPERTIES seguido del nombre de la sesión
+fn properties_buffer(flags: EVENT_TRACE_FLAG) -> Vec<u64> {
+    let name_bytes = (unsafe { KERNEL_LOGGER_NAMEW.as_wide() }.len() + 1) * 2;
+    let size = std::mem::size_of::<EVENT_TRACE_PROPERTIES>() + name_bytes;
+    let mut buffer = vec![0u64; size.div_ceil(8)];
+    let properties = unsafe { &mut *(buffer.as_mut_ptr() as *mut EVENT_TRACE_PROPERTIES) };
+    properties.Wnode.BufferSize = size as u32;
+    properties.Wnode.Guid = SystemTraceControlGuid;
+    properties.Wnode.ClientContext = 1; // Marcas de tiempo QPC
+    properties.Wnode.Flags = WNODE_FLAG_TRACED_GUID;
+    properties.LogFileMode = EVENT_TRACE_REAL_TIME_MODE;
+    properties.EnableFlags = flags;
+    properties.LoggerNameOffset = std::mem::size_of::<EVENT_TRACE_PROPERTIES>() as u32;
+    buffer
+}
+
+fn stop_kernel_session(control: CONTROLTRACE_HANDLE) {
+    let mut buffer = properties_buffer(EVENT_TRACE_FLAG(0));
+    unsafe {
+        ControlTraceW(
+            control,
+            KERNEL_LOGGER_NAMEW,
+            buffer.as_mut_ptr() as *mut EVENT_TRACE_PROPERTIES,
+            EVENT_TRACE_CONTROL_STOP,
+        )
+    };
+}
+
+// Inicia la sesión con los flags indicados y procesa eventos en un hilo propio
+pub fn start(flags: EVENT_TRACE_FLAG, handler: impl Fn(&KernelEvent) + Send + Sync + 'static) -> Result<(), String> {
+    // Solo se detiene la sesión propia: "NT Kernel Logger" es única en el
+    // sistema y puede tenerla otra herramienta (WPR, Process Monitor, xperf)
+    stop();
+
+    let mut buffer = properties_buffer(flags);
+    let mut control = CONTROLTRACE_HANDLE::default();
+    let status = unsafe {
+        StartTraceW(&mut control, KERNEL_LOGGER_NAMEW, buffer.as_mut_ptr() as *mut EVENT_TRACE_PROPERTIES)
+    };
+    if status == ERROR_ALREADY_EXISTS {
+        return Err("La sesión ETW del kernel ya está en uso por otra herramienta (WPR, Process Monitor, xperf...)"
+            .to_string());
+    }
+    if status.is_err() {
+        return Err(format!(
+            "No se pudo iniciar la sesión ETW del kernel (¿se ejecuta como administrador?): {}",
+            windows::core::Error::from(status.to_hresult())
+        ));
+    }
+
+    let context = Box::into_raw(Box::new(Box::new(handler) as Handler));
+    let mut logfile = EVENT_TRACE_LOGFILEW {
+        LoggerName: PWSTR(KERNEL_LOGGER_NAMEW.as_ptr() as *mut u16),
+        Context: context as *mut _,
+        ..Default::default()
+    };
+    logfile.Anonymous1.ProcessTraceMode = PROCESS_TRACE_MODE_REAL_TIME | PROCESS_TRACE_MODE_EVENT_RECORD;
+    logfile.Anonymous2.EventRecordCallback = Some(on_event);
+
+    let trace = unsafe { OpenTraceW(&mut logfile) };
+    if trace.0 == u64::MAX {
+        stop_kernel_session(control);
+        drop(unsafe { Box::from_raw(context) });
+        return Err(format!("No se pudo abrir la sesión ETW: {}", windows::core::Error::from_win32()));
+    }
+
+    let thread = std::thread::spawn(move || {
+        // Bloquea hasta que se detiene la sesión
+        unsafe { ProcessTrace(&[trace], None, None) };
+    });
+    *SESSION.lock().unwrap() = Some(Session { control, trace, thread, handler: context });
+    Ok(())
+}
+
+pub fn stop() {
+    if let Some(session) = SESSION.lock().unwrap().take() {
+        stop_kernel_session(session.control);
+        unsafe { CloseTrace(session.trace) };
+        let _ = session.thread.join();
+        drop(unsafe { Box::from_raw(session.handler) });
+    }
+}
@@ -0,0 +1,114 @@
+// src-tauri/src/exitcodes.rs
+// Códigos de salida de los procesos que la aplicación termina o vigila, para
+// poder responder después a "¿falló o terminó bien?". Solo se pueden leer con
+// un handle abierto antes de que el proceso salga, así que se capturan donde
+// ya se tiene uno: al terminarlo, en las vigilancias por PID y en los avisos
+// de fin. La entrada de auditoría de una terminación incluye el código, que
+// se completa después si el proceso tarda en salir
+
+use serde::Serialize;
+use std::collections::VecDeque;
+use tauri::State;
+
+use crate::AppState;
+
+const CAPACITY: usize = 500;
+// Antigüedad máxima para asociar un código a la acción que lo provocó
+const AUDIT_WINDOW_MS: u64 = 10_000;
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExitKind {
+    Success, // 0
+    Error,   // Código propio del programa
+    Crash,   // NTSTATUS de error (0xC0000000 en adelante), p. ej. 0xC0000005
+}
+
+impl ExitKind {
+    pub fn of(code: u32) -> Self {
+        match code {
+            0 => ExitKind::Success,
+            0xC000_0000.. => ExitKind::Crash,
+            _ => ExitKind::Error,
+        }
+    }
+}
+
+// Los NTSTATUS se leen mejor en hexadecimal
+pub fn code_text(code: u32) -> String {
+    if code > 0xFFFF { format!("0x{:08X}", code) } else { code.to_string() }
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ExitRecord {
+    pub pid: u32,
+    pub name: String,
+    pub exit_code: u32,
+    pub kind: ExitKind,
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct ExitCodes {
+    recent: VecDeque<ExitRecord>,
+}
+
+impl ExitCodes {
+    pub fn record(&mut self, pid: u32, name: &str, exit_code: u32) {
+        if self.recent.len() == CAPACITY {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(ExitRecord {
+            pid,
+            name: name.to_string(),
+            exit_code,
+            kind: ExitKind::of(exit_code),
+            timestamp: crate::stats::now_millis(),
+        });
+    }
+
+    // Código recién capturado del PID, para la entrada de auditoría
+    pub fn just_captured(&self, pid: u32) -> Option<u32> {
+        let now = crate::stats::now_millis();
+        self.recent
+            .iter()
+            .rev()
+            .take_while(|record| now.saturating_sub(record.timestamp) <= AUDIT_WINDOW_MS)
+            .find(|record| record.pid == pid)
+            .map(|record| record.exit_code)
+    }
+}
+
+// Anota el código de salida del proceso del handle, que se acaba de terminar.
+// Casi siempre ya ha salido al volver taskkill y el código queda a tiempo
+// para la entrada de auditoría; si no, se espera en segundo plano para no
+// bloquear el comando y se completa la entrada ya escrita. El registro de
+// auditoría se bloquea antes que los códigos, igual que en audit::record, para
+// que el código no caiga entre la lectura y la escritura de la entrada
+#[cfg(target_os = "windows")]
+pub fn capture(state: &AppState, pid: u32, process: crate::win::OwnedHandle) {
+    use windows::Win32::System::Threading::WaitForSingleObject;
+
+    let name = crate::audit::process_name(state, pid);
+    if let Some(code) = crate::win::exit_code(&process) {
+        state.exit_codes.lock().unwrap().record(pid, &name, code);
+        return;
+    }
+    let (audit, exit_codes) = (state.audit.clone(), state.exit_codes.clone());
+    let since = crate::stats::now_millis();
+    std::thread::spawn(move || {
+        unsafe { WaitForSingleObject(process.0, 2000) };
+        if let Some(code) = crate::win::exit_code(&process) {
+            let mut audit = audit.lock().unwrap();
+            exit_codes.lock().unwrap().record(pid, &name, code);
+            let _ = audit.set_exit_code(pid, since, code);
+        }
+    });
+}
+
+// Del más antiguo al más reciente
+#[tauri::command]
+pub fn get_exit_codes(state: State<AppState>, pid: Option<u32>) -> Vec<ExitRecord> {
+    let exit_codes = state.exit_codes.lock().unwrap();
+    exit_codes.recent.iter().filter(|record| pid.is_none_or(|pid| record.pid == pid)).cloned().collect()
+}
@@ -0,0 +1,172 @@
+// src-tauri/src/exitwatch.rs
+// "Avisarme cuando termine": para procesos largos (renderizados, compilaciones)
+// se abre un handle al proceso y un hilo espera a que salga. Entonces se emite
+// "process-exited" y se avisa como una alerta más (historial y horas de
+// silencio) con el código de salida y la CPU consumida. El handle abierto permite leer ambos aunque el proceso ya
+// haya terminado, cosa que el refresco periódico no puede hacer
+
+use serde::Serialize;
+use tauri::{AppHandle, State};
+
+use crate::exitcodes::ExitKind;
+use crate::AppState;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ExitWatch {
+    pub id: u64,
+    pub pid: u32,
+    pub name: String,
+    pub since: u64,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ProcessExit {
+    pub watch_id: u64,
+    pub pid: u32,
+    pub name: String,
+    pub exit_code: Option<u32>,   // None si no se pudo leer
+    pub cpu_time_ms: Option<u64>, // Usuario y núcleo, durante toda la vida del proceso
+    pub run_time_ms: Option<u64>,
+    pub exited_at: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct ExitWatches {
+    next_id: u64,
+    watches: Vec<ExitWatch>,
+}
+
+fn duration_text(ms: u64) -> String {
+    let secs = ms / 1000;
+    match secs {
+        0..=59 => format!("{:.1} s", ms as f64 / 1000.0),
+        60..=3599 => format!("{} min {} s", secs / 60, secs % 60),
+        _ => format!("{} h {} min", secs / 3600, secs % 3600 / 60),
+    }
+}
+
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn notify(app: &AppHandle, exit: &ProcessExit) {
+    use tauri::Emitter;
+
+    use crate::alerts::{AlertEvent, AlertMetric, AlertTarget};
+
+    let mut body = match exit.exit_code {
+        Some(code) => format!("Código de salida {}", crate::exitcodes::code_text(code)),
+        None => "Código de salida desconocido".to_string(),
+    };
+    if let Some(cpu_time) = exit.cpu_time_ms {
+        body += &format!(" · {} de CPU", duration_text(cpu_time));
+    }
+    if let Some(run_time) = exit.run_time_ms {
+        body += &format!(" en {}", duration_text(run_time));
+    }
+    let title = match exit.exit_code.map(ExitKind::of) {
+        Some(ExitKind::Crash) => format!("Ha fallado {} ({})", exit.name, exit.pid),
+        _ => format!("Ha terminado {} ({})", exit.name, exit.pid),
+    };
+    // Sin regla asociada: rule_id 0 y el código como valor
+    let event = AlertEvent {
+        rule_id: 0,
+        target: AlertTarget::Process(exit.name.clone()),
+        metric: AlertMetric::ExitCode,
+        value: exit.exit_code.unwrap_or(0) as f64,
+        threshold: 0.0,
+        duration_secs: exit.run_time_ms.unwrap_or(0) / 1000,
+        pids: vec![exit.pid],
+        timestamp: exit.exited_at,
+        notification_suppressed: false,
+    };
+    crate::alerts::raise(app, event, Some((title, body)));
+    let _ = app.emit("process-exited", exit);
+}
+
+#[cfg(target_os = "windows")]
+fn wait(app: AppHandle, watch: ExitWatch, process: crate::win::OwnedHandle) {
+    use tauri::Manager;
+    use windows::Win32::Foundation::{WAIT_OBJECT_0, WAIT_TIMEOUT};
+    use windows::Win32::System::Threading::WaitForSingleObject;
+
+    // Se despierta cada segundo para ver si se canceló la vigilancia
+    let registered = || {
+        let state = app.state::<AppState>();
+        let watches = state.exit_watches.lock().unwrap();
+        watches.watches.iter().any(|registered| registered.id == watch.id)
+    };
+    let unregister = || {
+        app.state::<AppState>().exit_watches.lock().unwrap().watches.retain(|registered| registered.id != watch.id);
+    };
+    loop {
+        match unsafe { WaitForSingleObject(process.0, 1000) } {
+            WAIT_OBJECT_0 => break,
+            WAIT_TIMEOUT if registered() => continue,
+            WAIT_TIMEOUT => return,
+            // WAIT_FAILED: el handle ya no sirve y no avisaría nunca
+            _ => return unregister(),
+        }
+    }
+    unregister();
+    let times = crate::win::process_times(&process);
+    let exit = ProcessExit {
+        watch_id: watch.id,
+        pid: watch.pid,
+        name: watch.name,
+        exit_code: crate::win::exit_code(&process),
+        cpu_time_ms: times.map(|times| times.0),
+        run_time_ms: times.map(|times| times.1),
+        exited_at: crate::stats::now_millis(),
+    };
+    if let Some(code) = exit.exit_code {
+        app.state::<AppState>().exit_codes.lock().unwrap().record(exit.pid, &exit.name, code);
+    }
+    notify(&app, &exit);
+}
+
+#[tauri::command]
+pub fn notify_on_exit(app: AppHandle, state: State<AppState>, pid: u32) -> Result<ExitWatch, String> {
+    let name = crate::audit::process_name(&state, pid);
+
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::System::Threading::{PROCESS_QUERY_LIMITED_INFORMATION, PROCESS_SYNCHRONIZE};
+
+        let process = crate::win::open_process(pid, PROCESS_SYNCHRONIZE | PROCESS_QUERY_LIMITED_INFORMATION)?;
+        // Comprobación e inserción con el mismo bloqueo: dos llamadas a la vez
+        // no lanzan dos hilos ni dos avisos
+        let watch = {
+            let mut watches = state.exit_watches.lock().unwrap();
+            if let Some(existing) = watches.watches.iter().find(|watch| watch.pid == pid) {
+                return Ok(existing.clone());
+            }
+            watches.next_id += 1;
+            let watch = ExitWatch { id: watches.next_id, pid, name, since: crate::stats::now_millis() };
+            watches.watches.push(watch.clone());
+            watch
+        };
+        let waiting = watch.clone();
+        std::thread::spawn(move || wait(app, waiting, process));
+        Ok(watch)
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = (app, name);
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
+
+#[tauri::command]
+pub fn list_exit_watches(state: State<AppState>) -> Vec<ExitWatch> {
+    state.exit_watches.lock().unwrap().watches.clone()
+}
+
+#[tauri::command]
+pub fn cancel_exit_watch(state: State<AppState>, id: u64) -> Result<(), String> {
+    let mut watches = state.exit_watches.lock().unwrap();
+    let count = watches.watches.len();
+    watches.watches.retain(|watch| watch.id != id);
+    if watches.watches.len() == count {
+        return Err(format!("No existe la vigilancia {}", id));
+    }
+    Ok(())
+}
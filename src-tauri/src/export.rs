@@ -0,0 +1,240 @@
+// src-tauri/src/export.rs
+// Exportación de datos de procesos (y del historial del sistema) a CSV/JSON
+
+use serde::{Serialize, Deserialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tauri::State;
+
+use crate::format::{format_bytes, FormatOptions};
+use crate::stats::SystemStats;
+use crate::{AppState, ProcessInfo, ProcessTree};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+        }
+    }
+}
+
+// El JSON de un árbol de procesos completo ocupa unas diez veces menos comprimido
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Compression {
+    #[default]
+    None,
+    Gzip,
+}
+
+// Excel solo reconoce un CSV como UTF-8 (nombres en cirílico, CJK...) si
+// empieza con la marca de orden de bytes; sin ella usa la página ANSI
+const UTF8_BOM: &str = "\u{FEFF}";
+
+// Con gzip se añade ".gz" al nombre (snapshot.json -> snapshot.json.gz);
+// devuelve la ruta escrita
+fn write_output(path: &Path, content: &[u8], compression: Compression) -> Result<PathBuf, String> {
+    use std::io::Write;
+
+    let write_error = |path: &Path, e: std::io::Error| format!("No se pudo escribir {}: {}", path.display(), e);
+    match compression {
+        Compression::None => {
+            fs::write(path, content).map_err(|e| write_error(path, e))?;
+            Ok(path.to_path_buf())
+        }
+        Compression::Gzip => {
+            let mut name = path.file_name().unwrap_or_default().to_os_string();
+            name.push(".gz");
+            let path = path.with_file_name(name);
+            let file = fs::File::create(&path).map_err(|e| write_error(&path, e))?;
+            let mut encoder = flate2::write::GzEncoder::new(file, flate2::Compression::default());
+            encoder.write_all(content).map_err(|e| write_error(&path, e))?;
+            encoder.finish().map_err(|e| write_error(&path, e))?;
+            Ok(path)
+        }
+    }
+}
+
+pub fn csv_escape(value: &str, separator: &str) -> String {
+    if value.contains(['"', '\n', '\r']) || value.contains(separator) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+// Los valores numéricos se exportan en bruto; la columna formateada usa las
+// mismas unidades que el resto de la aplicación. Separadores de campo y
+// decimales según la configuración regional, para que Excel lo abra en columnas
+pub fn processes_to_csv(processes: &[ProcessInfo], options: &FormatOptions) -> String {
+    let locale = options.locale();
+    let separator = locale.list_separator.as_str();
+    let header = [
+        "pid", "name", "parent_pid", "cpu_usage", "memory_usage", "memory_formatted", "disk_read_bytes",
+        "disk_write_bytes", "gpu_usage",
+    ];
+    let mut csv = header.join(separator) + "\n";
+    for process in processes {
+        let fields = [
+            process.pid.to_string(),
+            csv_escape(&process.name, separator),
+            process.parent_pid.map(|pid| pid.to_string()).unwrap_or_default(),
+            csv_escape(&locale.number(process.cpu_usage as f64, 2), separator),
+            process.memory_usage.to_string(),
+            csv_escape(&format_bytes(process.memory_usage as f64, options), separator),
+            process.disk_read_bytes.to_string(),
+            process.disk_write_bytes.to_string(),
+            csv_escape(&locale.number(process.gpu_usage as f64, 2), separator),
+        ];
+        csv.push_str(&fields.join(separator));
+        csv.push('\n');
+    }
+    csv
+}
+
+pub fn write_processes(path: &Path, format: ExportFormat, processes: &[ProcessInfo], options: &FormatOptions) -> Result<(), String> {
+    let content = match format {
+        ExportFormat::Csv => format!("{}{}", UTF8_BOM, processes_to_csv(processes, options)),
+        ExportFormat::Json => serde_json::to_string_pretty(processes)
+            .map_err(|e| format!("Error al serializar los procesos: {}", e))?,
+    };
+    fs::write(path, content).map_err(|e| format!("No se pudo escribir {}: {}", path.display(), e))
+}
+
+// Muestra el archivo seleccionado en el explorador de archivos del sistema
+pub fn reveal_in_file_manager(path: &Path) -> Result<(), String> {
+    #[cfg(target_os = "windows")]
+    let result = {
+        use std::os::windows::process::CommandExt;
+        Command::new("explorer").raw_arg(format!("/select,\"{}\"", path.display())).spawn()
+    };
+
+    #[cfg(target_os = "macos")]
+    let result = Command::new("open").arg("-R").arg(path).spawn();
+
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    let result = Command::new("xdg-open").arg(path.parent().unwrap_or(path)).spawn();
+
+    result
+        .map(|_| ())
+        .map_err(|e| format!("No se pudo abrir el explorador de archivos: {}", e))
+}
+
+// Materializa las filas seleccionadas en un archivo temporal y devuelve su ruta,
+// lista para arrastrarla a un correo o ticket
+#[tauri::command]
+pub fn export_selection(state: State<AppState>, pids: Vec<u32>, format: ExportFormat, reveal: bool) -> Result<PathBuf, String> {
+    let selected: Vec<ProcessInfo> = state
+        .processes
+        .read()
+        .unwrap()
+        .processes
+        .iter()
+        .filter(|process| pids.contains(&process.pid))
+        .cloned()
+        .collect();
+    if selected.is_empty() {
+        return Err("Ninguno de los procesos seleccionados sigue en ejecución".to_string());
+    }
+
+    let options = state.settings.read().unwrap().format;
+    let path = std::env::temp_dir().join(format!(
+        "task-manager-seleccion-{}.{}",
+        crate::stats::now_millis(),
+        format.extension()
+    ));
+    write_processes(&path, format, &selected, &options)?;
+
+    if reveal {
+        reveal_in_file_manager(&path)?;
+    }
+    Ok(path)
+}
+
+// Historial del sistema en CSV, con los mismos separadores que los procesos
+pub fn history_to_csv(samples: &[SystemStats], options: &FormatOptions) -> String {
+    let locale = options.locale();
+    let separator = locale.list_separator.as_str();
+    let header = [
+        "timestamp", "cpu_usage", "used_memory", "available_memory", "total_memory", "used_swap", "process_count",
+        "thread_count", "handle_count",
+    ];
+    let mut csv = header.join(separator) + "\n";
+    for sample in samples {
+        let fields = [
+            sample.timestamp.to_string(),
+            csv_escape(&locale.number(sample.cpu_usage as f64, 2), separator),
+            sample.used_memory.to_string(),
+            sample.available_memory.to_string(),
+            sample.total_memory.to_string(),
+            sample.used_swap.to_string(),
+            sample.process_count.to_string(),
+            sample.thread_count.map(|count| count.to_string()).unwrap_or_default(),
+            sample.handle_count.map(|count| count.to_string()).unwrap_or_default(),
+        ];
+        csv.push_str(&fields.join(separator));
+        csv.push('\n');
+    }
+    csv
+}
+
+#[derive(Serialize)]
+struct Snapshot<'a> {
+    exported_at: u64,
+    #[serde(flatten)]
+    tree: &'a ProcessTree,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    history: Option<Vec<SystemStats>>,
+}
+
+// Estado completo del equipo (árbol de procesos, totales y, si se pide, el
+// historial del sistema) para analizarlo más tarde o adjuntarlo a un ticket.
+// En CSV el historial va en un segundo archivo, <nombre>_history.csv; se
+// devuelven las rutas escritas (con ".gz" si se comprime)
+#[tauri::command]
+pub fn export_snapshot(
+    state: State<AppState>,
+    format: ExportFormat,
+    path: String,
+    include_history: bool,
+    compression: Option<Compression>,
+) -> Result<Vec<PathBuf>, String> {
+    let compression = compression.unwrap_or_default();
+    let path = PathBuf::from(path.trim());
+    if path.as_os_str().is_empty() {
+        return Err("Indica dónde guardar la exportación".to_string());
+    }
+    let tree = state.processes.read().unwrap().clone();
+    let history = include_history.then(|| state.history.read().unwrap().since(0));
+    let options = state.settings.read().unwrap().format;
+
+    let mut written = Vec::new();
+    match format {
+        ExportFormat::Json => {
+            let snapshot = Snapshot { exported_at: crate::stats::now_millis(), tree: &tree, history };
+            let content = serde_json::to_string_pretty(&snapshot)
+                .map_err(|e| format!("Error al serializar la instantánea: {}", e))?;
+            written.push(write_output(&path, content.as_bytes(), compression)?);
+        }
+        ExportFormat::Csv => {
+            let content = format!("{}{}", UTF8_BOM, processes_to_csv(&tree.processes, &options));
+            written.push(write_output(&path, content.as_bytes(), compression)?);
+            if let Some(history) = history {
+                let stem = path.file_stem().map(|stem| stem.to_string_lossy().to_string()).unwrap_or_default();
+                let history_path = path.with_file_name(format!("{}_history.csv", stem));
+                let content = format!("{}{}", UTF8_BOM, history_to_csv(&history, &options));
+                written.push(write_output(&history_path, content.as_bytes(), compression)?);
+            }
+        }
+    }
+    Ok(written)
+}
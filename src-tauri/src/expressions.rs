@@ -0,0 +1,417 @@
+// src-tauri/src/expressions.rs
+// Expresiones de vigilancia y columnas calculadas: métricas derivadas definidas por el usuario,
+// p. ej. `chrome.exe.total_memory / system.memory_total`. Las comparaciones y
+// los operadores lógicos dan 1 o 0, para usarlas como condición en reglas y
+// alertas: `chrome.exe.total_memory / system.memory_total > 0.5`
+
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+use tauri::{AppHandle, State};
+
+use crate::provider::SystemSnapshot;
+use crate::{AppState, ProcessInfo};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WatchExpression {
+    pub name: String,
+    pub expression: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WatchValue {
+    pub name: String,
+    pub value: Option<f64>,
+    pub error: Option<String>,
+}
+
+// Columna calculada por proceso, p. ej. `memory_usage / system_total * 100`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CustomColumn {
+    pub name: String,
+    pub formula: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Greater,
+    GreaterEqual,
+    Less,
+    LessEqual,
+    Equal,
+    And,
+    Or,
+}
+
+#[derive(Debug, Clone)]
+pub enum Expr {
+    Number(f64),
+    Variable(String),
+    Neg(Box<Expr>),
+    Binary(Box<Expr>, BinaryOp, Box<Expr>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Ident(String),
+    Op(BinaryOp),
+    LParen,
+    RParen,
+}
+
+// Operador que empieza en `chars[i]` y los caracteres que ocupa
+fn operator(chars: &[char], i: usize) -> Option<(BinaryOp, usize)> {
+    let next = chars.get(i + 1).copied();
+    Some(match (chars[i], next) {
+        ('>', Some('=')) => (BinaryOp::GreaterEqual, 2),
+        ('<', Some('=')) => (BinaryOp::LessEqual, 2),
+        ('=', Some('=')) => (BinaryOp::Equal, 2),
+        ('&', Some('&')) => (BinaryOp::And, 2),
+        ('|', Some('|')) => (BinaryOp::Or, 2),
+        ('>', _) => (BinaryOp::Greater, 1),
+        ('<', _) => (BinaryOp::Less, 1),
+        ('+', _) => (BinaryOp::Add, 1),
+        ('-', _) => (BinaryOp::Sub, 1),
+        ('*', _) => (BinaryOp::Mul, 1),
+        ('/', _) => (BinaryOp::Div, 1),
+        _ => return None,
+    })
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '"' {
+            // Entre comillas, un identificador con cualquier carácter: "my-app.exe.count"
+            let start = i + 1;
+            i = start;
+            while i < chars.len() && chars[i] != '"' {
+                i += 1;
+            }
+            if i == chars.len() {
+                return Err("Faltan las comillas de cierre".to_string());
+            }
+            tokens.push(Token::Ident(chars[start..i].iter().collect()));
+            i += 1;
+        } else if c.is_alphanumeric() || c == '_' {
+            // Los identificadores admiten puntos para nombres como "chrome.exe". Pueden
+            // empezar por una cifra ("7zfm.exe"): es un número solo si no lo sigue una letra.
+            // El guion es siempre la resta; los nombres con guion van entre comillas
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || matches!(chars[i], '_' | '.')) {
+                i += 1;
+            }
+            let text: String = chars[start..i].iter().collect();
+            if text.chars().all(|c| c.is_ascii_digit() || c == '.') {
+                let value = text.parse::<f64>().map_err(|_| format!("Número inválido: {}", text))?;
+                tokens.push(Token::Number(value));
+            } else {
+                tokens.push(Token::Ident(text));
+            }
+        } else if let Some((op, len)) = operator(&chars, i) {
+            tokens.push(Token::Op(op));
+            i += len;
+        } else if c == '(' {
+            tokens.push(Token::LParen);
+            i += 1;
+        } else if c == ')' {
+            tokens.push(Token::RParen);
+            i += 1;
+        } else {
+            return Err(format!("Carácter inesperado '{}'", c));
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    // Operador binario siguiente si es uno de `ops`
+    fn operator(&mut self, ops: &[BinaryOp]) -> Option<BinaryOp> {
+        match self.peek() {
+            Some(Token::Op(op)) if ops.contains(op) => {
+                let op = *op;
+                self.pos += 1;
+                Some(op)
+            }
+            _ => None,
+        }
+    }
+
+    // expr := and ('||' and)*
+    fn expr(&mut self) -> Result<Expr, String> {
+        let mut left = self.and()?;
+        while let Some(op) = self.operator(&[BinaryOp::Or]) {
+            left = Expr::Binary(Box::new(left), op, Box::new(self.and()?));
+        }
+        Ok(left)
+    }
+
+    // and := comparison ('&&' comparison)*
+    fn and(&mut self) -> Result<Expr, String> {
+        let mut left = self.comparison()?;
+        while let Some(op) = self.operator(&[BinaryOp::And]) {
+            left = Expr::Binary(Box::new(left), op, Box::new(self.comparison()?));
+        }
+        Ok(left)
+    }
+
+    // comparison := sum (('>' | '>=' | '<' | '<=' | '==') sum)?
+    fn comparison(&mut self) -> Result<Expr, String> {
+        let left = self.sum()?;
+        let comparisons =
+            [BinaryOp::Greater, BinaryOp::GreaterEqual, BinaryOp::Less, BinaryOp::LessEqual, BinaryOp::Equal];
+        match self.operator(&comparisons) {
+            Some(op) => Ok(Expr::Binary(Box::new(left), op, Box::new(self.sum()?))),
+            None => Ok(left),
+        }
+    }
+
+    // sum := term (('+' | '-') term)*
+    fn sum(&mut self) -> Result<Expr, String> {
+        let mut left = self.term()?;
+        while let Some(op) = self.operator(&[BinaryOp::Add, BinaryOp::Sub]) {
+            left = Expr::Binary(Box::new(left), op, Box::new(self.term()?));
+        }
+        Ok(left)
+    }
+
+    // term := factor (('*' | '/') factor)*
+    fn term(&mut self) -> Result<Expr, String> {
+        let mut left = self.factor()?;
+        while let Some(op) = self.operator(&[BinaryOp::Mul, BinaryOp::Div]) {
+            left = Expr::Binary(Box::new(left), op, Box::new(self.factor()?));
+        }
+        Ok(left)
+    }
+
+    // factor := número | variable | '-' factor | '(' expr ')'
+    fn factor(&mut self) -> Result<Expr, String> {
+        match self.next() {
+            Some(Token::Number(value)) => Ok(Expr::Number(value)),
+            Some(Token::Ident(name)) => Ok(Expr::Variable(name)),
+            Some(Token::Op(BinaryOp::Sub)) => Ok(Expr::Neg(Box::new(self.factor()?))),
+            Some(Token::LParen) => {
+                let inner = self.expr()?;
+                match self.next() {
+                    Some(Token::RParen) => Ok(inner),
+                    _ => Err("Falta ')'".to_string()),
+                }
+            }
+            Some(token) => Err(format!("Token inesperado: {:?}", token)),
+            None => Err("Expresión incompleta".to_string()),
+        }
+    }
+}
+
+impl Expr {
+    pub fn parse(input: &str) -> Result<Expr, String> {
+        let mut parser = Parser { tokens: tokenize(input)?, pos: 0 };
+        let expr = parser.expr()?;
+        if parser.pos < parser.tokens.len() {
+            return Err(format!("Sobra texto tras la posición {}", parser.pos));
+        }
+        Ok(expr)
+    }
+
+    // Comparación u operador lógico en la raíz: vale 1 o 0
+    pub fn is_condition(&self) -> bool {
+        let arithmetic = [BinaryOp::Add, BinaryOp::Sub, BinaryOp::Mul, BinaryOp::Div];
+        matches!(self, Expr::Binary(_, op, _) if !arithmetic.contains(op))
+    }
+
+    pub fn eval(&self, lookup: &dyn Fn(&str) -> Option<f64>) -> Result<f64, String> {
+        match self {
+            Expr::Number(value) => Ok(*value),
+            Expr::Variable(name) => lookup(name).ok_or_else(|| format!("Métrica desconocida: {}", name)),
+            Expr::Neg(inner) => Ok(-inner.eval(lookup)?),
+            Expr::Binary(left, op, right) => {
+                let (a, b) = (left.eval(lookup)?, right.eval(lookup)?);
+                let truth = |condition: bool| if condition { 1.0 } else { 0.0 };
+                match op {
+                    BinaryOp::Add => Ok(a + b),
+                    BinaryOp::Sub => Ok(a - b),
+                    BinaryOp::Mul => Ok(a * b),
+                    BinaryOp::Div if b == 0.0 => Err("División por cero".to_string()),
+                    BinaryOp::Div => Ok(a / b),
+                    BinaryOp::Greater => Ok(truth(a > b)),
+                    BinaryOp::GreaterEqual => Ok(truth(a >= b)),
+                    BinaryOp::Less => Ok(truth(a < b)),
+                    BinaryOp::LessEqual => Ok(truth(a <= b)),
+                    BinaryOp::Equal => Ok(truth(a == b)),
+                    BinaryOp::And => Ok(truth(a != 0.0 && b != 0.0)),
+                    BinaryOp::Or => Ok(truth(a != 0.0 || b != 0.0)),
+                }
+            }
+        }
+    }
+}
+
+// Métricas disponibles para las expresiones en un instante dado
+#[derive(Debug, Default)]
+pub struct MetricContext {
+    values: HashMap<String, f64>,
+}
+
+impl MetricContext {
+    pub fn from_snapshot(snapshot: &SystemSnapshot) -> Self {
+        let mut values = HashMap::new();
+        values.insert("system.cpu_usage".to_string(), snapshot.cpu_usage as f64);
+        values.insert("system.memory_total".to_string(), snapshot.total_memory as f64);
+        values.insert("system.memory_used".to_string(), snapshot.used_memory as f64);
+        values.insert("system.memory_available".to_string(), snapshot.available_memory as f64);
+        values.insert("system.swap_used".to_string(), snapshot.used_swap as f64);
+        values.insert("system.process_count".to_string(), snapshot.processes.len() as f64);
+        if let Some(idle) = crate::idle::idle_seconds() {
+            values.insert("system.idle_seconds".to_string(), idle as f64);
+        }
+
+        // Agregados por nombre de ejecutable (todas las instancias)
+        for process in &snapshot.processes {
+            let name = process.name.to_lowercase();
+            for (metric, value) in [
+                ("count", 1.0),
+                ("total_cpu", process.cpu_usage as f64),
+                ("total_memory", process.memory as f64),
+                ("total_disk_read", process.disk_read_bytes as f64),
+                ("total_disk_write", process.disk_written_bytes as f64),
+            ] {
+                *values.entry(format!("{}.{}", name, metric)).or_insert(0.0) += value;
+            }
+        }
+
+        MetricContext { values }
+    }
+
+    pub fn get(&self, name: &str) -> Option<f64> {
+        if let Some(value) = self.values.get(&name.to_lowercase()) {
+            return Some(*value);
+        }
+        // Un proceso que no se está ejecutando cuenta como cero
+        let (_, metric) = name.rsplit_once('.')?;
+        match metric {
+            "count" | "total_cpu" | "total_memory" | "total_disk_read" | "total_disk_write"
+                if !name.starts_with("system.") => Some(0.0),
+            _ => None,
+        }
+    }
+}
+
+pub fn evaluate_all(context: &MetricContext, expressions: &[WatchExpression]) -> Vec<WatchValue> {
+    expressions
+        .iter()
+        .map(|watch| {
+            let result = Expr::parse(&watch.expression).and_then(|expr| expr.eval(&|name| context.get(name)));
+            match result {
+                Ok(value) => WatchValue { name: watch.name.clone(), value: Some(value), error: None },
+                Err(e) => WatchValue { name: watch.name.clone(), value: None, error: Some(e) },
+            }
+        })
+        .collect()
+}
+
+// Variables de las columnas: los campos numéricos del proceso, `system_total`
+// (memoria total) y, si hay contexto, cualquier métrica de las expresiones
+fn process_variable(process: &ProcessInfo, system_total: f64, context: Option<&MetricContext>, name: &str) -> Option<f64> {
+    Some(match name {
+        "pid" => process.pid as f64,
+        "cpu_usage" => process.cpu_usage as f64,
+        "memory_usage" => process.memory_usage as f64,
+        "disk_read_bytes" => process.disk_read_bytes as f64,
+        "disk_write_bytes" => process.disk_write_bytes as f64,
+        "gpu_usage" => process.gpu_usage as f64,
+        "gpu_dedicated_memory" => process.gpu_dedicated_memory as f64,
+        "gpu_shared_memory" => process.gpu_shared_memory as f64,
+        "network_send_rate" => process.network_send_rate,
+        "network_receive_rate" => process.network_receive_rate,
+        "cpu_delta" => process.cpu_delta? as f64,
+        "memory_growth" => process.memory_growth?,
+        "handle_growth" => process.handle_growth?,
+        "system_total" => system_total,
+        _ => return context.and_then(|context| context.get(name)),
+    })
+}
+
+// Las fórmulas no válidas o con error (p. ej. división por cero) se omiten
+// en ese proceso
+pub fn evaluate_columns(
+    columns: &[CustomColumn],
+    processes: &mut [ProcessInfo],
+    system_total: f64,
+    context: Option<&MetricContext>,
+) {
+    let parsed: Vec<(&str, Expr)> = columns
+        .iter()
+        .filter_map(|column| Expr::parse(&column.formula).ok().map(|expr| (column.name.as_str(), expr)))
+        .collect();
+    for process in processes {
+        for (name, expr) in &parsed {
+            if let Ok(value) = expr.eval(&|variable| process_variable(process, system_total, context, variable)) {
+                process.custom_columns.insert(name.to_string(), value);
+            }
+        }
+    }
+}
+
+#[tauri::command]
+pub fn add_custom_column(app: AppHandle, state: State<AppState>, name: String, formula: String) -> Result<(), String> {
+    Expr::parse(&formula)?;
+    let mut settings = state.settings.write().unwrap();
+    settings.custom_columns.retain(|column| column.name != name);
+    settings.custom_columns.push(CustomColumn { name, formula });
+    crate::settings::save(&app, &settings)
+}
+
+#[tauri::command]
+pub fn remove_custom_column(app: AppHandle, state: State<AppState>, name: String) -> Result<(), String> {
+    let mut settings = state.settings.write().unwrap();
+    settings.custom_columns.retain(|column| column.name != name);
+    crate::settings::save(&app, &settings)
+}
+
+#[tauri::command]
+pub fn add_watch_expression(app: AppHandle, state: State<AppState>, name: String, expression: String) -> Result<(), String> {
+    Expr::parse(&expression)?;
+    let mut settings = state.settings.write().unwrap();
+    settings.watch_expressions.retain(|watch| watch.name != name);
+    settings.watch_expressions.push(WatchExpression { name, expression });
+    crate::settings::save(&app, &settings)
+}
+
+#[tauri::command]
+pub fn remove_watch_expression(app: AppHandle, state: State<AppState>, name: String) -> Result<(), String> {
+    let mut settings = state.settings.write().unwrap();
+    settings.watch_expressions.retain(|watch| watch.name != name);
+    crate::settings::save(&app, &settings)
+}
+
+// Últimos valores calculados en el refresco de procesos
+#[tauri::command]
+pub fn get_watch_values(state: State<AppState>) -> Vec<WatchValue> {
+    state.watch_values.read().unwrap().clone()
+}
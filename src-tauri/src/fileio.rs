@@ -0,0 +1,290 @@
+// src-tauri/src/fileio.rs
+// E/S de archivos por proceso a partir de los eventos FileIo de ETW,
+// desglosada por unidad de destino
+
+use serde::{Serialize, Deserialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::Instant;
+use tauri::State;
+
+use crate::AppState;
+
+// Límite del mapa de objetos de archivo para no crecer sin control
+#[cfg(target_os = "windows")]
+const MAX_FILE_NAMES: usize = 200_000;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DriveIo {
+    pub pid: u32,
+    pub drive: String,      // "C:", "D:"... o "?" si no se pudo resolver
+    pub read_bytes: u64,    // Total de la sesión
+    pub write_bytes: u64,
+    pub read_rate: f64,     // Bytes por segundo
+    pub write_rate: f64,
+}
+
+// Operaciones recientes del proceso vigilado (FileMon ligero)
+const ACTIVITY_CAPACITY: usize = 1000;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FileActivity {
+    pub timestamp: u64,
+    pub operation: String, // create, read, write, delete, rename
+    pub path: String,
+    pub size: Option<u32>,
+    pub status: Option<u32>, // NTSTATUS al completarse (0 = éxito)
+    #[serde(skip)]
+    irp: u64,
+}
+
+#[derive(Debug, Default)]
+struct DriveCounters {
+    read: u64,
+    write: u64,
+    sampled_read: u64,
+    sampled_write: u64,
+    read_rate: f64,
+    write_rate: f64,
+}
+
+#[derive(Debug, Default)]
+pub struct FileIoCollector {
+    #[cfg(target_os = "windows")]
+    file_names: HashMap<u64, String>, // FileObject -> ruta con letra de unidad
+    #[cfg(target_os = "windows")]
+    devices: Option<Vec<(String, String)>>, // (\Device\HarddiskVolumeN, C:)
+    per_process: HashMap<u32, HashMap<String, DriveCounters>>,
+    last_sample: Option<Instant>,
+    trace_pid: Option<u32>,
+    activity: VecDeque<FileActivity>,
+    pub error: Option<String>,
+}
+
+// Unidad de una ruta ya traducida a letra ("C:\...")
+#[cfg(target_os = "windows")]
+fn drive_of(path: &str) -> String {
+    match path.as_bytes() {
+        [letter, b':', ..] if letter.is_ascii_alphabetic() => path[..2].to_ascii_uppercase(),
+        _ => "?".to_string(),
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn dos_devices() -> Vec<(String, String)> {
+    use windows::Win32::Storage::FileSystem::QueryDosDeviceW;
+
+    let mut devices = Vec::new();
+    for letter in b'A'..=b'Z' {
+        let drive = format!("{}:", letter as char);
+        let wide = crate::win::to_wide(&drive);
+        let mut target = vec![0u16; 1024];
+        let len = unsafe { QueryDosDeviceW(windows::core::PCWSTR(wide.as_ptr()), Some(&mut target)) };
+        if len > 0 {
+            devices.push((crate::win::from_wide(&target), drive));
+        }
+    }
+    devices
+}
+
+impl FileIoCollector {
+    // Traduce "\Device\HarddiskVolume3\..." a "C:\..."
+    #[cfg(target_os = "windows")]
+    fn translate(&mut self, path: &str) -> String {
+        let devices = self.devices.get_or_insert_with(dos_devices);
+        for (device, drive) in devices.iter() {
+            if let Some(rest) = path.strip_prefix(device.as_str()) {
+                if rest.is_empty() || rest.starts_with('\\') {
+                    return format!("{}{}", drive, rest);
+                }
+            }
+        }
+        path.to_string()
+    }
+
+    pub fn record(&mut self, pid: u32, drive: String, write: bool, bytes: u64) {
+        let counters = self.per_process.entry(pid).or_default().entry(drive).or_default();
+        if write {
+            counters.write += bytes;
+        } else {
+            counters.read += bytes;
+        }
+    }
+
+    pub fn sample(&mut self, alive: &HashSet<u32>) {
+        let now = Instant::now();
+        let elapsed = self
+            .last_sample
+            .map(|last| now.duration_since(last).as_secs_f64())
+            .unwrap_or(0.0);
+        self.last_sample = Some(now);
+
+        self.per_process.retain(|pid, _| alive.contains(pid));
+        for drives in self.per_process.values_mut() {
+            for counters in drives.values_mut() {
+                if elapsed > 0.0 {
+                    counters.read_rate = (counters.read - counters.sampled_read) as f64 / elapsed;
+                    counters.write_rate = (counters.write - counters.sampled_write) as f64 / elapsed;
+                }
+                counters.sampled_read = counters.read;
+                counters.sampled_write = counters.write;
+            }
+        }
+    }
+
+    pub fn drive_usage(&self, pid: Option<u32>) -> Vec<DriveIo> {
+        let mut usage: Vec<DriveIo> = self
+            .per_process
+            .iter()
+            .filter(|(process, _)| pid.is_none_or(|pid| pid == **process))
+            .flat_map(|(process, drives)| {
+                drives.iter().map(move |(drive, counters)| DriveIo {
+                    pid: *process,
+                    drive: drive.clone(),
+                    read_bytes: counters.read,
+                    write_bytes: counters.write,
+                    read_rate: counters.read_rate,
+                    write_rate: counters.write_rate,
+                })
+            })
+            .collect();
+        usage.sort_by(|a, b| (b.read_rate + b.write_rate).total_cmp(&(a.read_rate + a.write_rate)));
+        usage
+    }
+
+    pub fn start_trace(&mut self, pid: u32) {
+        self.trace_pid = Some(pid);
+        self.activity.clear();
+    }
+
+    pub fn stop_trace(&mut self) {
+        self.trace_pid = None;
+    }
+
+    #[cfg(target_os = "windows")]
+    fn push_activity(&mut self, pid: u32, irp: u64, operation: &str, path: String, size: Option<u32>) {
+        if self.trace_pid != Some(pid) {
+            return;
+        }
+        if self.activity.len() == ACTIVITY_CAPACITY {
+            self.activity.pop_front();
+        }
+        self.activity.push_back(FileActivity {
+            timestamp: crate::stats::now_millis(),
+            operation: operation.to_string(),
+            path,
+            size,
+            status: None,
+            irp,
+        });
+    }
+
+    #[cfg(target_os = "windows")]
+    fn path_for(&self, file_key: u64) -> String {
+        self.file_names.get(&file_key).cloned().unwrap_or_default()
+    }
+
+    // FileIo: Name/FileCreate/FileRundown (0/32/36) asocian FileObject y ruta,
+    // FileDelete (35) la olvida, Read/Write (67/68) traen FileKey, TTID y tamaño
+    #[cfg(target_os = "windows")]
+    pub fn on_event(&mut self, event: &crate::etw::KernelEvent) {
+        if event.provider != crate::etw::FILEIO_GUID {
+            return;
+        }
+        let ptr = event.pointer_size;
+        match event.opcode {
+            64 if self.trace_pid.is_some() => {
+                // Create: IrpPtr, FileObject, TTID, CreateOptions, FileAttributes, ShareAccess, OpenPath
+                if let (Some(irp), Some(thread_id)) = (event.pointer_at(0), event.u32_at(2 * ptr)) {
+                    let path = event.wide_string_at(2 * ptr + 16).unwrap_or_default();
+                    let path = self.translate(&path);
+                    self.push_activity(event.pid_for_thread(thread_id), irp, "create", path, None);
+                }
+            }
+            70 | 71 if self.trace_pid.is_some() => {
+                // Delete/Rename: IrpPtr, FileObject, FileKey, ExtraInfo, TTID, InfoClass
+                if let (Some(irp), Some(file_key), Some(thread_id)) =
+                    (event.pointer_at(0), event.pointer_at(2 * ptr), event.u32_at(4 * ptr))
+                {
+                    let operation = if event.opcode == 70 { "delete" } else { "rename" };
+                    let path = self.path_for(file_key);
+                    self.push_activity(event.pid_for_thread(thread_id), irp, operation, path, None);
+                }
+            }
+            76 if self.trace_pid.is_some() => {
+                // OpEnd: IrpPtr, ExtraInfo, NtStatus
+                if let (Some(irp), Some(status)) = (event.pointer_at(0), event.u32_at(2 * ptr)) {
+                    if let Some(entry) = self.activity.iter_mut().rev().find(|entry| entry.irp == irp && entry.status.is_none()) {
+                        entry.status = Some(status);
+                    }
+                }
+            }
+            0 | 32 | 36 => {
+                if let (Some(file_object), Some(name)) = (event.pointer_at(0), event.wide_string_at(ptr)) {
+                    if self.file_names.len() >= MAX_FILE_NAMES {
+                        self.file_names.clear();
+                    }
+                    let path = self.translate(&name);
+                    self.file_names.insert(file_object, path);
+                }
+            }
+            35 => {
+                if let Some(file_object) = event.pointer_at(0) {
+                    self.file_names.remove(&file_object);
+                }
+            }
+            67 | 68 => {
+                // Offset (u64), IrpPtr, FileObject, FileKey, TTID (u32), IoSize (u32)
+                let file_key = event.pointer_at(8 + 2 * ptr);
+                let thread_id = event.u32_at(8 + 3 * ptr);
+                let size = event.u32_at(8 + 3 * ptr + 4);
+                if let (Some(file_key), Some(thread_id), Some(size)) = (file_key, thread_id, size) {
+                    let drive = self
+                        .file_names
+                        .get(&file_key)
+                        .map(|path| drive_of(path))
+                        .unwrap_or_else(|| "?".to_string());
+                    let pid = event.pid_for_thread(thread_id);
+                    self.record(pid, drive, event.opcode == 68, size as u64);
+
+                    if let Some(irp) = event.pointer_at(8) {
+                        let operation = if event.opcode == 68 { "write" } else { "read" };
+                        let path = self.path_for(file_key);
+                        self.push_activity(pid, irp, operation, path, Some(size));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+// E/S por unidad de destino, de un proceso concreto o de todos
+#[tauri::command]
+pub fn get_drive_io(state: State<AppState>, pid: Option<u32>) -> Result<Vec<DriveIo>, String> {
+    let fileio = state.fileio.lock().unwrap();
+    if let Some(error) = &fileio.error {
+        return Err(error.clone());
+    }
+    Ok(fileio.drive_usage(pid))
+}
+
+// Activa el flujo de operaciones de archivo de un proceso (descarta el anterior)
+#[tauri::command]
+pub fn start_file_trace(state: State<AppState>, pid: u32) -> Result<(), String> {
+    let mut fileio = state.fileio.lock().unwrap();
+    if let Some(error) = &fileio.error {
+        return Err(error.clone());
+    }
+    fileio.start_trace(pid);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn stop_file_trace(state: State<AppState>) {
+    state.fileio.lock().unwrap().stop_trace();
+}
+
+#[tauri::command]
+pub fn get_file_activity(state: State<AppState>) -> Vec<FileActivity> {
+    state.fileio.lock().unwrap().activity.iter().cloned().collect()
+}
@@ -0,0 +1,210 @@
+// src-tauri/src/foreground.rs
+// Prioridad para la aplicación en primer plano: al cambiar el foco (hook
+// EVENT_SYSTEM_FOREGROUND en un hilo con su propio bucle de mensajes) se sube
+// la prioridad del proceso que lo recibe y se devuelve la original al que lo
+// pierde. Pensado para quien nota la latencia (juegos, audio) con tareas
+// pesadas de fondo. No se tocan los ejecutables excluidos ni los procesos que
+// ya tienen una prioridad igual o mayor
+
+use serde::{Serialize, Deserialize};
+use tauri::{AppHandle, Manager, State};
+
+use crate::priority::PriorityClass;
+use crate::AppState;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+pub struct ForegroundBoostSettings {
+    pub enabled: bool,
+    pub priority: PriorityClass, // AboveNormal o High; Realtime podría dejar sin CPU al sistema
+    pub excluded: Vec<String>,   // Ejecutables que nunca se suben
+}
+
+impl Default for ForegroundBoostSettings {
+    fn default() -> Self {
+        ForegroundBoostSettings {
+            enabled: false,
+            priority: PriorityClass::AboveNormal,
+            excluded: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct BoostedProcess {
+    pub pid: u32,
+    pub name: String,
+    pub original: PriorityClass, // Se restaura al perder el foco
+    pub boosted: PriorityClass,
+    pub since: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct ForegroundBooster {
+    settings: Option<ForegroundBoostSettings>, // None con el modo desactivado
+    boosted: Option<BoostedProcess>,
+    #[cfg(target_os = "windows")]
+    listener: Option<crate::win::MessageLoop>, // Hilo que tiene instalado el hook
+}
+
+pub fn validate(settings: &ForegroundBoostSettings) -> Result<(), String> {
+    if !matches!(settings.priority, PriorityClass::AboveNormal | PriorityClass::High) {
+        return Err("La prioridad en primer plano debe ser Por encima de lo normal o Alta".to_string());
+    }
+    Ok(())
+}
+
+// Si mientras tanto se ha cambiado la prioridad a mano (o el PID ya es de
+// otro proceso), se deja como está
+#[cfg(target_os = "windows")]
+fn restore(boosted: BoostedProcess) {
+    let same_process = crate::win::process_image_name(boosted.pid).is_some_and(|name| name == boosted.name);
+    if same_process && crate::priority::query(boosted.pid).0 == Some(boosted.boosted) {
+        let _ = crate::priority::apply(boosted.pid, boosted.original);
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn on_focus(app: &AppHandle, pid: u32) {
+    let state = app.state::<AppState>();
+    let mut booster = state.foreground.lock().unwrap();
+    let Some(settings) = booster.settings.clone() else { return };
+    if booster.boosted.as_ref().is_some_and(|boosted| boosted.pid == pid) {
+        return;
+    }
+    if let Some(previous) = booster.boosted.take() {
+        restore(previous);
+    }
+    if pid == 0 || pid == std::process::id() {
+        return;
+    }
+    let Some(name) = crate::win::process_image_name(pid) else { return };
+    if settings.excluded.iter().any(|excluded| crate::text::same_name(excluded, &name)) {
+        return;
+    }
+    let Some(original) = crate::priority::query(pid).0.filter(|priority| *priority < settings.priority) else {
+        return;
+    };
+    if crate::priority::apply(pid, settings.priority).is_ok() {
+        booster.boosted = Some(BoostedProcess {
+            pid,
+            name,
+            original,
+            boosted: settings.priority,
+            since: crate::stats::now_millis(),
+        });
+    }
+}
+
+#[cfg(target_os = "windows")]
+unsafe extern "system" fn on_foreground(
+    _hook: windows::Win32::UI::Accessibility::HWINEVENTHOOK,
+    _event: u32,
+    hwnd: windows::Win32::Foundation::HWND,
+    _object: i32,
+    _child: i32,
+    _thread: u32,
+    _time: u32,
+) {
+    use windows::Win32::UI::WindowsAndMessaging::GetWindowThreadProcessId;
+
+    let mut pid = 0u32;
+    GetWindowThreadProcessId(hwnd, Some(&mut pid));
+    if let Some(app) = crate::win::message_loop_app() {
+        on_focus(&app, pid);
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn install_hook(app: &AppHandle) -> Result<crate::win::MessageLoop, String> {
+    use windows::Win32::Foundation::HMODULE;
+    use windows::Win32::UI::Accessibility::{SetWinEventHook, UnhookWinEvent};
+    use windows::Win32::UI::WindowsAndMessaging::{EVENT_SYSTEM_FOREGROUND, MSG, WINEVENT_OUTOFCONTEXT};
+
+    crate::win::MessageLoop::start(app, "foreground-boost", || {
+        let hook = unsafe {
+            SetWinEventHook(
+                EVENT_SYSTEM_FOREGROUND,
+                EVENT_SYSTEM_FOREGROUND,
+                HMODULE(0),
+                Some(on_foreground),
+                0,
+                0,
+                WINEVENT_OUTOFCONTEXT,
+            )
+        };
+        if hook.0 == 0 {
+            return Err("No se pudo instalar el hook de primer plano".to_string());
+        }
+        if let (Some(app), Some(pid)) = (crate::win::message_loop_app(), crate::win::foreground_pid()) {
+            on_focus(&app, pid);
+        }
+        let cleanup = move || unsafe {
+            UnhookWinEvent(hook);
+        };
+        Ok((|_: &MSG| {}, cleanup))
+    })
+}
+
+// Retira el hook y devuelve su prioridad al proceso subido; también al salir
+pub fn stop(app: &AppHandle) {
+    let state = app.state::<AppState>();
+    let mut booster = state.foreground.lock().unwrap();
+    booster.settings = None;
+    let boosted = booster.boosted.take();
+
+    #[cfg(target_os = "windows")]
+    {
+        // Sin el bloqueo: el hook lo toma mientras el hilo termina
+        let listener = booster.listener.take();
+        drop(booster);
+        if let Some(listener) = listener {
+            listener.stop();
+        }
+        if let Some(boosted) = boosted {
+            restore(boosted);
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    let _ = (booster, boosted);
+}
+
+// Activa o desactiva el modo según la configuración; se vuelve a llamar al
+// cambiarla (también la lista de exclusión)
+pub fn apply(app: &AppHandle, settings: &ForegroundBoostSettings) -> Result<(), String> {
+    stop(app);
+    if !settings.enabled {
+        return Ok(());
+    }
+    validate(settings)?;
+
+    #[cfg(target_os = "windows")]
+    {
+        let state = app.state::<AppState>();
+        state.foreground.lock().unwrap().settings = Some(settings.clone());
+        let installed = install_hook(app);
+        let mut booster = state.foreground.lock().unwrap();
+        match installed {
+            Ok(listener) => {
+                booster.listener = Some(listener);
+                Ok(())
+            }
+            Err(e) => {
+                booster.settings = None;
+                Err(e)
+            }
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
+
+// Proceso con la prioridad subida ahora mismo, si lo hay
+#[tauri::command]
+pub fn get_foreground_boost(state: State<AppState>) -> Option<BoostedProcess> {
+    state.foreground.lock().unwrap().boosted.clone()
+}
@@ -0,0 +1,98 @@
+// src-tauri/src/format.rs
+// Formateo de unidades compartido por exportaciones, alertas y API remota,
+// para que "1.0 GB" signifique lo mismo en todas partes.
+
+use serde::{Serialize, Deserialize};
+
+use crate::locale::Locale;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum UnitSystem {
+    #[default]
+    Binary,  // Potencias de 1024 (KiB, MiB, GiB)
+    Decimal, // Potencias de 1000 (KB, MB, GB)
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum RateMode {
+    #[default]
+    PerSecond, // Mostrar tasas (B/s)
+    Total,     // Mostrar contadores acumulados
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+#[serde(default)]
+pub struct FormatOptions {
+    pub units: UnitSystem,
+    pub rate_mode: RateMode,
+    pub decimals: usize,
+    pub use_system_locale: bool, // Separadores y fechas del sistema; si no, formato neutro
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        FormatOptions {
+            units: UnitSystem::Binary,
+            rate_mode: RateMode::PerSecond,
+            decimals: 1,
+            use_system_locale: true,
+        }
+    }
+}
+
+impl FormatOptions {
+    pub fn locale(&self) -> &'static Locale {
+        if self.use_system_locale {
+            Locale::system()
+        } else {
+            Locale::invariant()
+        }
+    }
+}
+
+const BINARY_SUFFIXES: [&str; 6] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB"];
+const DECIMAL_SUFFIXES: [&str; 6] = ["B", "KB", "MB", "GB", "TB", "PB"];
+
+pub fn format_bytes(bytes: f64, options: &FormatOptions) -> String {
+    let (base, suffixes) = match options.units {
+        UnitSystem::Binary => (1024.0, &BINARY_SUFFIXES),
+        UnitSystem::Decimal => (1000.0, &DECIMAL_SUFFIXES),
+    };
+
+    let mut value = bytes.max(0.0);
+    let mut unit = 0;
+    while value >= base && unit < suffixes.len() - 1 {
+        value /= base;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} {}", value.round() as u64, suffixes[0])
+    } else {
+        format!("{} {}", options.locale().number(value, options.decimals), suffixes[unit])
+    }
+}
+
+// Formatea una métrica de E/S según el modo configurado: la tasa por segundo
+// o el total acumulado.
+pub fn format_throughput(total_bytes: u64, bytes_per_sec: f64, options: &FormatOptions) -> String {
+    match options.rate_mode {
+        RateMode::PerSecond => format!("{}/s", format_bytes(bytes_per_sec, options)),
+        RateMode::Total => format_bytes(total_bytes as f64, options),
+    }
+}
+
+// Permiten al frontend formatear con exactamente las mismas reglas que el backend
+#[tauri::command]
+pub fn format_value(state: tauri::State<crate::AppState>, bytes: f64) -> String {
+    let options = state.settings.read().unwrap().format;
+    format_bytes(bytes, &options)
+}
+
+#[tauri::command]
+pub fn format_io(state: tauri::State<crate::AppState>, total_bytes: u64, bytes_per_sec: f64) -> String {
+    let options = state.settings.read().unwrap().format;
+    format_throughput(total_bytes, bytes_per_sec, &options)
+}
@@ -0,0 +1,390 @@
+// src-tauri/src/gpu.rs
+// Funciones relacionadas con la GPU
+
+use nvml_wrapper::enums::device::UsedGpuMemory;
+use nvml_wrapper::Nvml;
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use tauri::State;
+
+use crate::{AppState, ProcessInfo};
+
+// Uso de un tipo de motor (3D, Copy, VideoDecode...) en todo el adaptador
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct GpuEngineUsage {
+    pub engine: String,
+    pub utilization: f32, // Porcentaje
+}
+
+// Totales de un adaptador para la cabecera de resumen, junto a CPU y RAM
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct GpuAdapterUsage {
+    pub adapter: String, // LUID en Windows ("0x00000000_0x0000C1D5"); índice NVML en el resto
+    pub name: Option<String>,
+    pub utilization: f32, // El motor más ocupado, como el Administrador de tareas
+    pub engines: Vec<GpuEngineUsage>,
+    pub dedicated_used: u64,          // Bytes de VRAM
+    pub dedicated_total: Option<u64>, // None si no se pudo consultar el adaptador
+    pub shared_used: u64,             // Memoria del sistema usada por la GPU
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct GpuProcessUsage {
+    utilization: f32,      // Porcentaje
+    dedicated_memory: u64, // Bytes
+    shared_memory: u64,
+}
+
+// NVML se carga una sola vez para toda la aplicación (colector, sensores
+// térmicos y capacidades); None sin controlador de NVIDIA
+pub fn nvml() -> Option<&'static Nvml> {
+    static NVML: OnceLock<Option<Nvml>> = OnceLock::new();
+    NVML.get_or_init(|| Nvml::init().ok()).as_ref()
+}
+
+// Uso de GPU y memoria de vídeo por PID, muestreados una vez por refresco.
+// En las NVIDIA se pregunta a NVML; en Windows, además, a los contadores
+// `GPU Engine` y `GPU Process Memory`, que cubren cualquier adaptador
+// (AMD, Intel integradas...)
+#[derive(Default)]
+pub struct GpuCollector {
+    #[cfg(target_os = "windows")]
+    query: Option<crate::pdh::PdhQuery>,
+    nvml_timestamp: u64, // Última muestra de utilización leída (µs)
+    usage: HashMap<u32, GpuProcessUsage>,
+    adapters: Vec<GpuAdapterUsage>,
+    #[cfg(target_os = "windows")]
+    adapter_info: Option<HashMap<String, (String, u64)>>, // LUID -> (nombre, VRAM); se consulta una vez
+    disabled: bool,
+    pub error: Option<String>,
+}
+
+// Instancia "pid_1234_luid_0x..._phys_0[_eng_3_engtype_3D]" -> 1234
+#[cfg(target_os = "windows")]
+fn parse_instance_pid(instance: &str) -> Option<u32> {
+    instance.strip_prefix("pid_")?.split('_').next()?.parse().ok()
+}
+
+// "..._luid_0x00000000_0x0000C1D5_phys_0..." -> "0x00000000_0x0000C1D5"
+#[cfg(target_os = "windows")]
+fn parse_instance_luid(instance: &str) -> Option<&str> {
+    instance.split_once("luid_")?.1.split("_phys").next()
+}
+
+#[cfg(target_os = "windows")]
+fn adapter_entry<'a>(
+    adapters: &'a mut HashMap<String, GpuAdapterUsage>,
+    info: &HashMap<String, (String, u64)>,
+    luid: &str,
+) -> &'a mut GpuAdapterUsage {
+    adapters.entry(luid.to_string()).or_insert_with(|| GpuAdapterUsage {
+        adapter: luid.to_string(),
+        name: info.get(luid).map(|(name, _)| name.clone()),
+        dedicated_total: info.get(luid).map(|(_, total)| *total),
+        ..Default::default()
+    })
+}
+
+type Sample = (HashMap<u32, GpuProcessUsage>, Vec<GpuAdapterUsage>);
+
+impl GpuCollector {
+    // Como el Administrador de tareas: se suman los motores de cada tipo y el
+    // proceso muestra el tipo más ocupado
+    #[cfg(target_os = "windows")]
+    fn read_counters(&mut self) -> Result<Sample, String> {
+        if self.query.is_none() {
+            let mut query = crate::pdh::PdhQuery::open()?;
+            query.add_counter(r"\GPU Engine(*)\Utilization Percentage")?;
+            query.add_counter(r"\GPU Process Memory(*)\Dedicated Usage")?;
+            query.add_counter(r"\GPU Process Memory(*)\Shared Usage")?;
+            query.add_counter(r"\GPU Adapter Memory(*)\Dedicated Usage")?;
+            query.add_counter(r"\GPU Adapter Memory(*)\Shared Usage")?;
+            query.collect()?;
+            self.query = Some(query);
+        }
+        let adapter_info = self.adapter_info.get_or_insert_with(crate::gpuinfo::adapter_memory).clone();
+        let query = self.query.as_ref().unwrap();
+        query.collect()?;
+
+        let mut per_engine: HashMap<(u32, String), f64> = HashMap::new();
+        let mut adapter_engines: HashMap<(String, String), f64> = HashMap::new();
+        for (instance, value) in query.values(0)? {
+            let Some(engine_type) = instance.split("_engtype_").nth(1) else { continue };
+            if let Some(pid) = parse_instance_pid(&instance) {
+                *per_engine.entry((pid, engine_type.to_string())).or_default() += value;
+            }
+            if let Some(luid) = parse_instance_luid(&instance) {
+                *adapter_engines.entry((luid.to_string(), engine_type.to_string())).or_default() += value;
+            }
+        }
+        let mut usage: HashMap<u32, GpuProcessUsage> = HashMap::new();
+        for ((pid, _), value) in per_engine {
+            let entry = usage.entry(pid).or_default();
+            entry.utilization = entry.utilization.max(value.min(100.0) as f32);
+        }
+        // Una instancia por proceso y adaptador
+        for (instance, value) in query.values(1)? {
+            if let Some(pid) = parse_instance_pid(&instance) {
+                usage.entry(pid).or_default().dedicated_memory += value as u64;
+            }
+        }
+        for (instance, value) in query.values(2)? {
+            if let Some(pid) = parse_instance_pid(&instance) {
+                usage.entry(pid).or_default().shared_memory += value as u64;
+            }
+        }
+
+        // Una instancia por adaptador físico en los contadores de memoria
+        let mut adapters: HashMap<String, GpuAdapterUsage> = HashMap::new();
+        for ((luid, engine), value) in adapter_engines {
+            let utilization = value.min(100.0) as f32;
+            let entry = adapter_entry(&mut adapters, &adapter_info, &luid);
+            entry.utilization = entry.utilization.max(utilization);
+            entry.engines.push(GpuEngineUsage { engine, utilization });
+        }
+        for (instance, value) in query.values(3)? {
+            if let Some(luid) = parse_instance_luid(&instance) {
+                adapter_entry(&mut adapters, &adapter_info, luid).dedicated_used += value as u64;
+            }
+        }
+        for (instance, value) in query.values(4)? {
+            if let Some(luid) = parse_instance_luid(&instance) {
+                adapter_entry(&mut adapters, &adapter_info, luid).shared_used += value as u64;
+            }
+        }
+        let mut adapters: Vec<GpuAdapterUsage> = adapters.into_values().collect();
+        adapters.sort_by(|a, b| a.adapter.cmp(&b.adapter));
+        for adapter in &mut adapters {
+            adapter.engines.sort_by(|a, b| a.engine.cmp(&b.engine));
+        }
+        Ok((usage, adapters))
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    fn read_counters(&mut self) -> Result<Sample, String> {
+        Err("No se encontró NVML: el uso de GPU por proceso requiere el controlador de NVIDIA".to_string())
+    }
+
+    // Utilización SM y memoria de las NVIDIA; None si NVML no está disponible.
+    // Bajo WDDM el controlador no informa de la memoria por proceso
+    fn read_nvml(&mut self) -> Option<Sample> {
+        let nvml = nvml()?;
+        let mut usage: HashMap<u32, GpuProcessUsage> = HashMap::new();
+        let mut adapters = Vec::new();
+        let mut latest = self.nvml_timestamp;
+        for index in 0..nvml.device_count().ok()? {
+            let Ok(device) = nvml.device_by_index(index) else { continue };
+            let memory = device.memory_info().ok();
+            let engines: Vec<GpuEngineUsage> = [
+                ("Graphics", device.utilization_rates().ok().map(|rates| rates.gpu)),
+                ("VideoEncode", device.encoder_utilization().ok().map(|encoder| encoder.utilization)),
+                ("VideoDecode", device.decoder_utilization().ok().map(|decoder| decoder.utilization)),
+            ]
+            .into_iter()
+            .filter_map(|(engine, value)| {
+                value.map(|value| GpuEngineUsage { engine: engine.to_string(), utilization: value as f32 })
+            })
+            .collect();
+            adapters.push(GpuAdapterUsage {
+                adapter: index.to_string(),
+                name: device.name().ok(),
+                utilization: engines.iter().map(|engine| engine.utilization).fold(0.0, f32::max),
+                engines,
+                dedicated_used: memory.as_ref().map_or(0, |memory| memory.used),
+                dedicated_total: memory.map(|memory| memory.total),
+                shared_used: 0,
+            });
+            // Solo las muestras posteriores a la última lectura; un proceso
+            // puede tener varias en el intervalo
+            let mut utilization: HashMap<u32, u32> = HashMap::new();
+            for sample in device.process_utilization_stats(self.nvml_timestamp).unwrap_or_default() {
+                latest = latest.max(sample.timestamp);
+                let entry = utilization.entry(sample.pid).or_default();
+                *entry = (*entry).max(sample.sm_util);
+            }
+            // Un proceso puede aparecer como gráfico y como cómputo a la vez
+            let mut memory: HashMap<u32, u64> = HashMap::new();
+            let processes = device
+                .running_graphics_processes()
+                .unwrap_or_default()
+                .into_iter()
+                .chain(device.running_compute_processes().unwrap_or_default());
+            for process in processes {
+                let bytes = match process.used_gpu_memory {
+                    UsedGpuMemory::Used(bytes) => bytes,
+                    UsedGpuMemory::Unavailable => 0,
+                };
+                let entry = memory.entry(process.pid).or_default();
+                *entry = (*entry).max(bytes);
+            }
+
+            for (pid, sm) in utilization {
+                let entry = usage.entry(pid).or_default();
+                entry.utilization = (entry.utilization + sm as f32).min(100.0);
+            }
+            for (pid, bytes) in memory {
+                usage.entry(pid).or_default().dedicated_memory += bytes;
+            }
+        }
+        self.nvml_timestamp = latest;
+        Some((usage, adapters))
+    }
+
+    // Al desactivarlo se liberan la consulta de contadores y NVML
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.disabled = !enabled;
+        if self.disabled {
+            #[cfg(target_os = "windows")]
+            {
+                self.query = None;
+            }
+            self.usage.clear();
+            self.adapters.clear();
+            self.error = Some("El colector de GPU está desactivado".to_string());
+        } else {
+            self.error = None;
+        }
+    }
+
+    // Desactivado en la configuración (no por un fallo)
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    // Ni contadores de GPU ni NVML en este equipo: no es un fallo
+    pub fn is_unavailable(&self) -> bool {
+        #[cfg(target_os = "windows")]
+        return self.query.is_none() && nvml().is_none();
+        #[cfg(not(target_os = "windows"))]
+        nvml().is_none()
+    }
+
+    pub fn sample(&mut self) {
+        if self.disabled {
+            return;
+        }
+        let nvml = self.read_nvml();
+        match (self.read_counters(), nvml) {
+            // NVML es más preciso para sus procesos; los contadores aportan el
+            // resto de adaptadores y la memoria compartida. Los totales por
+            // adaptador salen de los contadores, que ya incluyen las NVIDIA
+            (Ok((mut usage, adapters)), Some((nvml, _))) => {
+                for (pid, measured) in nvml {
+                    let entry = usage.entry(pid).or_default();
+                    entry.utilization = measured.utilization;
+                    entry.dedicated_memory = entry.dedicated_memory.max(measured.dedicated_memory);
+                }
+                (self.usage, self.adapters) = (usage, adapters);
+                self.error = None;
+            }
+            (Ok((usage, adapters)), None) | (Err(_), Some((usage, adapters))) => {
+                (self.usage, self.adapters) = (usage, adapters);
+                self.error = None;
+            }
+            (Err(e), None) => {
+                self.usage.clear();
+                self.adapters.clear();
+                self.error = Some(e);
+            }
+        }
+    }
+
+    // Totales por adaptador de la última muestra
+    pub fn adapters(&self) -> Vec<GpuAdapterUsage> {
+        self.adapters.clone()
+    }
+
+    pub fn annotate(&self, processes: &mut [ProcessInfo]) {
+        for process in processes {
+            let usage = self.usage.get(&process.pid).copied().unwrap_or_default();
+            process.gpu_usage = usage.utilization;
+            process.gpu_dedicated_memory = usage.dedicated_memory;
+            process.gpu_shared_memory = usage.shared_memory;
+        }
+    }
+}
+
+// Clases de prioridad de planificación de la GPU (D3DKMT_SCHEDULINGPRIORITYCLASS)
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum GpuPriority {
+    Idle,
+    BelowNormal,
+    Normal,
+    AboveNormal,
+    High,
+    Realtime,
+}
+
+impl GpuPriority {
+    #[cfg(target_os = "windows")]
+    fn from_raw(value: i32) -> Option<Self> {
+        Some(match value {
+            0 => GpuPriority::Idle,
+            1 => GpuPriority::BelowNormal,
+            2 => GpuPriority::Normal,
+            3 => GpuPriority::AboveNormal,
+            4 => GpuPriority::High,
+            5 => GpuPriority::Realtime,
+            _ => return None,
+        })
+    }
+}
+
+#[cfg(target_os = "windows")]
+#[link(name = "gdi32")]
+extern "system" {
+    fn D3DKMTGetProcessSchedulingPriorityClass(process: windows::Win32::Foundation::HANDLE, priority: *mut i32) -> i32;
+    fn D3DKMTSetProcessSchedulingPriorityClass(process: windows::Win32::Foundation::HANDLE, priority: i32) -> i32;
+}
+
+#[tauri::command]
+pub fn get_gpu_priority(pid: u32) -> Result<GpuPriority, String> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::System::Threading::PROCESS_QUERY_LIMITED_INFORMATION;
+
+        let process = crate::win::open_process(pid, PROCESS_QUERY_LIMITED_INFORMATION)?;
+        let mut priority = 0i32;
+        let status = unsafe { D3DKMTGetProcessSchedulingPriorityClass(process.0, &mut priority) };
+        if status < 0 {
+            return Err(format!("No se pudo leer la prioridad de GPU (NTSTATUS 0x{:08X})", status));
+        }
+        GpuPriority::from_raw(priority).ok_or_else(|| format!("Prioridad de GPU desconocida: {}", priority))
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = pid;
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
+
+#[tauri::command]
+pub fn set_gpu_priority(state: State<AppState>, pid: u32, priority: GpuPriority) -> Result<(), String> {
+    let action = format!("gpu_priority:{:?}", priority);
+    crate::actions::run_once(&state, pid, &action, || apply_gpu_priority(pid, priority))
+}
+
+fn apply_gpu_priority(pid: u32, priority: GpuPriority) -> Result<(), String> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::System::Threading::PROCESS_SET_INFORMATION;
+
+        let process = crate::win::open_process(pid, PROCESS_SET_INFORMATION)?;
+        let status = unsafe { D3DKMTSetProcessSchedulingPriorityClass(process.0, priority as i32) };
+        if status < 0 {
+            return Err(format!("No se pudo cambiar la prioridad de GPU (NTSTATUS 0x{:08X})", status));
+        }
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = (pid, priority);
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
@@ -0,0 +1,207 @@
+// src-tauri/src/gpuinfo.rs
+// Diagnóstico de los adaptadores gráficos: versión y fecha del controlador,
+// versión de WDDM y nivel de características de DirectX. Es lo primero que
+// revisar cuando las métricas de GPU no cuadran
+
+use serde::Serialize;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct GpuAdapterInfo {
+    pub name: String,
+    pub vendor_id: u32,
+    pub device_id: u32,
+    pub dedicated_memory: u64, // Bytes de VRAM
+    pub driver_version: Option<String>, // "31.0.15.3623"
+    pub driver_date: Option<String>,    // AAAA-MM-DD
+    pub driver_provider: Option<String>,
+    pub wddm_version: Option<String>,   // "3.1", deducida de la versión del controlador
+    pub feature_level: Option<String>,  // Nivel de Direct3D más alto, p. ej. "12_1"
+}
+
+// El primer número de la versión del controlador identifica el modelo WDDM
+// (20 -> 2.0, 31 -> 3.1...; de Vista a 8.1 se usaban 7-10)
+fn wddm_version(driver_version: &str) -> Option<String> {
+    let major: u32 = driver_version.split('.').next()?.parse().ok()?;
+    match major {
+        7 => Some("1.0".to_string()),
+        8 => Some("1.1".to_string()),
+        9 => Some("1.2".to_string()),
+        10 => Some("1.3".to_string()),
+        20..=99 => Some(format!("{}.{}", major / 10, major % 10)),
+        _ => None,
+    }
+}
+
+// "5-12-2023" (formato del registro) -> "2023-05-12"
+fn iso_date(date: &str) -> Option<String> {
+    let mut parts = date.trim().split('-').map(|part| part.parse::<u32>().ok());
+    let (month, day, year) = (parts.next()??, parts.next()??, parts.next()??);
+    Some(format!("{:04}-{:02}-{:02}", year, month, day))
+}
+
+#[cfg(target_os = "windows")]
+mod dxgi {
+    use windows::core::ComInterface;
+    use windows::Win32::Foundation::HMODULE;
+    use windows::Win32::Graphics::Direct3D::*;
+    use windows::Win32::Graphics::Direct3D11::{D3D11CreateDevice, D3D11_CREATE_DEVICE_FLAG, D3D11_SDK_VERSION};
+    use windows::Win32::Graphics::Dxgi::{
+        CreateDXGIFactory1, IDXGIAdapter, IDXGIAdapter1, IDXGIDevice, IDXGIFactory1, DXGI_ADAPTER_DESC1,
+        DXGI_ADAPTER_FLAG_SOFTWARE,
+    };
+
+    // De mayor a menor; los 12_x solo se aceptan en Windows 10 o posterior
+    const LEVELS: [(D3D_FEATURE_LEVEL, &str); 10] = [
+        (D3D_FEATURE_LEVEL_12_2, "12_2"),
+        (D3D_FEATURE_LEVEL_12_1, "12_1"),
+        (D3D_FEATURE_LEVEL_12_0, "12_0"),
+        (D3D_FEATURE_LEVEL_11_1, "11_1"),
+        (D3D_FEATURE_LEVEL_11_0, "11_0"),
+        (D3D_FEATURE_LEVEL_10_1, "10_1"),
+        (D3D_FEATURE_LEVEL_10_0, "10_0"),
+        (D3D_FEATURE_LEVEL_9_3, "9_3"),
+        (D3D_FEATURE_LEVEL_9_2, "9_2"),
+        (D3D_FEATURE_LEVEL_9_1, "9_1"),
+    ];
+
+    pub struct Adapter {
+        pub desc: DXGI_ADAPTER_DESC1,
+        pub umd_version: Option<String>, // Versión del controlador en modo usuario
+        pub feature_level: Option<&'static str>,
+    }
+
+    fn feature_level(adapter: &IDXGIAdapter) -> Option<&'static str> {
+        let levels: Vec<D3D_FEATURE_LEVEL> = LEVELS.iter().map(|(level, _)| *level).collect();
+        for candidates in [&levels[..], &levels[3..]] {
+            let mut reached = D3D_FEATURE_LEVEL::default();
+            let created = unsafe {
+                D3D11CreateDevice(
+                    adapter,
+                    D3D_DRIVER_TYPE_UNKNOWN,
+                    HMODULE::default(),
+                    D3D11_CREATE_DEVICE_FLAG(0),
+                    Some(candidates),
+                    D3D11_SDK_VERSION,
+                    None,
+                    Some(&mut reached),
+                    None,
+                )
+            };
+            if created.is_ok() {
+                return LEVELS.iter().find(|(level, _)| *level == reached).map(|(_, name)| *name);
+            }
+        }
+        None
+    }
+
+    // Adaptadores de hardware con su descripción, sin crear dispositivos
+    pub fn hardware() -> Result<Vec<(IDXGIAdapter1, DXGI_ADAPTER_DESC1)>, String> {
+        let factory: IDXGIFactory1 =
+            unsafe { CreateDXGIFactory1() }.map_err(|e| format!("No se pudo crear la factoría DXGI: {}", e))?;
+        let mut adapters = Vec::new();
+        let mut index = 0;
+        while let Ok(adapter) = unsafe { factory.EnumAdapters1(index) } {
+            index += 1;
+            let mut desc = DXGI_ADAPTER_DESC1::default();
+            if unsafe { adapter.GetDesc1(&mut desc) }.is_err() || desc.Flags & DXGI_ADAPTER_FLAG_SOFTWARE.0 != 0 {
+                continue;
+            }
+            adapters.push((adapter, desc));
+        }
+        Ok(adapters)
+    }
+
+    pub fn adapters() -> Result<Vec<Adapter>, String> {
+        let mut adapters = Vec::new();
+        for (adapter, desc) in hardware()? {
+            let Ok(adapter) = adapter.cast::<IDXGIAdapter>() else { continue };
+            let umd_version = unsafe { adapter.CheckInterfaceSupport(&IDXGIDevice::IID) }.ok().map(|version| {
+                let part = |shift: u32| (version as u64 >> shift) & 0xFFFF;
+                format!("{}.{}.{}.{}", part(48), part(32), part(16), part(0))
+            });
+            adapters.push(Adapter { desc, umd_version, feature_level: feature_level(&adapter) });
+        }
+        Ok(adapters)
+    }
+}
+
+// (versión, fecha, proveedor) del controlador
+#[cfg(target_os = "windows")]
+type DriverEntry = (Option<String>, Option<String>, Option<String>);
+
+// Datos del controlador en la clase de dispositivos de pantalla del registro,
+// buscando por VEN/DEV o, si no, por descripción
+#[cfg(target_os = "windows")]
+fn registry_driver(vendor_id: u32, device_id: u32, name: &str) -> Option<DriverEntry> {
+    use crate::win::read_registry_string;
+    use windows::Win32::System::Registry::HKEY_LOCAL_MACHINE;
+
+    const DISPLAY_CLASS: &str = r"SYSTEM\CurrentControlSet\Control\Class\{4d36e968-e325-11ce-bfc1-08002be10318}";
+    let hardware_id = format!("ven_{:04x}&dev_{:04x}", vendor_id, device_id);
+    let keys: Vec<String> = (0..64)
+        .map(|index| format!(r"{}\{:04}", DISPLAY_CLASS, index))
+        .filter(|key| read_registry_string(HKEY_LOCAL_MACHINE, key, "DriverDesc").is_some())
+        .collect();
+    let by_id = keys.iter().find(|key| {
+        read_registry_string(HKEY_LOCAL_MACHINE, key, "MatchingDeviceId")
+            .is_some_and(|id| id.to_lowercase().contains(&hardware_id))
+    });
+    let by_name = || {
+        keys.iter().find(|key| {
+            read_registry_string(HKEY_LOCAL_MACHINE, key, "DriverDesc").is_some_and(|desc| desc.trim() == name)
+        })
+    };
+    let key = by_id.or_else(by_name)?;
+    let value = |name: &str| read_registry_string(HKEY_LOCAL_MACHINE, key, name).filter(|value| !value.is_empty());
+    Some((value("DriverVersion"), value("DriverDate").and_then(|date| iso_date(&date)), value("ProviderName")))
+}
+
+// LUID -> (nombre, VRAM dedicada) para los totales de gpu.rs. El LUID se
+// escribe como en las instancias de los contadores de GPU
+#[cfg(target_os = "windows")]
+pub fn adapter_memory() -> std::collections::HashMap<String, (String, u64)> {
+    dxgi::hardware()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(_, desc)| {
+            let luid = format!("0x{:08X}_0x{:08X}", desc.AdapterLuid.HighPart as u32, desc.AdapterLuid.LowPart);
+            let name = crate::win::from_wide(&desc.Description).trim().to_string();
+            (luid, (name, desc.DedicatedVideoMemory as u64))
+        })
+        .collect()
+}
+
+#[tauri::command]
+pub fn get_gpu_adapters() -> Result<Vec<GpuAdapterInfo>, String> {
+    #[cfg(target_os = "windows")]
+    {
+        Ok(dxgi::adapters()?
+            .into_iter()
+            .map(|adapter| {
+                let name = crate::win::from_wide(&adapter.desc.Description).trim().to_string();
+                let (vendor_id, device_id) = (adapter.desc.VendorId, adapter.desc.DeviceId);
+                let (version, driver_date, driver_provider) =
+                    registry_driver(vendor_id, device_id, &name).unwrap_or_default();
+                // DXGI da la versión instalada aunque falte la entrada del registro
+                let driver_version = version.or(adapter.umd_version);
+                GpuAdapterInfo {
+                    wddm_version: driver_version.as_deref().and_then(wddm_version),
+                    feature_level: adapter.feature_level.map(str::to_string),
+                    dedicated_memory: adapter.desc.DedicatedVideoMemory as u64,
+                    name,
+                    vendor_id,
+                    device_id,
+                    driver_version,
+                    driver_date,
+                    driver_provider,
+                }
+            })
+            .collect())
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = (wddm_version, iso_date);
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
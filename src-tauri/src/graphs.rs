@@ -0,0 +1,105 @@
+// src-tauri/src/graphs.rs
+// Ventanas independientes con un gráfico de una métrica o un proceso; se
+// alimentan de la instantánea compartida que publica get_processes
+
+use serde::{Serialize, Deserialize};
+use tauri::{AppHandle, Manager, State, WebviewUrl, WebviewWindowBuilder};
+
+use crate::AppState;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum GraphTarget {
+    Cpu,
+    Memory,
+    Network,
+    Disk,
+    Gpu,
+    Process { pid: u32 },
+}
+
+impl GraphTarget {
+    // Etiqueta de ventana estable: abrir dos veces el mismo gráfico lo enfoca
+    fn label(&self) -> String {
+        match self {
+            GraphTarget::Cpu => "graph-cpu".to_string(),
+            GraphTarget::Memory => "graph-memory".to_string(),
+            GraphTarget::Network => "graph-network".to_string(),
+            GraphTarget::Disk => "graph-disk".to_string(),
+            GraphTarget::Gpu => "graph-gpu".to_string(),
+            GraphTarget::Process { pid } => format!("graph-process-{}", pid),
+        }
+    }
+
+    fn title(&self) -> String {
+        match self {
+            GraphTarget::Cpu => "CPU".to_string(),
+            GraphTarget::Memory => "Memoria".to_string(),
+            GraphTarget::Network => "Red".to_string(),
+            GraphTarget::Disk => "Disco".to_string(),
+            GraphTarget::Gpu => "GPU".to_string(),
+            GraphTarget::Process { pid } => format!("Proceso {}", pid),
+        }
+    }
+
+    // Parámetros de consulta que la UI lee para saber qué dibujar
+    fn query(&self) -> String {
+        match self {
+            GraphTarget::Process { pid } => format!("graph=process&pid={}", pid),
+            _ => format!("graph={}", self.label().trim_start_matches("graph-")),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GraphSample {
+    pub timestamp: u64,
+    pub value: f64,             // %, bytes o bytes/s según el objetivo
+    pub secondary: Option<f64>, // Escritura / envío en disco y red
+}
+
+#[tauri::command]
+pub fn open_graph_window(app: AppHandle, target: GraphTarget) -> Result<String, String> {
+    let label = target.label();
+    if let Some(window) = app.get_webview_window(&label) {
+        window.set_focus().map_err(|e| format!("No se pudo enfocar la ventana: {}", e))?;
+        return Ok(label);
+    }
+    let url = WebviewUrl::App(format!("index.html?{}", target.query()).into());
+    WebviewWindowBuilder::new(&app, &label, url)
+        .title(target.title())
+        .inner_size(420.0, 260.0)
+        .build()
+        .map_err(|e| format!("No se pudo abrir la ventana del gráfico: {}", e))?;
+    Ok(label)
+}
+
+// Último valor del objetivo según el refresco más reciente
+#[tauri::command]
+pub fn get_graph_sample(state: State<AppState>, target: GraphTarget) -> Result<GraphSample, String> {
+    let tree = state.processes.read().unwrap();
+    let totals = &tree.totals;
+    let (value, secondary) = match target {
+        GraphTarget::Cpu => (totals.cpu_usage as f64, None),
+        GraphTarget::Memory => (totals.memory_usage as f64, None),
+        GraphTarget::Network => (
+            totals.network_received_bytes as f64,
+            Some(totals.network_transmitted_bytes as f64),
+        ),
+        GraphTarget::Disk => (totals.disk_read_bytes as f64, Some(totals.disk_write_bytes as f64)),
+        GraphTarget::Gpu => (totals.gpu_usage as f64, None),
+        GraphTarget::Process { pid } => {
+            let process = tree
+                .processes
+                .iter()
+                .find(|process| process.pid == pid)
+                .ok_or_else(|| format!("El proceso {} ya no está en ejecución", pid))?;
+            (process.cpu_usage as f64, Some(process.memory_usage as f64))
+        }
+    };
+    Ok(GraphSample {
+        timestamp: crate::stats::now_millis(),
+        value,
+        secondary,
+    })
+}
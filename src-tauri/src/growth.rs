@@ -0,0 +1,114 @@
+// src-tauri/src/growth.rs
+// Columnas de tasa de cambio: variación de CPU respecto al refresco anterior
+// y crecimiento de memoria y de handles por minuto, para ordenar directamente
+// por fugas y bucles desbocados. El crecimiento se mide entre la muestra más
+// antigua de la ventana y la actual; hasta cubrir MIN_SPAN queda vacío. Son
+// opcionales (growth_columns): contar handles abre cada proceso en cada refresco.
+// El tiempo es el del fotograma, así que una reproducción acelerada o un
+// escenario simulado dan las mismas tasas que en vivo
+
+use std::collections::{HashMap, VecDeque};
+
+use crate::format::{FormatOptions, UnitSystem};
+use crate::provider::SystemSnapshot;
+use crate::ProcessInfo;
+
+const WINDOW_MS: u64 = 60_000;
+const MIN_SPAN_MS: u64 = 10_000;
+
+#[derive(Debug, Clone, Copy)]
+struct Sample {
+    at: u64, // timestamp del fotograma, en milisegundos
+    memory: u64, // Bytes
+    handles: Option<u32>,
+}
+
+#[derive(Debug)]
+struct History {
+    start_time: u64, // Distingue un PID reutilizado
+    cpu_usage: f32,
+    samples: VecDeque<Sample>,
+}
+
+#[derive(Debug, Default)]
+pub struct GrowthTracker {
+    processes: HashMap<u32, History>,
+}
+
+// Handles del proceso; en Linux, descriptores abiertos
+#[cfg(target_os = "windows")]
+fn handle_count(pid: u32) -> Option<u32> {
+    crate::win::process_handle_count(pid)
+}
+
+#[cfg(target_os = "linux")]
+fn handle_count(pid: u32) -> Option<u32> {
+    std::fs::read_dir(format!("/proc/{}/fd", pid)).ok().map(|fds| fds.count() as u32)
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
+fn handle_count(_pid: u32) -> Option<u32> {
+    None
+}
+
+// Unidades por minuto entre la primera muestra de la ventana y la última
+fn per_minute(samples: &VecDeque<Sample>, value: impl Fn(&Sample) -> Option<f64>) -> Option<f64> {
+    let (first, last) = (samples.front()?, samples.back()?);
+    let span = last.at.saturating_sub(first.at);
+    if span < MIN_SPAN_MS {
+        return None;
+    }
+    Some((value(last)? - value(first)?) / span as f64 * 60_000.0)
+}
+
+impl GrowthTracker {
+    // Con `live` a false (reproducción o sistema simulado) los PID no son
+    // del sistema real y no se cuentan handles. La memoria va en MiB o MB
+    // según las unidades configuradas
+    pub fn sample(
+        &mut self,
+        snapshot: &SystemSnapshot,
+        processes: &mut [ProcessInfo],
+        live: bool,
+        format: &FormatOptions,
+    ) {
+        let now = snapshot.timestamp;
+        let megabyte = match format.units {
+            UnitSystem::Binary => 1024.0 * 1024.0,
+            UnitSystem::Decimal => 1000.0 * 1000.0,
+        };
+        let samples = snapshot.process_map();
+        let mut current = HashMap::with_capacity(processes.len());
+        for process in processes.iter_mut() {
+            let Some(info) = samples.get(&process.pid) else { continue };
+            let mut history = self
+                .processes
+                .remove(&process.pid)
+                .filter(|history| history.start_time == info.start_time)
+                .unwrap_or_else(|| History {
+                    start_time: info.start_time,
+                    cpu_usage: process.cpu_usage,
+                    samples: VecDeque::new(),
+                });
+            process.cpu_delta = Some(process.cpu_usage - history.cpu_usage);
+            history.cpu_usage = process.cpu_usage;
+
+            let handles = if live { handle_count(process.pid) } else { None };
+            // Un fotograma anterior a las muestras (se pasó de vivo a una
+            // grabación o se rebobinó) descarta la historia
+            history.samples.retain(|sample| sample.at <= now);
+            history.samples.push_back(Sample { at: now, memory: process.memory_usage, handles });
+            while history.samples.front().is_some_and(|sample| now - sample.at > WINDOW_MS) {
+                history.samples.pop_front();
+            }
+            process.memory_growth = per_minute(&history.samples, |sample| Some(sample.memory as f64 / megabyte));
+            process.handle_growth = per_minute(&history.samples, |sample| sample.handles.map(f64::from));
+            current.insert(process.pid, history);
+        }
+        self.processes = current;
+    }
+
+    pub fn clear(&mut self) {
+        self.processes.clear();
+    }
+}
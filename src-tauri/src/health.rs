@@ -0,0 +1,184 @@
+// src-tauri/src/health.rs
+// Modo seguro de los colectores: uno que falla en muchos refrescos seguidos o
+// que entra en pánico se desactiva durante el resto de la sesión, con el
+// motivo, en lugar de tumbar el hilo de muestreo o la sesión ETW. El resto
+// del refresco sigue con sus columnas a cero y get_capabilities lo muestra
+// como degradado. Un colector que no existe en este equipo (contadores PDH
+// ausentes, fuera de Windows) no informa de error y no cuenta como fallo
+
+use serde::Serialize;
+use std::any::Any;
+use std::collections::HashMap;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+
+const MAX_CONSECUTIVE_FAILURES: u32 = 10;
+const EVENT_RECHECK_MS: u64 = 1000; // Cada cuánto releen los eventos ETW si su colector se degradó
+pub const REFRESH: &str = "refresh";
+
+#[derive(Debug, Serialize, Clone)]
+pub struct DegradedCollector {
+    pub collector: String,
+    pub reason: String,
+    pub failures: u32,
+    pub panicked: bool,
+    pub disabled_at: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct CollectorHealth {
+    failures: HashMap<&'static str, u32>, // Fallos seguidos
+    degraded: Vec<DegradedCollector>,
+    unreported: Vec<DegradedCollector>, // Aún sin evento "collector-degraded"
+}
+
+impl CollectorHealth {
+    pub fn reason(&self, collector: &str) -> Option<String> {
+        self.degraded
+            .iter()
+            .find(|degraded| degraded.collector == collector)
+            .map(|degraded| degraded.reason.clone())
+    }
+
+    pub fn degraded(&self) -> Vec<DegradedCollector> {
+        self.degraded.clone()
+    }
+
+    // Desactivados desde la última llamada, para avisar a la interfaz
+    pub fn take_unreported(&mut self) -> Vec<DegradedCollector> {
+        std::mem::take(&mut self.unreported)
+    }
+
+    fn degrade(&mut self, collector: &'static str, reason: String, panicked: bool) {
+        let degraded = DegradedCollector {
+            collector: collector.to_string(),
+            reason,
+            failures: self.failures.remove(collector).unwrap_or(0),
+            panicked,
+            disabled_at: crate::stats::now_millis(),
+        };
+        self.degraded.push(degraded.clone());
+        self.unreported.push(degraded);
+    }
+
+    // Pánico en el refresco fuera de los colectores: se pierde ese refresco,
+    // pero el muestreo sigue. Queda como degradado con el número de pánicos
+    pub fn refresh_panicked(&mut self, message: String) {
+        let panics = self.failures.entry(REFRESH).or_insert(0);
+        *panics += 1;
+        let (panics, reason) = (*panics, format!("Refrescos perdidos por pánicos: {}", message));
+        match self.degraded.iter_mut().find(|degraded| degraded.collector == REFRESH) {
+            Some(degraded) => {
+                degraded.failures = panics;
+                degraded.reason = reason;
+            }
+            None => {
+                let degraded = DegradedCollector {
+                    collector: REFRESH.to_string(),
+                    reason,
+                    failures: panics,
+                    panicked: true,
+                    disabled_at: crate::stats::now_millis(),
+                };
+                self.degraded.push(degraded.clone());
+                self.unreported.push(degraded);
+            }
+        }
+    }
+
+    fn record(&mut self, collector: &'static str, error: Option<String>) {
+        let Some(error) = error else {
+            self.failures.remove(collector);
+            return;
+        };
+        let failures = self.failures.entry(collector).or_insert(0);
+        *failures += 1;
+        if *failures >= MAX_CONSECUTIVE_FAILURES {
+            let reason = format!("Desactivado tras {} fallos seguidos: {}", failures, error);
+            self.degrade(collector, reason, false);
+        }
+    }
+}
+
+pub fn panic_message(panic: &(dyn Any + Send)) -> String {
+    panic
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| panic.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "pánico sin mensaje".to_string())
+}
+
+// Ejecuta un paso del colector salvo que ya esté degradado. `error` lee el
+// fallo que dejó el paso (None si fue bien o si el colector no informa de
+// errores, como los de ETW). Tras un pánico se limpia el envenenamiento del
+// mutex para que los comandos que lo leen sigan funcionando
+pub fn guard<C, T>(
+    health: &Mutex<CollectorHealth>,
+    name: &'static str,
+    collector: &Mutex<C>,
+    step: impl FnOnce(&mut C) -> T,
+    error: impl FnOnce(&C) -> Option<String>,
+) -> Option<T> {
+    if health.lock().unwrap().reason(name).is_some() {
+        return None;
+    }
+    let outcome = catch_unwind(AssertUnwindSafe(|| {
+        let mut collector = collector.lock().unwrap_or_else(PoisonError::into_inner);
+        let value = step(&mut collector);
+        (value, error(&collector))
+    }));
+    let mut health = health.lock().unwrap();
+    match outcome {
+        Ok((value, failure)) => {
+            health.record(name, failure);
+            Some(value)
+        }
+        Err(panic) => {
+            collector.clear_poison();
+            health.degrade(name, format!("Desactivado tras un pánico: {}", panic_message(panic.as_ref())), true);
+            None
+        }
+    }
+}
+
+// `guard` para los eventos ETW, que llegan a miles por segundo: un evento que
+// va bien no toca el mutex de salud, y si el colector se degradó se relee como
+// mucho una vez por segundo. Los eventos no informan de errores, solo de pánicos
+pub struct EventGuard {
+    health: Arc<Mutex<CollectorHealth>>,
+    name: &'static str,
+    degraded: AtomicBool,
+    checked_at: AtomicU64,
+}
+
+impl EventGuard {
+    pub fn new(health: Arc<Mutex<CollectorHealth>>, name: &'static str) -> Self {
+        EventGuard { health, name, degraded: AtomicBool::new(false), checked_at: AtomicU64::new(0) }
+    }
+
+    pub fn run<C, T>(&self, collector: &Mutex<C>, step: impl FnOnce(&mut C) -> T) -> Option<T> {
+        let now = crate::stats::now_millis();
+        if now.saturating_sub(self.checked_at.load(Ordering::Relaxed)) >= EVENT_RECHECK_MS {
+            self.checked_at.store(now, Ordering::Relaxed);
+            let degraded = self.health.lock().unwrap().reason(self.name).is_some();
+            self.degraded.store(degraded, Ordering::Relaxed);
+        }
+        if self.degraded.load(Ordering::Relaxed) {
+            return None;
+        }
+        let outcome = catch_unwind(AssertUnwindSafe(|| {
+            step(&mut collector.lock().unwrap_or_else(PoisonError::into_inner))
+        }));
+        match outcome {
+            Ok(value) => Some(value),
+            Err(panic) => {
+                collector.clear_poison();
+                self.degraded.store(true, Ordering::Relaxed);
+                let reason = format!("Desactivado tras un pánico: {}", panic_message(panic.as_ref()));
+                self.health.lock().unwrap().degrade(self.name, reason, true);
+                None
+            }
+        }
+    }
+}
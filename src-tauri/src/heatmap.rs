@@ -0,0 +1,126 @@
+// src-tauri/src/heatmap.rs
+// Mapa de calor proceso-núcleo: para los procesos seleccionados se reparten
+// los ciclos de cada hilo entre refrescos sobre su procesador ideal
+
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use tauri::State;
+
+use crate::cores::{self, CoreTopology};
+use crate::stats::{now_millis, HISTORY_CAPACITY};
+use crate::AppState;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct HeatmapSample {
+    pub timestamp: u64,
+    pub cycles: Vec<u64>,  // Por procesador lógico, en el orden de la topología
+    pub threads: Vec<u32>, // Hilos con ese procesador ideal
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct CoreHeatmapData {
+    pub pid: u32,
+    pub topology: CoreTopology,
+    pub samples: Vec<HeatmapSample>,
+}
+
+#[derive(Debug, Default)]
+struct HeatmapWatch {
+    previous_cycles: HashMap<u32, u64>, // TID -> ciclos en la muestra anterior
+    samples: VecDeque<HeatmapSample>,
+}
+
+#[derive(Debug, Default)]
+pub struct CoreHeatmap {
+    topology: Option<CoreTopology>,
+    watched: HashMap<u32, HeatmapWatch>,
+    pub error: Option<String>,
+}
+
+impl CoreHeatmap {
+    // Una muestra por proceso vigilado; no hace nada si no hay ninguno
+    pub fn sample(&mut self) {
+        if self.watched.is_empty() {
+            return;
+        }
+        if self.topology.is_none() {
+            match cores::topology() {
+                Ok(topology) => self.topology = Some(topology),
+                Err(e) => {
+                    self.error = Some(e);
+                    return;
+                }
+            }
+        }
+        let topology = self.topology.as_ref().unwrap();
+        let positions: HashMap<(u16, u8), usize> = topology
+            .cpus
+            .iter()
+            .enumerate()
+            .map(|(index, cpu)| ((cpu.group, cpu.number), index))
+            .collect();
+
+        // Los procesos que ya no existen dejan de vigilarse
+        self.watched.retain(|pid, watch| {
+            let Ok(placements) = cores::thread_placements(*pid) else { return false };
+            let mut sample = HeatmapSample {
+                timestamp: now_millis(),
+                cycles: vec![0; positions.len()],
+                threads: vec![0; positions.len()],
+            };
+            let mut current = HashMap::new();
+            for placement in placements {
+                // Un hilo nuevo solo cuenta desde la muestra siguiente
+                let delta = watch
+                    .previous_cycles
+                    .get(&placement.tid)
+                    .map_or(0, |previous| placement.cycles.saturating_sub(*previous));
+                current.insert(placement.tid, placement.cycles);
+                if let Some(&index) = positions.get(&(placement.group, placement.number)) {
+                    sample.cycles[index] += delta;
+                    sample.threads[index] += 1;
+                }
+            }
+            // La primera lectura solo fija la referencia de ciclos
+            if !watch.previous_cycles.is_empty() {
+                if watch.samples.len() == HISTORY_CAPACITY {
+                    watch.samples.pop_front();
+                }
+                watch.samples.push_back(sample);
+            }
+            watch.previous_cycles = current;
+            true
+        });
+        self.error = None;
+    }
+}
+
+#[tauri::command]
+pub fn watch_core_heatmap(state: State<AppState>, pid: u32) -> Result<(), String> {
+    // Se comprueba el acceso a los hilos antes de registrarlo
+    cores::thread_placements(pid)?;
+    state.core_heatmap.lock().unwrap().watched.entry(pid).or_default();
+    Ok(())
+}
+
+#[tauri::command]
+pub fn unwatch_core_heatmap(state: State<AppState>, pid: u32) {
+    state.core_heatmap.lock().unwrap().watched.remove(&pid);
+}
+
+#[tauri::command]
+pub fn get_core_heatmap(state: State<AppState>, pid: u32) -> Result<CoreHeatmapData, String> {
+    let heatmap = state.core_heatmap.lock().unwrap();
+    if let Some(error) = &heatmap.error {
+        return Err(error.clone());
+    }
+    let watch = heatmap
+        .watched
+        .get(&pid)
+        .ok_or_else(|| format!("El proceso {} no está vigilado", pid))?;
+    let topology = match &heatmap.topology {
+        Some(topology) => topology.clone(),
+        None => cores::topology()?,
+    };
+    Ok(CoreHeatmapData { pid, topology, samples: watch.samples.iter().cloned().collect() })
+}
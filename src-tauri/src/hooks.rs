@@ -0,0 +1,278 @@
+// src-tauri/src/hooks.rs
+// Detección heurística de procesos que capturan teclado o ratón: Windows no
+// permite enumerar los ganchos globales de otros procesos, así que se combinan
+// las señales visibles (funciones importadas, DLL inyectadas en este mismo
+// proceso, ausencia de ventanas) para señalar posibles keyloggers o utilidades
+// que añaden latencia a la entrada
+
+use serde::Serialize;
+use std::collections::HashMap;
+use std::time::SystemTime;
+use tauri::State;
+
+use crate::AppState;
+
+#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum HookEvidence {
+    ImportsHookApi,    // SetWindowsHookEx
+    ImportsRawInput,   // RegisterRawInputDevices
+    ImportsKeyPolling, // GetAsyncKeyState / GetKeyboardState
+    // Una DLL suya está cargada en este proceso (gancho global que no es LL)
+    InjectedModule { path: String },
+    NoVisibleWindow, // Se ejecuta en segundo plano
+    SystemComponent, // Ejecutable de la carpeta de Windows
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum HookRisk {
+    Low,
+    Medium,
+    High,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct InputHookSuspect {
+    pub pid: u32,
+    pub name: String,
+    pub path: Option<String>,
+    pub evidence: Vec<HookEvidence>,
+    pub risk: HookRisk,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct InputHookReport {
+    pub suspects: Vec<InputHookSuspect>, // De mayor a menor riesgo
+    // DLL ajenas inyectadas en este proceso sin un proceso al que atribuirlas
+    pub unattributed_modules: Vec<String>,
+}
+
+#[cfg(target_os = "windows")]
+fn risk(evidence: &[HookEvidence]) -> HookRisk {
+    let score: i32 = evidence
+        .iter()
+        .map(|evidence| match evidence {
+            HookEvidence::ImportsHookApi => 2,
+            HookEvidence::ImportsRawInput => 1,
+            HookEvidence::ImportsKeyPolling => 1,
+            HookEvidence::InjectedModule { .. } => 2,
+            HookEvidence::NoVisibleWindow => 2,
+            HookEvidence::SystemComponent => -3,
+        })
+        .sum();
+    match score {
+        5.. => HookRisk::High,
+        3..=4 => HookRisk::Medium,
+        _ => HookRisk::Low,
+    }
+}
+
+// Lectura mínima de la tabla de importaciones de un PE, limitada a las
+// funciones por nombre de user32.dll (las que sirven para capturar la entrada)
+#[cfg(target_os = "windows")]
+mod pe {
+    use std::collections::HashSet;
+
+    fn u16_at(data: &[u8], offset: usize) -> Option<u16> {
+        Some(u16::from_le_bytes(data.get(offset..offset + 2)?.try_into().ok()?))
+    }
+
+    fn u32_at(data: &[u8], offset: usize) -> Option<u32> {
+        Some(u32::from_le_bytes(data.get(offset..offset + 4)?.try_into().ok()?))
+    }
+
+    fn u64_at(data: &[u8], offset: usize) -> Option<u64> {
+        Some(u64::from_le_bytes(data.get(offset..offset + 8)?.try_into().ok()?))
+    }
+
+    fn c_string(data: &[u8], offset: usize) -> Option<&str> {
+        let bytes = data.get(offset..)?;
+        let end = bytes.iter().take(256).position(|&byte| byte == 0)?;
+        std::str::from_utf8(&bytes[..end]).ok()
+    }
+
+    pub fn user32_imports(data: &[u8]) -> Option<HashSet<String>> {
+        if data.get(..2)? != b"MZ" {
+            return None;
+        }
+        let pe = u32_at(data, 0x3C)? as usize;
+        if data.get(pe..pe + 4)? != b"PE\0\0" {
+            return None;
+        }
+        let sections = u16_at(data, pe + 6)? as usize;
+        let optional_size = u16_at(data, pe + 20)? as usize;
+        let optional = pe + 24;
+        let wide = match u16_at(data, optional)? {
+            0x10B => false,
+            0x20B => true,
+            _ => return None,
+        };
+        let directories = optional + if wide { 112 } else { 96 };
+        let import_rva = u32_at(data, directories + 8)?;
+        if import_rva == 0 {
+            return Some(HashSet::new());
+        }
+
+        let table = optional + optional_size;
+        let to_offset = |rva: u32| -> Option<usize> {
+            (0..sections.min(96)).find_map(|index| {
+                let section = table + index * 40;
+                let virtual_size = u32_at(data, section + 8)?;
+                let address = u32_at(data, section + 12)?;
+                let raw_size = u32_at(data, section + 16)?;
+                let raw = u32_at(data, section + 20)?;
+                let size = virtual_size.max(raw_size);
+                (rva >= address && rva < address + size).then(|| (rva - address + raw) as usize)
+            })
+        };
+
+        let mut names = HashSet::new();
+        let mut descriptor = to_offset(import_rva)?;
+        for _ in 0..512 {
+            let lookup = u32_at(data, descriptor)?;
+            let name = u32_at(data, descriptor + 12)?;
+            let thunks = u32_at(data, descriptor + 16)?;
+            if name == 0 && thunks == 0 {
+                break;
+            }
+            descriptor += 20;
+            let is_user32 = to_offset(name)
+                .and_then(|offset| c_string(data, offset))
+                .is_some_and(|dll| dll.eq_ignore_ascii_case("user32.dll"));
+            if !is_user32 {
+                continue;
+            }
+            // Sin tabla de búsqueda (enlazadores antiguos) vale la de direcciones
+            let Some(mut thunk) = to_offset(if lookup != 0 { lookup } else { thunks }) else { continue };
+            for _ in 0..4096 {
+                let (entry, by_ordinal) = if wide {
+                    let value = u64_at(data, thunk)?;
+                    (value & 0x7FFF_FFFF, value & (1 << 63) != 0)
+                } else {
+                    let value = u32_at(data, thunk)? as u64;
+                    (value & 0x7FFF_FFFF, value & (1 << 31) != 0)
+                };
+                if entry == 0 && !by_ordinal {
+                    break;
+                }
+                thunk += if wide { 8 } else { 4 };
+                if by_ordinal {
+                    continue;
+                }
+                if let Some(function) = to_offset(entry as u32).and_then(|offset| c_string(data, offset + 2)) {
+                    names.insert(function.to_string());
+                }
+            }
+        }
+        Some(names)
+    }
+}
+
+// Importaciones por ruta, con la fecha de modificación con la que se leyeron:
+// los ejecutables no cambian entre consultas
+#[derive(Debug, Default)]
+pub struct ImportCache {
+    entries: HashMap<String, (SystemTime, Vec<HookEvidence>)>,
+}
+
+#[cfg(target_os = "windows")]
+fn import_evidence(state: &AppState, path: &str) -> Vec<HookEvidence> {
+    let Ok(modified) = std::fs::metadata(path).and_then(|metadata| metadata.modified()) else {
+        return Vec::new();
+    };
+    let key = path.to_lowercase();
+    let cached = state.hook_imports.lock().unwrap().entries.get(&key).filter(|(at, _)| *at == modified).cloned();
+    if let Some((_, evidence)) = cached {
+        return evidence;
+    }
+    let imports = std::fs::read(path).ok().and_then(|data| pe::user32_imports(&data)).unwrap_or_default();
+    let has = |names: &[&str]| names.iter().any(|name| imports.contains(*name));
+    let mut evidence = Vec::new();
+    if has(&["SetWindowsHookExW", "SetWindowsHookExA", "SetWindowsHookW", "SetWindowsHookA"]) {
+        evidence.push(HookEvidence::ImportsHookApi);
+    }
+    if has(&["RegisterRawInputDevices"]) {
+        evidence.push(HookEvidence::ImportsRawInput);
+    }
+    if has(&["GetAsyncKeyState", "GetKeyboardState"]) {
+        evidence.push(HookEvidence::ImportsKeyPolling);
+    }
+    state.hook_imports.lock().unwrap().entries.insert(key, (modified, evidence.clone()));
+    evidence
+}
+
+// Los ganchos globales que no son LL cargan su DLL en todos los procesos con
+// interfaz, también en este: las DLL de fuera de Windows y de esta aplicación
+#[cfg(target_os = "windows")]
+fn foreign_modules() -> Vec<String> {
+    let windows_dir = std::env::var("SystemRoot").unwrap_or_else(|_| r"C:\Windows".to_string()).to_lowercase();
+    let own_dir = std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(|dir| dir.display().to_string().to_lowercase()))
+        .unwrap_or_default();
+    crate::modules::get_process_modules(std::process::id())
+        .unwrap_or_default()
+        .into_iter()
+        .map(|module| module.path)
+        .filter(|path| {
+            let lower = path.to_lowercase();
+            lower.ends_with(".dll")
+                && !lower.starts_with(&windows_dir)
+                && (own_dir.is_empty() || !lower.starts_with(&own_dir))
+        })
+        .collect()
+}
+
+#[tauri::command]
+pub fn get_input_hooks(state: State<AppState>) -> Result<InputHookReport, String> {
+    #[cfg(target_os = "windows")]
+    {
+        let windows_dir = std::env::var("SystemRoot").unwrap_or_else(|_| r"C:\Windows".to_string()).to_lowercase();
+        let processes: Vec<(u32, String)> = state
+            .processes
+            .read()
+            .unwrap()
+            .processes
+            .iter()
+            .filter(|process| process.pid != std::process::id())
+            .map(|process| (process.pid, process.name.clone()))
+            .collect();
+        let mut unattributed = foreign_modules();
+
+        let mut suspects = Vec::new();
+        for (pid, name) in processes {
+            let path = crate::win::process_image_path(pid);
+            let mut evidence = path.as_deref().map(|path| import_evidence(&state, path)).unwrap_or_default();
+            // La DLL inyectada se atribuye al proceso cuya carpeta la contiene
+            let dir = path.as_deref().and_then(|path| path.rsplit_once('\\')).map(|(dir, _)| dir.to_lowercase());
+            if let Some(dir) = dir {
+                unattributed.retain(|module| {
+                    let inside = module.to_lowercase().starts_with(&format!("{}\\", dir));
+                    if inside {
+                        evidence.push(HookEvidence::InjectedModule { path: module.clone() });
+                    }
+                    !inside
+                });
+            }
+            if evidence.is_empty() {
+                continue;
+            }
+            if crate::win::visible_window_titles(pid).is_empty() {
+                evidence.push(HookEvidence::NoVisibleWindow);
+            }
+            if path.as_deref().is_some_and(|path| path.to_lowercase().starts_with(&windows_dir)) {
+                evidence.push(HookEvidence::SystemComponent);
+            }
+            suspects.push(InputHookSuspect { pid, name, risk: risk(&evidence), path, evidence });
+        }
+        suspects.sort_by(|a, b| b.risk.cmp(&a.risk).then_with(|| a.name.cmp(&b.name)));
+        Ok(InputHookReport { suspects, unattributed_modules: unattributed })
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = state;
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
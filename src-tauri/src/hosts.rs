@@ -0,0 +1,106 @@
+// src-tauri/src/hosts.rs
+// Tráfico de cada proceso por host remoto: direcciones del colector de red
+// resueltas con la caché DNS del sistema ("api.github.com 12 MB")
+
+use serde::Serialize;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::State;
+
+use crate::AppState;
+
+// La caché DNS se vuelve a leer como mucho cada este tiempo
+const DNS_CACHE_TTL: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Serialize, Clone)]
+pub struct HostTraffic {
+    pub host: String, // Nombre de la caché DNS o, si no aparece, la dirección
+    pub addresses: Vec<String>,
+    pub sent_bytes: u64,
+    pub received_bytes: u64,
+}
+
+static DNS_CACHE: Mutex<Option<(Instant, HashMap<IpAddr, String>)>> = Mutex::new(None);
+
+// Interpreta `ipconfig /displaydns`. Las etiquetas dependen del idioma, así
+// que se usa la estructura: cada bloque empieza tras una línea de guiones, su
+// primer campo es el nombre del registro y los valores que son direcciones
+// IP pertenecen a ese nombre
+fn parse_displaydns(output: &str) -> HashMap<IpAddr, String> {
+    let mut names = HashMap::new();
+    let mut current: Option<String> = None;
+    let mut expect_name = false;
+    for line in output.lines().map(str::trim) {
+        if line.starts_with("---") {
+            expect_name = true;
+            continue;
+        }
+        let Some((_, value)) = line.split_once(" : ") else { continue };
+        let value = value.trim();
+        if expect_name {
+            current = Some(value.to_lowercase());
+            expect_name = false;
+        } else if let (Ok(address), Some(name)) = (value.parse::<IpAddr>(), &current) {
+            names.insert(address, name.clone());
+        }
+    }
+    names
+}
+
+fn dns_names() -> HashMap<IpAddr, String> {
+    let mut cache = DNS_CACHE.lock().unwrap();
+    if let Some((read_at, names)) = cache.as_ref() {
+        if read_at.elapsed() < DNS_CACHE_TTL {
+            return names.clone();
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    let names = std::process::Command::new("ipconfig")
+        .arg("/displaydns")
+        .output()
+        .map(|output| parse_displaydns(&crate::win::decode_console_output(&output.stdout)))
+        .unwrap_or_default();
+
+    #[cfg(not(target_os = "windows"))]
+    let names = HashMap::new();
+
+    *cache = Some((Instant::now(), names.clone()));
+    names
+}
+
+// Tráfico de la sesión agrupado por host, de mayor a menor volumen
+#[tauri::command]
+pub fn get_process_hosts(state: State<AppState>, pid: u32) -> Result<Vec<HostTraffic>, String> {
+    let by_address: Vec<(IpAddr, (u64, u64))> = {
+        let bandwidth = state.bandwidth.lock().unwrap();
+        if let Some(error) = &bandwidth.error {
+            return Err(error.clone());
+        }
+        bandwidth
+            .hosts(pid)
+            .map(|hosts| hosts.iter().map(|(address, bytes)| (*address, *bytes)).collect())
+            .unwrap_or_default()
+    };
+
+    let names = dns_names();
+    let mut hosts: HashMap<String, HostTraffic> = HashMap::new();
+    for (address, (sent, received)) in by_address {
+        let host = names.get(&address).cloned().unwrap_or_else(|| address.to_string());
+        let entry = hosts.entry(host.clone()).or_insert_with(|| HostTraffic {
+            host,
+            addresses: Vec::new(),
+            sent_bytes: 0,
+            received_bytes: 0,
+        });
+        entry.addresses.push(address.to_string());
+        entry.sent_bytes += sent;
+        entry.received_bytes += received;
+    }
+
+    let mut hosts: Vec<HostTraffic> = hosts.into_values().collect();
+    hosts.sort_by_key(|host| std::cmp::Reverse(host.sent_bytes + host.received_bytes));
+    Ok(hosts)
+}
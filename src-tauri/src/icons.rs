@@ -0,0 +1,181 @@
+// src-tauri/src/icons.rs
+// Icono del ejecutable de cada proceso como PNG en base64 para la lista de
+// procesos. Se guarda en una caché LRU por ruta: la interfaz lo pide en cada
+// refresco y casi siempre es el mismo
+
+use std::collections::{HashMap, VecDeque};
+use tauri::State;
+
+use crate::AppState;
+
+const CACHE_CAPACITY: usize = 256;
+
+// Ruta del ejecutable -> PNG en base64 (None si no tiene icono, para no
+// volver a intentarlo)
+#[derive(Debug, Default)]
+pub struct IconCache {
+    entries: HashMap<String, Option<String>>,
+    order: VecDeque<String>, // De menos a más reciente
+}
+
+impl IconCache {
+    fn get(&mut self, path: &str) -> Option<Option<String>> {
+        let icon = self.entries.get(path)?.clone();
+        self.touch(path);
+        Some(icon)
+    }
+
+    fn touch(&mut self, path: &str) {
+        if let Some(index) = self.order.iter().position(|entry| entry == path) {
+            self.order.remove(index);
+        }
+        self.order.push_back(path.to_string());
+    }
+
+    fn insert(&mut self, path: &str, icon: Option<String>) {
+        if !self.entries.contains_key(path) && self.entries.len() == CACHE_CAPACITY {
+            if let Some(oldest) = self.order.pop_front() {
+                self.entries.remove(&oldest);
+            }
+        }
+        self.entries.insert(path.to_string(), icon);
+        self.touch(path);
+    }
+}
+
+// Píxeles de un mapa de bits en BGRA, de arriba abajo
+#[cfg(target_os = "windows")]
+fn bitmap_pixels(bitmap: windows::Win32::Graphics::Gdi::HBITMAP) -> Option<(u32, u32, Vec<u8>)> {
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::Graphics::Gdi::{
+        GetDC, GetDIBits, GetObjectW, ReleaseDC, BITMAP, BITMAPINFO, BITMAPINFOHEADER, BI_RGB, DIB_RGB_COLORS,
+    };
+
+    let mut header = BITMAP::default();
+    let size = std::mem::size_of::<BITMAP>() as i32;
+    if bitmap.is_invalid() || unsafe { GetObjectW(bitmap, size, Some(&mut header as *mut _ as *mut _)) } == 0 {
+        return None;
+    }
+    let (width, height) = (header.bmWidth, header.bmHeight);
+    let mut info = BITMAPINFO {
+        bmiHeader: BITMAPINFOHEADER {
+            biSize: std::mem::size_of::<BITMAPINFOHEADER>() as u32,
+            biWidth: width,
+            biHeight: -height, // Negativo: filas de arriba abajo
+            biPlanes: 1,
+            biBitCount: 32,
+            biCompression: BI_RGB.0 as u32,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut pixels = vec![0u8; (width * height * 4) as usize];
+    let dc = unsafe { GetDC(HWND(0)) };
+    let lines = unsafe {
+        GetDIBits(dc, bitmap, 0, height as u32, Some(pixels.as_mut_ptr() as *mut _), &mut info, DIB_RGB_COLORS)
+    };
+    unsafe { ReleaseDC(HWND(0), dc) };
+    (lines == height).then_some((width as u32, height as u32, pixels))
+}
+
+// Icono grande del shell (el mismo que muestra el Explorador) convertido a RGBA
+#[cfg(target_os = "windows")]
+fn extract_rgba(path: &str) -> Option<(u32, u32, Vec<u8>)> {
+    use windows::core::PCWSTR;
+    use windows::Win32::Graphics::Gdi::DeleteObject;
+    use windows::Win32::Storage::FileSystem::FILE_FLAGS_AND_ATTRIBUTES;
+    use windows::Win32::UI::Shell::{SHGetFileInfoW, SHFILEINFOW, SHGFI_ICON, SHGFI_LARGEICON};
+    use windows::Win32::UI::WindowsAndMessaging::{DestroyIcon, GetIconInfo, ICONINFO};
+
+    let wide = crate::win::to_wide(path);
+    let mut file_info = SHFILEINFOW::default();
+    let found = unsafe {
+        SHGetFileInfoW(
+            PCWSTR(wide.as_ptr()),
+            FILE_FLAGS_AND_ATTRIBUTES(0),
+            Some(&mut file_info),
+            std::mem::size_of::<SHFILEINFOW>() as u32,
+            SHGFI_ICON | SHGFI_LARGEICON,
+        )
+    };
+    if found == 0 || file_info.hIcon.is_invalid() {
+        return None;
+    }
+    let mut icon = ICONINFO::default();
+    let ok = unsafe { GetIconInfo(file_info.hIcon, &mut icon) }.as_bool();
+    unsafe { DestroyIcon(file_info.hIcon) };
+    if !ok {
+        return None;
+    }
+    // Los iconos monocromos (sin hbmColor) no se convierten
+    let color = bitmap_pixels(icon.hbmColor);
+    let mask = bitmap_pixels(icon.hbmMask);
+    unsafe {
+        DeleteObject(icon.hbmColor);
+        DeleteObject(icon.hbmMask);
+    }
+    let (width, height, mut pixels) = color?;
+
+    // Sin canal alfa (iconos antiguos) la transparencia la da la máscara:
+    // blanco = transparente
+    let has_alpha = pixels.chunks_exact(4).any(|pixel| pixel[3] != 0);
+    let mask = mask.filter(|(mask_width, mask_height, _)| *mask_width == width && *mask_height == height);
+    for (index, pixel) in pixels.chunks_exact_mut(4).enumerate() {
+        pixel.swap(0, 2);
+        if !has_alpha {
+            pixel[3] = match &mask {
+                Some((_, _, mask)) if mask[index * 4] != 0 => 0,
+                _ => 255,
+            };
+        }
+    }
+    Some((width, height, pixels))
+}
+
+#[cfg(target_os = "windows")]
+fn encode_png(width: u32, height: u32, rgba: &[u8]) -> Option<Vec<u8>> {
+    let mut output = Vec::new();
+    {
+        let mut encoder = png::Encoder::new(&mut output, width, height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.write_header().ok()?.write_image_data(rgba).ok()?;
+    }
+    Some(output)
+}
+
+fn load_icon(path: &str) -> Option<String> {
+    #[cfg(target_os = "windows")]
+    {
+        use base64::Engine;
+
+        let (width, height, rgba) = extract_rgba(path)?;
+        let png = encode_png(width, height, &rgba)?;
+        Some(base64::engine::general_purpose::STANDARD.encode(png))
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = path;
+        None
+    }
+}
+
+// PNG en base64, o None si el ejecutable no tiene icono o fuera de Windows
+#[tauri::command]
+pub fn get_process_icon(state: State<AppState>, pid: u32) -> Result<Option<String>, String> {
+    #[cfg(target_os = "windows")]
+    let path = crate::win::process_image_path(pid);
+    #[cfg(not(target_os = "windows"))]
+    let path: Option<String> = None;
+    let path = path.or_else(|| crate::relaunch::capture(&state, pid).map(|process| process.exe));
+    let path = path.ok_or_else(|| format!("No se pudo obtener la ruta del proceso {}", pid))?;
+
+    if let Some(icon) = state.icons.lock().unwrap().get(&path) {
+        return Ok(icon);
+    }
+    // Sin el bloqueo: la extracción puede tardar y otras filas no deben esperar
+    let icon = load_icon(&path);
+    state.icons.lock().unwrap().insert(&path, icon.clone());
+    Ok(icon)
+}
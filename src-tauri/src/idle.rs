@@ -0,0 +1,54 @@
+// src-tauri/src/idle.rs
+// Tiempo de inactividad del usuario (sin teclado ni ratón): lo usan las reglas
+// y el sondeo de la UI, que se ralentiza mientras nadie mira
+
+use serde::Serialize;
+use tauri::State;
+
+use crate::settings::Settings;
+use crate::AppState;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct IdleInfo {
+    pub idle_seconds: u64,
+    pub idle: bool,               // Supera el umbral de la configuración
+    pub refresh_interval_ms: u64, // Intervalo de sondeo recomendado ahora mismo
+}
+
+// Segundos desde la última entrada del usuario en la sesión
+pub fn idle_seconds() -> Option<u64> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::System::SystemInformation::GetTickCount;
+        use windows::Win32::UI::Input::KeyboardAndMouse::{GetLastInputInfo, LASTINPUTINFO};
+
+        let mut info = LASTINPUTINFO { cbSize: std::mem::size_of::<LASTINPUTINFO>() as u32, dwTime: 0 };
+        if !unsafe { GetLastInputInfo(&mut info) }.as_bool() {
+            return None;
+        }
+        // Ambos contadores son de 32 bits y se desbordan a los ~49 días
+        Some(unsafe { GetTickCount() }.wrapping_sub(info.dwTime) as u64 / 1000)
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        None
+    }
+}
+
+// Intervalo de refresco según la presencia del usuario
+pub fn refresh_interval(settings: &Settings, idle: bool) -> u64 {
+    if idle {
+        settings.idle_refresh_interval_ms.max(settings.refresh_interval_ms)
+    } else {
+        settings.refresh_interval_ms
+    }
+}
+
+#[tauri::command]
+pub fn get_idle_time(state: State<AppState>) -> Result<IdleInfo, String> {
+    let idle_seconds = idle_seconds().ok_or("No se pudo obtener el tiempo de inactividad")?;
+    let settings = state.settings.read().unwrap();
+    let idle = idle_seconds >= settings.idle_threshold_secs;
+    Ok(IdleInfo { idle_seconds, idle, refresh_interval_ms: refresh_interval(&settings, idle) })
+}
@@ -0,0 +1,76 @@
+// src-tauri/src/impact.rs
+// Puntuación de impacto (0-100) que combina CPU, memoria, disco, GPU y red en
+// un solo número, para ordenar por "lo que más pesa" sin interpretar cada columna
+
+use serde::{Serialize, Deserialize};
+
+use crate::{ProcessInfo, ProcessTotals};
+
+// Por debajo de estas tasas el total del sistema no se usa como referencia:
+// un proceso que lee unos pocos KB/s en un equipo en reposo no llega al 100 %
+const DISK_REFERENCE_RATE: f64 = 1024.0 * 1024.0;
+const NETWORK_REFERENCE_RATE: f64 = 1024.0 * 1024.0;
+
+// Pesos relativos de cada recurso; no hace falta que sumen 1
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct ImpactWeights {
+    pub cpu: f64,
+    pub memory: f64,
+    pub disk: f64,
+    pub gpu: f64,
+    pub network: f64,
+}
+
+impl Default for ImpactWeights {
+    fn default() -> Self {
+        ImpactWeights {
+            cpu: 0.35,
+            memory: 0.25,
+            disk: 0.15,
+            gpu: 0.15,
+            network: 0.10,
+        }
+    }
+}
+
+// Cada recurso se normaliza a 0-1: CPU sobre todos los núcleos, memoria sobre
+// la total, GPU en porcentaje, y disco y red como parte del total del sistema
+pub fn annotate(
+    processes: &mut [ProcessInfo],
+    totals: &ProcessTotals,
+    weights: &ImpactWeights,
+    cpu_count: usize,
+    total_memory: u64,
+) {
+    // Un peso negativo cuenta como 0
+    let weights = ImpactWeights {
+        cpu: weights.cpu.max(0.0),
+        memory: weights.memory.max(0.0),
+        disk: weights.disk.max(0.0),
+        gpu: weights.gpu.max(0.0),
+        network: weights.network.max(0.0),
+    };
+    let weight_sum = weights.cpu + weights.memory + weights.disk + weights.gpu + weights.network;
+    let disk_total = (totals.disk_read_rate + totals.disk_write_rate).max(DISK_REFERENCE_RATE);
+    let network_processes: f64 =
+        processes.iter().map(|process| process.network_send_rate + process.network_receive_rate).sum();
+    let network_total = network_processes
+        .max(totals.network_receive_rate + totals.network_send_rate)
+        .max(NETWORK_REFERENCE_RATE);
+    let cpu_total = 100.0 * cpu_count.max(1) as f64;
+
+    for process in processes.iter_mut() {
+        if weight_sum <= 0.0 {
+            process.impact_score = 0.0;
+            continue;
+        }
+        let share = |value: f64, total: f64| if total > 0.0 { (value / total).clamp(0.0, 1.0) } else { 0.0 };
+        let score = weights.cpu * share(process.cpu_usage as f64, cpu_total)
+            + weights.memory * share(process.memory_usage as f64, total_memory as f64)
+            + weights.disk * share(process.disk_read_rate + process.disk_write_rate, disk_total)
+            + weights.gpu * share(process.gpu_usage as f64, 100.0)
+            + weights.network * share(process.network_send_rate + process.network_receive_rate, network_total);
+        process.impact_score = 100.0 * score / weight_sum;
+    }
+}
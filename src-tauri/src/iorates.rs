@@ -0,0 +1,73 @@
+// src-tauri/src/iorates.rs
+// Tasas de E/S por segundo a partir de los contadores acumulados de cada
+// proceso y de las interfaces de red, para que la interfaz no tenga que
+// restar muestras. Un PID reutilizado o un contador que retrocede empiezan de cero
+
+use std::collections::HashMap;
+use std::time::Instant;
+
+use crate::provider::SystemSnapshot;
+use crate::{ProcessInfo, ProcessTotals};
+
+#[derive(Debug, Clone, Copy)]
+struct Counters {
+    start_time: u64, // Distingue un PID reutilizado
+    read: u64,
+    written: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct IoRateTracker {
+    processes: HashMap<u32, Counters>,
+    network: HashMap<String, (u64, u64)>, // Interfaz -> (recibidos, enviados)
+    last_sample: Option<Instant>,
+}
+
+// Bytes por segundo; sin muestra anterior válida la tasa es 0
+fn rate(current: u64, previous: Option<u64>, seconds: f64) -> f64 {
+    match previous {
+        Some(previous) if current >= previous && seconds > 0.0 => (current - previous) as f64 / seconds,
+        _ => 0.0,
+    }
+}
+
+impl IoRateTracker {
+    // Solo con el refresco completo: un refresco parcial acortaría el intervalo
+    pub fn sample(&mut self, snapshot: &SystemSnapshot, processes: &mut [ProcessInfo], totals: &mut ProcessTotals) {
+        let now = Instant::now();
+        let seconds = self.last_sample.map_or(0.0, |at| now.duration_since(at).as_secs_f64());
+        self.last_sample = Some(now);
+
+        let samples = snapshot.process_map();
+        let mut current = HashMap::with_capacity(processes.len());
+        for process in processes.iter_mut() {
+            let Some(info) = samples.get(&process.pid) else { continue };
+            let counters = Counters {
+                start_time: info.start_time,
+                read: info.total_read_bytes,
+                written: info.total_written_bytes,
+            };
+            let previous =
+                self.processes.get(&process.pid).filter(|previous| previous.start_time == counters.start_time);
+            process.disk_read_rate = rate(counters.read, previous.map(|previous| previous.read), seconds);
+            process.disk_write_rate = rate(counters.written, previous.map(|previous| previous.written), seconds);
+            current.insert(process.pid, counters);
+        }
+        self.processes = current;
+        totals.disk_read_rate = processes.iter().map(|process| process.disk_read_rate).sum();
+        totals.disk_write_rate = processes.iter().map(|process| process.disk_write_rate).sum();
+
+        let mut network = HashMap::new();
+        let (mut received, mut transmitted) = (0.0, 0.0);
+        for data in &snapshot.networks {
+            let counters = (data.total_received, data.total_transmitted);
+            let previous = self.network.get(&data.name);
+            received += rate(counters.0, previous.map(|previous| previous.0), seconds);
+            transmitted += rate(counters.1, previous.map(|previous| previous.1), seconds);
+            network.insert(data.name.clone(), counters);
+        }
+        self.network = network;
+        totals.network_receive_rate = received;
+        totals.network_send_rate = transmitted;
+    }
+}
@@ -0,0 +1,145 @@
+// src-tauri/src/jobs.rs
+// Tareas largas en segundo plano: el comando devuelve un id de tarea al
+// instante y el progreso y el resultado llegan por eventos de Tauri
+// ("job-progress" y "job-finished"). La cancelación es cooperativa: cada
+// tarea comprueba el indicador entre pasos y termina con un error
+
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Emitter};
+
+static NEXT_JOB_ID: AtomicU64 = AtomicU64::new(1);
+// Id -> (tipo, indicador de cancelación) de las tareas en curso
+type RunningJobs = HashMap<u64, (String, Arc<AtomicBool>)>;
+static RUNNING: Mutex<Option<RunningJobs>> = Mutex::new(None);
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct JobProgress {
+    pub id: u64,
+    pub kind: String,
+    pub progress: f32, // 0.0 - 1.0
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct JobFinished {
+    pub id: u64,
+    pub kind: String,
+    pub result: Option<serde_json::Value>,
+    pub error: Option<String>,
+    pub cancelled: bool, // El error se debe a cancel_job
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct RunningJob {
+    pub id: u64,
+    pub kind: String,
+    pub cancelling: bool, // Cancelada, a la espera de que la tarea lo vea
+}
+
+// Permite a la tarea informar de su avance
+pub struct JobReporter {
+    app: AppHandle,
+    id: u64,
+    kind: String,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl JobReporter {
+    pub fn progress(&self, progress: f32, message: impl Into<String>) {
+        let _ = self.app.emit(
+            "job-progress",
+            JobProgress {
+                id: self.id,
+                kind: self.kind.clone(),
+                progress: progress.clamp(0.0, 1.0),
+                message: Some(message.into()),
+            },
+        );
+    }
+
+    // La tarea decide dónde comprobarlo y termina con un error
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    // Punto de cancelación para usar con `?` entre pasos
+    pub fn check_cancelled(&self) -> Result<(), String> {
+        if self.is_cancelled() {
+            return Err("Tarea cancelada".to_string());
+        }
+        Ok(())
+    }
+}
+
+// Lanza `work` en un hilo del pool bloqueante y devuelve su id
+pub fn spawn<T, F>(app: &AppHandle, kind: &str, work: F) -> u64
+where
+    T: Serialize,
+    F: FnOnce(&JobReporter) -> Result<T, String> + Send + 'static,
+{
+    let id = NEXT_JOB_ID.fetch_add(1, Ordering::Relaxed);
+    let cancelled = Arc::new(AtomicBool::new(false));
+    RUNNING.lock().unwrap().get_or_insert_with(HashMap::new).insert(id, (kind.to_string(), cancelled.clone()));
+    let reporter = JobReporter {
+        app: app.clone(),
+        id,
+        kind: kind.to_string(),
+        cancelled,
+    };
+    tauri::async_runtime::spawn_blocking(move || {
+        let outcome = work(&reporter).and_then(|value| {
+            serde_json::to_value(value).map_err(|e| format!("Error al serializar el resultado: {}", e))
+        });
+        if let Some(running) = RUNNING.lock().unwrap().as_mut() {
+            running.remove(&id);
+        }
+        let (result, error) = match outcome {
+            Ok(value) => (Some(value), None),
+            Err(e) => (None, Some(e)),
+        };
+        let cancelled = error.is_some() && reporter.is_cancelled();
+        let _ = reporter.app.emit(
+            "job-finished",
+            JobFinished {
+                id,
+                kind: reporter.kind.clone(),
+                result,
+                error,
+                cancelled,
+            },
+        );
+    });
+    id
+}
+
+// Pide a una tarea en curso que se detenga; su "job-finished" llega con error
+#[tauri::command]
+pub fn cancel_job(id: u64) -> Result<(), String> {
+    let running = RUNNING.lock().unwrap();
+    let (_, cancelled) = running
+        .as_ref()
+        .and_then(|running| running.get(&id))
+        .ok_or_else(|| format!("No hay ninguna tarea {} en curso", id))?;
+    cancelled.store(true, Ordering::Relaxed);
+    Ok(())
+}
+
+// Tareas en curso, p. ej. para ofrecer cancelarlas al recargar la interfaz
+#[tauri::command]
+pub fn get_running_jobs() -> Vec<RunningJob> {
+    let running = RUNNING.lock().unwrap();
+    let mut jobs: Vec<RunningJob> = running
+        .iter()
+        .flatten()
+        .map(|(id, (kind, cancelled))| RunningJob {
+            id: *id,
+            kind: kind.clone(),
+            cancelling: cancelled.load(Ordering::Relaxed),
+        })
+        .collect();
+    jobs.sort_by_key(|job| job.id);
+    jobs
+}
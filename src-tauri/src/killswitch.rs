@@ -0,0 +1,226 @@
+// src-tauri/src/killswitch.rs
+// Atajo global que termina la aplicación de la ventana en primer plano, para
+// cuando un juego se cuelga a pantalla completa y no deja llegar a nada más.
+// Lo registra el backend (RegisterHotKey en un hilo con su propio bucle de
+// mensajes), así funciona aunque la interfaz esté oculta en la bandeja. En
+// modo ordenado se envía WM_CLOSE y solo se fuerza si no termina a tiempo
+
+use serde::{Serialize, Deserialize};
+use tauri::{AppHandle, Manager};
+
+use crate::AppState;
+
+// Nunca se terminan desde el atajo: el escritorio y el compositor también
+// pueden estar en primer plano (o suplantar una ventana colgada)
+const EXCLUDED: &[&str] = &["explorer.exe", "dwm.exe", "csrss.exe", "winlogon.exe", "lockapp.exe"];
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+pub struct KillSwitchSettings {
+    pub enabled: bool,
+    pub hotkey: String, // "Ctrl+Alt+F4": modificadores y una tecla separados por "+"
+    pub graceful: bool,
+    pub grace_ms: u64, // Espera tras WM_CLOSE antes de forzar
+}
+
+impl KillSwitchSettings {
+    // Lo que obliga a volver a registrar el atajo; el resto se lee al pulsarlo
+    pub fn registration(&self) -> (bool, &str) {
+        (self.enabled, &self.hotkey)
+    }
+}
+
+impl Default for KillSwitchSettings {
+    fn default() -> Self {
+        KillSwitchSettings {
+            enabled: false,
+            hotkey: "Ctrl+Alt+F4".to_string(),
+            graceful: true,
+            grace_ms: 3000,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct KillSwitchEvent {
+    pub pid: u32,
+    pub name: String,
+    pub closed_gracefully: bool, // Terminó tras WM_CLOSE, sin forzar
+    pub error: Option<String>,
+    pub timestamp: u64,
+}
+
+// Hilo que tiene registrado el atajo
+#[derive(Debug, Default)]
+pub struct KillSwitch {
+    #[cfg(target_os = "windows")]
+    listener: Option<crate::win::MessageLoop>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hotkey {
+    pub modifiers: u32, // MOD_ALT, MOD_CONTROL, MOD_SHIFT, MOD_WIN
+    pub key: u32,       // Código de tecla virtual
+}
+
+// Sin modificadores el atajo robaría la tecla a todas las aplicaciones
+pub fn parse_hotkey(text: &str) -> Result<Hotkey, String> {
+    let invalid = || format!("Atajo no válido: {}", text);
+    let parts: Vec<String> = text.split('+').map(|part| part.trim().to_lowercase()).collect();
+    let (key, modifiers) = parts.split_last().ok_or_else(invalid)?;
+    let mut hotkey = Hotkey { modifiers: 0, key: 0 };
+    for modifier in modifiers {
+        hotkey.modifiers |= match modifier.as_str() {
+            "alt" => 0x1,
+            "ctrl" | "control" => 0x2,
+            "shift" => 0x4,
+            "win" | "super" => 0x8,
+            _ => return Err(invalid()),
+        };
+    }
+    if hotkey.modifiers == 0 {
+        return Err("El atajo necesita al menos un modificador (Ctrl, Alt, Shift o Win)".to_string());
+    }
+    hotkey.key = match key.as_str() {
+        single if single.len() == 1 && single.chars().all(|c| c.is_ascii_alphanumeric()) => {
+            single.to_ascii_uppercase().as_bytes()[0] as u32
+        }
+        function if function.starts_with('f') && function.len() > 1 => match function[1..].parse::<u32>() {
+            Ok(number @ 1..=24) => 0x6F + number,
+            _ => return Err(invalid()),
+        },
+        "end" => 0x23,
+        "home" => 0x24,
+        "insert" => 0x2D,
+        "delete" => 0x2E,
+        "pause" => 0x13,
+        "escape" | "esc" => 0x1B,
+        "space" => 0x20,
+        "backspace" => 0x08,
+        "printscreen" => 0x2C,
+        "scrolllock" => 0x91,
+        _ => return Err(invalid()),
+    };
+    Ok(hotkey)
+}
+
+#[cfg(target_os = "windows")]
+fn terminate_foreground(app: &AppHandle) -> Option<KillSwitchEvent> {
+    use windows::Win32::Foundation::{LPARAM, WPARAM};
+    use windows::Win32::UI::WindowsAndMessaging::{
+        GetForegroundWindow, GetWindowThreadProcessId, PostMessageW, WM_CLOSE,
+    };
+
+    let hwnd = unsafe { GetForegroundWindow() };
+    let mut pid = 0u32;
+    if hwnd.0 != 0 {
+        unsafe { GetWindowThreadProcessId(hwnd, Some(&mut pid)) };
+    }
+    if pid == 0 || pid == std::process::id() {
+        return None;
+    }
+    let name = crate::win::process_image_name(pid).unwrap_or_else(|| format!("PID {}", pid));
+    let event = |closed_gracefully, error| KillSwitchEvent {
+        pid,
+        name: name.clone(),
+        closed_gracefully,
+        error,
+        timestamp: crate::stats::now_millis(),
+    };
+    if EXCLUDED.iter().any(|excluded| excluded.eq_ignore_ascii_case(&name)) {
+        return Some(event(false, Some(format!("{} no se termina desde el atajo", name))));
+    }
+
+    let state = app.state::<crate::AppState>();
+    let settings = state.settings.read().unwrap().kill_switch.clone();
+    let action = format!("kill_switch:{}", settings.graceful);
+    let result = crate::actions::run_once(&state, pid, &action, || {
+        if settings.graceful
+            && unsafe { PostMessageW(hwnd, WM_CLOSE, WPARAM(0), LPARAM(0)) }.as_bool()
+            && crate::win::wait_for_exit(pid, settings.grace_ms)
+        {
+            return Ok(true);
+        }
+        // Forzado: sin el aviso de trabajo sin guardar, la aplicación no responde
+        crate::terminate_process(&state, pid, true).map(|_| false)
+    });
+    Some(match result {
+        Ok(closed_gracefully) => event(closed_gracefully, None),
+        Err(e) => event(false, Some(e)),
+    })
+}
+
+// Atajo global en un hilo con su propio bucle de mensajes; `on_press` se
+// ejecuta en otro hilo para que una espera larga no bloquee el bucle. Lo usan
+// también las listas de vigilancia
+#[cfg(target_os = "windows")]
+pub fn register_hotkey(
+    app: &AppHandle,
+    name: &str,
+    hotkey: Hotkey,
+    on_press: impl Fn(&AppHandle) + Send + Sync + 'static,
+) -> Result<crate::win::MessageLoop, String> {
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::UI::Input::KeyboardAndMouse::{
+        RegisterHotKey, UnregisterHotKey, HOT_KEY_MODIFIERS, MOD_NOREPEAT,
+    };
+    use windows::Win32::UI::WindowsAndMessaging::{MSG, WM_HOTKEY};
+
+    const HOTKEY_ID: i32 = 1; // Por hilo: cada atajo tiene el suyo
+    let app = app.clone();
+    let on_press = std::sync::Arc::new(on_press);
+    crate::win::MessageLoop::start(&app.clone(), name, move || {
+        let modifiers = HOT_KEY_MODIFIERS(hotkey.modifiers) | MOD_NOREPEAT;
+        if !unsafe { RegisterHotKey(HWND(0), HOTKEY_ID, modifiers, hotkey.key) }.as_bool() {
+            let error = windows::core::Error::from_win32();
+            return Err(format!("No se pudo registrar el atajo (¿lo usa otra aplicación?): {}", error));
+        }
+        let on_message = move |msg: &MSG| {
+            if msg.message == WM_HOTKEY {
+                let (app, on_press) = (app.clone(), on_press.clone());
+                std::thread::spawn(move || on_press(&app));
+            }
+        };
+        let cleanup = || unsafe {
+            UnregisterHotKey(HWND(0), HOTKEY_ID);
+        };
+        Ok((on_message, cleanup))
+    })
+}
+
+#[cfg(target_os = "windows")]
+fn register(app: AppHandle, hotkey: Hotkey) -> Result<crate::win::MessageLoop, String> {
+    use tauri::Emitter;
+
+    register_hotkey(&app, "kill-switch", hotkey, |app| {
+        if let Some(event) = terminate_foreground(app) {
+            let _ = app.emit("kill-switch", event);
+        }
+    })
+}
+
+// Registra (o retira) el atajo según la configuración; se vuelve a llamar al
+// cambiarla. Devuelve el error si otra aplicación ya tiene ese atajo
+pub fn apply(app: &AppHandle, settings: &KillSwitchSettings) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    #[cfg(target_os = "windows")]
+    if let Some(listener) = state.kill_switch.lock().unwrap().listener.take() {
+        listener.stop();
+    }
+    if !settings.enabled {
+        return Ok(());
+    }
+    let hotkey = parse_hotkey(&settings.hotkey)?;
+
+    #[cfg(target_os = "windows")]
+    {
+        state.kill_switch.lock().unwrap().listener = Some(register(app.clone(), hotkey)?);
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = (state, hotkey);
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
@@ -0,0 +1,261 @@
+// src-tauri/src/launch.rs
+// Lanzamiento de procesos: suspendidos (para adjuntar un depurador antes de la
+// primera instrucción), como tareas ligadas a la vida de la aplicación o
+// desde "Ejecutar nueva tarea", con elevación opcional
+
+use tauri::State;
+
+use crate::AppState;
+
+// Crea el proceso con su hilo principal suspendido
+#[cfg(target_os = "windows")]
+fn create_suspended(path: &str, args: &[String]) -> Result<windows::Win32::System::Threading::PROCESS_INFORMATION, String> {
+    use windows::core::{PCWSTR, PWSTR};
+    use windows::Win32::System::Threading::{
+        CreateProcessW, CREATE_SUSPENDED, PROCESS_INFORMATION, STARTUPINFOW,
+    };
+
+    let application = crate::win::to_wide(path);
+    let mut command_line = crate::win::to_wide(&crate::win::build_command_line(path, args));
+    let startup = STARTUPINFOW {
+        cb: std::mem::size_of::<STARTUPINFOW>() as u32,
+        ..Default::default()
+    };
+    let mut info = PROCESS_INFORMATION::default();
+
+    let created = unsafe {
+        CreateProcessW(
+            PCWSTR(application.as_ptr()),
+            PWSTR(command_line.as_mut_ptr()),
+            None,
+            None,
+            false,
+            CREATE_SUSPENDED,
+            None,
+            PCWSTR::null(),
+            &startup,
+            &mut info,
+        )
+    };
+    if !created.as_bool() {
+        return Err(format!("No se pudo crear el proceso: {}", windows::core::Error::from_win32()));
+    }
+    Ok(info)
+}
+
+// Job object de la aplicación: al cerrarse su handle (al salir) el sistema
+// termina todos los procesos asignados
+#[cfg(target_os = "windows")]
+fn cleanup_job(state: &AppState) -> Result<windows::Win32::Foundation::HANDLE, String> {
+    use windows::Win32::Foundation::HANDLE;
+    use windows::Win32::System::JobObjects::{
+        CreateJobObjectW, JobObjectExtendedLimitInformation, SetInformationJobObject,
+        JOBOBJECT_EXTENDED_LIMIT_INFORMATION, JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE,
+    };
+
+    let mut job = state.cleanup_job.lock().unwrap();
+    if let Some(handle) = *job {
+        return Ok(HANDLE(handle));
+    }
+
+    let handle = unsafe { CreateJobObjectW(None, windows::core::PCWSTR::null()) }
+        .map_err(|e| format!("No se pudo crear el job object: {}", e))?;
+    let mut limits = JOBOBJECT_EXTENDED_LIMIT_INFORMATION::default();
+    limits.BasicLimitInformation.LimitFlags = JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE;
+    let ok = unsafe {
+        SetInformationJobObject(
+            handle,
+            JobObjectExtendedLimitInformation,
+            &limits as *const _ as *const _,
+            std::mem::size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+        )
+    };
+    if !ok.as_bool() {
+        return Err(format!("No se pudo configurar el job object: {}", windows::core::Error::from_win32()));
+    }
+    *job = Some(handle.0);
+    Ok(handle)
+}
+
+// Ejecuta una tarea; con `kill_on_exit` se termina automáticamente al cerrar
+// el administrador de tareas (útil para herramientas de diagnóstico temporales)
+#[tauri::command]
+pub fn run_task(state: State<AppState>, path: String, args: Vec<String>, kill_on_exit: bool) -> Result<u32, String> {
+    spawn_task(&state, &path, &args, kill_on_exit)
+}
+
+pub fn spawn_task(state: &AppState, path: &str, args: &[String], kill_on_exit: bool) -> Result<u32, String> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::Foundation::CloseHandle;
+        use windows::Win32::System::JobObjects::AssignProcessToJobObject;
+        use windows::Win32::System::Threading::{ResumeThread, TerminateProcess};
+
+        let info = create_suspended(path, args)?;
+        // Se asigna antes de reanudar para que ningún hijo escape del job
+        let assigned = if kill_on_exit {
+            cleanup_job(state).and_then(|job| {
+                if unsafe { AssignProcessToJobObject(job, info.hProcess) }.as_bool() {
+                    Ok(())
+                } else {
+                    Err(format!("No se pudo asignar el proceso al job: {}", windows::core::Error::from_win32()))
+                }
+            })
+        } else {
+            Ok(())
+        };
+
+        if let Err(e) = assigned {
+            unsafe {
+                TerminateProcess(info.hProcess, 1);
+                CloseHandle(info.hThread);
+                CloseHandle(info.hProcess);
+            }
+            return Err(e);
+        }
+
+        unsafe {
+            ResumeThread(info.hThread);
+            CloseHandle(info.hThread);
+            CloseHandle(info.hProcess);
+        }
+        Ok(info.dwProcessId)
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = (state, kill_on_exit);
+        std::process::Command::new(path)
+            .args(args)
+            .spawn()
+            .map(|child| child.id())
+            .map_err(|e| format!("Error al ejecutar {}: {}", path, e))
+    }
+}
+
+#[tauri::command]
+pub fn launch_suspended(state: State<AppState>, path: String, args: Vec<String>) -> Result<u32, String> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::Foundation::CloseHandle;
+
+        let info = create_suspended(&path, &args)?;
+
+        // Se conserva el hilo principal para poder reanudarlo más tarde
+        unsafe { CloseHandle(info.hProcess) };
+        state.suspended_launches.lock().unwrap().insert(info.dwProcessId, info.hThread.0);
+        Ok(info.dwProcessId)
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = (state, path, args);
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
+
+#[tauri::command]
+pub fn resume_launched(state: State<AppState>, pid: u32) -> Result<(), String> {
+    let thread = state
+        .suspended_launches
+        .lock()
+        .unwrap()
+        .remove(&pid)
+        .ok_or_else(|| format!("El proceso {} no fue lanzado suspendido por la aplicación", pid))?;
+
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::Foundation::{CloseHandle, HANDLE};
+        use windows::Win32::System::Threading::ResumeThread;
+
+        let handle = HANDLE(thread);
+        let result = unsafe { ResumeThread(handle) };
+        unsafe { CloseHandle(handle) };
+        if result == u32::MAX {
+            return Err(format!("No se pudo reanudar el proceso: {}", windows::core::Error::from_win32()));
+        }
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = thread;
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
+
+// "Ejecutar nueva tarea": ejecutable, documento o URL como lo abriría el
+// shell (PATH, App Paths, asociaciones). Con `elevated` pide elevación por UAC.
+// None si el shell no creó un proceso nuevo (p. ej. un documento abierto en
+// una instancia que ya estaba en ejecución)
+#[tauri::command]
+pub fn create_process(
+    command: String,
+    args: Vec<String>,
+    working_dir: Option<String>,
+    elevated: bool,
+) -> Result<Option<u32>, String> {
+    let command = command.trim();
+    if command.is_empty() {
+        return Err("Falta el programa a ejecutar".to_string());
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        use windows::core::PCWSTR;
+        use windows::Win32::Foundation::{CloseHandle, ERROR_CANCELLED, ERROR_FILE_NOT_FOUND};
+        use windows::Win32::System::Threading::GetProcessId;
+        use windows::Win32::UI::Shell::{
+            ShellExecuteExW, SEE_MASK_FLAG_NO_UI, SEE_MASK_NOCLOSEPROCESS, SHELLEXECUTEINFOW,
+        };
+        use windows::Win32::UI::WindowsAndMessaging::SW_SHOWNORMAL;
+
+        let verb = crate::win::to_wide(if elevated { "runas" } else { "open" });
+        let file = crate::win::to_wide(command);
+        let parameters = crate::win::to_wide(&crate::win::quote_args(&args));
+        let directory = working_dir.as_deref().filter(|dir| !dir.is_empty()).map(crate::win::to_wide);
+        let mut info = SHELLEXECUTEINFOW {
+            cbSize: std::mem::size_of::<SHELLEXECUTEINFOW>() as u32,
+            // Sin cuadros de error del shell: el error se devuelve a la interfaz
+            fMask: SEE_MASK_NOCLOSEPROCESS | SEE_MASK_FLAG_NO_UI,
+            lpVerb: PCWSTR(verb.as_ptr()),
+            lpFile: PCWSTR(file.as_ptr()),
+            lpParameters: PCWSTR(parameters.as_ptr()),
+            lpDirectory: directory.as_ref().map_or(PCWSTR::null(), |dir| PCWSTR(dir.as_ptr())),
+            nShow: SW_SHOWNORMAL.0 as i32,
+            ..Default::default()
+        };
+        if !unsafe { ShellExecuteExW(&mut info) }.as_bool() {
+            let error = windows::core::Error::from_win32();
+            return Err(if error.code() == ERROR_CANCELLED.to_hresult() {
+                "Se canceló la solicitud de elevación".to_string()
+            } else if error.code() == ERROR_FILE_NOT_FOUND.to_hresult() {
+                format!("No se encontró {}", command)
+            } else {
+                format!("No se pudo ejecutar {}: {}", command, error)
+            });
+        }
+        if info.hProcess.is_invalid() {
+            return Ok(None);
+        }
+        let pid = unsafe { GetProcessId(info.hProcess) };
+        unsafe { CloseHandle(info.hProcess) };
+        Ok((pid != 0).then_some(pid))
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        if elevated {
+            return Err("La ejecución como administrador solo está disponible en Windows".to_string());
+        }
+        let mut process = std::process::Command::new(command);
+        process.args(&args);
+        if let Some(dir) = working_dir.as_deref().filter(|dir| !dir.is_empty()) {
+            process.current_dir(dir);
+        }
+        process
+            .spawn()
+            .map(|child| Some(child.id()))
+            .map_err(|e| format!("No se pudo ejecutar {}: {}", command, e))
+    }
+}
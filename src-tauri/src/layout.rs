@@ -0,0 +1,132 @@
+// src-tauri/src/layout.rs
+// Disposición de la ventana principal (posición, tamaño, pestaña) y modo flotante
+
+use serde::{Serialize, Deserialize};
+use tauri::{AppHandle, Manager, PhysicalPosition, PhysicalSize, State, Window};
+
+use crate::AppState;
+
+// Mínimo visible (en píxeles físicos) para considerar la ventana accesible
+const MIN_VISIBLE: i64 = 100;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub struct WindowLayout {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub maximized: bool,
+}
+
+// Si está maximizada se conserva la posición y tamaño previos, para que al
+// restaurarla no ocupe la pantalla completa sin marco
+fn capture(window: &Window, previous: Option<WindowLayout>) -> Option<WindowLayout> {
+    let maximized = window.is_maximized().ok()?;
+    if maximized {
+        if let Some(previous) = previous {
+            return Some(WindowLayout { maximized, ..previous });
+        }
+    }
+    let position = window.outer_position().ok()?;
+    let size = window.inner_size().ok()?;
+    Some(WindowLayout {
+        x: position.x,
+        y: position.y,
+        width: size.width,
+        height: size.height,
+        maximized,
+    })
+}
+
+// Comprueba que la ventana quede sobre algún monitor conectado (p. ej. tras
+// desconectar un segundo monitor)
+fn is_on_screen(window: &Window, layout: &WindowLayout) -> bool {
+    let monitors = window.available_monitors().unwrap_or_default();
+    monitors.iter().any(|monitor| {
+        let origin = monitor.position();
+        let size = monitor.size();
+        let left = (layout.x as i64).max(origin.x as i64);
+        let top = (layout.y as i64).max(origin.y as i64);
+        let right = (layout.x as i64 + layout.width as i64).min(origin.x as i64 + size.width as i64);
+        let bottom = (layout.y as i64 + layout.height as i64).min(origin.y as i64 + size.height as i64);
+        right - left >= MIN_VISIBLE && bottom - top >= MIN_VISIBLE
+    })
+}
+
+pub fn restore(window: &Window, layout: &WindowLayout) {
+    let _ = window.set_size(PhysicalSize::new(layout.width.max(200), layout.height.max(150)));
+    if is_on_screen(window, layout) {
+        let _ = window.set_position(PhysicalPosition::new(layout.x, layout.y));
+    } else {
+        let _ = window.center();
+    }
+    if layout.maximized {
+        let _ = window.maximize();
+    }
+}
+
+// Guarda la disposición actual en la configuración (al cerrar la ventana)
+pub fn persist(window: &Window) -> Result<(), String> {
+    let app = window.app_handle();
+    let state = app.state::<AppState>();
+    let mut settings = state.settings.write().unwrap();
+    let Some(layout) = capture(window, settings.window_layout) else {
+        return Ok(());
+    };
+    if settings.window_layout == Some(layout) {
+        return Ok(());
+    }
+    settings.window_layout = Some(layout);
+    crate::settings::save(app, &settings)
+}
+
+#[tauri::command]
+pub fn save_window_layout(window: Window) -> Result<(), String> {
+    persist(&window)
+}
+
+// Pestaña activa de la UI, restaurada al volver a abrir
+#[tauri::command]
+pub fn set_selected_tab(app: AppHandle, state: State<AppState>, tab: String) -> Result<(), String> {
+    let mut settings = state.settings.write().unwrap();
+    settings.selected_tab = Some(tab);
+    crate::settings::save(&app, &settings)
+}
+
+// Ventana flotante sobre juegos o la salida de una compilación
+#[tauri::command]
+pub fn set_always_on_top(window: Window, enabled: bool) -> Result<(), String> {
+    window
+        .set_always_on_top(enabled)
+        .map_err(|e| format!("No se pudo cambiar el modo siempre visible: {}", e))
+}
+
+// Opacidad de la ventana (20-100 %) mediante WS_EX_LAYERED
+#[tauri::command]
+pub fn set_window_opacity(window: Window, percent: u8) -> Result<(), String> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::Foundation::{COLORREF, HWND};
+        use windows::Win32::UI::WindowsAndMessaging::{
+            GetWindowLongPtrW, SetLayeredWindowAttributes, SetWindowLongPtrW, GWL_EXSTYLE, LWA_ALPHA, WS_EX_LAYERED,
+        };
+
+        let hwnd = window.hwnd().map_err(|e| format!("No se pudo obtener la ventana nativa: {}", e))?;
+        let hwnd = HWND(hwnd.0 as isize);
+        let alpha = (percent.clamp(20, 100) as u32 * 255 / 100) as u8;
+        unsafe {
+            let style = GetWindowLongPtrW(hwnd, GWL_EXSTYLE);
+            SetWindowLongPtrW(hwnd, GWL_EXSTYLE, style | WS_EX_LAYERED.0 as isize);
+            if !SetLayeredWindowAttributes(hwnd, COLORREF(0), alpha, LWA_ALPHA).as_bool() {
+                return Err(format!("No se pudo cambiar la opacidad: {}", windows::core::Error::from_win32()));
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = (window, percent);
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
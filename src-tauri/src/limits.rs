@@ -0,0 +1,101 @@
+// src-tauri/src/limits.rs
+// Límites diarios de uso (control parental / concentración): cuando el tiempo
+// en primer plano de un ejecutable agota su límite, se avisa o se cierra
+
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::audit::AuditOrigin;
+use crate::rules::{RuleAction, RuleEvent};
+use crate::AppState;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum LimitAction {
+    Warn,
+    Terminate, // Se cierra cada vez que vuelve al primer plano
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TimeLimit {
+    pub name: String, // Ejecutable, p. ej. "game.exe"
+    pub daily_minutes: u64,
+    pub action: LimitAction,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct LimitEvent {
+    pub name: String,
+    pub pid: u32,
+    pub used_minutes: f64,
+    pub limit_minutes: u64,
+    pub action: LimitAction,
+    pub error: Option<String>,
+}
+
+// Aplica el límite del ejecutable en primer plano, si lo tiene y se ha agotado
+// hoy; `warned` recuerda el día del último aviso de cada ejecutable
+pub fn enforce(app: &AppHandle, pid: u32, name: &str, used_minutes: f64, warned: &mut HashMap<String, String>) {
+    let state = app.state::<AppState>();
+    let limit = {
+        let settings = state.settings.read().unwrap();
+        if !settings.time_limits_enabled {
+            return;
+        }
+        settings.time_limits.iter().find(|limit| crate::text::same_name(&limit.name, name)).cloned()
+    };
+    let Some(limit) = limit.filter(|limit| used_minutes >= limit.daily_minutes as f64) else {
+        return;
+    };
+
+    let simulate = state.settings.read().unwrap().simulate_automation;
+    let error = match limit.action {
+        // En simulación se anota como las reglas, una vez por ejecutable y día
+        LimitAction::Terminate if simulate => {
+            let today = crate::apphistory::today();
+            if warned.get(&name.to_lowercase()) == Some(&today) {
+                return;
+            }
+            warned.insert(name.to_lowercase(), today);
+            state.rules.lock().unwrap().push_log(RuleEvent {
+                timestamp: crate::stats::now_millis(),
+                rule: format!("Límite diario: {}", name),
+                event: "time_limit".to_string(),
+                pid: Some(pid),
+                name: Some(name.to_string()),
+                value: Some(used_minutes),
+                script_error: None,
+                action: Some(RuleAction::Kill),
+                action_error: None,
+                simulated: true,
+                paused: false,
+            });
+            return;
+        }
+        LimitAction::Terminate => {
+            let result = crate::terminate_process(&state, pid, true);
+            let detail = Some(format!("Límite diario: {} min", limit.daily_minutes));
+            crate::audit::record(&state, AuditOrigin::Automation, "kill:true", name, Some(pid), detail, &result);
+            result.err()
+        }
+        LimitAction::Warn => {
+            // Un aviso por ejecutable y día
+            let today = crate::apphistory::today();
+            if warned.get(&name.to_lowercase()) == Some(&today) {
+                return;
+            }
+            warned.insert(name.to_lowercase(), today);
+            None
+        }
+    };
+    state.session.lock().unwrap().alert(&format!("Límite diario: {}", name));
+    let _ = app.emit("time-limit-exceeded", LimitEvent {
+        name: name.to_string(),
+        pid,
+        used_minutes,
+        limit_minutes: limit.daily_minutes,
+        action: limit.action,
+        error,
+    });
+}
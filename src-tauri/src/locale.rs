@@ -0,0 +1,228 @@
+// src-tauri/src/locale.rs
+// Convenciones regionales del sistema (separador decimal, separador de listas,
+// formato de fecha y hora) para que exportaciones, informes y copias al
+// portapapeles se lean bien en un Excel que no esté en inglés
+
+use serde::Serialize;
+use std::sync::OnceLock;
+use tauri::State;
+
+use crate::AppState;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct Locale {
+    pub decimal_separator: String,
+    pub list_separator: String, // Separador de campos que espera Excel al abrir un CSV
+    pub date_pattern: String,   // Patrón de Windows: dd/MM/yyyy, M/d/yyyy...
+    pub time_pattern: String,   // HH:mm:ss, h:mm:ss tt...
+}
+
+// Fecha y hora local ya descompuestas
+pub struct LocalTime {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+    pub hour: u32,
+    pub minute: u32,
+    pub second: u32,
+    pub weekday: u32, // 0 = domingo
+}
+
+impl Locale {
+    // Formato neutro: el que entienden las herramientas, sea cual sea el idioma
+    pub fn invariant() -> &'static Locale {
+        static INVARIANT: OnceLock<Locale> = OnceLock::new();
+        INVARIANT.get_or_init(|| Locale {
+            decimal_separator: ".".to_string(),
+            list_separator: ",".to_string(),
+            date_pattern: "yyyy-MM-dd".to_string(),
+            time_pattern: "HH:mm:ss".to_string(),
+        })
+    }
+
+    // Se lee una vez: cambiar la configuración regional requiere reiniciar
+    pub fn system() -> &'static Locale {
+        static SYSTEM: OnceLock<Locale> = OnceLock::new();
+        SYSTEM.get_or_init(read_system_locale)
+    }
+
+    // Sin separador de miles, que en un CSV se confundiría con otra columna
+    pub fn number(&self, value: f64, decimals: usize) -> String {
+        let formatted = format!("{:.*}", decimals, value);
+        if self.decimal_separator == "." {
+            formatted
+        } else {
+            formatted.replace('.', &self.decimal_separator)
+        }
+    }
+
+    pub fn timestamp(&self, millis: u64) -> String {
+        let time = local_time(millis);
+        format!("{} {}", apply_pattern(&self.date_pattern, &time), apply_pattern(&self.time_pattern, &time))
+    }
+}
+
+// Hora local (0-23) de una marca de tiempo en milisegundos
+pub fn local_hour(millis: u64) -> u32 {
+    local_time(millis).hour
+}
+
+// Día de la semana (0 = domingo) y minuto del día (0-1439) en hora local
+pub fn local_weekday_minute(millis: u64) -> (u32, u32) {
+    let time = local_time(millis);
+    (time.weekday, time.hour * 60 + time.minute)
+}
+
+#[cfg(target_os = "windows")]
+fn read_system_locale() -> Locale {
+    use windows::core::PCWSTR;
+    use windows::Win32::Globalization::{
+        GetLocaleInfoEx, LOCALE_SDECIMAL, LOCALE_SLIST, LOCALE_SSHORTDATE, LOCALE_STIMEFORMAT,
+    };
+
+    // PCWSTR nulo = configuración del usuario actual
+    let read = |kind: u32, fallback: &str| -> String {
+        let mut buffer = [0u16; 128];
+        let length = unsafe { GetLocaleInfoEx(PCWSTR::null(), kind, Some(&mut buffer)) };
+        if length <= 1 {
+            return fallback.to_string();
+        }
+        String::from_utf16_lossy(&buffer[..length as usize - 1])
+    };
+    let invariant = Locale::invariant();
+    Locale {
+        decimal_separator: read(LOCALE_SDECIMAL, &invariant.decimal_separator),
+        list_separator: read(LOCALE_SLIST, &invariant.list_separator),
+        date_pattern: read(LOCALE_SSHORTDATE, &invariant.date_pattern),
+        time_pattern: read(LOCALE_STIMEFORMAT, &invariant.time_pattern),
+    }
+}
+
+// Fuera de Windows solo se conoce el nombre de la configuración (es_ES.UTF-8):
+// se deducen las convenciones más habituales de cada idioma
+#[cfg(not(target_os = "windows"))]
+fn read_system_locale() -> Locale {
+    let name = ["LC_ALL", "LC_NUMERIC", "LANG"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.is_empty())
+        .unwrap_or_default();
+    let tag = name.split(['.', '@']).next().unwrap_or_default();
+    let language = tag.split(['_', '-']).next().unwrap_or_default().to_lowercase();
+
+    let comma = matches!(
+        language.as_str(),
+        "es" | "fr" | "de" | "it" | "pt" | "nl" | "ru" | "pl" | "cs" | "sk" | "sv" | "da" | "nb" | "fi" | "tr"
+            | "uk" | "ro" | "hu" | "el" | "ca" | "id"
+    );
+    let date_pattern = match language.as_str() {
+        "en" if tag.ends_with("US") => "M/d/yyyy",
+        "de" | "ru" | "pl" | "cs" | "sk" | "fi" | "nb" | "tr" | "uk" | "ro" => "dd.MM.yyyy",
+        "sv" | "lt" => "yyyy-MM-dd",
+        "ja" | "zh" | "ko" | "hu" => "yyyy/MM/dd",
+        "" | "c" | "posix" => "yyyy-MM-dd",
+        _ => "dd/MM/yyyy",
+    };
+    Locale {
+        decimal_separator: if comma { "," } else { "." }.to_string(),
+        list_separator: if comma { ";" } else { "," }.to_string(),
+        date_pattern: date_pattern.to_string(),
+        time_pattern: "HH:mm:ss".to_string(),
+    }
+}
+
+#[cfg(target_os = "windows")]
+pub fn local_time(millis: u64) -> LocalTime {
+    use windows::Win32::Foundation::{FILETIME, SYSTEMTIME};
+    use windows::Win32::System::Time::{FileTimeToSystemTime, SystemTimeToTzSpecificLocalTime};
+
+    // FILETIME cuenta intervalos de 100 ns desde 1601
+    let ticks = (millis + 11_644_473_600_000) * 10_000;
+    let filetime = FILETIME { dwLowDateTime: ticks as u32, dwHighDateTime: (ticks >> 32) as u32 };
+    let (mut utc, mut local) = (SYSTEMTIME::default(), SYSTEMTIME::default());
+    unsafe {
+        FileTimeToSystemTime(&filetime, &mut utc);
+        if !SystemTimeToTzSpecificLocalTime(None, &utc, &mut local).as_bool() {
+            local = utc;
+        }
+    }
+    LocalTime {
+        year: local.wYear as i32,
+        month: local.wMonth as u32,
+        day: local.wDay as u32,
+        hour: local.wHour as u32,
+        minute: local.wMinute as u32,
+        second: local.wSecond as u32,
+        weekday: local.wDayOfWeek as u32,
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn local_time(millis: u64) -> LocalTime {
+    let seconds = (millis / 1000) as libc::time_t;
+    let mut tm: libc::tm = unsafe { std::mem::zeroed() };
+    unsafe { libc::localtime_r(&seconds, &mut tm) };
+    LocalTime {
+        year: tm.tm_year + 1900,
+        month: tm.tm_mon as u32 + 1,
+        day: tm.tm_mday as u32,
+        hour: tm.tm_hour as u32,
+        minute: tm.tm_min as u32,
+        second: tm.tm_sec as u32,
+        weekday: tm.tm_wday as u32,
+    }
+}
+
+// Patrones de Windows (yyyy, MM, d, HH, h, mm, ss, tt y texto entre comillas
+// simples). Los nombres de mes y día se escriben como números
+fn apply_pattern(pattern: &str, time: &LocalTime) -> String {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut output = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '\'' {
+            let end = chars[i + 1..].iter().position(|&q| q == '\'').map_or(chars.len(), |p| i + 1 + p);
+            output.extend(&chars[i + 1..end]);
+            i = end + 1;
+            continue;
+        }
+        let count = chars[i..].iter().take_while(|&&next| next == c).count();
+        let pad = |value: u32| if count >= 2 { format!("{:02}", value) } else { value.to_string() };
+        match c {
+            'y' if count >= 3 => output += &format!("{:04}", time.year),
+            'y' => output += &format!("{:02}", time.year.rem_euclid(100)),
+            'M' => output += &pad(time.month),
+            'd' => output += &pad(time.day),
+            'H' => output += &pad(time.hour),
+            'h' => output += &pad((time.hour + 11) % 12 + 1),
+            'm' => output += &pad(time.minute),
+            's' => output += &pad(time.second),
+            't' => {
+                let marker = if time.hour < 12 { "AM" } else { "PM" };
+                output += &marker[..count.min(2)];
+            }
+            _ => output.extend(&chars[i..i + count]),
+        }
+        i += count;
+    }
+    output
+}
+
+// Convenciones activas según la configuración de formato
+#[tauri::command]
+pub fn get_locale(state: State<AppState>) -> Locale {
+    state.settings.read().unwrap().format.locale().clone()
+}
+
+// Para copiar al portapapeles con las mismas reglas que las exportaciones
+#[tauri::command]
+pub fn format_number(state: State<AppState>, value: f64, decimals: Option<usize>) -> String {
+    let options = state.settings.read().unwrap().format;
+    options.locale().number(value, decimals.unwrap_or(options.decimals))
+}
+
+#[tauri::command]
+pub fn format_timestamp(state: State<AppState>, millis: u64) -> String {
+    state.settings.read().unwrap().format.locale().timestamp(millis)
+}
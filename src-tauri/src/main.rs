@@ -6,60 +6,643 @@
 
 use serde::{Serialize, Deserialize};
 use std::process::Command;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use sysinfo::{ProcessExt, System, SystemExt, PidExt};
-use tauri::{State, Manager};
-use std::sync::{Arc, Mutex};
-use windows::Win32::NetworkManagement::IpHelper::{GetExtendedTcpTable, TCP_TABLE_CLASS, MIB_TCPROW_OWNER_PID};
-use windows::Win32::Foundation::ERROR_INSUFFICIENT_BUFFER;
-use windows::core::PWSTR;
-use std::mem;
+use tauri::{State, Manager, Emitter};
+use std::sync::{Arc, Mutex, RwLock};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+mod actions;
+mod alertlog;
+mod alerts;
+mod anomaly;
+mod apphistory;
+mod arch;
+mod audit;
+mod bandwidth;
+mod baselines;
+mod bulk;
+mod bundle;
+mod calibration;
+mod changefeed;
+mod cleanup;
+mod clipboard;
+mod clocks;
+mod compare;
+mod conflicts;
+mod connections;
+mod cores;
+mod datausage;
+mod debugger;
+mod defender;
+mod delta;
+mod dependents;
+mod details;
+mod disks;
+mod diskscan;
+mod dump;
+#[cfg(target_os = "windows")]
+mod etw;
+mod exitcodes;
+mod exitwatch;
+mod export;
+mod expressions;
+mod fileio;
+mod foreground;
+mod format;
+mod gpu;
+mod gpuinfo;
+mod graphs;
+mod growth;
+mod health;
+mod heatmap;
+mod hooks;
+mod hosts;
+mod icons;
+mod idle;
+mod impact;
+mod iorates;
+mod jobs;
+mod killswitch;
+mod launch;
+mod layout;
+mod limits;
+mod locale;
+mod modules;
+mod netinfo;
+mod overlays;
+mod pagefile;
+mod paging;
+mod palette;
+#[cfg(target_os = "windows")]
+mod pdh;
+mod pidwatch;
+mod platform;
+mod plugins;
+mod priority;
+mod probe;
+mod profiles;
+mod provider;
+mod query;
+mod quiethours;
+mod regtrace;
+mod relaunch;
+mod replay;
+mod report;
+mod restarts;
+mod rules;
+mod sampler;
+mod schema;
+mod screentime;
+mod security;
+mod selfmon;
+mod services;
+mod session;
+mod settings;
+mod signature;
+mod startup;
+mod stats;
+mod status;
+mod stress;
+mod summary;
+mod suspend;
+mod text;
+mod thermal;
+mod tray;
+mod unsaved;
+mod updates;
+mod usagestats;
+mod usersessions;
+mod watchdog;
+mod watchlists;
+#[cfg(target_os = "windows")]
+mod win;
+mod windowctl;
+#[cfg(target_os = "windows")]
+mod wmi;
+
+use actions::ActionMap;
+use alertlog::AlertLog;
+use alerts::{AlertEngine, AlertMetric};
+use anomaly::AnomalyDetector;
+use apphistory::AppHistory;
+use audit::AuditLog;
+use bandwidth::BandwidthCollector;
+use baselines::BaselineStore;
+use calibration::Calibration;
+use changefeed::ChangeFeed;
+use clipboard::ClipboardMonitor;
+use clocks::ClockCollector;
+use compare::ProcessHistory;
+use connections::ConnectionWatcher;
+use datausage::DataUsage;
+use delta::DeltaLog;
+use disks::DiskPerfCollector;
+use diskscan::DiskScanCache;
+use exitcodes::ExitCodes;
+use exitwatch::ExitWatches;
+use expressions::WatchValue;
+use fileio::FileIoCollector;
+use foreground::ForegroundBooster;
+use gpu::GpuCollector;
+use growth::GrowthTracker;
+use health::CollectorHealth;
+use heatmap::CoreHeatmap;
+use hooks::ImportCache;
+use icons::IconCache;
+use iorates::IoRateTracker;
+use killswitch::KillSwitch;
+use pidwatch::ProcessWatches;
+use plugins::PluginRegistry;
+use priority::PriorityClass;
+use provider::{MockSystem, SystemProvider, SystemSnapshot};
+use quiethours::QuietHoursLog;
+use regtrace::RegistryTracer;
+use relaunch::KilledProcess;
+use replay::ReplayState;
+use restarts::RestartTracker;
+use rules::RuleEngine;
+use sampler::RefreshSchedule;
+use schema::SchemaVersion;
+use selfmon::SelfMetrics;
+use session::SessionTracker;
+use settings::Settings;
+use stats::SystemHistory;
+use status::ProcessStatus;
+use stress::StressTask;
+use thermal::ThermalCollector;
+use unsaved::UnsavedWorkWarning;
+use usagestats::UsageStats;
+use watchdog::ServiceWatchdog;
+use watchlists::WatchlistHotkey;
+
+// Los campos ausentes (cargas de versiones anteriores) toman su valor por defecto
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[serde(default)]
 struct ProcessInfo {
     pid: u32,
     name: String,
     cpu_usage: f32,
     memory_usage: u64,      // En bytes
-    disk_read_bytes: u64,
+    disk_read_bytes: u64,   // Desde el refresco anterior
     disk_write_bytes: u64,
+    disk_read_rate: f64,    // Bytes/s
+    disk_write_rate: f64,
     gpu_usage: f32,         // En porcentaje
+    gpu_dedicated_memory: u64, // VRAM propia, en bytes
+    gpu_shared_memory: u64,    // Memoria del sistema usada por la GPU
     parent_pid: Option<u32>, // PID del proceso padre
     has_children: bool,     // Indica si tiene subprocesos
+    network_send_rate: f64,    // Bytes/s (colector ETW)
+    network_receive_rate: f64,
+    priority: Option<PriorityClass>, // None si no hay acceso al proceso
+    efficiency_mode: bool,           // EcoQoS activo
+    restart_loop: bool,              // El ejecutable arranca una y otra vez
+    suspended: bool,
+    #[serde(default)]
+    status: ProcessStatus, // Incluye "No responde" (ventana colgada)
+    impact_score: f64,     // 0-100, combinación ponderada de recursos
+    cpu_delta: Option<f32>,     // Puntos de CPU respecto al refresco anterior; None sin growth_columns
+    memory_growth: Option<f64>, // MB/min (MiB o MB según format.units) en el último minuto
+    handle_growth: Option<f64>, // Handles/min; None sin acceso al proceso
+    above_usual: Vec<AlertMetric>, // Métricas muy por encima de la calibración inicial
+    // Columnas calculadas de la configuración; en su propio mapa para que un
+    // nombre como "pid" o "name" no pise los campos del proceso
+    custom_columns: HashMap<String, f64>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+// Totales de las columnas para la fila de resumen de la UI
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+struct ProcessTotals {
+    process_count: usize,
+    cpu_usage: f32,
+    memory_usage: u64,
+    disk_read_bytes: u64,
+    disk_write_bytes: u64,
+    disk_read_rate: f64, // Bytes/s
+    disk_write_rate: f64,
+    gpu_usage: f32,
+    network_received_bytes: u64,    // Todas las interfaces, desde el último refresco
+    network_transmitted_bytes: u64,
+    network_receive_rate: f64, // Bytes/s, todas las interfaces
+    network_send_rate: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
 struct ProcessTree {
+    #[serde(default = "SchemaVersion::legacy")]
+    schema_version: SchemaVersion,
     processes: Vec<ProcessInfo>,
     process_relationships: HashMap<u32, Vec<u32>>, // Mapa de PID a lista de PIDs hijos
+    totals: ProcessTotals,
+}
+
+// Estado por dominios: `system` solo se bloquea en escritura mientras dura el
+// refresco de sysinfo; los demás lectores (kill, exportación...) usan el
+// bloqueo de lectura o la instantánea `processes` para no esperar a un
+// refresco lento
+pub struct AppState {
+    system: Arc<RwLock<System>>,
+    mock_system: Option<Arc<Mutex<MockSystem>>>, // Arnés de pruebas (provider.rs)
+    replay: Arc<Mutex<ReplayState>>,
+    refresh_schedule: Arc<Mutex<RefreshSchedule>>,
+    processes: Arc<RwLock<ProcessTree>>, // Último árbol publicado por get_processes
+    settings: Arc<RwLock<Settings>>,
+    history: Arc<RwLock<SystemHistory>>,
+    process_history: Arc<RwLock<ProcessHistory>>,
+    watch_values: Arc<RwLock<Vec<WatchValue>>>,
+    suspended_launches: Arc<Mutex<HashMap<u32, isize>>>, // PID -> handle del hilo principal
+    bandwidth: Arc<Mutex<BandwidthCollector>>,
+    disk_perf: Arc<Mutex<DiskPerfCollector>>,
+    gpu: Arc<Mutex<GpuCollector>>,
+    fileio: Arc<Mutex<FileIoCollector>>,
+    regtrace: Arc<Mutex<RegistryTracer>>,
+    cleanup_job: Arc<Mutex<Option<isize>>>, // Job object con KILL_ON_JOB_CLOSE
+    pending_actions: Arc<Mutex<ActionMap>>,
+    stress_tasks: Arc<Mutex<Vec<StressTask>>>,
+    self_metrics: Arc<Mutex<SelfMetrics>>,
+    collector_health: Arc<Mutex<CollectorHealth>>, // Colectores desactivados por fallos
+    plugins: Arc<Mutex<PluginRegistry>>,
+    rules: Arc<Mutex<RuleEngine>>,
+    app_history: Arc<Mutex<AppHistory>>,
+    session: Arc<Mutex<SessionTracker>>,
+    data_usage: Arc<Mutex<DataUsage>>,
+    connections: Arc<Mutex<ConnectionWatcher>>,
+    thermal: Arc<Mutex<ThermalCollector>>,
+    clocks: Arc<Mutex<ClockCollector>>,
+    core_heatmap: Arc<Mutex<CoreHeatmap>>,
+    process_deltas: Arc<Mutex<DeltaLog>>,
+    change_feed: Arc<Mutex<ChangeFeed>>, // Arranques, finales y cambios significativos por cursor
+    service_watchdog: Arc<Mutex<ServiceWatchdog>>,
+    restarts: Arc<Mutex<RestartTracker>>,
+    usage_stats: Arc<Mutex<UsageStats>>,
+    audit: Arc<Mutex<AuditLog>>,
+    recently_killed: Arc<Mutex<VecDeque<KilledProcess>>>, // Para relanzarlos
+    io_rates: Arc<Mutex<IoRateTracker>>,
+    growth: Arc<Mutex<GrowthTracker>>,
+    alerts: Arc<Mutex<AlertEngine>>,
+    alert_log: Arc<Mutex<AlertLog>>, // Alertas disparadas y si se han confirmado
+    quiet_hours: Arc<Mutex<QuietHoursLog>>, // Notificaciones omitidas en horas de silencio
+    process_watches: Arc<Mutex<ProcessWatches>>,
+    exit_watches: Arc<Mutex<ExitWatches>>, // Procesos de los que avisar al terminar
+    exit_codes: Arc<Mutex<ExitCodes>>,
+    icons: Arc<Mutex<IconCache>>,
+    disk_scans: Arc<Mutex<DiskScanCache>>, // Análisis de espacio recientes
+    hook_imports: Arc<Mutex<ImportCache>>,
+    clipboard: Arc<Mutex<ClipboardMonitor>>,
+    foreground: Arc<Mutex<ForegroundBooster>>, // Prioridad para la aplicación en primer plano
+    kill_switch: Arc<Mutex<KillSwitch>>,
+    watchlist_hotkey: Arc<Mutex<WatchlistHotkey>>, // Atajo que pasa a la lista de vigilancia siguiente
+    anomalies: Arc<Mutex<AnomalyDetector>>,
+    baselines: Arc<Mutex<BaselineStore>>,
+    calibration: Arc<Mutex<Calibration>>, // Comportamiento habitual medido en el primer arranque
 }
 
-struct AppState {
-    system: Arc<Mutex<System>>,
+impl AppState {
+    // Tras un pánico en el refresco, los mutex que tenía tomados quedan
+    // envenenados y cada `lock().unwrap()` posterior volvería a entrar en
+    // pánico: se limpian todos para que el siguiente refresco funcione
+    fn clear_poison(&self) {
+        self.system.clear_poison();
+        if let Some(mock) = &self.mock_system {
+            mock.clear_poison();
+        }
+        self.replay.clear_poison();
+        self.refresh_schedule.clear_poison();
+        self.processes.clear_poison();
+        self.settings.clear_poison();
+        self.history.clear_poison();
+        self.process_history.clear_poison();
+        self.watch_values.clear_poison();
+        self.suspended_launches.clear_poison();
+        self.bandwidth.clear_poison();
+        self.disk_perf.clear_poison();
+        self.gpu.clear_poison();
+        self.fileio.clear_poison();
+        self.regtrace.clear_poison();
+        self.cleanup_job.clear_poison();
+        self.pending_actions.clear_poison();
+        self.stress_tasks.clear_poison();
+        self.self_metrics.clear_poison();
+        self.collector_health.clear_poison();
+        self.plugins.clear_poison();
+        self.rules.clear_poison();
+        self.app_history.clear_poison();
+        self.session.clear_poison();
+        self.data_usage.clear_poison();
+        self.connections.clear_poison();
+        self.thermal.clear_poison();
+        self.clocks.clear_poison();
+        self.core_heatmap.clear_poison();
+        self.process_deltas.clear_poison();
+        self.change_feed.clear_poison();
+        self.service_watchdog.clear_poison();
+        self.restarts.clear_poison();
+        self.usage_stats.clear_poison();
+        self.audit.clear_poison();
+        self.recently_killed.clear_poison();
+        self.io_rates.clear_poison();
+        self.growth.clear_poison();
+        self.alerts.clear_poison();
+        self.alert_log.clear_poison();
+        self.quiet_hours.clear_poison();
+        self.process_watches.clear_poison();
+        self.exit_watches.clear_poison();
+        self.exit_codes.clear_poison();
+        self.icons.clear_poison();
+        self.disk_scans.clear_poison();
+        self.hook_imports.clear_poison();
+        self.clipboard.clear_poison();
+        self.foreground.clear_poison();
+        self.kill_switch.clear_poison();
+        self.watchlist_hotkey.clear_poison();
+        self.anomalies.clear_poison();
+        self.baselines.clear_poison();
+        self.calibration.clear_poison();
+    }
 }
 
 #[tauri::command]
-fn get_processes(state: State<AppState>) -> ProcessTree {
-    let mut system = state.system.lock().unwrap();
-    system.refresh_all();
-    
+fn get_processes(app: tauri::AppHandle, state: State<AppState>) -> ProcessTree {
+    // Con el muestreo en segundo plano activo se devuelve su última
+    // instantánea: un segundo refresco alteraría el intervalo del cálculo de CPU
+    if sampler::is_active() {
+        let snapshot = state.processes.read().unwrap();
+        if !snapshot.processes.is_empty() {
+            return snapshot.clone();
+        }
+    }
+    refresh_processes(&app, &state)
+}
+
+// Refresco completo: procesos, colectores, reglas e historiales
+fn refresh_processes(app: &tauri::AppHandle, state: &AppState) -> ProcessTree {
+    let mut laps = selfmon::Laps::start();
+    let replayed = state.replay.lock().unwrap().next_frame();
+    let live = replayed.is_none() && state.mock_system.is_none();
+    let snapshot = match (replayed, &state.mock_system) {
+        (Some(snapshot), _) => snapshot,
+        (None, Some(mock)) => {
+            let mut mock = mock.lock().unwrap();
+            mock.advance();
+            mock.snapshot()
+        }
+        (None, None) => {
+            state.refresh_schedule.lock().unwrap().refresh(&mut state.system.write().unwrap());
+            laps.lap("sysinfo");
+            let system = state.system.read().unwrap();
+            health::guard(
+                &state.collector_health,
+                "clocks",
+                &state.clocks,
+                |clocks| clocks.sample(&system),
+                |clocks| clocks.error.clone().filter(|_| !clocks.is_unavailable()),
+            );
+            laps.lap("clocks");
+            let snapshot = system.snapshot();
+            drop(system);
+            if let Err(e) = state.replay.lock().unwrap().record(&snapshot) {
+                let _ = app.emit("recording-failed", e);
+            }
+            snapshot
+        }
+    };
+    state.data_usage.lock().unwrap().record(&snapshot.networks);
+    let mut tree = build_process_tree(&snapshot, None);
+    state.io_rates.lock().unwrap().sample(&snapshot, &mut tree.processes, &mut tree.totals);
+    laps.lap("process_tree");
+    let (growth_columns, format) = {
+        let settings = state.settings.read().unwrap();
+        (settings.growth_columns, settings.format)
+    };
+    if growth_columns {
+        state.growth.lock().unwrap().sample(&snapshot, &mut tree.processes, live, &format);
+        laps.lap("growth");
+    } else {
+        state.growth.lock().unwrap().clear();
+    }
+
+    // Expresiones de vigilancia y reglas con los datos de este refresco
+    let (expressions, rules, columns) = {
+        let settings = state.settings.read().unwrap();
+        (settings.watch_expressions.clone(), settings.rules.clone(), settings.custom_columns.clone())
+    };
+    let alert_rules = watchlists::alert_rules(&state.settings.read().unwrap());
+    let expression_alerts =
+        alert_rules.iter().any(|rule| matches!(rule.target, alerts::AlertTarget::Expression(_)));
+    let context = (!expressions.is_empty() || !rules.is_empty() || !columns.is_empty() || expression_alerts)
+        .then(|| expressions::MetricContext::from_snapshot(&snapshot));
+    let total_memory = snapshot.total_memory as f64;
+    let cpu_count = snapshot.cpu_count;
+    let reading = alerts::SystemReading::of(&snapshot);
+    drop(snapshot);
+    // Enumera los hilos de los procesos vigilados. Cada colector pasa por
+    // health::guard: si falla una y otra vez o entra en pánico se desactiva
+    health::guard(&state.collector_health, "core_heatmap", &state.core_heatmap, |heatmap| heatmap.sample(), |_| None);
+    laps.lap("core_heatmap");
+    if let Some(context) = context.as_ref().filter(|_| !expressions.is_empty()) {
+        *state.watch_values.write().unwrap() = expressions::evaluate_all(context, &expressions);
+        laps.lap("expressions");
+    }
+
+    let alive: HashSet<u32> = tree.processes.iter().map(|process| process.pid).collect();
+    suspend::retain_alive(&alive);
+    let network_deltas = health::guard(
+        &state.collector_health,
+        "etw_network",
+        &state.bandwidth,
+        |bandwidth| {
+            let deltas = bandwidth.sample(&alive);
+            bandwidth.annotate(&mut tree.processes);
+            deltas
+        },
+        |_| None,
+    )
+    .unwrap_or_default();
+    health::guard(&state.collector_health, "etw_fileio", &state.fileio, |fileio| fileio.sample(&alive), |_| None);
+    laps.lap("etw_sampling");
+
+    // Una sola consulta de GPU por refresco, unida por PID
+    health::guard(
+        &state.collector_health,
+        "gpu",
+        &state.gpu,
+        |gpu| {
+            gpu.sample();
+            gpu.annotate(&mut tree.processes);
+        },
+        |gpu| gpu.error.clone().filter(|_| !gpu.is_disabled() && !gpu.is_unavailable()),
+    );
+    tree.totals.gpu_usage = tree.processes.iter().map(|process| process.gpu_usage).sum();
+    laps.lap("gpu");
+
+    let throttles = health::guard(
+        &state.collector_health,
+        "thermal",
+        &state.thermal,
+        |thermal| thermal.sample(),
+        |thermal| thermal.error.clone().filter(|_| !thermal.is_unavailable()),
+    );
+    for event in throttles.into_iter().flatten() {
+        let _ = app.emit("thermal-throttle", event);
+    }
+    laps.lap("thermal");
+    for degraded in state.collector_health.lock().unwrap().take_unreported() {
+        let _ = app.emit("collector-degraded", degraded);
+    }
+
+    let (threshold, window_minutes) = {
+        let settings = state.settings.read().unwrap();
+        (settings.restart_loop_threshold, settings.restart_loop_window_minutes)
+    };
+    let mut restarts = state.restarts.lock().unwrap();
+    for restart_loop in restarts.record(&tree.processes, threshold, window_minutes) {
+        let _ = app.emit("restart-loop", restart_loop);
+    }
+    restarts.annotate(&mut tree.processes);
+    drop(restarts);
+
+    let impact_weights = state.settings.read().unwrap().impact_weights;
+    impact::annotate(&mut tree.processes, &tree.totals, &impact_weights, cpu_count, total_memory as u64);
+    laps.lap("impact");
+
+    if !columns.is_empty() {
+        expressions::evaluate_columns(&columns, &mut tree.processes, total_memory, context.as_ref());
+        laps.lap("custom_columns");
+    }
+    // Una grabación o el sistema simulado no son historial real, y sus PID
+    // pueden coincidir con procesos locales: las reglas solo se simulan
+    let mut app_history = state.app_history.lock().unwrap();
+    if live {
+        app_history.record(&tree.processes);
+        app_history.record_network(&tree.processes, &network_deltas);
+        laps.lap("app_history");
+    }
+    if let Some(context) = context.as_ref().filter(|_| !rules.is_empty()) {
+        let mut executor = rules::Executor {
+            simulate: !live || state.settings.read().unwrap().simulate_automation,
+            paused: state.settings.read().unwrap().quiet_hours.pauses_automation(),
+            act: &mut |rule, action, pid| rules::apply_action(state, rule, action, pid),
+        };
+        let fired = state.rules.lock().unwrap().evaluate(&rules, context, &tree.processes, &app_history, &mut executor);
+        let mut session = state.session.lock().unwrap();
+        for event in fired {
+            session.alert(&event.rule);
+            let _ = app.emit("rule-triggered", event);
+        }
+        laps.lap("rules");
+    }
+    drop(app_history);
+    if !alert_rules.is_empty() {
+        let fired = state.alerts.lock().unwrap().evaluate(
+            &alert_rules,
+            &tree.processes,
+            &tree.totals,
+            &reading,
+            context.as_ref(),
+        );
+        for event in fired {
+            state.session.lock().unwrap().alert(&format!("alerta:{}", event.rule_id));
+            alerts::dispatch(app, &alert_rules, event);
+        }
+        laps.lap("alerts");
+    }
+    let (triggered, ended) = state.process_watches.lock().unwrap().evaluate(&tree.processes);
+    for event in triggered {
+        let _ = app.emit("process-watch-triggered", event);
+    }
+    for event in ended {
+        if let Some(code) = event.exit_code {
+            state.exit_codes.lock().unwrap().record(event.pid, &event.name, code);
+        }
+        let _ = app.emit("process-watch-ended", event);
+    }
+    let (anomaly_detection, anomaly_sigma) = {
+        let settings = state.settings.read().unwrap();
+        (settings.anomaly_detection, settings.anomaly_sigma)
+    };
+    if anomaly_detection {
+        for event in state.anomalies.lock().unwrap().evaluate(&tree.processes, anomaly_sigma) {
+            let _ = app.emit("anomaly-detected", event);
+        }
+        laps.lap("anomalies");
+    }
+    calibration::record(app, state, &tree.processes, live);
+    state.calibration.lock().unwrap().annotate(&mut tree.processes);
+    state.session.lock().unwrap().record(reading.cpu_usage, reading.used_memory, &tree.processes);
+
+    let history_window = std::time::Duration::from_secs(state.settings.read().unwrap().process_history_minutes * 60);
+    state.process_history.write().unwrap().record(stats::now_millis(), &tree.processes, history_window);
+    state.process_deltas.lock().unwrap().record(&tree);
+    state.change_feed.lock().unwrap().record(&tree.processes);
+    *state.processes.write().unwrap() = tree.clone();
+    laps.lap("history");
+    laps.finish(&state.self_metrics);
+    if state.settings.read().unwrap().usage_stats {
+        if let Some(own) = tree.processes.iter().find(|process| process.pid == std::process::id()) {
+            let mut usage_stats = state.usage_stats.lock().unwrap();
+            usage_stats.record_overhead(own.cpu_usage, own.memory_usage, &state.self_metrics.lock().unwrap());
+        }
+    }
+    tree
+}
+
+// Refresca solo `pid` y sus descendientes, para que un nodo expandido se
+// actualice más a menudo que la lista completa sin su coste
+#[tauri::command]
+fn get_subtree(state: State<AppState>, pid: u32) -> Result<ProcessTree, String> {
+    let mut system = state.system.write().unwrap();
+
+    // Descendientes según las relaciones del último refresco
+    let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
+    for (child, process) in system.processes() {
+        if let Some(parent) = process.parent() {
+            children.entry(parent.as_u32()).or_default().push(child.as_u32());
+        }
+    }
+    let mut subtree = HashSet::new();
+    let mut pending = vec![pid];
+    while let Some(current) = pending.pop() {
+        if subtree.insert(current) {
+            pending.extend(children.get(&current).into_iter().flatten());
+        }
+    }
+
+    subtree.retain(|pid| system.refresh_process(sysinfo::Pid::from_u32(*pid)));
+    if !subtree.contains(&pid) {
+        return Err(format!("El proceso {} ya no está en ejecución", pid));
+    }
+    let mut tree = build_process_tree(&provider::system_snapshot(&system, Some(&subtree)), Some(&subtree));
+    drop(system);
+
+    // Tasas de red y GPU del último muestreo global
+    state.bandwidth.lock().unwrap().annotate(&mut tree.processes);
+    state.gpu.lock().unwrap().annotate(&mut tree.processes);
+    state.restarts.lock().unwrap().annotate(&mut tree.processes);
+    tree.totals.gpu_usage = tree.processes.iter().map(|process| process.gpu_usage).sum();
+    Ok(tree)
+}
+
+// Construye el árbol de procesos a partir de la instantánea del último
+// refresco; con `only` se limita a esos PIDs (sin los totales de red)
+fn build_process_tree(snapshot: &SystemSnapshot, only: Option<&HashSet<u32>>) -> ProcessTree {
     let mut processes = Vec::new();
     let mut process_relationships: HashMap<u32, Vec<u32>> = HashMap::new();
     let mut process_parents: HashMap<u32, Option<u32>> = HashMap::new();
     
     // Primera pasada: recopilar todos los procesos y sus PIDs
-    for (pid, process) in system.processes() {
-        let pid_u32 = pid.as_u32();
+    for process in &snapshot.processes {
+        let pid_u32 = process.pid;
         process_parents.insert(pid_u32, None);
         process_relationships.insert(pid_u32, Vec::new());
     }
     
     // Segunda pasada: establecer relaciones padre-hijo
-    for (pid, process) in system.processes() {
-        let pid_u32 = pid.as_u32();
+    for process in &snapshot.processes {
+        let pid_u32 = process.pid;
         
-        if let Some(parent_pid) = process.parent() {
-            let parent_pid_u32 = parent_pid.as_u32();
+        if let Some(parent_pid_u32) = process.parent {
             process_parents.insert(pid_u32, Some(parent_pid_u32));
             
             // Agregar a la lista de hijos del padre
@@ -70,40 +653,169 @@ fn get_processes(state: State<AppState>) -> ProcessTree {
     }
     
     // Tercera pasada: crear la información del proceso
-    for (pid, process) in system.processes() {
-        let pid_u32 = pid.as_u32();
-        let parent_pid = process_parents.get(&pid_u32).unwrap_or(&None).clone();
+    for process in &snapshot.processes {
+        let pid_u32 = process.pid;
+        if only.is_some_and(|only| !only.contains(&pid_u32)) {
+            continue;
+        }
+        let parent_pid = process_parents.get(&pid_u32).copied().flatten();
         let has_children = !process_relationships.get(&pid_u32).unwrap_or(&Vec::new()).is_empty();
+        let suspended = process.suspended || process.stopped;
         
         processes.push(ProcessInfo {
             pid: pid_u32,
-            name: process.name().to_string(),
-            cpu_usage: process.cpu_usage(),
-            memory_usage: process.memory(),
-            disk_read_bytes: process.disk_usage().read_bytes,
-            disk_write_bytes: process.disk_usage().written_bytes,
-            gpu_usage: get_gpu_usage(&process.name()),
+            name: process.name.clone(),
+            cpu_usage: process.cpu_usage,
+            memory_usage: process.memory,
+            disk_read_bytes: process.disk_read_bytes,
+            disk_write_bytes: process.disk_written_bytes,
+            disk_read_rate: 0.0, // Lo rellena IoRateTracker en el refresco completo
+            disk_write_rate: 0.0,
+            gpu_usage: 0.0, // Lo rellena el colector de GPU
+            gpu_dedicated_memory: 0,
+            gpu_shared_memory: 0,
             parent_pid,
             has_children,
+            network_send_rate: 0.0,
+            network_receive_rate: 0.0,
+            priority: process.priority,
+            efficiency_mode: process.efficiency_mode,
+            restart_loop: false,
+            suspended,
+            status: ProcessStatus::resolve(suspended, process.not_responding),
+            impact_score: 0.0, // Lo calcula impact::annotate con todos los colectores
+            cpu_delta: None,   // Los rellena GrowthTracker si están activadas
+            memory_growth: None,
+            handle_growth: None,
+            above_usual: Vec::new(), // Lo rellena la calibración
+            custom_columns: HashMap::new(),
         });
     }
     
+    let mut totals = ProcessTotals {
+        process_count: processes.len(),
+        ..Default::default()
+    };
+    for process in &processes {
+        totals.cpu_usage += process.cpu_usage;
+        totals.memory_usage += process.memory_usage;
+        totals.disk_read_bytes += process.disk_read_bytes;
+        totals.disk_write_bytes += process.disk_write_bytes;
+        totals.gpu_usage += process.gpu_usage;
+    }
+    if let Some(only) = only {
+        process_relationships.retain(|pid, _| only.contains(pid));
+    } else {
+        for network in &snapshot.networks {
+            totals.network_received_bytes += network.received;
+            totals.network_transmitted_bytes += network.transmitted;
+        }
+    }
+
     ProcessTree {
+        schema_version: SchemaVersion::default(),
         processes,
         process_relationships,
+        totals,
     }
 }
 
+// Resultado de `kill_process`: terminado o pendiente de confirmación
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "status", rename_all = "snake_case")]
+enum KillOutcome {
+    Killed,
+    UnsavedWork(UnsavedWorkWarning), // Repetir con `force` para terminarlo igualmente
+}
+
+// Los dobles clics o repeticiones rápidas se agrupan en una sola ejecución
+#[tauri::command]
+fn kill_process(state: State<AppState>, pid: u32, force: Option<bool>) -> Result<KillOutcome, String> {
+    let force = force.unwrap_or(false);
+    actions::run_once(&state, pid, &format!("kill:{}", force), || terminate_process(&state, pid, force))
+}
+
+// Resultado por proceso de `kill_process_tree`
+#[derive(Debug, Serialize, Clone)]
+struct TreeKillResult {
+    pid: u32,
+    outcome: Option<KillOutcome>, // None si falló
+    error: Option<String>,
+}
+
+// Termina el proceso y sus descendientes, cada hijo antes que su padre, para
+// no dejar huérfanos. Un fallo no detiene el resto
 #[tauri::command]
-fn kill_process(pid: u32) -> Result<bool, String> {
+fn kill_process_tree(state: State<AppState>, pid: u32, force: Option<bool>) -> Result<Vec<TreeKillResult>, String> {
+    let force = force.unwrap_or(false);
+    actions::run_once(&state, pid, &format!("kill_tree:{}", force), || {
+        let order = tree_order(&state.processes.read().unwrap(), pid);
+        Ok(order
+            .into_iter()
+            .map(|target| match terminate_process(&state, target, force) {
+                Ok(outcome) => TreeKillResult { pid: target, outcome: Some(outcome), error: None },
+                Err(e) => TreeKillResult { pid: target, outcome: None, error: Some(e) },
+            })
+            .collect())
+    })
+}
+
+// El proceso y sus descendientes del último refresco, con cada hijo antes
+// que su padre (el preorden invertido) y el proceso raíz al final
+fn tree_order(tree: &ProcessTree, pid: u32) -> Vec<u32> {
+    let mut order = Vec::new();
+    let mut pending = vec![pid];
+    while let Some(current) = pending.pop() {
+        if !order.contains(&current) {
+            order.push(current);
+            pending.extend(tree.process_relationships.get(&current).into_iter().flatten());
+        }
+    }
+    order.reverse();
+    order
+}
+
+fn terminate_process(state: &AppState, pid: u32, force: bool) -> Result<KillOutcome, String> {
+    if !force && state.settings.read().unwrap().warn_unsaved_work {
+        let name = state
+            .processes
+            .read()
+            .unwrap()
+            .processes
+            .iter()
+            .find(|process| process.pid == pid)
+            .map(|process| process.name.clone());
+        if let Some(warning) = name.and_then(|name| unsaved::check(pid, &name)) {
+            return Ok(KillOutcome::UnsavedWork(warning));
+        }
+    }
+
+    // Ejecutable y argumentos por si hay que relanzarlo
+    let snapshot = relaunch::capture(state, pid);
+    let result = signal_kill(state, pid, force);
+    if let (Ok(KillOutcome::Killed), Some(snapshot)) = (&result, snapshot) {
+        relaunch::remember(state, snapshot);
+    }
+    result
+}
+
+fn signal_kill(state: &AppState, pid: u32, force: bool) -> Result<KillOutcome, String> {
     #[cfg(target_os = "windows")]
     {
-        match Command::new("taskkill").args(&["/F", "/PID", &pid.to_string()]).output() {
+        use windows::Win32::System::Threading::{PROCESS_QUERY_LIMITED_INFORMATION, PROCESS_SYNCHRONIZE};
+
+        let _ = force;
+        // Abierto antes de terminarlo: después ya no se podría leer el código de salida
+        let process = win::open_process(pid, PROCESS_SYNCHRONIZE | PROCESS_QUERY_LIMITED_INFORMATION).ok();
+        match Command::new("taskkill").args(["/F", "/PID", &pid.to_string()]).output() {
             Ok(output) => {
                 if output.status.success() {
-                    Ok(true)
+                    if let Some(process) = process {
+                        exitcodes::capture(state, pid, process);
+                    }
+                    Ok(KillOutcome::Killed)
                 } else {
-                    let error = String::from_utf8_lossy(&output.stderr).to_string();
+                    let error = win::decode_console_output(&output.stderr);
                     Err(format!("No se pudo terminar el proceso: {}", error))
                 }
             },
@@ -111,36 +823,447 @@ fn kill_process(pid: u32) -> Result<bool, String> {
         }
     }
     
+    // Linux y macOS: SIGTERM para que pueda cerrar ordenadamente, SIGKILL si se fuerza
     #[cfg(not(target_os = "windows"))]
     {
-        Err("Esta función solo está disponible en Windows".to_string())
+        let signal = if force { sysinfo::Signal::Kill } else { sysinfo::Signal::Term };
+        let system = state.system.read().unwrap();
+        let process = system
+            .process(sysinfo::Pid::from_u32(pid))
+            .ok_or_else(|| format!("El proceso {} ya no está en ejecución", pid))?;
+        match process.kill_with(signal) {
+            Some(true) => Ok(KillOutcome::Killed),
+            Some(false) => Err(format!("No se pudo terminar el proceso {}", pid)),
+            None => Err("La señal no está disponible en esta plataforma".to_string()),
+        }
     }
 }
 
- 
-fn get_gpu_usage(process_name: &str) -> f32 {
-    // Para obtener el uso real de GPU necesitarías usar NVML (NVIDIA) o
-    // las APIs AMD equivalentes. Esto es un placeholder.
-    
-    // Podría implementarse usando el comando "nvidia-smi" en sistemas con GPU NVIDIA
-    match Command::new("nvidia-smi")
-        .args(&["--query-compute-apps=pid,used_memory", "--format=csv,noheader"])
-        .output() {
-            Ok(output) => {
-                // Analizar la salida para encontrar el PID y extraer uso de GPU
-                // Este es un ejemplo simplificado
-                0.0
-            },
-            Err(_) => 0.0,
+// Colectores en segundo plano según la configuración (ETW requiere
+// privilegios de administrador). Se vuelve a llamar al cambiar los colectores
+// activos: la sesión ETW se reinicia solo con los proveedores necesarios
+fn start_collectors(state: &AppState) {
+    let enabled = state.settings.read().unwrap().collectors;
+    let disabled = |collector: &str| Some(format!("El colector de {} está desactivado", collector));
+
+    #[cfg(target_os = "windows")]
+    let error = {
+        let bandwidth = state.bandwidth.clone();
+        let fileio = state.fileio.clone();
+        let regtrace = state.regtrace.clone();
+        let self_metrics = state.self_metrics.clone();
+        let fileio_guard = health::EventGuard::new(state.collector_health.clone(), "etw_fileio");
+        let regtrace_guard = health::EventGuard::new(state.collector_health.clone(), "etw_registry");
+        let network_guard = health::EventGuard::new(state.collector_health.clone(), "etw_network");
+        let mut flags = etw::EVENT_TRACE_FLAG(0);
+        if enabled.network {
+            flags |= etw::EVENT_TRACE_FLAG_NETWORK_TCPIP;
+        }
+        if enabled.file_io {
+            // Los eventos de archivo solo traen el TID: hacen falta los de hilos
+            flags |= etw::EVENT_TRACE_FLAG_FILE_IO
+                | etw::EVENT_TRACE_FLAG_FILE_IO_INIT
+                | etw::EVENT_TRACE_FLAG_DISK_FILE_IO
+                | etw::EVENT_TRACE_FLAG_THREAD;
+        }
+        if enabled.registry {
+            flags |= etw::EVENT_TRACE_FLAG_REGISTRY;
+        }
+        if flags.0 == 0 {
+            etw::stop();
+            None
+        } else {
+            etw::start(flags, move |event| {
+                let started = std::time::Instant::now();
+                // Un pánico aquí cruzaría la frontera FFI de ETW y abortaría el proceso
+                let collector = if event.provider == etw::FILEIO_GUID {
+                    fileio_guard.run(&fileio, |fileio| fileio.on_event(event));
+                    "etw_fileio"
+                } else if event.provider == etw::REGISTRY_GUID {
+                    regtrace_guard.run(&regtrace, |tracer| tracer.on_event(event));
+                    "etw_registry"
+                } else {
+                    network_guard.run(&bandwidth, |network| network.on_event(event));
+                    "etw_network"
+                };
+                self_metrics.lock().unwrap().record(collector, started.elapsed());
+            })
+            .err()
         }
+    };
+
+    #[cfg(not(target_os = "windows"))]
+    let error = Some("Los colectores ETW solo están disponibles en Windows".to_string());
+
+    // Los colectores desactivados pierden los datos acumulados
+    let mut bandwidth = state.bandwidth.lock().unwrap();
+    if !enabled.network {
+        *bandwidth = BandwidthCollector::default();
+    }
+    bandwidth.error = if enabled.network { error.clone() } else { disabled("red") };
+    drop(bandwidth);
+
+    let mut fileio = state.fileio.lock().unwrap();
+    if !enabled.file_io {
+        *fileio = FileIoCollector::default();
+    }
+    fileio.error = if enabled.file_io { error.clone() } else { disabled("archivos") };
+    drop(fileio);
+
+    let mut regtrace = state.regtrace.lock().unwrap();
+    if !enabled.registry {
+        *regtrace = RegistryTracer::default();
+    }
+    regtrace.error = if enabled.registry { error } else { disabled("registro") };
+    drop(regtrace);
+
+    state.gpu.lock().unwrap().set_enabled(enabled.gpu);
+}
+
+fn stop_collectors() {
+    #[cfg(target_os = "windows")]
+    etw::stop();
+}
+
+// sysinfo calcula el uso de CPU como diferencia entre dos refrescos: se hace
+// una segunda muestra tras el intervalo mínimo para que el primer fotograma
+// de la UI ya tenga valores reales en lugar de 0%
+fn warm_system() -> System {
+    let mut system = System::new_all();
+    std::thread::sleep(System::MINIMUM_CPU_UPDATE_INTERVAL);
+    system.refresh_cpu();
+    system.refresh_processes();
+    system
 }
 
 fn main() {
-    let system = Arc::new(Mutex::new(System::new_all()));
+    // Proceso hijo lanzado por start_stress: no abre la interfaz
+    if stress::run_from_args() {
+        return;
+    }
+
+    let system = Arc::new(RwLock::new(warm_system()));
+    let mock_system = MockSystem::from_env()
+        .unwrap_or_else(|e| panic!("{}", e))
+        .map(|mock| Arc::new(Mutex::new(mock)));
     
     tauri::Builder::default()
-        .manage(AppState { system })
-        .invoke_handler(tauri::generate_handler![get_processes, kill_process])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .plugin(tauri_plugin_updater::Builder::new().build())
+        .plugin(tauri_plugin_notification::init())
+        .manage(AppState {
+            system,
+            mock_system,
+            replay: Arc::new(Mutex::new(ReplayState::default())),
+            refresh_schedule: Arc::new(Mutex::new(RefreshSchedule::default())),
+            processes: Arc::new(RwLock::new(ProcessTree::default())),
+            settings: Arc::new(RwLock::new(Settings::default())),
+            history: Arc::new(RwLock::new(SystemHistory::default())),
+            process_history: Arc::new(RwLock::new(ProcessHistory::default())),
+            watch_values: Arc::new(RwLock::new(Vec::new())),
+            suspended_launches: Arc::new(Mutex::new(HashMap::new())),
+            bandwidth: Arc::new(Mutex::new(BandwidthCollector::default())),
+            disk_perf: Arc::new(Mutex::new(DiskPerfCollector::default())),
+            gpu: Arc::new(Mutex::new(GpuCollector::default())),
+            fileio: Arc::new(Mutex::new(FileIoCollector::default())),
+            regtrace: Arc::new(Mutex::new(RegistryTracer::default())),
+            cleanup_job: Arc::new(Mutex::new(None)),
+            pending_actions: Arc::new(Mutex::new(HashMap::new())),
+            stress_tasks: Arc::new(Mutex::new(Vec::new())),
+            self_metrics: Arc::new(Mutex::new(SelfMetrics::default())),
+            collector_health: Arc::new(Mutex::new(CollectorHealth::default())),
+            plugins: Arc::new(Mutex::new(PluginRegistry::default())),
+            rules: Arc::new(Mutex::new(RuleEngine::default())),
+            app_history: Arc::new(Mutex::new(AppHistory::default())),
+            session: Arc::new(Mutex::new(SessionTracker::default())),
+            data_usage: Arc::new(Mutex::new(DataUsage::default())),
+            connections: Arc::new(Mutex::new(ConnectionWatcher::default())),
+            thermal: Arc::new(Mutex::new(ThermalCollector::default())),
+            clocks: Arc::new(Mutex::new(ClockCollector::default())),
+            core_heatmap: Arc::new(Mutex::new(CoreHeatmap::default())),
+            process_deltas: Arc::new(Mutex::new(DeltaLog::default())),
+            change_feed: Arc::new(Mutex::new(ChangeFeed::default())),
+            service_watchdog: Arc::new(Mutex::new(ServiceWatchdog::default())),
+            restarts: Arc::new(Mutex::new(RestartTracker::default())),
+            usage_stats: Arc::new(Mutex::new(UsageStats::default())),
+            audit: Arc::new(Mutex::new(AuditLog::default())),
+            recently_killed: Arc::new(Mutex::new(VecDeque::new())),
+            io_rates: Arc::new(Mutex::new(IoRateTracker::default())),
+            growth: Arc::new(Mutex::new(GrowthTracker::default())),
+            alerts: Arc::new(Mutex::new(AlertEngine::default())),
+            alert_log: Arc::new(Mutex::new(AlertLog::default())),
+            quiet_hours: Arc::new(Mutex::new(QuietHoursLog::default())),
+            process_watches: Arc::new(Mutex::new(ProcessWatches::default())),
+            exit_watches: Arc::new(Mutex::new(ExitWatches::default())),
+            exit_codes: Arc::new(Mutex::new(ExitCodes::default())),
+            icons: Arc::new(Mutex::new(IconCache::default())),
+            disk_scans: Arc::new(Mutex::new(DiskScanCache::default())),
+            hook_imports: Arc::new(Mutex::new(ImportCache::default())),
+            clipboard: Arc::new(Mutex::new(ClipboardMonitor::default())),
+            foreground: Arc::new(Mutex::new(ForegroundBooster::default())),
+            kill_switch: Arc::new(Mutex::new(KillSwitch::default())),
+            watchlist_hotkey: Arc::new(Mutex::new(WatchlistHotkey::default())),
+            anomalies: Arc::new(Mutex::new(AnomalyDetector::default())),
+            baselines: Arc::new(Mutex::new(BaselineStore::default())),
+            calibration: Arc::new(Mutex::new(Calibration::default())),
+        })
+        .setup(|app| {
+            let loaded = settings::load(app.handle());
+            if let (Some(layout), Some(window)) = (&loaded.window_layout, app.get_webview_window("main")) {
+                layout::restore(&window.as_ref().window(), layout);
+            }
+            *app.state::<AppState>().settings.write().unwrap() = loaded;
+            start_collectors(&app.state::<AppState>());
+            if let Ok(dir) = app.path().app_data_dir() {
+                *app.state::<AppState>().plugins.lock().unwrap() = PluginRegistry::load_dir(&dir.join("plugins"));
+                *app.state::<AppState>().app_history.lock().unwrap() = AppHistory::load(&dir.join("app_history.json"));
+                *app.state::<AppState>().data_usage.lock().unwrap() = DataUsage::load(&dir.join("data_usage.json"));
+                *app.state::<AppState>().usage_stats.lock().unwrap() = UsageStats::load(&dir.join("usage_stats.json"));
+                *app.state::<AppState>().audit.lock().unwrap() = AuditLog::load(&dir.join("audit_log.jsonl"));
+                *app.state::<AppState>().alert_log.lock().unwrap() = AlertLog::load(&dir.join("alert_log.jsonl"));
+                *app.state::<AppState>().anomalies.lock().unwrap() =
+                    AnomalyDetector::load(&dir.join("anomaly_baselines.json"));
+                *app.state::<AppState>().baselines.lock().unwrap() = BaselineStore::load(&dir.join("baselines.json"));
+                *app.state::<AppState>().calibration.lock().unwrap() = Calibration::load(&dir.join("calibration.json"));
+            }
+            screentime::start(app.handle().clone());
+            session::watch_end_session(app.handle().clone());
+            clipboard::apply(app.handle(), app.state::<AppState>().settings.read().unwrap().clipboard_monitor);
+            let kill_switch = app.state::<AppState>().settings.read().unwrap().kill_switch.clone();
+            let _ = killswitch::apply(app.handle(), &kill_switch);
+            let watchlist_hotkey = app.state::<AppState>().settings.read().unwrap().watchlist_hotkey.clone();
+            let _ = watchlists::apply_hotkey(app.handle(), watchlist_hotkey.as_deref());
+            let foreground_boost = app.state::<AppState>().settings.read().unwrap().foreground_boost.clone();
+            let _ = foreground::apply(app.handle(), &foreground_boost);
+            connections::start(app.handle().clone());
+            sampler::start(app.handle().clone());
+            watchdog::start(app.handle().clone());
+
+            // La ventana se crea oculta y solo se muestra si no se arrancó en la bandeja
+            tray::create(app)?;
+            if !tray::start_minimized() {
+                tray::show_main_window(app.handle());
+            }
+            Ok(())
+        })
+        .on_window_event(|window, event| {
+            if let tauri::WindowEvent::CloseRequested { .. } = event {
+                if window.label() == "main" {
+                    let _ = layout::persist(window);
+                    let _ = window.state::<AppState>().app_history.lock().unwrap().save();
+                    let _ = window.state::<AppState>().data_usage.lock().unwrap().save();
+                    let _ = window.state::<AppState>().anomalies.lock().unwrap().save();
+                    let state = window.state::<AppState>();
+                    let _ = state.usage_stats.lock().unwrap().save(&state.self_metrics.lock().unwrap());
+                }
+            }
+        })
+        .invoke_handler({
+            let handler: Box<dyn Fn(tauri::ipc::Invoke) -> bool + Send + Sync> = Box::new(tauri::generate_handler![
+                get_processes,
+                get_subtree,
+                query::query_processes,
+                details::get_process_details,
+                delta::get_process_delta,
+                changefeed::get_changes,
+                sampler::set_refresh_interval,
+                sampler::pause_updates,
+                kill_process,
+                kill_process_tree,
+                relaunch::get_recently_killed,
+                relaunch::relaunch_last_killed,
+                relaunch::get_launch_command,
+                relaunch::restart_process_with,
+                foreground::get_foreground_boost,
+                dependents::get_dependency_hints,
+                bundle::create_support_bundle,
+                platform::get_platform_capabilities,
+                platform::get_capabilities,
+                palette::list_actions,
+                icons::get_process_icon,
+                windowctl::list_process_windows,
+                windowctl::window_action,
+                modules::get_process_modules,
+                signature::verify_process_signature,
+                diskscan::analyze_disk,
+                jobs::cancel_job,
+                jobs::get_running_jobs,
+                cleanup::estimate_cleanup,
+                cleanup::run_cleanup,
+                usersessions::get_user_sessions,
+                usersessions::logoff_session,
+                usersessions::disconnect_session,
+                disks::empty_recycle_bin,
+                pagefile::get_page_files,
+                dump::create_process_dump,
+                gpuinfo::get_gpu_adapters,
+                clipboard::get_clipboard_owner,
+                hooks::get_input_hooks,
+                overlays::get_overlays,
+                pidwatch::watch_process,
+                pidwatch::list_process_watches,
+                pidwatch::unwatch_process,
+                exitwatch::notify_on_exit,
+                exitwatch::list_exit_watches,
+                exitwatch::cancel_exit_watch,
+                exitcodes::get_exit_codes,
+                audit::get_action_log_page,
+                stats::get_system_history_page,
+                compare::get_process_history_page,
+                modules::get_process_modules_page,
+                schema::get_schema_info,
+                replay::start_recording,
+                replay::stop_recording,
+                replay::start_replay,
+                replay::seek_replay,
+                replay::set_replay_speed,
+                replay::get_replay_status,
+                replay::stop_replay,
+                watchlists::list_watchlists,
+                watchlists::save_watchlist,
+                watchlists::remove_watchlist,
+                watchlists::activate_watchlist,
+                watchlists::cycle_watchlist,
+                defender::get_security_status,
+                quiethours::get_quiet_hours_status,
+                quiethours::set_quiet_hours,
+                quiethours::get_suppressed_notifications,
+                quiethours::clear_suppressed_notifications,
+                alertlog::get_alerts,
+                alertlog::ack_alert,
+                alertlog::ack_all_alerts,
+                baselines::capture_baseline,
+                baselines::list_baselines,
+                baselines::delete_baseline,
+                baselines::compare_to_baseline,
+                calibration::get_calibration,
+                calibration::start_calibration,
+                calibration::cancel_calibration,
+                suspend::suspend_process,
+                suspend::resume_process,
+                settings::get_settings,
+                settings::update_settings,
+                settings::export_config,
+                settings::import_config,
+                format::format_value,
+                format::format_io,
+                locale::get_locale,
+                locale::format_number,
+                locale::format_timestamp,
+                stats::get_system_stats,
+                stats::get_system_history,
+                stress::start_stress,
+                stress::get_stress_tasks,
+                stress::stop_stress,
+                expressions::add_watch_expression,
+                expressions::remove_watch_expression,
+                expressions::get_watch_values,
+                expressions::add_custom_column,
+                expressions::remove_custom_column,
+                launch::launch_suspended,
+                launch::resume_launched,
+                launch::run_task,
+                launch::create_process,
+                layout::save_window_layout,
+                layout::set_selected_tab,
+                layout::set_always_on_top,
+                layout::set_window_opacity,
+                debugger::debug_process,
+                gpu::get_gpu_priority,
+                gpu::set_gpu_priority,
+                graphs::open_graph_window,
+                graphs::get_graph_sample,
+                idle::get_idle_time,
+                apphistory::get_app_history,
+                apphistory::get_app_history_days,
+                apphistory::get_app_data_usage,
+                apphistory::clear_app_history,
+                screentime::get_screen_time,
+                screentime::get_foreground_timeline,
+                session::get_session_summaries,
+                datausage::get_data_usage,
+                datausage::get_data_usage_months,
+                connections::get_connections,
+                connections::get_network_connections,
+                connections::watch_connections,
+                connections::unwatch_connections,
+                hosts::get_process_hosts,
+                netinfo::get_network_info,
+                probe::ping_host,
+                probe::traceroute,
+                thermal::get_thermal_status,
+                thermal::get_throttle_events,
+                clocks::get_clock_history,
+                cores::get_core_topology,
+                cores::get_core_distribution,
+                cores::get_process_affinity,
+                cores::set_process_affinity,
+                priority::set_process_priority,
+                priority::set_efficiency_mode,
+                bulk::bulk_action,
+                heatmap::watch_core_heatmap,
+                heatmap::unwatch_core_heatmap,
+                heatmap::get_core_heatmap,
+                export::export_selection,
+                export::export_snapshot,
+                report::generate_report,
+                services::list_services,
+                services::start_service,
+                services::stop_service,
+                services::restart_service,
+                startup::get_startup_items,
+                startup::enable_startup_item,
+                startup::disable_startup_item,
+                services::get_process_for_service,
+                services::get_services_for_process,
+                watchdog::set_service_watched,
+                watchdog::get_watched_services,
+                watchdog::get_service_restart_log,
+                bandwidth::get_network_usage,
+                compare::compare_processes,
+                compare::get_process_history,
+                conflicts::get_conflicts,
+                updates::check_for_updates,
+                disks::get_disk_performance,
+                disks::get_disks,
+                fileio::get_drive_io,
+                fileio::start_file_trace,
+                fileio::stop_file_trace,
+                fileio::get_file_activity,
+                plugins::get_plugins,
+                plugins::get_plugin_columns,
+                plugins::run_plugin_action,
+                regtrace::start_registry_trace,
+                regtrace::stop_registry_trace,
+                regtrace::get_registry_activity,
+                profiles::switch_profile,
+                alerts::add_alert_rule,
+                alerts::list_alert_rules,
+                alerts::remove_alert_rule,
+                anomaly::get_recent_anomalies,
+                rules::get_rule_log,
+                rules::get_simulated_actions,
+                rules::set_simulation_mode,
+                restarts::get_restart_loops,
+                security::get_process_privileges,
+                selfmon::get_self_metrics,
+                summary::describe_system_state,
+                usagestats::get_usage_stats,
+                usagestats::clear_usage_stats,
+                audit::get_action_log,
+            ]);
+            // Cada comando cuenta como un uso en las estadísticas locales
+            move |invoke| {
+                usagestats::record_command(invoke.message.webview().app_handle(), invoke.message.command());
+                handler(invoke)
+            }
+        })
+        .build(tauri::generate_context!())
+        .expect("error while running tauri application")
+        .run(|app, event| {
+            if let tauri::RunEvent::Exit = event {
+                stop_collectors();
+                foreground::stop(app);
+                let _ = session::write_summary(app, "exit");
+            }
+        });
 }
\ No newline at end of file
@@ -0,0 +1,123 @@
+// src-tauri/src/modules.rs
+// Módulos cargados por un proceso (DLL en Windows, bibliotecas mapeadas en
+// Linux) con su ruta, dirección base y tamaño, para revisar qué ha cargado un
+// proceso sospechoso
+
+use serde::Serialize;
+
+use crate::paging::{paginate, Page};
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ProcessModule {
+    pub name: String,
+    pub path: String,
+    pub base_address: u64,
+    pub size: u64, // Bytes de la imagen en memoria
+}
+
+// Ordenados por dirección base; el primero suele ser el propio ejecutable
+#[tauri::command]
+pub fn get_process_modules(pid: u32) -> Result<Vec<ProcessModule>, String> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::Foundation::HMODULE;
+        use windows::Win32::System::ProcessStatus::{
+            EnumProcessModulesEx, GetModuleFileNameExW, GetModuleInformation, LIST_MODULES_ALL, MODULEINFO,
+        };
+        use windows::Win32::System::Threading::{PROCESS_QUERY_INFORMATION, PROCESS_VM_READ};
+
+        let process = crate::win::open_process(pid, PROCESS_QUERY_INFORMATION | PROCESS_VM_READ)?;
+        // La lista puede crecer entre llamadas: se repite con el tamaño que pida
+        let mut handles = vec![HMODULE::default(); 256];
+        loop {
+            let capacity = (handles.len() * std::mem::size_of::<HMODULE>()) as u32;
+            let mut needed = 0u32;
+            if !unsafe { EnumProcessModulesEx(process.0, handles.as_mut_ptr(), capacity, &mut needed, LIST_MODULES_ALL) }
+                .as_bool()
+            {
+                return Err(format!(
+                    "No se pudieron enumerar los módulos del proceso {}: {}",
+                    pid,
+                    windows::core::Error::from_win32()
+                ));
+            }
+            let count = needed as usize / std::mem::size_of::<HMODULE>();
+            if needed <= capacity {
+                handles.truncate(count);
+                break;
+            }
+            handles.resize(count, HMODULE::default());
+        }
+
+        let mut modules = Vec::with_capacity(handles.len());
+        for module in handles {
+            let mut buffer = [0u16; 1024];
+            let len = unsafe { GetModuleFileNameExW(process.0, module, &mut buffer) } as usize;
+            if len == 0 {
+                continue;
+            }
+            let path = crate::win::from_wide(&buffer[..len]);
+            let mut info = MODULEINFO::default();
+            let size = std::mem::size_of::<MODULEINFO>() as u32;
+            let has_info = unsafe { GetModuleInformation(process.0, module, &mut info, size) }.as_bool();
+            modules.push(ProcessModule {
+                name: path.rsplit(['\\', '/']).next().unwrap_or(&path).to_string(),
+                base_address: if has_info { info.lpBaseOfDll as u64 } else { module.0 as u64 },
+                size: if has_info { info.SizeOfImage as u64 } else { 0 },
+                path,
+            });
+        }
+        modules.sort_by_key(|module| module.base_address);
+        Ok(modules)
+    }
+
+    // Linux: cada archivo mapeado en /proc/<pid>/maps, del primer al último tramo
+    #[cfg(target_os = "linux")]
+    {
+        use std::collections::HashMap;
+
+        let maps = std::fs::read_to_string(format!("/proc/{}/maps", pid))
+            .map_err(|e| format!("No se pudo leer /proc/{}/maps: {}", pid, e))?;
+        let mut ranges: HashMap<String, (u64, u64)> = HashMap::new();
+        for line in maps.lines() {
+            // inicio-fin permisos desplazamiento dispositivo inodo ruta
+            let mut fields = line.splitn(6, ' ');
+            let Some(range) = fields.next() else { continue };
+            let Some(path) = fields.nth(4).map(str::trim).filter(|path| path.starts_with('/')) else { continue };
+            let Some((start, end)) = range.split_once('-') else { continue };
+            let (Ok(start), Ok(end)) = (u64::from_str_radix(start, 16), u64::from_str_radix(end, 16)) else {
+                continue;
+            };
+            let entry = ranges.entry(path.to_string()).or_insert((start, end));
+            *entry = (entry.0.min(start), entry.1.max(end));
+        }
+        let mut modules: Vec<ProcessModule> = ranges
+            .into_iter()
+            .map(|(path, (start, end))| ProcessModule {
+                name: path.rsplit('/').next().unwrap_or(&path).to_string(),
+                base_address: start,
+                size: end - start,
+                path,
+            })
+            .collect();
+        modules.sort_by_key(|module| module.base_address);
+        Ok(modules)
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "linux")))]
+    {
+        let _ = pid;
+        Err("Esta función solo está disponible en Windows y Linux".to_string())
+    }
+}
+
+// Para los procesos con miles de módulos; el cursor es la dirección base
+#[tauri::command]
+pub fn get_process_modules_page(
+    pid: u32,
+    cursor: Option<String>,
+    limit: Option<usize>,
+) -> Result<Page<ProcessModule>, String> {
+    let modules = get_process_modules(pid)?;
+    paginate(&modules, |module| module.base_address, cursor.as_deref(), limit)
+}
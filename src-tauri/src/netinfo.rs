@@ -0,0 +1,162 @@
+// src-tauri/src/netinfo.rs
+// Configuración de proxy del sistema y adaptadores VPN activos, con los
+// procesos cuyas conexiones salen por ellos
+
+use serde::Serialize;
+use std::collections::BTreeSet;
+
+use crate::connections::list_connections;
+
+#[derive(Debug, Serialize, Clone, Default)]
+pub struct ProxySettings {
+    pub enabled: bool,
+    pub server: Option<String>,          // host:puerto o lista por protocolo
+    pub bypass: Option<String>,          // Excepciones (ProxyOverride)
+    pub auto_config_url: Option<String>, // Script PAC
+    pub environment: Vec<(String, String)>, // HTTP_PROXY, HTTPS_PROXY...
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct VpnAdapter {
+    pub name: String,
+    pub description: String,
+    pub addresses: Vec<String>,
+    pub pids: Vec<u32>, // Procesos con conexiones desde sus direcciones
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct NetworkInfo {
+    pub proxy: ProxySettings,
+    pub vpn_adapters: Vec<VpnAdapter>,
+}
+
+const INTERNET_SETTINGS_KEY: &str = r"Software\Microsoft\Windows\CurrentVersion\Internet Settings";
+
+// Proxy de WinINet del usuario actual (el de "Configuración de proxy") más
+// las variables de entorno que respetan muchas herramientas de línea de comandos
+fn proxy_settings() -> ProxySettings {
+    let environment = ["HTTP_PROXY", "HTTPS_PROXY", "ALL_PROXY", "NO_PROXY"]
+        .iter()
+        .filter_map(|name| std::env::var(name).ok().map(|value| (name.to_string(), value)))
+        .collect();
+
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::System::Registry::HKEY_CURRENT_USER;
+
+        let read = |value| crate::win::read_registry_string(HKEY_CURRENT_USER, INTERNET_SETTINGS_KEY, value);
+        ProxySettings {
+            enabled: crate::win::read_registry_dword(HKEY_CURRENT_USER, INTERNET_SETTINGS_KEY, "ProxyEnable")
+                .is_some_and(|enabled| enabled != 0),
+            server: read("ProxyServer").filter(|server| !server.is_empty()),
+            bypass: read("ProxyOverride").filter(|bypass| !bypass.is_empty()),
+            auto_config_url: read("AutoConfigURL").filter(|url| !url.is_empty()),
+            environment,
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = INTERNET_SETTINGS_KEY;
+        ProxySettings { environment, ..Default::default() }
+    }
+}
+
+// Adaptadores activos que parecen túneles: PPP, túnel o virtuales cuyo
+// nombre delata un cliente VPN
+#[cfg(target_os = "windows")]
+fn vpn_adapters() -> Result<Vec<VpnAdapter>, String> {
+    use windows::Win32::Foundation::{ERROR_BUFFER_OVERFLOW, NO_ERROR, WIN32_ERROR};
+    use windows::Win32::NetworkManagement::IpHelper::{
+        GetAdaptersAddresses, GAA_FLAG_SKIP_ANYCAST, GAA_FLAG_SKIP_DNS_SERVER, GAA_FLAG_SKIP_MULTICAST,
+        IP_ADAPTER_ADDRESSES_LH,
+    };
+    use windows::Win32::NetworkManagement::Ndis::IfOperStatusUp;
+
+    const IF_TYPE_PPP: u32 = 23;
+    const IF_TYPE_PROP_VIRTUAL: u32 = 53;
+    const IF_TYPE_TUNNEL: u32 = 131;
+    const VPN_KEYWORDS: [&str; 8] = ["vpn", "tap-", "tap ", "wireguard", "wintun", "openvpn", "anyconnect", "fortinet"];
+
+    let flags = GAA_FLAG_SKIP_ANYCAST | GAA_FLAG_SKIP_MULTICAST | GAA_FLAG_SKIP_DNS_SERVER;
+    let mut size = 16 * 1024u32;
+    let mut buffer: Vec<u64> = Vec::new();
+    // AF_UNSPEC: IPv4 e IPv6; el tamaño necesario puede cambiar entre llamadas
+    for _ in 0..4 {
+        buffer = vec![0u64; (size as usize).div_ceil(8)];
+        let status = unsafe {
+            GetAdaptersAddresses(0, flags, None, Some(buffer.as_mut_ptr() as *mut IP_ADAPTER_ADDRESSES_LH), &mut size)
+        };
+        if status == NO_ERROR.0 {
+            break;
+        }
+        if status != ERROR_BUFFER_OVERFLOW.0 {
+            let error = windows::core::Error::from(WIN32_ERROR(status).to_hresult());
+            return Err(format!("No se pudieron leer los adaptadores de red: {}", error));
+        }
+    }
+
+    let mut adapters = Vec::new();
+    let mut current = buffer.as_ptr() as *const IP_ADAPTER_ADDRESSES_LH;
+    while let Some(adapter) = unsafe { current.as_ref() } {
+        current = adapter.Next;
+        if adapter.OperStatus != IfOperStatusUp {
+            continue;
+        }
+        let name = unsafe { adapter.FriendlyName.to_string() }.unwrap_or_default();
+        let description = unsafe { adapter.Description.to_string() }.unwrap_or_default();
+        let lower = format!("{} {}", name, description).to_lowercase();
+        let is_vpn = matches!(adapter.IfType, IF_TYPE_PPP | IF_TYPE_TUNNEL)
+            || VPN_KEYWORDS.iter().any(|keyword| lower.contains(keyword))
+            || (adapter.IfType == IF_TYPE_PROP_VIRTUAL && lower.contains("tun"));
+        if !is_vpn {
+            continue;
+        }
+
+        // Direcciones unicast leídas del SOCKADDR en bruto (familia en los dos primeros bytes)
+        let mut addresses = Vec::new();
+        let mut unicast = adapter.FirstUnicastAddress;
+        while let Some(entry) = unsafe { unicast.as_ref() } {
+            unicast = entry.Next;
+            let sockaddr = entry.Address;
+            if sockaddr.lpSockaddr.is_null() {
+                continue;
+            }
+            let bytes = unsafe {
+                std::slice::from_raw_parts(sockaddr.lpSockaddr as *const u8, sockaddr.iSockaddrLength as usize)
+            };
+            let address = match u16::from_le_bytes([bytes[0], bytes[1]]) {
+                2 if bytes.len() >= 8 => <[u8; 4]>::try_from(&bytes[4..8]).ok().map(std::net::IpAddr::from),
+                23 if bytes.len() >= 24 => <[u8; 16]>::try_from(&bytes[8..24]).ok().map(std::net::IpAddr::from),
+                _ => None,
+            };
+            addresses.extend(address.map(|address| address.to_string()));
+        }
+        adapters.push(VpnAdapter { name, description, addresses, pids: Vec::new() });
+    }
+    Ok(adapters)
+}
+
+#[tauri::command]
+pub fn get_network_info() -> Result<NetworkInfo, String> {
+    #[cfg(target_os = "windows")]
+    let mut vpn_adapters = vpn_adapters()?;
+
+    #[cfg(not(target_os = "windows"))]
+    let mut vpn_adapters: Vec<VpnAdapter> = Vec::new();
+
+    if !vpn_adapters.is_empty() {
+        // Una conexión sale por el túnel si su dirección local es la del adaptador
+        let connections = list_connections().unwrap_or_default();
+        for adapter in &mut vpn_adapters {
+            let pids: BTreeSet<u32> = connections
+                .iter()
+                .filter(|connection| adapter.addresses.contains(&connection.local_address))
+                .map(|connection| connection.pid)
+                .collect();
+            adapter.pids = pids.into_iter().collect();
+        }
+    }
+
+    Ok(NetworkInfo { proxy: proxy_settings(), vpn_adapters })
+}
@@ -0,0 +1,115 @@
+// src-tauri/src/overlays.rs
+// Superposiciones (Discord, RTSS, GeForce Experience, Steam...) cargadas
+// dentro de otros procesos. Se inyectan en los juegos para dibujar encima y
+// son de las primeras sospechosas cuando un juego se cierra sin motivo
+
+use serde::Serialize;
+use tauri::State;
+
+use crate::AppState;
+
+// (nombre del módulo en minúsculas, producto)
+const OVERLAY_MODULES: &[(&str, &str)] = &[
+    ("discordhook64.dll", "Discord"),
+    ("discordhook.dll", "Discord"),
+    ("rtsshooks64.dll", "RivaTuner Statistics Server"),
+    ("rtsshooks.dll", "RivaTuner Statistics Server"),
+    ("nvspcap64.dll", "NVIDIA GeForce Experience"),
+    ("nvspcap.dll", "NVIDIA GeForce Experience"),
+    ("gameoverlayrenderer64.dll", "Steam"),
+    ("gameoverlayrenderer.dll", "Steam"),
+    ("gameoverlayrenderer.so", "Steam"),
+    ("graphics-hook64.dll", "OBS Studio"),
+    ("graphics-hook32.dll", "OBS Studio"),
+    ("eosovh-win64-shipping.dll", "Epic Games"),
+    ("eosovh-win32-shipping.dll", "Epic Games"),
+    ("libmangohud.so", "MangoHud"),
+    ("libvkbasalt.so", "vkBasalt"),
+];
+
+// Bibliotecas gráficas que delatan que el proceso dibuja con la GPU
+const GRAPHICS_MODULES: &[(&str, &str)] = &[
+    ("d3d9.dll", "Direct3D 9"),
+    ("d3d11.dll", "Direct3D 11"),
+    ("d3d12.dll", "Direct3D 12"),
+    ("vulkan-1.dll", "Vulkan"),
+    ("opengl32.dll", "OpenGL"),
+    ("libvulkan.so.1", "Vulkan"),
+    ("libgl.so.1", "OpenGL"),
+];
+
+// Nombres de DLL del sistema que ReShade y similares suplantan desde la
+// carpeta del juego para cargarse antes que la original
+#[cfg(target_os = "windows")]
+const PROXY_MODULES: &[&str] = &["dxgi.dll", "d3d9.dll", "d3d11.dll", "opengl32.dll", "dinput8.dll"];
+
+#[derive(Debug, Serialize, Clone)]
+pub struct OverlayModule {
+    pub product: String,
+    pub name: String,
+    pub path: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ProcessOverlays {
+    pub pid: u32,
+    pub name: String,
+    pub graphics_apis: Vec<&'static str>, // Vacío si no parece un juego
+    pub overlays: Vec<OverlayModule>,
+}
+
+fn proxy_product(file: &str, path: &str) -> Option<&'static str> {
+    #[cfg(target_os = "windows")]
+    {
+        let windows_dir = std::env::var("SystemRoot").unwrap_or_else(|_| r"C:\Windows".to_string()).to_lowercase();
+        (PROXY_MODULES.contains(&file) && !path.to_lowercase().starts_with(&windows_dir))
+            .then_some("Sustituto de DLL del sistema (ReShade u otro inyector)")
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = (file, path);
+        None
+    }
+}
+
+fn scan(pid: u32, name: String) -> Option<ProcessOverlays> {
+    let modules = crate::modules::get_process_modules(pid).ok()?;
+    let mut graphics_apis = Vec::new();
+    let mut overlays = Vec::new();
+    for module in modules {
+        let file = module.name.to_lowercase();
+        if let Some((_, api)) = GRAPHICS_MODULES.iter().find(|(graphics, _)| *graphics == file) {
+            if !graphics_apis.contains(api) {
+                graphics_apis.push(*api);
+            }
+        }
+        let product = OVERLAY_MODULES
+            .iter()
+            .find(|(overlay, _)| *overlay == file)
+            .map(|(_, product)| *product)
+            .or_else(|| proxy_product(&file, &module.path));
+        if let Some(product) = product {
+            overlays.push(OverlayModule { product: product.to_string(), name: module.name, path: module.path });
+        }
+    }
+    (!overlays.is_empty()).then_some(ProcessOverlays { pid, name, graphics_apis, overlays })
+}
+
+// Solo los procesos con alguna superposición; primero los que parecen juegos.
+// Los procesos protegidos o de otros usuarios sin privilegios se omiten
+#[tauri::command]
+pub fn get_overlays(state: State<AppState>) -> Vec<ProcessOverlays> {
+    let processes: Vec<(u32, String)> = state
+        .processes
+        .read()
+        .unwrap()
+        .processes
+        .iter()
+        .filter(|process| process.pid != std::process::id())
+        .map(|process| (process.pid, process.name.clone()))
+        .collect();
+    let mut found: Vec<ProcessOverlays> = processes.into_iter().filter_map(|(pid, name)| scan(pid, name)).collect();
+    found.sort_by(|a, b| a.graphics_apis.is_empty().cmp(&b.graphics_apis.is_empty()).then_with(|| a.name.cmp(&b.name)));
+    found
+}
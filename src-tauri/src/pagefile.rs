@@ -0,0 +1,112 @@
+// src-tauri/src/pagefile.rs
+// Configuración de los archivos de paginación (ubicación, tamaño, uso y si los
+// administra el sistema) leída de WMI, junto con la carga confirmada frente al
+// límite para completar el diagnóstico de falta de memoria
+
+use serde::Serialize;
+
+// A partir de este porcentaje del límite de confirmación se avisa
+#[cfg(target_os = "windows")]
+const COMMIT_WARNING_PERCENT: f64 = 90.0;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct PageFile {
+    pub path: String,
+    pub allocated_size: u64, // Bytes; WMI los da en MB
+    pub current_usage: u64,
+    pub peak_usage: u64,
+    pub initial_size: Option<u64>, // None si lo administra el sistema
+    pub maximum_size: Option<u64>,
+    pub system_managed: bool,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct PageFileReport {
+    pub automatic: bool, // "Administrar automáticamente el tamaño" para todas las unidades
+    pub files: Vec<PageFile>,
+    pub commit_total: u64, // Bytes
+    pub commit_limit: u64,
+    pub commit_peak: u64,
+    pub commit_percent: f64,
+    pub warning: Option<String>, // Presente si la carga confirmada se acerca al límite
+}
+
+#[cfg(target_os = "windows")]
+fn commit_charge() -> Result<(u64, u64, u64), String> {
+    use windows::Win32::System::ProcessStatus::{GetPerformanceInfo, PERFORMANCE_INFORMATION};
+
+    let mut info = PERFORMANCE_INFORMATION::default();
+    let size = std::mem::size_of::<PERFORMANCE_INFORMATION>() as u32;
+    if !unsafe { GetPerformanceInfo(&mut info, size) }.as_bool() {
+        return Err(format!("No se pudo leer la carga confirmada: {}", windows::core::Error::from_win32()));
+    }
+    let page = info.PageSize as u64;
+    Ok((info.CommitTotal as u64 * page, info.CommitLimit as u64 * page, info.CommitPeak as u64 * page))
+}
+
+#[tauri::command]
+pub fn get_page_files() -> Result<PageFileReport, String> {
+    #[cfg(target_os = "windows")]
+    {
+        use crate::wmi::{query, WmiValue};
+        use std::collections::HashMap;
+
+        const MB: u64 = 1024 * 1024;
+        let megabytes = |value: &WmiValue| value.as_u64().unwrap_or(0) * MB;
+
+        let automatic = query("SELECT AutomaticManagedPagefile FROM Win32_ComputerSystem", &["AutomaticManagedPagefile"])?
+            .into_iter()
+            .next()
+            .and_then(|row| row[0].as_bool())
+            .unwrap_or(false);
+        // Sin administración automática, cada archivo configurado tiene su
+        // entrada; tamaños 0/0 indican que ese archivo lo administra el sistema
+        let settings: HashMap<String, (u64, u64)> = query(
+            "SELECT Name, InitialSize, MaximumSize FROM Win32_PageFileSetting",
+            &["Name", "InitialSize", "MaximumSize"],
+        )?
+        .into_iter()
+        .filter_map(|mut row| {
+            let sizes = (megabytes(&row[1]), megabytes(&row[2]));
+            Some((row.swap_remove(0).into_text()?.to_lowercase(), sizes))
+        })
+        .collect();
+
+        let files = query(
+            "SELECT Name, AllocatedBaseSize, CurrentUsage, PeakUsage FROM Win32_PageFileUsage",
+            &["Name", "AllocatedBaseSize", "CurrentUsage", "PeakUsage"],
+        )?
+        .into_iter()
+        .filter_map(|mut row| {
+            let (allocated_size, current_usage) = (megabytes(&row[1]), megabytes(&row[2]));
+            let peak_usage = megabytes(&row[3]);
+            let path = row.swap_remove(0).into_text()?;
+            let configured = settings.get(&path.to_lowercase()).filter(|sizes| **sizes != (0, 0));
+            Some(PageFile {
+                allocated_size,
+                current_usage,
+                peak_usage,
+                initial_size: configured.filter(|_| !automatic).map(|sizes| sizes.0),
+                maximum_size: configured.filter(|_| !automatic).map(|sizes| sizes.1),
+                system_managed: automatic || configured.is_none(),
+                path,
+            })
+        })
+        .collect();
+
+        let (commit_total, commit_limit, commit_peak) = commit_charge()?;
+        let commit_percent = if commit_limit > 0 { commit_total as f64 / commit_limit as f64 * 100.0 } else { 0.0 };
+        let warning = (commit_percent >= COMMIT_WARNING_PERCENT).then(|| {
+            format!(
+                "Carga confirmada al {:.0} % del límite; al alcanzarlo no se podrá reservar más memoria",
+                commit_percent
+            )
+        });
+        Ok(PageFileReport { automatic, files, commit_total, commit_limit, commit_peak, commit_percent, warning })
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
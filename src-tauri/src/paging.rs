@@ -0,0 +1,64 @@
+// src-tauri/src/paging.rs
+// Paginación por cursor para las respuestas que pueden ser enormes (registro de
+// auditoría, historiales, módulos), de modo que ninguna llamada IPC serialice
+// decenas de megabytes. El cursor apunta a la clave de orden del último
+// elemento devuelto: la página siguiente no se desplaza aunque se descarten
+// elementos antiguos entre una llamada y otra
+
+use serde::Serialize;
+
+const DEFAULT_LIMIT: usize = 500;
+const MAX_LIMIT: usize = 5000;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>, // None en la última página
+    pub total: usize,                // Elementos en total, en todas las páginas
+}
+
+// "clave:repeticiones": cuántos elementos con esa misma clave ya se devolvieron.
+// El flujo de cambios usa el mismo formato con "sesión:secuencia"
+pub fn parse_cursor(cursor: &str) -> Result<(u64, usize), String> {
+    let invalid = || format!("Cursor de paginación no válido: {}", cursor);
+    let (key, ties) = cursor.split_once(':').ok_or_else(invalid)?;
+    Ok((key.parse().map_err(|_| invalid())?, ties.parse().map_err(|_| invalid())?))
+}
+
+pub fn clamp_limit(limit: Option<usize>) -> usize {
+    limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT)
+}
+
+// `items` debe venir ordenado de menor a mayor `key`; las claves repetidas
+// (varias entradas en el mismo milisegundo) no se pierden entre páginas
+pub fn paginate<'a, T: Clone + 'a>(
+    items: impl IntoIterator<Item = &'a T>,
+    key: impl Fn(&T) -> u64,
+    cursor: Option<&str>,
+    limit: Option<usize>,
+) -> Result<Page<T>, String> {
+    let after = cursor.filter(|cursor| !cursor.is_empty()).map(parse_cursor).transpose()?;
+    let limit = clamp_limit(limit);
+
+    let mut page = Vec::new();
+    let mut total = 0;
+    let mut more = false;
+    let mut run: (Option<u64>, usize) = (None, 0); // Clave actual y cuántas van con ella
+    let mut last = (0, 0);
+    for item in items {
+        total += 1;
+        let item_key = key(item);
+        run = if run.0 == Some(item_key) { (run.0, run.1 + 1) } else { (Some(item_key), 1) };
+        let already_sent = after.is_some_and(|(key, ties)| item_key < key || (item_key == key && run.1 <= ties));
+        if already_sent || more {
+            continue;
+        }
+        if page.len() == limit {
+            more = true;
+            continue;
+        }
+        page.push(item.clone());
+        last = (item_key, run.1);
+    }
+    Ok(Page { items: page, next_cursor: more.then(|| format!("{}:{}", last.0, last.1)), total })
+}
@@ -0,0 +1,238 @@
+// src-tauri/src/palette.rs
+// Registro de acciones para la paleta de comandos (Ctrl+K): cada acción que
+// la interfaz puede ofrecer, con el comando que la ejecuta, palabras clave
+// para buscarla y si está disponible en este equipo. Con un PID se indica
+// además si tiene sentido para ese proceso (reanudar solo uno suspendido...).
+// Las acciones de los plugins cargados se añaden al final, así que la paleta
+// sigue al backend sin mantener una lista propia
+
+use serde::Serialize;
+use tauri::State;
+
+use crate::platform::PlatformCapabilities;
+use crate::text::{normalize, SearchNormalization};
+use crate::{AppState, ProcessInfo};
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ActionScope {
+    Global,
+    Process, // El comando recibe `pid`
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Requires {
+    Nothing,
+    Windows,
+    Affinity,
+    Services,
+    Etw,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum AppliesTo {
+    Any,
+    Others,    // Ni el propio administrador ni el proceso inactivo del sistema
+    Running,   // No suspendido
+    Suspended,
+    Parent,    // Con procesos hijos
+}
+
+type Keywords = &'static [&'static str];
+
+struct Definition {
+    id: &'static str, // Comando que se invoca
+    title: &'static str,
+    category: &'static str,
+    keywords: Keywords,
+    scope: ActionScope,
+    destructive: bool,
+    requires: Requires,
+    applies_to: AppliesTo,
+}
+
+const fn global(id: &'static str, title: &'static str, category: &'static str, keywords: Keywords) -> Definition {
+    Definition {
+        id,
+        title,
+        category,
+        keywords,
+        scope: ActionScope::Global,
+        destructive: false,
+        requires: Requires::Nothing,
+        applies_to: AppliesTo::Any,
+    }
+}
+
+const fn process(id: &'static str, title: &'static str, category: &'static str, keywords: Keywords) -> Definition {
+    Definition { scope: ActionScope::Process, applies_to: AppliesTo::Others, ..global(id, title, category, keywords) }
+}
+
+const fn destructive(definition: Definition) -> Definition {
+    Definition { destructive: true, ..definition }
+}
+
+const fn requires(requires: Requires, definition: Definition) -> Definition {
+    Definition { requires, ..definition }
+}
+
+const fn applies_to(applies_to: AppliesTo, definition: Definition) -> Definition {
+    Definition { applies_to, ..definition }
+}
+
+const ACTIONS: &[Definition] = &[
+    destructive(process("kill_process", "Terminar proceso", "Proceso", &["matar", "cerrar", "kill", "finalizar"])),
+    destructive(applies_to(
+        AppliesTo::Parent,
+        process("kill_process_tree", "Terminar árbol de procesos", "Proceso", &["matar", "hijos", "kill"]),
+    )),
+    destructive(process("restart_process_with", "Reiniciar con otros argumentos", "Proceso", &["relanzar", "restart"])),
+    applies_to(AppliesTo::Running, process("suspend_process", "Suspender", "Proceso", &["pausar", "congelar"])),
+    applies_to(AppliesTo::Suspended, process("resume_process", "Reanudar", "Proceso", &["continuar", "resume"])),
+    process("set_process_priority", "Cambiar prioridad", "Proceso", &["prioridad", "priority", "nice"]),
+    requires(Requires::Affinity, process("set_process_affinity", "Cambiar afinidad", "Proceso", &["núcleos", "cpu"])),
+    requires(Requires::Windows, process("set_efficiency_mode", "Modo de eficiencia", "Proceso", &["ecoqos"])),
+    requires(Requires::Windows, process("set_gpu_priority", "Cambiar prioridad de GPU", "Proceso", &["gpu"])),
+    applies_to(AppliesTo::Any, process("get_process_details", "Ver detalles", "Proceso", &["propiedades", "info"])),
+    applies_to(AppliesTo::Any, process("get_process_modules", "Ver módulos cargados", "Proceso", &["dll"])),
+    process("verify_process_signature", "Verificar firma digital", "Proceso", &["certificado"]),
+    process("get_dependency_hints", "Ver qué depende de este proceso", "Proceso", &["dependencias", "servicios"]),
+    requires(Requires::Windows, process("list_process_windows", "Ver ventanas", "Proceso", &["ventana", "window"])),
+    requires(Requires::Windows, process("get_process_privileges", "Ver privilegios", "Proceso", &["token"])),
+    requires(Requires::Services, process("get_services_for_process", "Ver servicios", "Proceso", &["servicio"])),
+    requires(Requires::Windows, process("create_process_dump", "Crear volcado de memoria", "Diagnóstico", &["dump"])),
+    process("debug_process", "Depurar", "Diagnóstico", &["debugger", "depurador"]),
+    process("watch_process", "Avisar si supera un umbral", "Vigilancia", &["alerta", "umbral"]),
+    requires(Requires::Windows, process("notify_on_exit", "Avisar cuando termine", "Vigilancia", &["fin", "salida"])),
+    global("relaunch_last_killed", "Relanzar el último proceso terminado", "Proceso", &["deshacer", "undo"]),
+    global("pause_updates", "Pausar actualizaciones", "Vista", &["congelar", "refresco"]),
+    global("cycle_watchlist", "Cambiar de lista de vigilancia", "Vista", &["filtro", "watchlist"]),
+    global("switch_profile", "Cambiar de perfil", "Vista", &["perfil", "profile"]),
+    global("capture_baseline", "Capturar línea base", "Diagnóstico", &["baseline", "comparar"]),
+    global("start_calibration", "Recalibrar el uso habitual", "Diagnóstico", &["calibración", "anomalías"]),
+    global("start_recording", "Grabar sesión", "Diagnóstico", &["grabar", "replay"]),
+    global("generate_report", "Generar informe", "Diagnóstico", &["informe", "report"]),
+    global("create_support_bundle", "Crear paquete de diagnóstico", "Diagnóstico", &["soporte", "zip", "bundle"]),
+    global("export_snapshot", "Exportar instantánea", "Diagnóstico", &["csv", "json", "exportar"]),
+    global("start_stress", "Generar carga de prueba", "Diagnóstico", &["stress", "carga"]),
+    requires(Requires::Etw, global("start_file_trace", "Rastrear actividad de archivos", "Diagnóstico", &["etw"])),
+    requires(Requires::Etw, global("start_registry_trace", "Rastrear el registro", "Diagnóstico", &["etw"])),
+    global("ack_all_alerts", "Marcar todas las alertas como vistas", "Alertas", &["confirmar", "leídas"]),
+    global("set_quiet_hours", "Configurar horas de silencio", "Alertas", &["no molestar", "silencio"]),
+    global("estimate_cleanup", "Liberar espacio en disco", "Sistema", &["limpieza", "temporales"]),
+    destructive(requires(
+        Requires::Windows,
+        global("empty_recycle_bin", "Vaciar la papelera", "Sistema", &["papelera", "recycle"]),
+    )),
+    requires(Requires::Services, global("list_services", "Ver servicios", "Sistema", &["servicios"])),
+    global("get_startup_items", "Ver programas de inicio", "Sistema", &["inicio", "startup", "autorun"]),
+    requires(Requires::Windows, global("get_user_sessions", "Ver sesiones de usuario", "Sistema", &["usuarios"])),
+    requires(Requires::Windows, global("get_security_status", "Ver estado de seguridad", "Sistema", &["antivirus"])),
+    global("get_connections", "Ver conexiones de red", "Red", &["tcp", "udp", "puertos"]),
+    global("ping_host", "Hacer ping", "Red", &["ping", "latencia"]),
+    global("check_for_updates", "Buscar actualizaciones", "Aplicación", &["update", "versión"]),
+    global("export_config", "Exportar configuración", "Aplicación", &["ajustes", "backup"]),
+];
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ActionInfo {
+    pub id: String,      // Único; para los plugins "plugin:<plugin>:<acción>"
+    pub command: String, // Comando IPC que la ejecuta
+    pub plugin: Option<String>,
+    pub title: String,
+    pub category: String,
+    pub keywords: Vec<String>,
+    pub scope: ActionScope,
+    pub destructive: bool, // La interfaz debe pedir confirmación
+    pub available: bool,
+    pub unavailable_reason: Option<String>,
+    pub applicable: Option<bool>, // Solo con `pid` y en acciones de proceso
+}
+
+fn unavailable_reason(requires: Requires, platform: &PlatformCapabilities) -> Option<String> {
+    let available = match requires {
+        Requires::Nothing => true,
+        Requires::Windows => cfg!(target_os = "windows"),
+        Requires::Affinity => platform.affinity,
+        Requires::Services => platform.services,
+        Requires::Etw => platform.etw,
+    };
+    (!available).then(|| "No está disponible en este sistema".to_string())
+}
+
+fn applies(applies_to: AppliesTo, process: &ProcessInfo) -> bool {
+    let other = process.pid != 0 && process.pid != std::process::id();
+    match applies_to {
+        AppliesTo::Any => true,
+        AppliesTo::Others => other,
+        AppliesTo::Running => other && !process.suspended,
+        AppliesTo::Suspended => process.suspended,
+        AppliesTo::Parent => other && process.has_children,
+    }
+}
+
+fn matches(action: &ActionInfo, terms: &[String]) -> bool {
+    let normalization = SearchNormalization { fold_diacritics: true, fold_width: true };
+    let haystack = normalize(
+        &format!("{} {} {} {}", action.title, action.category, action.id, action.keywords.join(" ")),
+        normalization,
+    );
+    terms.iter().all(|term| haystack.contains(&normalize(term, normalization)))
+}
+
+// `query` filtra por título, categoría y palabras clave (todas las palabras,
+// sin distinguir acentos); con `pid`, `applicable` dice si vale para ese proceso
+#[tauri::command]
+pub fn list_actions(state: State<AppState>, query: Option<String>, pid: Option<u32>) -> Vec<ActionInfo> {
+    let platform = crate::platform::get_platform_capabilities();
+    let target = pid.and_then(|pid| {
+        state.processes.read().unwrap().processes.iter().find(|process| process.pid == pid).cloned()
+    });
+
+    let mut actions: Vec<ActionInfo> = ACTIONS
+        .iter()
+        .map(|definition| {
+            let unavailable_reason = unavailable_reason(definition.requires, &platform);
+            let applicable = pid.filter(|_| definition.scope == ActionScope::Process).map(|_| {
+                unavailable_reason.is_none()
+                    && target.as_ref().is_some_and(|process| applies(definition.applies_to, process))
+            });
+            ActionInfo {
+                id: definition.id.to_string(),
+                command: definition.id.to_string(),
+                plugin: None,
+                title: definition.title.to_string(),
+                category: definition.category.to_string(),
+                keywords: definition.keywords.iter().map(|keyword| keyword.to_string()).collect(),
+                scope: definition.scope,
+                destructive: definition.destructive,
+                available: unavailable_reason.is_none(),
+                unavailable_reason,
+                applicable,
+            }
+        })
+        .collect();
+
+    // Las acciones de plugin siempre reciben el PID del proceso seleccionado
+    for manifest in crate::plugins::get_plugins(state.clone()).plugins {
+        for action in manifest.actions {
+            actions.push(ActionInfo {
+                id: format!("plugin:{}:{}", manifest.name, action.id),
+                command: "run_plugin_action".to_string(),
+                plugin: Some(manifest.name.clone()),
+                title: action.title,
+                category: manifest.name.clone(),
+                keywords: vec![action.id],
+                scope: ActionScope::Process,
+                destructive: false,
+                available: true,
+                unavailable_reason: None,
+                applicable: pid.map(|_| target.is_some()),
+            });
+        }
+    }
+
+    let terms: Vec<String> = query.as_deref().unwrap_or_default().split_whitespace().map(str::to_string).collect();
+    actions.retain(|action| matches(action, &terms));
+    actions
+}
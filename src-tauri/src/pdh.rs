@@ -0,0 +1,81 @@
+// src-tauri/src/pdh.rs
+// Envoltorio mínimo de los contadores de rendimiento (PDH)
+
+use windows::Win32::System::Performance::{
+    PdhAddEnglishCounterW, PdhCloseQuery, PdhCollectQueryData, PdhGetFormattedCounterArrayW,
+    PdhOpenQueryW, PDH_FMT_COUNTERVALUE_ITEM_W, PDH_FMT_DOUBLE, PDH_MORE_DATA,
+};
+
+pub struct PdhQuery {
+    query: isize,
+    counters: Vec<isize>,
+}
+
+impl PdhQuery {
+    pub fn open() -> Result<Self, String> {
+        let mut query = 0isize;
+        let status = unsafe { PdhOpenQueryW(None, 0, &mut query) };
+        if status != 0 {
+            return Err(format!("No se pudo abrir la consulta PDH (0x{:08X})", status));
+        }
+        Ok(PdhQuery { query, counters: Vec::new() })
+    }
+
+    // Añade un contador por su ruta en inglés (independiente del idioma del sistema)
+    // y devuelve su índice
+    pub fn add_counter(&mut self, path: &str) -> Result<usize, String> {
+        let wide = crate::win::to_wide(path);
+        let mut counter = 0isize;
+        let status = unsafe {
+            PdhAddEnglishCounterW(self.query, windows::core::PCWSTR(wide.as_ptr()), 0, &mut counter)
+        };
+        if status != 0 {
+            return Err(format!("No se pudo añadir el contador {} (0x{:08X})", path, status));
+        }
+        self.counters.push(counter);
+        Ok(self.counters.len() - 1)
+    }
+
+    pub fn collect(&self) -> Result<(), String> {
+        let status = unsafe { PdhCollectQueryData(self.query) };
+        if status != 0 {
+            return Err(format!("Error al recoger los contadores PDH (0x{:08X})", status));
+        }
+        Ok(())
+    }
+
+    // Valores por instancia de un contador con comodín, p. ej. `\PhysicalDisk(*)\...`
+    pub fn values(&self, index: usize) -> Result<Vec<(String, f64)>, String> {
+        let counter = self.counters[index];
+        let mut size = 0u32;
+        let mut count = 0u32;
+        let status = unsafe { PdhGetFormattedCounterArrayW(counter, PDH_FMT_DOUBLE, &mut size, &mut count, None) };
+        if status != PDH_MORE_DATA {
+            return if status == 0 { Ok(Vec::new()) } else { Err(format!("Error al leer el contador (0x{:08X})", status)) };
+        }
+
+        let item_size = std::mem::size_of::<PDH_FMT_COUNTERVALUE_ITEM_W>();
+        let mut buffer = vec![PDH_FMT_COUNTERVALUE_ITEM_W::default(); (size as usize).div_ceil(item_size)];
+        let status = unsafe {
+            PdhGetFormattedCounterArrayW(counter, PDH_FMT_DOUBLE, &mut size, &mut count, Some(buffer.as_mut_ptr()))
+        };
+        if status != 0 {
+            return Err(format!("Error al leer el contador (0x{:08X})", status));
+        }
+
+        Ok(buffer[..count as usize]
+            .iter()
+            .filter(|item| item.FmtValue.CStatus == 0)
+            .map(|item| {
+                let name = unsafe { item.szName.to_string() }.unwrap_or_default();
+                (name, unsafe { item.FmtValue.Anonymous.doubleValue })
+            })
+            .collect())
+    }
+}
+
+impl Drop for PdhQuery {
+    fn drop(&mut self) {
+        unsafe { PdhCloseQuery(self.query) };
+    }
+}
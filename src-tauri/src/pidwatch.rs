@@ -0,0 +1,170 @@
+// src-tauri/src/pidwatch.rs
+// Vigilancia de un proceso concreto (por PID, a diferencia de las alertas por
+// ejecutable): en cuanto una métrica supera el umbral en un refresco se emite
+// "process-watch-triggered", sin tiempo mínimo. Se rearma al bajar del umbral
+// y se retira sola cuando el proceso termina ("process-watch-ended", con el
+// código de salida leído del handle que se abre al crear la vigilancia)
+
+use serde::Serialize;
+use tauri::State;
+
+use crate::alerts::AlertMetric;
+use crate::{AppState, ProcessInfo};
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ProcessWatch {
+    pub id: u64,
+    pub pid: u32,
+    pub name: String, // Si el PID pasa a otro ejecutable, el original terminó
+    pub metric: AlertMetric,
+    pub threshold: f64,
+    pub armed: bool, // false tras dispararse, hasta que el valor baje del umbral
+    pub trigger_count: u32,
+    pub last_triggered: Option<u64>, // Marca de tiempo en ms
+    pub last_value: Option<f64>,     // En el último refresco
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct WatchTriggered {
+    pub watch_id: u64,
+    pub pid: u32,
+    pub name: String,
+    pub metric: AlertMetric,
+    pub value: f64,
+    pub threshold: f64,
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct WatchEnded {
+    pub watch_id: u64,
+    pub pid: u32,
+    pub name: String,
+    pub trigger_count: u32,
+    pub exit_code: Option<u32>, // None sin acceso al proceso o fuera de Windows
+}
+
+#[derive(Debug, Default)]
+pub struct ProcessWatches {
+    next_id: u64,
+    watches: Vec<ProcessWatch>,
+    #[cfg(target_os = "windows")]
+    handles: std::collections::HashMap<u64, crate::win::OwnedHandle>, // Para leer el código de salida
+}
+
+impl ProcessWatches {
+    #[cfg(target_os = "windows")]
+    fn exit_code(&mut self, id: u64) -> Option<u32> {
+        self.handles.remove(&id).and_then(|process| crate::win::exit_code(&process))
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    fn exit_code(&mut self, _id: u64) -> Option<u32> {
+        None
+    }
+
+    // Disparos de este refresco y vigilancias retiradas por fin del proceso
+    pub fn evaluate(&mut self, processes: &[ProcessInfo]) -> (Vec<WatchTriggered>, Vec<WatchEnded>) {
+        let mut triggered = Vec::new();
+        let mut ended = Vec::new();
+        self.watches.retain_mut(|watch| {
+            let Some(process) = processes.iter().find(|process| process.pid == watch.pid && process.name == watch.name)
+            else {
+                ended.push(WatchEnded {
+                    watch_id: watch.id,
+                    pid: watch.pid,
+                    name: watch.name.clone(),
+                    trigger_count: watch.trigger_count,
+                    exit_code: None,
+                });
+                return false;
+            };
+            let value = crate::alerts::metric(process, watch.metric);
+            watch.last_value = Some(value);
+            if value < watch.threshold {
+                watch.armed = true;
+            } else if watch.armed {
+                let timestamp = crate::stats::now_millis();
+                watch.armed = false;
+                watch.trigger_count += 1;
+                watch.last_triggered = Some(timestamp);
+                triggered.push(WatchTriggered {
+                    watch_id: watch.id,
+                    pid: watch.pid,
+                    name: watch.name.clone(),
+                    metric: watch.metric,
+                    value,
+                    threshold: watch.threshold,
+                    timestamp,
+                });
+            }
+            true
+        });
+        for event in &mut ended {
+            event.exit_code = self.exit_code(event.watch_id);
+        }
+        (triggered, ended)
+    }
+}
+
+#[tauri::command]
+pub fn watch_process(
+    state: State<AppState>,
+    pid: u32,
+    metric: AlertMetric,
+    threshold: f64,
+) -> Result<ProcessWatch, String> {
+    if !threshold.is_finite() || threshold < 0.0 {
+        return Err("El umbral debe ser un número positivo".to_string());
+    }
+    let name = state
+        .processes
+        .read()
+        .unwrap()
+        .processes
+        .iter()
+        .find(|process| process.pid == pid)
+        .map(|process| process.name.clone())
+        .ok_or_else(|| format!("El proceso {} ya no está en ejecución", pid))?;
+    let mut watches = state.process_watches.lock().unwrap();
+    watches.next_id += 1;
+    let watch = ProcessWatch {
+        id: watches.next_id,
+        pid,
+        name,
+        metric,
+        threshold,
+        armed: true,
+        trigger_count: 0,
+        last_triggered: None,
+        last_value: None,
+    };
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::System::Threading::PROCESS_QUERY_LIMITED_INFORMATION;
+
+        if let Ok(process) = crate::win::open_process(pid, PROCESS_QUERY_LIMITED_INFORMATION) {
+            watches.handles.insert(watch.id, process);
+        }
+    }
+    watches.watches.push(watch.clone());
+    Ok(watch)
+}
+
+#[tauri::command]
+pub fn list_process_watches(state: State<AppState>) -> Vec<ProcessWatch> {
+    state.process_watches.lock().unwrap().watches.clone()
+}
+
+#[tauri::command]
+pub fn unwatch_process(state: State<AppState>, id: u64) -> Result<(), String> {
+    let mut watches = state.process_watches.lock().unwrap();
+    let count = watches.watches.len();
+    watches.watches.retain(|watch| watch.id != id);
+    #[cfg(target_os = "windows")]
+    watches.handles.remove(&id);
+    if watches.watches.len() == count {
+        return Err(format!("No existe la vigilancia {}", id));
+    }
+    Ok(())
+}
@@ -0,0 +1,146 @@
+// src-tauri/src/platform.rs
+// Funciones disponibles en el sistema actual, para que la UI oculte las que
+// solo existen en Windows en lugar de mostrar errores, y estado en este equipo
+// de los subsistemas que dependen de permisos o controladores (columnas que
+// de otro modo mostrarían ceros)
+
+use serde::Serialize;
+use tauri::State;
+
+use crate::arch::{self, Architecture};
+use crate::health::DegradedCollector;
+use crate::AppState;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct PlatformCapabilities {
+    pub os: String, // windows, linux, macos
+    pub cpu_architecture: Architecture, // La del sistema operativo
+    pub emulated: bool,                 // El administrador se ejecuta emulado (p. ej. x64 en ARM64)
+    pub kill_process: bool,
+    pub suspend_process: bool,
+    pub priority: bool, // Clase de prioridad (nice en Unix)
+    pub efficiency_mode: bool, // EcoQoS y prioridad de GPU
+    pub affinity: bool,
+    pub services: bool,
+    pub network_connections: bool, // Tablas TCP/UDP por proceso
+    pub etw: bool,                 // Red, disco y registro por proceso (requiere administrador)
+    pub gpu_usage: bool,
+    pub thermal: bool,
+    pub screen_time: bool,
+}
+
+#[tauri::command]
+pub fn get_platform_capabilities() -> PlatformCapabilities {
+    let windows = cfg!(target_os = "windows");
+    PlatformCapabilities {
+        os: std::env::consts::OS.to_string(),
+        cpu_architecture: arch::native(),
+        emulated: arch::query(std::process::id()).is_some_and(|own| own.emulated),
+        kill_process: true,
+        suspend_process: true,
+        priority: true,
+        efficiency_mode: windows,
+        affinity: windows || cfg!(target_os = "linux"),
+        services: windows,
+        network_connections: windows,
+        etw: windows,
+        // Fuera de Windows depende de NVML (controlador de NVIDIA)
+        gpu_usage: windows || crate::gpu::nvml().is_some(),
+        thermal: windows,
+        screen_time: windows,
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct Subsystem {
+    pub available: bool,
+    pub reason: Option<String>, // Por qué no está disponible
+}
+
+impl Subsystem {
+    fn from_error(error: Option<String>) -> Self {
+        Subsystem { available: error.is_none(), reason: error }
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct Capabilities {
+    #[serde(flatten)]
+    pub platform: PlatformCapabilities,
+    pub elevated: bool, // Administrador (Windows) o root
+    pub nvml: bool,     // Controlador de NVIDIA con NVML
+    pub etw: Subsystem, // Red, archivos y registro por proceso
+    pub gpu_counters: Subsystem,
+    pub performance_counters: Subsystem, // PDH: GPU, disco y temperatura en Windows
+    pub thermal: Subsystem,
+    // Desactivados en esta sesión por fallos; "refresh" cuenta los refrescos perdidos por pánicos
+    pub degraded: Vec<DegradedCollector>,
+}
+
+fn is_elevated() -> bool {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::Security::TOKEN_QUERY;
+
+        crate::security::open_process_token(std::process::id(), TOKEN_QUERY)
+            .ok()
+            .and_then(|token| crate::security::token_integrity(&token).1)
+            .unwrap_or(false)
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        unsafe { libc::geteuid() == 0 }
+    }
+}
+
+// Los contadores de rendimiento pueden estar dañados o desactivados en el registro
+fn performance_counters() -> Option<String> {
+    #[cfg(target_os = "windows")]
+    {
+        crate::pdh::PdhQuery::open()
+            .and_then(|mut query| query.add_counter(r"\Processor(_Total)\% Processor Time"))
+            .err()
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        Some("Los contadores de rendimiento solo están disponibles en Windows".to_string())
+    }
+}
+
+#[tauri::command]
+pub fn get_capabilities(state: State<AppState>) -> Capabilities {
+    let platform = get_platform_capabilities();
+    let elevated = is_elevated();
+    let collectors = state.settings.read().unwrap().collectors;
+    let health = state.collector_health.lock().unwrap();
+
+    // El error de ETW se guarda en cada colector que lo usa; uno desactivado no cuenta
+    let etw = if !platform.etw {
+        Some("ETW solo está disponible en Windows".to_string())
+    } else if !elevated {
+        Some("Requiere ejecutar como administrador".to_string())
+    } else {
+        [
+            (collectors.network, health.reason("etw_network").or(state.bandwidth.lock().unwrap().error.clone())),
+            (collectors.file_io, health.reason("etw_fileio").or(state.fileio.lock().unwrap().error.clone())),
+            (collectors.registry, health.reason("etw_registry").or(state.regtrace.lock().unwrap().error.clone())),
+        ]
+        .into_iter()
+        .find_map(|(enabled, error)| error.filter(|_| enabled))
+    };
+    let gpu_error = health.reason("gpu").or(state.gpu.lock().unwrap().error.clone());
+    let thermal_error = health.reason("thermal").or(state.thermal.lock().unwrap().error.clone());
+
+    Capabilities {
+        elevated,
+        nvml: crate::gpu::nvml().is_some(),
+        etw: Subsystem::from_error(etw),
+        gpu_counters: Subsystem::from_error(gpu_error),
+        performance_counters: Subsystem::from_error(performance_counters()),
+        thermal: Subsystem::from_error(thermal_error),
+        degraded: health.degraded(),
+        platform,
+    }
+}
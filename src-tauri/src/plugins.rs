@@ -0,0 +1,186 @@
+// src-tauri/src/plugins.rs
+// Plugins de terceros como bibliotecas dinámicas (en <datos de la app>/plugins)
+// que añaden columnas de métricas y acciones de menú contextual.
+//
+// Interfaz C que debe exportar cada biblioteca (cadenas UTF-8 terminadas en
+// nulo; las devueltas las libera el plugin con `tm_plugin_free`):
+//   const char* tm_plugin_manifest(void);        -> PluginManifest en JSON
+//   char* tm_plugin_columns(const char* pids);   -> {"<pid>": {"<columna>": valor}}
+//   char* tm_plugin_action(const char* action, uint32_t pid) -> {"ok": ...} o {"error": "..."}
+//   void tm_plugin_free(char* value);
+
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+use std::ffi::{c_char, CStr, CString};
+use std::path::Path;
+use tauri::State;
+
+use crate::AppState;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PluginColumn {
+    pub id: String,
+    pub title: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PluginAction {
+    pub id: String,
+    pub title: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PluginManifest {
+    pub name: String,
+    pub version: String,
+    #[serde(default)]
+    pub columns: Vec<PluginColumn>,
+    #[serde(default)]
+    pub actions: Vec<PluginAction>,
+}
+
+type ManifestFn = unsafe extern "C" fn() -> *const c_char;
+type ColumnsFn = unsafe extern "C" fn(*const c_char) -> *mut c_char;
+type ActionFn = unsafe extern "C" fn(*const c_char, u32) -> *mut c_char;
+type FreeFn = unsafe extern "C" fn(*mut c_char);
+
+pub struct Plugin {
+    pub manifest: PluginManifest,
+    columns: Option<ColumnsFn>,
+    action: Option<ActionFn>,
+    free: FreeFn,
+    _library: libloading::Library, // Mantiene cargados los símbolos anteriores
+}
+
+#[derive(Default)]
+pub struct PluginRegistry {
+    plugins: Vec<Plugin>,
+    pub errors: Vec<String>, // Bibliotecas que no se pudieron cargar
+}
+
+fn library_extension() -> &'static str {
+    if cfg!(target_os = "windows") {
+        "dll"
+    } else if cfg!(target_os = "macos") {
+        "dylib"
+    } else {
+        "so"
+    }
+}
+
+unsafe fn load_plugin(path: &Path) -> Result<Plugin, String> {
+    let library = libloading::Library::new(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let manifest_fn: ManifestFn = *library
+        .get::<ManifestFn>(b"tm_plugin_manifest\0")
+        .map_err(|e| format!("{}: falta tm_plugin_manifest ({})", path.display(), e))?;
+    let free: FreeFn = *library
+        .get::<FreeFn>(b"tm_plugin_free\0")
+        .map_err(|e| format!("{}: falta tm_plugin_free ({})", path.display(), e))?;
+    let columns = library.get::<ColumnsFn>(b"tm_plugin_columns\0").ok().map(|symbol| *symbol);
+    let action = library.get::<ActionFn>(b"tm_plugin_action\0").ok().map(|symbol| *symbol);
+
+    let raw = manifest_fn();
+    if raw.is_null() {
+        return Err(format!("{}: manifiesto vacío", path.display()));
+    }
+    let manifest: PluginManifest = serde_json::from_slice(CStr::from_ptr(raw).to_bytes())
+        .map_err(|e| format!("{}: manifiesto no válido ({})", path.display(), e))?;
+    Ok(Plugin { manifest, columns, action, free, _library: library })
+}
+
+impl Plugin {
+    // Convierte la cadena devuelta por el plugin y la libera
+    unsafe fn take_json(&self, raw: *mut c_char) -> Result<serde_json::Value, String> {
+        if raw.is_null() {
+            return Err(format!("El plugin {} no devolvió ningún resultado", self.manifest.name));
+        }
+        let parsed = serde_json::from_slice(CStr::from_ptr(raw).to_bytes());
+        (self.free)(raw);
+        parsed.map_err(|e| format!("Respuesta no válida del plugin {}: {}", self.manifest.name, e))
+    }
+
+    fn columns(&self, pids: &[u32]) -> Result<HashMap<u32, HashMap<String, serde_json::Value>>, String> {
+        let Some(columns) = self.columns else {
+            return Ok(HashMap::new());
+        };
+        let request = CString::new(serde_json::to_string(pids).unwrap_or_default()).unwrap_or_default();
+        let value = unsafe { self.take_json(columns(request.as_ptr()))? };
+        serde_json::from_value(value)
+            .map_err(|e| format!("Columnas no válidas del plugin {}: {}", self.manifest.name, e))
+    }
+
+    fn run_action(&self, action: &str, pid: u32) -> Result<serde_json::Value, String> {
+        let Some(run) = self.action else {
+            return Err(format!("El plugin {} no tiene acciones", self.manifest.name));
+        };
+        let action = CString::new(action).map_err(|_| "Identificador de acción no válido".to_string())?;
+        let value = unsafe { self.take_json(run(action.as_ptr(), pid))? };
+        match value.get("error").and_then(|error| error.as_str()) {
+            Some(error) => Err(format!("{}: {}", self.manifest.name, error)),
+            None => Ok(value.get("ok").cloned().unwrap_or(serde_json::Value::Null)),
+        }
+    }
+}
+
+impl PluginRegistry {
+    // Carga todas las bibliotecas del directorio (se llama una vez al iniciar)
+    pub fn load_dir(dir: &Path) -> Self {
+        let mut registry = PluginRegistry::default();
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return registry;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some(library_extension()) {
+                continue;
+            }
+            match unsafe { load_plugin(&path) } {
+                Ok(plugin) => registry.plugins.push(plugin),
+                Err(e) => registry.errors.push(e),
+            }
+        }
+        registry
+    }
+
+    fn find(&self, name: &str) -> Result<&Plugin, String> {
+        self.plugins
+            .iter()
+            .find(|plugin| plugin.manifest.name == name)
+            .ok_or_else(|| format!("No hay ningún plugin llamado {}", name))
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PluginList {
+    pub plugins: Vec<PluginManifest>,
+    pub errors: Vec<String>,
+}
+
+#[tauri::command]
+pub fn get_plugins(state: State<AppState>) -> PluginList {
+    let registry = state.plugins.lock().unwrap();
+    PluginList {
+        plugins: registry.plugins.iter().map(|plugin| plugin.manifest.clone()).collect(),
+        errors: registry.errors.clone(),
+    }
+}
+
+// Valores de las columnas de plugins: plugin -> PID -> columna -> valor
+#[tauri::command]
+pub fn get_plugin_columns(
+    state: State<AppState>,
+    pids: Vec<u32>,
+) -> HashMap<String, HashMap<u32, HashMap<String, serde_json::Value>>> {
+    let registry = state.plugins.lock().unwrap();
+    registry
+        .plugins
+        .iter()
+        .filter_map(|plugin| plugin.columns(&pids).ok().map(|values| (plugin.manifest.name.clone(), values)))
+        .collect()
+}
+
+#[tauri::command]
+pub fn run_plugin_action(state: State<AppState>, plugin: String, action: String, pid: u32) -> Result<serde_json::Value, String> {
+    let registry = state.plugins.lock().unwrap();
+    registry.find(&plugin)?.run_action(&action, pid)
+}
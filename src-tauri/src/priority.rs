@@ -0,0 +1,203 @@
+// src-tauri/src/priority.rs
+// Clase de prioridad de CPU y modo de eficiencia (EcoQoS) de los procesos
+
+use serde::{Serialize, Deserialize};
+use tauri::State;
+
+use crate::AppState;
+
+// De menor a mayor prioridad
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum PriorityClass {
+    Idle,
+    BelowNormal,
+    Normal,
+    AboveNormal,
+    High,
+    Realtime,
+}
+
+impl PriorityClass {
+    #[cfg(target_os = "windows")]
+    fn from_raw(value: u32) -> Option<Self> {
+        use windows::Win32::System::Threading::{
+            ABOVE_NORMAL_PRIORITY_CLASS, BELOW_NORMAL_PRIORITY_CLASS, HIGH_PRIORITY_CLASS,
+            IDLE_PRIORITY_CLASS, NORMAL_PRIORITY_CLASS, REALTIME_PRIORITY_CLASS,
+        };
+
+        Some(match value {
+            v if v == IDLE_PRIORITY_CLASS.0 => PriorityClass::Idle,
+            v if v == BELOW_NORMAL_PRIORITY_CLASS.0 => PriorityClass::BelowNormal,
+            v if v == NORMAL_PRIORITY_CLASS.0 => PriorityClass::Normal,
+            v if v == ABOVE_NORMAL_PRIORITY_CLASS.0 => PriorityClass::AboveNormal,
+            v if v == HIGH_PRIORITY_CLASS.0 => PriorityClass::High,
+            v if v == REALTIME_PRIORITY_CLASS.0 => PriorityClass::Realtime,
+            _ => return None,
+        })
+    }
+
+    #[cfg(target_os = "windows")]
+    fn to_raw(self) -> windows::Win32::System::Threading::PROCESS_CREATION_FLAGS {
+        use windows::Win32::System::Threading::{
+            ABOVE_NORMAL_PRIORITY_CLASS, BELOW_NORMAL_PRIORITY_CLASS, HIGH_PRIORITY_CLASS,
+            IDLE_PRIORITY_CLASS, NORMAL_PRIORITY_CLASS, REALTIME_PRIORITY_CLASS,
+        };
+
+        match self {
+            PriorityClass::Idle => IDLE_PRIORITY_CLASS,
+            PriorityClass::BelowNormal => BELOW_NORMAL_PRIORITY_CLASS,
+            PriorityClass::Normal => NORMAL_PRIORITY_CLASS,
+            PriorityClass::AboveNormal => ABOVE_NORMAL_PRIORITY_CLASS,
+            PriorityClass::High => HIGH_PRIORITY_CLASS,
+            PriorityClass::Realtime => REALTIME_PRIORITY_CLASS,
+        }
+    }
+
+    // Equivalencia aproximada con el valor nice de Unix (-20 a 19)
+    #[cfg(not(target_os = "windows"))]
+    fn to_nice(self) -> i32 {
+        match self {
+            PriorityClass::Idle => 19,
+            PriorityClass::BelowNormal => 10,
+            PriorityClass::Normal => 0,
+            PriorityClass::AboveNormal => -5,
+            PriorityClass::High => -10,
+            PriorityClass::Realtime => -20,
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    fn from_nice(nice: i32) -> Self {
+        match nice {
+            15.. => PriorityClass::Idle,
+            5..=14 => PriorityClass::BelowNormal,
+            -2..=4 => PriorityClass::Normal,
+            -7..=-3 => PriorityClass::AboveNormal,
+            -17..=-8 => PriorityClass::High,
+            _ => PriorityClass::Realtime,
+        }
+    }
+}
+
+// (clase de prioridad, modo de eficiencia); `None` si no se puede abrir el proceso
+pub fn query(pid: u32) -> (Option<PriorityClass>, bool) {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::System::Threading::{
+            GetPriorityClass, GetProcessInformation, ProcessPowerThrottling,
+            PROCESS_POWER_THROTTLING_EXECUTION_SPEED, PROCESS_POWER_THROTTLING_STATE,
+            PROCESS_QUERY_LIMITED_INFORMATION,
+        };
+
+        let process = match crate::win::open_process(pid, PROCESS_QUERY_LIMITED_INFORMATION) {
+            Ok(process) => process,
+            Err(_) => return (None, false),
+        };
+        let priority = PriorityClass::from_raw(unsafe { GetPriorityClass(process.0) });
+
+        // EcoQoS: el proceso controla y tiene activa la limitación de velocidad
+        let mut throttling = PROCESS_POWER_THROTTLING_STATE {
+            Version: 1,
+            ..Default::default()
+        };
+        let ok = unsafe {
+            GetProcessInformation(
+                process.0,
+                ProcessPowerThrottling,
+                &mut throttling as *mut _ as *mut _,
+                std::mem::size_of::<PROCESS_POWER_THROTTLING_STATE>() as u32,
+            )
+        };
+        let efficiency_mode = ok.as_bool()
+            && throttling.ControlMask & PROCESS_POWER_THROTTLING_EXECUTION_SPEED != 0
+            && throttling.StateMask & PROCESS_POWER_THROTTLING_EXECUTION_SPEED != 0;
+        (priority, efficiency_mode)
+    }
+
+    // getpriority puede devolver -1 como valor válido, y con PRIO_PROCESS solo
+    // falla si el proceso no existe: en ese caso lo confirma kill con la señal 0
+    #[cfg(not(target_os = "windows"))]
+    {
+        let nice = unsafe { libc::getpriority(libc::PRIO_PROCESS, pid as libc::id_t) };
+        if nice == -1
+            && unsafe { libc::kill(pid as libc::pid_t, 0) } == -1
+            && std::io::Error::last_os_error().raw_os_error() == Some(libc::ESRCH)
+        {
+            return (None, false);
+        }
+        (Some(PriorityClass::from_nice(nice)), false)
+    }
+}
+
+// Subir la prioridad por encima de Normal requiere permisos de administrador
+// (root en Unix); Windows rebaja Realtime a High sin avisar si no los hay
+pub fn apply(pid: u32, priority: PriorityClass) -> Result<(), String> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::System::Threading::{SetPriorityClass, PROCESS_SET_INFORMATION};
+
+        let process = crate::win::open_process(pid, PROCESS_SET_INFORMATION)?;
+        if !unsafe { SetPriorityClass(process.0, priority.to_raw()) }.as_bool() {
+            return Err(format!("No se pudo cambiar la prioridad: {}", windows::core::Error::from_win32()));
+        }
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        if unsafe { libc::setpriority(libc::PRIO_PROCESS, pid as libc::id_t, priority.to_nice()) } != 0 {
+            return Err(format!("No se pudo cambiar la prioridad: {}", std::io::Error::last_os_error()));
+        }
+        Ok(())
+    }
+}
+
+#[tauri::command]
+pub fn set_process_priority(state: State<AppState>, pid: u32, priority: PriorityClass) -> Result<(), String> {
+    let action = format!("priority:{:?}", priority);
+    crate::actions::run_once(&state, pid, &action, || apply(pid, priority))
+}
+
+// Como el Administrador de tareas de Windows: activar el modo de eficiencia
+// pone EcoQoS y la prioridad Baja; desactivarlo devuelve la Normal
+pub fn apply_efficiency_mode(pid: u32, enabled: bool) -> Result<(), String> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::System::Threading::{
+            ProcessPowerThrottling, SetProcessInformation, PROCESS_POWER_THROTTLING_EXECUTION_SPEED,
+            PROCESS_POWER_THROTTLING_STATE, PROCESS_SET_INFORMATION,
+        };
+
+        let process = crate::win::open_process(pid, PROCESS_SET_INFORMATION)?;
+        let throttling = PROCESS_POWER_THROTTLING_STATE {
+            Version: 1,
+            ControlMask: PROCESS_POWER_THROTTLING_EXECUTION_SPEED,
+            StateMask: if enabled { PROCESS_POWER_THROTTLING_EXECUTION_SPEED } else { 0 },
+        };
+        let ok = unsafe {
+            SetProcessInformation(
+                process.0,
+                ProcessPowerThrottling,
+                &throttling as *const _ as *const _,
+                std::mem::size_of::<PROCESS_POWER_THROTTLING_STATE>() as u32,
+            )
+        };
+        if !ok.as_bool() {
+            return Err(format!("No se pudo cambiar el modo de eficiencia: {}", windows::core::Error::from_win32()));
+        }
+        apply(pid, if enabled { PriorityClass::Idle } else { PriorityClass::Normal })
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = (pid, enabled);
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
+
+#[tauri::command]
+pub fn set_efficiency_mode(state: State<AppState>, pid: u32, enabled: bool) -> Result<(), String> {
+    let action = format!("efficiency_mode:{}", enabled);
+    crate::actions::run_once(&state, pid, &action, || apply_efficiency_mode(pid, enabled))
+}
@@ -0,0 +1,176 @@
+// src-tauri/src/probe.rs
+// Sondas de latencia: ping y traceroute con ICMP (IcmpSendEcho), solo IPv4.
+// Se ejecutan como tareas en segundo plano porque cada intento puede esperar
+// hasta el tiempo de espera completo
+
+use serde::Serialize;
+use std::net::{Ipv4Addr, ToSocketAddrs};
+use tauri::AppHandle;
+
+const DEFAULT_TIMEOUT_MS: u32 = 1000;
+const DEFAULT_PING_COUNT: u32 = 4;
+const DEFAULT_MAX_HOPS: u8 = 30;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct PingResult {
+    pub host: String,
+    pub address: String,
+    pub replies: Vec<Option<u32>>, // Tiempo de ida y vuelta en ms; None si no hubo respuesta
+    pub sent: u32,
+    pub received: u32,
+    pub min_ms: Option<u32>,
+    pub avg_ms: Option<f64>,
+    pub max_ms: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct TraceHop {
+    pub ttl: u8,
+    pub address: Option<String>, // None si el salto no respondió
+    pub rtt_ms: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct TraceResult {
+    pub host: String,
+    pub address: String,
+    pub hops: Vec<TraceHop>,
+    pub reached: bool,
+}
+
+fn resolve(host: &str) -> Result<Ipv4Addr, String> {
+    (host, 0)
+        .to_socket_addrs()
+        .map_err(|e| format!("No se pudo resolver {}: {}", host, e))?
+        .find_map(|address| match address.ip() {
+            std::net::IpAddr::V4(ip) => Some(ip),
+            std::net::IpAddr::V6(_) => None,
+        })
+        .ok_or_else(|| format!("{} no tiene dirección IPv4", host))
+}
+
+// Resultado de un eco: dirección que respondió, RTT y si es el destino
+// (false cuando responde un salto intermedio por TTL agotado)
+#[derive(Debug)]
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+struct EchoReply {
+    from: Ipv4Addr,
+    rtt_ms: u32,
+    reached: bool,
+}
+
+#[cfg(target_os = "windows")]
+fn echo(target: Ipv4Addr, ttl: u8, timeout_ms: u32) -> Result<Option<EchoReply>, String> {
+    use windows::Win32::NetworkManagement::IpHelper::{
+        IcmpCloseHandle, IcmpCreateFile, IcmpSendEcho, ICMP_ECHO_REPLY, IP_OPTION_INFORMATION,
+    };
+
+    const IP_SUCCESS: u32 = 0;
+    const IP_TTL_EXPIRED_TRANSIT: u32 = 11013;
+
+    let handle = unsafe { IcmpCreateFile() }.map_err(|e| format!("No se pudo abrir ICMP: {}", e))?;
+    let payload = [0x61u8; 32];
+    let options = IP_OPTION_INFORMATION { Ttl: ttl, ..Default::default() };
+    // Respuesta + carga + 8 bytes para un posible mensaje de error ICMP
+    let mut reply = vec![0u64; (std::mem::size_of::<ICMP_ECHO_REPLY>() + payload.len() + 8).div_ceil(8)];
+    let count = unsafe {
+        IcmpSendEcho(
+            handle,
+            u32::from_ne_bytes(target.octets()),
+            payload.as_ptr() as *const _,
+            payload.len() as u16,
+            Some(&options),
+            reply.as_mut_ptr() as *mut _,
+            (reply.len() * 8) as u32,
+            timeout_ms,
+        )
+    };
+    unsafe { IcmpCloseHandle(handle) };
+    if count == 0 {
+        return Ok(None);
+    }
+
+    let reply = unsafe { &*(reply.as_ptr() as *const ICMP_ECHO_REPLY) };
+    let from = Ipv4Addr::from(reply.Address.to_ne_bytes());
+    Ok(match reply.Status {
+        IP_SUCCESS => Some(EchoReply { from, rtt_ms: reply.RoundTripTime, reached: true }),
+        IP_TTL_EXPIRED_TRANSIT => Some(EchoReply { from, rtt_ms: reply.RoundTripTime, reached: false }),
+        _ => None,
+    })
+}
+
+#[cfg(not(target_os = "windows"))]
+fn echo(target: Ipv4Addr, ttl: u8, timeout_ms: u32) -> Result<Option<EchoReply>, String> {
+    let _ = (target, ttl, timeout_ms);
+    Err("Esta función solo está disponible en Windows".to_string())
+}
+
+// Tarea "ping"; el resultado es un PingResult
+#[tauri::command]
+pub fn ping_host(app: AppHandle, host: String, count: Option<u32>, timeout_ms: Option<u32>) -> u64 {
+    crate::jobs::spawn(&app, "ping", move |job| {
+        let target = resolve(&host)?;
+        let count = count.unwrap_or(DEFAULT_PING_COUNT).max(1);
+        let timeout_ms = timeout_ms.unwrap_or(DEFAULT_TIMEOUT_MS);
+        let mut replies = Vec::new();
+        for attempt in 0..count {
+            job.check_cancelled()?;
+            let reply = echo(target, 128, timeout_ms)?.filter(|reply| reply.reached);
+            let rtt = reply.map(|reply| reply.rtt_ms);
+            job.progress(
+                (attempt + 1) as f32 / count as f32,
+                match rtt {
+                    Some(rtt) => format!("Respuesta de {}: {} ms", target, rtt),
+                    None => "Tiempo de espera agotado".to_string(),
+                },
+            );
+            replies.push(rtt);
+            if attempt + 1 < count {
+                std::thread::sleep(std::time::Duration::from_secs(1));
+            }
+        }
+
+        let times: Vec<u32> = replies.iter().flatten().copied().collect();
+        Ok(PingResult {
+            host,
+            address: target.to_string(),
+            sent: count,
+            received: times.len() as u32,
+            min_ms: times.iter().min().copied(),
+            avg_ms: (!times.is_empty()).then(|| times.iter().sum::<u32>() as f64 / times.len() as f64),
+            max_ms: times.iter().max().copied(),
+            replies,
+        })
+    })
+}
+
+// Tarea "traceroute"; el resultado es un TraceResult
+#[tauri::command]
+pub fn traceroute(app: AppHandle, host: String, max_hops: Option<u8>, timeout_ms: Option<u32>) -> u64 {
+    crate::jobs::spawn(&app, "traceroute", move |job| {
+        let target = resolve(&host)?;
+        let max_hops = max_hops.unwrap_or(DEFAULT_MAX_HOPS).max(1);
+        let timeout_ms = timeout_ms.unwrap_or(DEFAULT_TIMEOUT_MS);
+        let mut hops = Vec::new();
+        let mut reached = false;
+        for ttl in 1..=max_hops {
+            job.check_cancelled()?;
+            let reply = echo(target, ttl, timeout_ms)?;
+            let hop = TraceHop {
+                ttl,
+                address: reply.as_ref().map(|reply| reply.from.to_string()),
+                rtt_ms: reply.as_ref().map(|reply| reply.rtt_ms),
+            };
+            job.progress(
+                ttl as f32 / max_hops as f32,
+                format!("{}: {}", ttl, hop.address.as_deref().unwrap_or("*")),
+            );
+            hops.push(hop);
+            if reply.is_some_and(|reply| reply.reached) {
+                reached = true;
+                break;
+            }
+        }
+        Ok(TraceResult { host, address: target.to_string(), hops, reached })
+    })
+}
@@ -0,0 +1,137 @@
+// src-tauri/src/profiles.rs
+// Perfiles de configuración con nombre (Trabajo, Juegos, Batería...): cada uno
+// sobrescribe el intervalo de refresco, las reglas, los colectores y el plan
+// de energía, y se aplican de una vez con switch_profile o desde la bandeja.
+// Si el plan no se puede activar, se deshace el resto del cambio
+
+use serde::{Serialize, Deserialize};
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::rules::Rule;
+use crate::settings::{CollectorSettings, Settings};
+use crate::AppState;
+
+// Los campos ausentes conservan el valor actual
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Profile {
+    pub name: String,
+    #[serde(default)]
+    pub refresh_interval_ms: Option<u64>,
+    #[serde(default)]
+    pub rules: Option<Vec<Rule>>,
+    #[serde(default)]
+    pub collectors: Option<CollectorSettings>,
+    #[serde(default)]
+    pub power_plan: Option<String>, // GUID del plan (powercfg /list)
+}
+
+fn set_power_plan(guid: &str) -> Result<(), String> {
+    #[cfg(target_os = "windows")]
+    {
+        let output = std::process::Command::new("powercfg")
+            .args(["/setactive", guid])
+            .output()
+            .map_err(|e| format!("Error al ejecutar powercfg: {}", e))?;
+        if !output.status.success() {
+            return Err(format!(
+                "No se pudo activar el plan de energía {}: {}",
+                guid,
+                crate::win::decode_console_output(&output.stdout).trim()
+            ));
+        }
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = guid;
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
+
+// GUID del plan activo, de la salida de powercfg /getactivescheme (cuyo texto
+// depende del idioma del sistema)
+fn active_power_plan() -> Result<String, String> {
+    #[cfg(target_os = "windows")]
+    {
+        let output = std::process::Command::new("powercfg")
+            .arg("/getactivescheme")
+            .output()
+            .map_err(|e| format!("Error al ejecutar powercfg: {}", e))?;
+        let text = crate::win::decode_console_output(&output.stdout);
+        text.split_whitespace()
+            .find(|word| word.len() == 36 && word.matches('-').count() == 4)
+            .map(str::to_string)
+            .ok_or_else(|| "No se pudo leer el plan de energía activo".to_string())
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
+
+pub fn validate(profile: &Profile) -> Result<(), String> {
+    if profile.name.trim().is_empty() {
+        return Err("Falta el nombre del perfil".to_string());
+    }
+    if let Some(interval) = profile.refresh_interval_ms {
+        crate::sampler::validate_interval(interval)?;
+    }
+    for rule in profile.rules.iter().flatten() {
+        crate::rules::validate(rule)?;
+    }
+    Ok(())
+}
+
+// La configuración se cambia de una vez (validada y guardada bajo el bloqueo)
+// y el plan de energía se activa al final; si falla, se vuelve al plan y a la
+// configuración anteriores
+pub fn switch(app: &AppHandle, name: &str) -> Result<Settings, String> {
+    let mut power_plan = None;
+    let previous = crate::settings::modify(app, |settings| {
+        let profile = settings
+            .profiles
+            .iter()
+            .find(|profile| profile.name == name)
+            .cloned()
+            .ok_or_else(|| format!("No existe el perfil {}", name))?;
+        if let Some(interval) = profile.refresh_interval_ms {
+            settings.refresh_interval_ms = interval;
+        }
+        if let Some(rules) = profile.rules {
+            settings.rules = rules;
+        }
+        if let Some(collectors) = profile.collectors {
+            settings.collectors = collectors;
+        }
+        settings.active_profile = Some(profile.name);
+        power_plan = profile.power_plan;
+        Ok(())
+    })?;
+
+    if let Some(guid) = &power_plan {
+        let previous_plan = active_power_plan().ok();
+        if let Err(error) = set_power_plan(guid) {
+            if let Some(previous_plan) = &previous_plan {
+                let _ = set_power_plan(previous_plan);
+            }
+            let _ = crate::settings::modify(app, |settings| {
+                settings.refresh_interval_ms = previous.refresh_interval_ms;
+                settings.rules = previous.rules.clone();
+                settings.collectors = previous.collectors;
+                settings.active_profile = previous.active_profile.clone();
+                Ok(())
+            });
+            return Err(error);
+        }
+    }
+    let settings = app.state::<AppState>().settings.read().unwrap().clone();
+    let _ = app.emit("profile-changed", &settings);
+    Ok(settings)
+}
+
+#[tauri::command]
+pub fn switch_profile(app: AppHandle, name: String) -> Result<Settings, String> {
+    switch(&app, &name)
+}
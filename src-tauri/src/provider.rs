@@ -0,0 +1,444 @@
+// src-tauri/src/provider.rs
+// Origen de los datos del refresco completo. El árbol de procesos, las tasas de
+// E/S, el consumo de datos, las expresiones, las reglas, las alertas, los
+// deltas y las exportaciones trabajan sobre un `SystemSnapshot`, no sobre
+// sysinfo. Así se pueden alimentar con datos deterministas: con la variable
+// TASK_MANAGER_MOCK_SYSTEM apuntando a un escenario JSON, cada refresco avanza
+// un fotograma en lugar de leer el sistema. Es el arnés de las pruebas de
+// integración; el resto de comandos (detalles, servicios...) sigue leyendo el
+// sistema real. El estado que no da sysinfo (prioridad, suspensión, ventanas
+// colgadas) también va en la instantánea, para que un escenario no dependa de
+// qué proceso real tenga el mismo PID
+
+use serde::{Serialize, Deserialize};
+use std::collections::{HashMap, HashSet};
+use sysinfo::{CpuExt, NetworkExt, PidExt, ProcessExt, System, SystemExt};
+
+use crate::priority::PriorityClass;
+
+pub const MOCK_ENV: &str = "TASK_MANAGER_MOCK_SYSTEM";
+const MOCK_FRAME_MS: u64 = 1000; // Separación de los fotogramas que no indican timestamp
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ProcessSample {
+    pub pid: u32,
+    pub name: String,
+    pub parent: Option<u32>,
+    pub cpu_usage: f32,
+    pub memory: u64,             // Bytes
+    pub disk_read_bytes: u64,    // Desde el refresco anterior
+    pub disk_written_bytes: u64,
+    pub total_read_bytes: u64,   // Acumulados desde que arrancó
+    pub total_written_bytes: u64,
+    pub start_time: u64,         // Segundos desde UNIX_EPOCH; distingue un PID reutilizado
+    pub stopped: bool,           // Detenido (SIGSTOP)
+    pub suspended: bool,         // Suspendido desde la aplicación
+    pub not_responding: bool,    // Alguna ventana colgada
+    pub priority: Option<PriorityClass>, // None sin acceso al proceso
+    pub efficiency_mode: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct NetworkSample {
+    pub name: String,
+    pub received: u64, // Desde el refresco anterior
+    pub transmitted: u64,
+    pub total_received: u64,
+    pub total_transmitted: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct SystemSnapshot {
+    pub timestamp: u64, // Milisegundos desde UNIX_EPOCH; en escenarios y grabaciones, relativo
+    pub processes: Vec<ProcessSample>,
+    pub networks: Vec<NetworkSample>,
+    pub cpu_usage: f32, // Porcentaje global
+    pub cpu_count: usize,
+    pub total_memory: u64,
+    pub used_memory: u64,
+    pub available_memory: u64,
+    pub used_swap: u64,
+}
+
+impl SystemSnapshot {
+    pub fn process_map(&self) -> HashMap<u32, &ProcessSample> {
+        self.processes.iter().map(|process| (process.pid, process)).collect()
+    }
+}
+
+pub trait SystemProvider {
+    fn snapshot(&self) -> SystemSnapshot;
+}
+
+impl SystemProvider for System {
+    fn snapshot(&self) -> SystemSnapshot {
+        system_snapshot(self, None)
+    }
+}
+
+// Con `only`, solo esos PIDs: la prioridad se consulta proceso a proceso
+pub fn system_snapshot(system: &System, only: Option<&HashSet<u32>>) -> SystemSnapshot {
+    let not_responding = crate::status::not_responding_pids();
+    SystemSnapshot {
+        timestamp: crate::stats::now_millis(),
+        processes: system
+            .processes()
+            .iter()
+            .filter(|(pid, _)| only.is_none_or(|only| only.contains(&pid.as_u32())))
+            .map(|(pid, process)| {
+                let pid = pid.as_u32();
+                let disk = process.disk_usage();
+                let (priority, efficiency_mode) = crate::priority::query(pid);
+                ProcessSample {
+                    pid,
+                    name: process.name().to_string(),
+                    parent: process.parent().map(|parent| parent.as_u32()),
+                    cpu_usage: process.cpu_usage(),
+                    memory: process.memory(),
+                    disk_read_bytes: disk.read_bytes,
+                    disk_written_bytes: disk.written_bytes,
+                    total_read_bytes: disk.total_read_bytes,
+                    total_written_bytes: disk.total_written_bytes,
+                    start_time: process.start_time(),
+                    stopped: process.status() == sysinfo::ProcessStatus::Stop,
+                    suspended: crate::suspend::is_suspended(pid),
+                    not_responding: not_responding.contains(&pid),
+                    priority,
+                    efficiency_mode,
+                }
+            })
+            .collect(),
+        networks: system
+            .networks()
+            .into_iter()
+            .map(|(name, network)| NetworkSample {
+                name: name.clone(),
+                received: network.received(),
+                transmitted: network.transmitted(),
+                total_received: network.total_received(),
+                total_transmitted: network.total_transmitted(),
+            })
+            .collect(),
+        cpu_usage: system.global_cpu_info().cpu_usage(),
+        cpu_count: system.cpus().len(),
+        total_memory: system.total_memory(),
+        used_memory: system.used_memory(),
+        available_memory: system.available_memory(),
+        used_swap: system.used_swap(),
+    }
+}
+
+// Escenario: {"frames": [SystemSnapshot, ...]}. Al agotarse se repite el último.
+// Un fotograma sin timestamp se sitúa a MOCK_FRAME_MS por posición
+#[derive(Debug, Deserialize, Default)]
+pub struct MockSystem {
+    frames: Vec<SystemSnapshot>,
+    #[serde(skip)]
+    position: Option<usize>,
+}
+
+impl MockSystem {
+    pub fn from_frames(mut frames: Vec<SystemSnapshot>) -> MockSystem {
+        for (index, frame) in frames.iter_mut().enumerate() {
+            if frame.timestamp == 0 {
+                frame.timestamp = index as u64 * MOCK_FRAME_MS;
+            }
+        }
+        MockSystem { frames, position: None }
+    }
+
+    // None si la variable no está definida
+    pub fn from_env() -> Result<Option<MockSystem>, String> {
+        let Some(path) = std::env::var_os(MOCK_ENV) else { return Ok(None) };
+        let path = std::path::PathBuf::from(path);
+        let content =
+            std::fs::read_to_string(&path).map_err(|e| format!("No se pudo leer {}: {}", path.display(), e))?;
+        let mock: MockSystem = serde_json::from_str(&content)
+            .map_err(|e| format!("Escenario de prueba no válido en {}: {}", path.display(), e))?;
+        if mock.frames.is_empty() {
+            return Err(format!("El escenario {} no tiene fotogramas", path.display()));
+        }
+        Ok(Some(MockSystem::from_frames(mock.frames)))
+    }
+
+    // Equivale al refresco de sysinfo
+    pub fn advance(&mut self) {
+        let last = self.frames.len().saturating_sub(1);
+        self.position = Some(self.position.map_or(0, |position| (position + 1).min(last)));
+    }
+
+    // Fotograma leído en el último avance; None antes del primero
+    pub fn position(&self) -> Option<usize> {
+        self.position
+    }
+
+    pub fn seek(&mut self, position: Option<usize>) {
+        self.position = position.map(|position| position.min(self.frames.len().saturating_sub(1)));
+    }
+}
+
+impl SystemProvider for MockSystem {
+    fn snapshot(&self) -> SystemSnapshot {
+        self.frames.get(self.position.unwrap_or(0)).cloned().unwrap_or_default()
+    }
+}
+
+// Pruebas de integración: escenarios de MockSystem a través del refresco
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alerts::{AlertEngine, AlertMetric, AlertRule, AlertTarget, SystemReading};
+    use crate::apphistory::AppHistory;
+    use crate::expressions::{Expr, MetricContext};
+    use crate::format::FormatOptions;
+    use crate::growth::GrowthTracker;
+    use crate::rules::{Executor, Rule, RuleAction, RuleEngine, RuleTrigger};
+    use crate::status::ProcessStatus;
+
+    fn process(pid: u32, name: &str, parent: Option<u32>, cpu_usage: f32) -> ProcessSample {
+        ProcessSample {
+            pid,
+            name: name.to_string(),
+            parent,
+            cpu_usage,
+            memory: 64 * 1024 * 1024,
+            start_time: 1000 + pid as u64,
+            ..Default::default()
+        }
+    }
+
+    fn frame(processes: Vec<ProcessSample>) -> SystemSnapshot {
+        SystemSnapshot {
+            processes,
+            cpu_usage: 25.0,
+            cpu_count: 4,
+            total_memory: 8 * 1024 * 1024 * 1024,
+            used_memory: 4 * 1024 * 1024 * 1024,
+            ..Default::default()
+        }
+    }
+
+    fn scenario() -> MockSystem {
+        MockSystem::from_frames(vec![
+            frame(vec![process(10, "shell.exe", None, 1.0), process(11, "worker.exe", Some(10), 5.0)]),
+            frame(vec![
+                process(10, "shell.exe", None, 2.0),
+                process(11, "worker.exe", Some(10), 45.0),
+                process(12, "build.exe", Some(10), 80.0),
+            ]),
+        ])
+    }
+
+    fn rule(name: &str, trigger: RuleTrigger) -> Rule {
+        Rule {
+            name: name.to_string(),
+            trigger,
+            script: None,
+            args: Vec::new(),
+            cooldown_secs: 0,
+            enabled: true,
+            min_idle_secs: None,
+            action: Some(RuleAction::Kill),
+        }
+    }
+
+    #[test]
+    fn mock_advances_and_repeats_last_frame() {
+        let mut mock = scenario();
+        assert_eq!(mock.position(), None);
+        mock.advance();
+        assert_eq!(mock.snapshot().processes.len(), 2);
+        mock.advance();
+        mock.advance();
+        assert_eq!(mock.position(), Some(1));
+        assert_eq!(mock.snapshot().processes.len(), 3);
+        mock.seek(Some(0));
+        assert_eq!(mock.snapshot().processes.len(), 2);
+    }
+
+    #[test]
+    fn builds_tree_from_snapshot() {
+        let mut mock = scenario();
+        mock.advance();
+        mock.advance();
+        let tree = crate::build_process_tree(&mock.snapshot(), None);
+        assert_eq!(tree.processes.len(), 3);
+        let mut children = tree.process_relationships[&10].clone();
+        children.sort();
+        assert_eq!(children, vec![11, 12]);
+        let shell = tree.processes.iter().find(|process| process.pid == 10).unwrap();
+        assert!(shell.has_children);
+        assert_eq!(shell.parent_pid, None);
+        let build = tree.processes.iter().find(|process| process.pid == 12).unwrap();
+        assert_eq!(build.parent_pid, Some(10));
+        assert!(!build.has_children);
+    }
+
+    #[test]
+    fn growth_reports_cpu_delta_between_frames() {
+        let mut mock = scenario();
+        let mut growth = GrowthTracker::default();
+        let mut deltas = HashMap::new();
+        for _ in 0..2 {
+            mock.advance();
+            let snapshot = mock.snapshot();
+            let mut tree = crate::build_process_tree(&snapshot, None);
+            growth.sample(&snapshot, &mut tree.processes, false, &FormatOptions::default());
+            deltas = tree.processes.iter().map(|process| (process.pid, process.cpu_delta)).collect();
+        }
+        assert_eq!(deltas[&11], Some(40.0));
+        assert_eq!(deltas[&10], Some(1.0));
+        // Proceso nuevo: sin refresco anterior la diferencia es cero
+        assert_eq!(deltas[&12], Some(0.0));
+    }
+
+    // El crecimiento usa el tiempo de los fotogramas, no el del reloj
+    #[test]
+    fn growth_uses_frame_timestamps() {
+        let grown = ProcessSample { memory: 94 * 1024 * 1024, ..process(11, "worker.exe", None, 5.0) };
+        let mut mock = MockSystem::from_frames(vec![
+            frame(vec![process(11, "worker.exe", None, 5.0)]),
+            SystemSnapshot { timestamp: 30_000, ..frame(vec![grown]) },
+        ]);
+        let mut growth = GrowthTracker::default();
+        let mut memory_growth = None;
+        for _ in 0..2 {
+            mock.advance();
+            let snapshot = mock.snapshot();
+            let mut tree = crate::build_process_tree(&snapshot, None);
+            growth.sample(&snapshot, &mut tree.processes, false, &FormatOptions::default());
+            memory_growth = tree.processes[0].memory_growth;
+        }
+        // 30 MiB en 30 segundos
+        assert_eq!(memory_growth, Some(60.0));
+    }
+
+    // No depende de Windows: expresiones y reglas sobre las métricas del escenario
+    #[test]
+    fn rules_fire_in_simulation_without_acting() {
+        let busy = "worker.exe.total_cpu > 40".to_string();
+        let rules = vec![
+            rule("build-started", RuleTrigger::ProcessStarted { name: "build.exe".to_string() }),
+            rule("worker-busy", RuleTrigger::Expression { expression: busy, target: Some("worker.exe".to_string()) }),
+        ];
+        let mut engine = RuleEngine::default();
+        let history = AppHistory::default();
+        let mut act = |_: &str, _: RuleAction, _: u32| -> Result<(), String> { panic!("la simulación no debe actuar") };
+        let mut mock = scenario();
+        let mut fired = Vec::new();
+        for _ in 0..2 {
+            mock.advance();
+            let snapshot = mock.snapshot();
+            let context = MetricContext::from_snapshot(&snapshot);
+            let tree = crate::build_process_tree(&snapshot, None);
+            let mut executor = Executor { simulate: true, paused: false, act: &mut act };
+            fired.push(engine.evaluate(&rules, &context, &tree.processes, &history, &mut executor));
+        }
+
+        // Primer fotograma: worker.exe usa 5 %, la condición no se cumple
+        assert!(fired[0].is_empty());
+        // Segundo: arranca build.exe y worker.exe pasa al 45 %
+        assert_eq!(fired[1].len(), 2);
+        let started = &fired[1][0];
+        assert_eq!((started.rule.as_str(), started.pid), ("build-started", Some(12)));
+        assert!(started.simulated);
+        assert_eq!(started.action, Some(RuleAction::Kill));
+        let busy = &fired[1][1];
+        assert_eq!((busy.rule.as_str(), busy.pid, busy.value), ("worker-busy", Some(11), Some(1.0)));
+        assert_eq!(busy.name.as_deref(), Some("worker.exe"));
+        assert_eq!(busy.action, Some(RuleAction::Kill));
+    }
+
+    #[test]
+    fn expressions_compare_and_combine() {
+        let context = MetricContext::from_snapshot(&frame(vec![process(11, "worker.exe", None, 45.0)]));
+        let eval = |input: &str| Expr::parse(input).and_then(|expr| expr.eval(&|name| context.get(name)));
+        assert_eq!(eval("worker.exe.total_cpu > 40"), Ok(1.0));
+        assert_eq!(eval("worker.exe.total_cpu > 40 && idle.exe.count >= 1"), Ok(0.0));
+        assert_eq!(eval("idle.exe.count == 0 || 1 < 0"), Ok(1.0));
+        assert_eq!(eval("worker.exe.total_memory / system.memory_total * 100 <= 1 + 2 * 1"), Ok(1.0));
+        assert!(Expr::parse("worker.exe.total_cpu > 40").unwrap().is_condition());
+        assert!(!Expr::parse("worker.exe.total_cpu - 40").unwrap().is_condition());
+    }
+
+    // El estado que no da sysinfo sale del escenario, no del proceso real con el mismo PID
+    #[test]
+    fn tree_state_comes_from_snapshot() {
+        let suspended = ProcessSample { suspended: true, ..process(12, "build.exe", None, 0.0) };
+        let hung = ProcessSample { not_responding: true, ..process(13, "app.exe", None, 0.0) };
+        let prioritized = ProcessSample {
+            priority: Some(PriorityClass::High),
+            efficiency_mode: true,
+            ..process(14, "game.exe", None, 0.0)
+        };
+        let tree = crate::build_process_tree(&frame(vec![suspended, hung, prioritized]), None);
+        let by_pid: HashMap<u32, &crate::ProcessInfo> =
+            tree.processes.iter().map(|process| (process.pid, process)).collect();
+        assert_eq!(by_pid[&12].status, ProcessStatus::Suspended);
+        assert_eq!(by_pid[&13].status, ProcessStatus::NotResponding);
+        assert_eq!(by_pid[&14].status, ProcessStatus::Running);
+        assert_eq!((by_pid[&14].priority, by_pid[&14].efficiency_mode), (Some(PriorityClass::High), true));
+        assert_eq!(by_pid[&12].priority, None);
+    }
+
+    fn alert(id: u64, target: AlertTarget, metric: AlertMetric, threshold: f64, duration_secs: u64) -> AlertRule {
+        AlertRule { id, target, metric, threshold, duration_secs, notify: false }
+    }
+
+    // Los tiempos de las alertas son los de los fotogramas
+    #[test]
+    fn alerts_require_threshold_for_duration() {
+        let worker = |cpu_usage: f32| vec![process(11, "worker.exe", None, cpu_usage)];
+        let mut mock = MockSystem::from_frames(vec![
+            frame(worker(95.0)),
+            SystemSnapshot { timestamp: 30_000, ..frame(worker(95.0)) },
+            SystemSnapshot { timestamp: 61_000, ..frame(worker(95.0)) },
+            SystemSnapshot { timestamp: 62_000, ..frame(worker(10.0)) },
+            SystemSnapshot { timestamp: 63_000, ..frame(worker(95.0)) },
+        ]);
+        let worker_name = AlertTarget::Process("Worker.exe".to_string());
+        let rules = vec![
+            alert(1, worker_name, AlertMetric::CpuUsage, 90.0, 60),
+            alert(2, AlertTarget::System, AlertMetric::CpuUsage, 20.0, 0),
+            alert(3, AlertTarget::Expression("worker.exe.total_cpu > 50".to_string()), AlertMetric::Expression, 1.0, 0),
+        ];
+        let mut engine = AlertEngine::default();
+        let mut fired = Vec::new();
+        for _ in 0..5 {
+            mock.advance();
+            let snapshot = mock.snapshot();
+            let context = MetricContext::from_snapshot(&snapshot);
+            let tree = crate::build_process_tree(&snapshot, None);
+            let reading = SystemReading::of(&snapshot);
+            let events = engine.evaluate(&rules, &tree.processes, &tree.totals, &reading, Some(&context));
+            fired.push(events.iter().map(|event| event.rule_id).collect::<Vec<_>>());
+        }
+
+        // El sistema y la expresión no piden tiempo mínimo y solo avisan una vez
+        assert_eq!(fired[0], vec![2, 3]);
+        assert!(fired[1].is_empty());
+        // worker.exe lleva 61 s por encima del 90 %
+        assert_eq!(fired[2], vec![1]);
+        // Baja y se rearma: la expresión vuelve a avisar, la de duración empieza de cero
+        assert!(fired[3].is_empty());
+        assert_eq!(fired[4], vec![3]);
+    }
+
+    #[test]
+    fn exports_scenario_as_csv() {
+        let mut mock = scenario();
+        mock.advance();
+        mock.advance();
+        let mut tree = crate::build_process_tree(&mock.snapshot(), None);
+        tree.processes.sort_by_key(|process| process.pid);
+        let options = FormatOptions { use_system_locale: false, ..FormatOptions::default() };
+        let csv = crate::export::processes_to_csv(&tree.processes, &options);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with("pid,name,parent_pid,cpu_usage,memory_usage,memory_formatted"));
+        assert_eq!(lines[3], "12,build.exe,10,80.00,67108864,64.0 MiB,0,0,0.00");
+    }
+}
@@ -0,0 +1,209 @@
+// src-tauri/src/query.rs
+// Filtrado, ordenación y paginación de la lista de procesos en el backend,
+// para que la interfaz no reordene miles de filas en cada refresco. En vista
+// de árbol los hijos quedan bajo su padre y se ordenan entre hermanos
+
+use serde::{Serialize, Deserialize};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use sysinfo::{PidExt, ProcessExt, SystemExt};
+use tauri::{AppHandle, State};
+
+use crate::text::{normalize, SearchNormalization};
+use crate::{AppState, ProcessInfo, ProcessTotals};
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SortKey {
+    Name,
+    Pid,
+    CpuUsage,
+    MemoryUsage,
+    DiskReadBytes,
+    DiskWriteBytes,
+    DiskReadRate,
+    DiskWriteRate,
+    GpuUsage,
+    NetworkSendRate,
+    NetworkReceiveRate,
+    ImpactScore,
+    CpuDelta,
+    MemoryGrowth,
+    HandleGrowth,
+    Column(String), // Columna calculada de la configuración
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SortDirection {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ProcessQuery {
+    pub filter: Option<String>, // Nombre, PID o ruta del ejecutable
+    pub sort_by: Option<SortKey>,
+    pub direction: SortDirection,
+    pub tree: bool,
+    pub offset: usize,
+    pub limit: Option<usize>,
+    pub all_processes: bool, // Ignora la lista de vigilancia activa
+    pub normalization: SearchNormalization,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ProcessRow {
+    #[serde(flatten)]
+    process: ProcessInfo,
+    depth: usize,  // Nivel de sangría en vista de árbol; 0 en vista plana
+    matched: bool, // false si solo se muestra como antecesor de una coincidencia
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ProcessPage {
+    pub rows: Vec<ProcessRow>,
+    pub total_rows: usize, // Filas antes de paginar
+    totals: ProcessTotals, // De todos los procesos, no solo de la página
+}
+
+fn compare(a: &ProcessInfo, b: &ProcessInfo, key: &SortKey) -> Ordering {
+    let by_float = |a: f64, b: f64| a.partial_cmp(&b).unwrap_or(Ordering::Equal);
+    match key {
+        SortKey::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+        SortKey::Pid => a.pid.cmp(&b.pid),
+        SortKey::CpuUsage => by_float(a.cpu_usage as f64, b.cpu_usage as f64),
+        SortKey::MemoryUsage => a.memory_usage.cmp(&b.memory_usage),
+        SortKey::DiskReadBytes => a.disk_read_bytes.cmp(&b.disk_read_bytes),
+        SortKey::DiskWriteBytes => a.disk_write_bytes.cmp(&b.disk_write_bytes),
+        SortKey::DiskReadRate => by_float(a.disk_read_rate, b.disk_read_rate),
+        SortKey::DiskWriteRate => by_float(a.disk_write_rate, b.disk_write_rate),
+        SortKey::GpuUsage => by_float(a.gpu_usage as f64, b.gpu_usage as f64),
+        SortKey::NetworkSendRate => by_float(a.network_send_rate, b.network_send_rate),
+        SortKey::NetworkReceiveRate => by_float(a.network_receive_rate, b.network_receive_rate),
+        SortKey::ImpactScore => by_float(a.impact_score, b.impact_score),
+        // Los procesos sin valor en la columna van al principio en orden ascendente
+        SortKey::CpuDelta => by_float(
+            a.cpu_delta.map_or(f64::NEG_INFINITY, f64::from),
+            b.cpu_delta.map_or(f64::NEG_INFINITY, f64::from),
+        ),
+        SortKey::MemoryGrowth => by_float(
+            a.memory_growth.unwrap_or(f64::NEG_INFINITY),
+            b.memory_growth.unwrap_or(f64::NEG_INFINITY),
+        ),
+        SortKey::HandleGrowth => by_float(
+            a.handle_growth.unwrap_or(f64::NEG_INFINITY),
+            b.handle_growth.unwrap_or(f64::NEG_INFINITY),
+        ),
+        SortKey::Column(column) => by_float(
+            a.custom_columns.get(column).copied().unwrap_or(f64::NEG_INFINITY),
+            b.custom_columns.get(column).copied().unwrap_or(f64::NEG_INFINITY),
+        ),
+    }
+}
+
+fn sort(processes: &mut [&ProcessInfo], query: &ProcessQuery) {
+    let Some(key) = &query.sort_by else { return };
+    // El PID desempata para que el orden no salte entre refrescos
+    processes.sort_by(|a, b| {
+        let order = compare(a, b, key).then(a.pid.cmp(&b.pid));
+        if query.direction == SortDirection::Descending { order.reverse() } else { order }
+    });
+}
+
+#[tauri::command]
+pub fn query_processes(app: AppHandle, state: State<AppState>, query: ProcessQuery) -> ProcessPage {
+    let tree = crate::get_processes(app, state.clone());
+    let normalization = query.normalization;
+    let filter = query
+        .filter
+        .as_deref()
+        .map(str::trim)
+        .filter(|filter| !filter.is_empty())
+        .map(|filter| normalize(filter, normalization));
+    let watchlist = if query.all_processes {
+        None
+    } else {
+        crate::watchlists::active(&state.settings.read().unwrap()).cloned()
+    };
+
+    // La ruta solo se consulta si el nombre y el PID no coinciden
+    let on_watchlist = |process: &&ProcessInfo| watchlist.as_ref().is_none_or(|watchlist| watchlist.matches(process));
+    let matched: HashSet<u32> = match &filter {
+        None => tree.processes.iter().filter(on_watchlist).map(|process| process.pid).collect(),
+        Some(filter) => {
+            let system = state.system.read().unwrap();
+            tree.processes
+                .iter()
+                .filter(on_watchlist)
+                .filter(|process| {
+                    normalize(&process.name, normalization).contains(filter)
+                        || process.pid.to_string().starts_with(filter.as_str())
+                        || system.process(sysinfo::Pid::from_u32(process.pid)).is_some_and(|info| {
+                            normalize(&info.exe().to_string_lossy(), normalization).contains(filter)
+                        })
+                })
+                .map(|process| process.pid)
+                .collect()
+        }
+    };
+
+    let rows = if query.tree {
+        tree_rows(&tree.processes, &matched, &query)
+    } else {
+        let mut processes: Vec<&ProcessInfo> =
+            tree.processes.iter().filter(|process| matched.contains(&process.pid)).collect();
+        sort(&mut processes, &query);
+        processes.into_iter().map(|process| ProcessRow { process: process.clone(), depth: 0, matched: true }).collect()
+    };
+
+    let total_rows = rows.len();
+    let rows = rows.into_iter().skip(query.offset).take(query.limit.unwrap_or(usize::MAX)).collect();
+    ProcessPage { rows, total_rows, totals: tree.totals }
+}
+
+// Coincidencias más sus antecesores, en profundidad y ordenando cada nivel
+fn tree_rows(processes: &[ProcessInfo], matched: &HashSet<u32>, query: &ProcessQuery) -> Vec<ProcessRow> {
+    let by_pid: HashMap<u32, &ProcessInfo> = processes.iter().map(|process| (process.pid, process)).collect();
+    let mut visible: HashSet<u32> = HashSet::new();
+    for pid in matched {
+        let mut current = Some(*pid);
+        while let Some(pid) = current.filter(|pid| visible.insert(*pid)) {
+            current = by_pid
+                .get(&pid)
+                .and_then(|process| process.parent_pid)
+                .filter(|parent| by_pid.contains_key(parent));
+        }
+    }
+
+    let mut children: HashMap<Option<u32>, Vec<&ProcessInfo>> = HashMap::new();
+    for process in processes.iter().filter(|process| visible.contains(&process.pid)) {
+        let parent = process.parent_pid.filter(|parent| visible.contains(parent) && *parent != process.pid);
+        children.entry(parent).or_default().push(process);
+    }
+    for siblings in children.values_mut() {
+        sort(siblings, query);
+    }
+
+    let mut rows = Vec::with_capacity(visible.len());
+    let mut emitted = HashSet::new();
+    let mut pending: Vec<(&ProcessInfo, usize)> =
+        children.get(&None).into_iter().flatten().rev().map(|process| (*process, 0)).collect();
+    while let Some((process, depth)) = pending.pop() {
+        if !emitted.insert(process.pid) {
+            continue;
+        }
+        rows.push(ProcessRow { process: process.clone(), depth, matched: matched.contains(&process.pid) });
+        if let Some(kids) = children.get(&Some(process.pid)) {
+            pending.extend(kids.iter().rev().map(|child| (*child, depth + 1)));
+        }
+    }
+    // Un ciclo padre-hijo (PIDs reutilizados) no tiene raíz: esas filas van al final
+    let orphaned = processes.iter().filter(|process| visible.contains(&process.pid) && !emitted.contains(&process.pid));
+    for process in orphaned {
+        rows.push(ProcessRow { process: process.clone(), depth: 0, matched: matched.contains(&process.pid) });
+    }
+    rows
+}
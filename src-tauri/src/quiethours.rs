@@ -0,0 +1,140 @@
+// src-tauri/src/quiethours.rs
+// Horas de silencio: durante el tramo configurado las alertas no muestran la
+// notificación del sistema (el evento "alert-triggered" se sigue emitiendo y
+// la notificación omitida queda registrada) y, si se pide, las reglas no
+// lanzan su script ni su acción: se anotan en su historial como pausadas. Lo
+// aplica el backend, así rige también con la interfaz cerrada en la bandeja.
+// Los límites diarios de uso no se pausan
+
+use serde::{Serialize, Deserialize};
+use std::collections::VecDeque;
+use tauri::{AppHandle, State};
+
+use crate::AppState;
+
+const LOG_CAPACITY: usize = 200;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+pub struct QuietHours {
+    pub enabled: bool,
+    pub start: String,  // "22:00", hora local
+    pub end: String,    // Anterior a start: el tramo cruza la medianoche; igual: el día entero
+    pub days: Vec<u32>, // Días en que empieza el tramo (0 = domingo); vacío, todos
+    pub pause_automation: bool, // También pausa los scripts y acciones de las reglas
+}
+
+impl Default for QuietHours {
+    fn default() -> Self {
+        QuietHours {
+            enabled: false,
+            start: "22:00".to_string(),
+            end: "07:00".to_string(),
+            days: Vec::new(),
+            pause_automation: false,
+        }
+    }
+}
+
+// Minuto del día de una hora "HH:MM"
+fn parse_time(text: &str) -> Result<u32, String> {
+    let invalid = || format!("Hora no válida (se espera HH:MM): {}", text);
+    let (hours, minutes) = text.trim().split_once(':').ok_or_else(invalid)?;
+    let hours: u32 = hours.parse().map_err(|_| invalid())?;
+    let minutes: u32 = minutes.parse().map_err(|_| invalid())?;
+    if hours > 23 || minutes > 59 {
+        return Err(invalid());
+    }
+    Ok(hours * 60 + minutes)
+}
+
+impl QuietHours {
+    pub fn validate(&self) -> Result<(), String> {
+        parse_time(&self.start)?;
+        parse_time(&self.end)?;
+        if let Some(day) = self.days.iter().find(|day| **day > 6) {
+            return Err(format!("Día de la semana no válido: {} (0 = domingo, 6 = sábado)", day));
+        }
+        Ok(())
+    }
+
+    fn contains(&self, weekday: u32, minute: u32) -> bool {
+        let (Ok(start), Ok(end)) = (parse_time(&self.start), parse_time(&self.end)) else { return false };
+        let starts_on = |day: u32| self.days.is_empty() || self.days.contains(&day);
+        if start < end {
+            return (start..end).contains(&minute) && starts_on(weekday);
+        }
+        // La parte de después de medianoche pertenece al tramo del día anterior
+        (minute >= start && starts_on(weekday)) || (minute < end && starts_on((weekday + 6) % 7))
+    }
+
+    pub fn is_active(&self) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        let (weekday, minute) = crate::locale::local_weekday_minute(crate::stats::now_millis());
+        self.contains(weekday, minute)
+    }
+
+    pub fn pauses_automation(&self) -> bool {
+        self.pause_automation && self.is_active()
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct SuppressedNotification {
+    pub timestamp: u64,
+    pub rule_id: u64, // Alerta que la habría mostrado
+    pub title: String,
+    pub body: String,
+}
+
+// Notificaciones omitidas durante las horas de silencio, para revisarlas después
+#[derive(Debug, Default)]
+pub struct QuietHoursLog {
+    suppressed: VecDeque<SuppressedNotification>,
+}
+
+impl QuietHoursLog {
+    pub fn push(&mut self, notification: SuppressedNotification) {
+        if self.suppressed.len() == LOG_CAPACITY {
+            self.suppressed.pop_front();
+        }
+        self.suppressed.push_back(notification);
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct QuietHoursStatus {
+    pub active: bool,
+    pub automation_paused: bool,
+    pub suppressed: usize, // Notificaciones omitidas en el registro
+}
+
+#[tauri::command]
+pub fn get_quiet_hours_status(state: State<AppState>) -> QuietHoursStatus {
+    let quiet_hours = state.settings.read().unwrap().quiet_hours.clone();
+    QuietHoursStatus {
+        active: quiet_hours.is_active(),
+        automation_paused: quiet_hours.pauses_automation(),
+        suppressed: state.quiet_hours.lock().unwrap().suppressed.len(),
+    }
+}
+
+#[tauri::command]
+pub fn set_quiet_hours(app: AppHandle, state: State<AppState>, quiet_hours: QuietHours) -> Result<(), String> {
+    quiet_hours.validate()?;
+    let mut settings = state.settings.write().unwrap();
+    settings.quiet_hours = quiet_hours;
+    crate::settings::save(&app, &settings)
+}
+
+#[tauri::command]
+pub fn get_suppressed_notifications(state: State<AppState>) -> Vec<SuppressedNotification> {
+    state.quiet_hours.lock().unwrap().suppressed.iter().cloned().collect()
+}
+
+#[tauri::command]
+pub fn clear_suppressed_notifications(state: State<AppState>) {
+    state.quiet_hours.lock().unwrap().suppressed.clear();
+}
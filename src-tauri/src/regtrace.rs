@@ -0,0 +1,141 @@
+// src-tauri/src/regtrace.rs
+// Flujo de actividad del registro de un proceso, a partir de los eventos
+// Registry de ETW (acotado y filtrable)
+
+use serde::{Serialize, Deserialize};
+use std::collections::VecDeque;
+use tauri::State;
+
+use crate::AppState;
+
+const ACTIVITY_CAPACITY: usize = 1000;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RegistryActivity {
+    pub timestamp: u64,
+    pub operation: String, // create_key, open_key, delete_key, query_key, set_value...
+    pub key: String,
+    pub value_name: Option<String>,
+    pub status: u32,       // NTSTATUS (0 = éxito)
+}
+
+#[derive(Debug, Default)]
+pub struct RegistryTracer {
+    #[cfg(target_os = "windows")]
+    key_names: std::collections::HashMap<u64, String>, // KCB -> ruta completa de la clave
+    trace_pid: Option<u32>,
+    filter: Option<String>,          // Subcadena (sin distinguir mayúsculas) de la ruta
+    activity: VecDeque<RegistryActivity>,
+    pub error: Option<String>,
+}
+
+fn matches_filter(activity: &RegistryActivity, filter: &str) -> bool {
+    let filter = filter.to_lowercase();
+    activity.key.to_lowercase().contains(&filter)
+        || activity
+            .value_name
+            .as_ref()
+            .is_some_and(|value| value.to_lowercase().contains(&filter))
+}
+
+impl RegistryTracer {
+    pub fn start_trace(&mut self, pid: u32, filter: Option<String>) {
+        self.trace_pid = Some(pid);
+        self.filter = filter.filter(|filter| !filter.is_empty());
+        self.activity.clear();
+    }
+
+    pub fn stop_trace(&mut self) {
+        self.trace_pid = None;
+    }
+
+    pub fn activity(&self, filter: Option<&str>) -> Vec<RegistryActivity> {
+        self.activity
+            .iter()
+            .filter(|activity| filter.is_none_or(|filter| matches_filter(activity, filter)))
+            .cloned()
+            .collect()
+    }
+
+    // Registry: InitialTime (i64), Status (u32), Index (u32), KeyHandle (KCB), KeyName.
+    // KCBCreate/KCBRundownEnd (22/25) dan la ruta completa de cada KCB
+    #[cfg(target_os = "windows")]
+    pub fn on_event(&mut self, event: &crate::etw::KernelEvent) {
+        if event.provider != crate::etw::REGISTRY_GUID {
+            return;
+        }
+        let (Some(status), Some(handle)) = (event.u32_at(8), event.pointer_at(16)) else {
+            return;
+        };
+        let name = event.wide_string_at(16 + event.pointer_size).unwrap_or_default();
+
+        let operation = match event.opcode {
+            22 | 25 => {
+                self.key_names.insert(handle, name);
+                return;
+            }
+            23 => {
+                self.key_names.remove(&handle);
+                return;
+            }
+            10 => "create_key",
+            11 => "open_key",
+            12 => "delete_key",
+            13 => "query_key",
+            14 => "set_value",
+            15 => "delete_value",
+            16 => "query_value",
+            17 => "enumerate_key",
+            18 => "enumerate_value",
+            20 => "set_information",
+            _ => return,
+        };
+        if self.trace_pid != Some(event.header_pid) {
+            return;
+        }
+
+        let base = self.key_names.get(&handle).cloned().unwrap_or_default();
+        let (key, value_name) = match event.opcode {
+            // En las operaciones de valor, KeyName es el nombre del valor
+            14..=16 | 18 => (base, Some(name)),
+            _ if base.is_empty() => (name, None),
+            _ if name.is_empty() => (base, None),
+            _ => (format!("{}\\{}", base, name), None),
+        };
+        let activity = RegistryActivity {
+            timestamp: crate::stats::now_millis(),
+            operation: operation.to_string(),
+            key,
+            value_name,
+            status,
+        };
+        if self.filter.as_deref().is_some_and(|filter| !matches_filter(&activity, filter)) {
+            return;
+        }
+
+        if self.activity.len() == ACTIVITY_CAPACITY {
+            self.activity.pop_front();
+        }
+        self.activity.push_back(activity);
+    }
+}
+
+#[tauri::command]
+pub fn start_registry_trace(state: State<AppState>, pid: u32, filter: Option<String>) -> Result<(), String> {
+    let mut tracer = state.regtrace.lock().unwrap();
+    if let Some(error) = &tracer.error {
+        return Err(error.clone());
+    }
+    tracer.start_trace(pid, filter);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn stop_registry_trace(state: State<AppState>) {
+    state.regtrace.lock().unwrap().stop_trace();
+}
+
+#[tauri::command]
+pub fn get_registry_activity(state: State<AppState>, filter: Option<String>) -> Vec<RegistryActivity> {
+    state.regtrace.lock().unwrap().activity(filter.as_deref())
+}
@@ -0,0 +1,208 @@
+// src-tauri/src/relaunch.rs
+// Procesos terminados recientemente con su ejecutable, argumentos y
+// directorio de trabajo, para volver a lanzarlos si se cerró el que no era.
+// También reinicia un proceso en ejecución con otros argumentos o entorno
+// (p. ej. un servidor de desarrollo con una opción nueva)
+
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
+use sysinfo::{PidExt, ProcessExt, SystemExt};
+use tauri::State;
+
+use crate::audit::AuditOrigin;
+use crate::unsaved::UnsavedWorkWarning;
+use crate::{AppState, KillOutcome};
+
+const CAPACITY: usize = 20;
+// Espera a que termine el proceso reiniciado antes de lanzar el nuevo
+const EXIT_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Serialize, Clone)]
+pub struct KilledProcess {
+    pub pid: u32,
+    pub name: String,
+    pub exe: String,
+    pub args: Vec<String>,
+    pub cwd: Option<String>,
+    pub killed_at: u64,
+}
+
+// Se toma antes de terminarlo: después el sistema ya no lo conoce. Sin ruta
+// del ejecutable (sin acceso al proceso) no se podría relanzar
+pub fn capture(state: &AppState, pid: u32) -> Option<KilledProcess> {
+    let system = state.system.read().unwrap();
+    let process = system.process(sysinfo::Pid::from_u32(pid))?;
+    let exe = process.exe().to_string_lossy().to_string();
+    if exe.is_empty() {
+        return None;
+    }
+    let cwd = process.cwd().to_string_lossy().to_string();
+    Some(KilledProcess {
+        pid,
+        name: process.name().to_string(),
+        exe,
+        args: process.cmd().iter().skip(1).cloned().collect(),
+        cwd: (!cwd.is_empty()).then_some(cwd),
+        killed_at: 0,
+    })
+}
+
+// Línea de órdenes actual de un proceso, para editarla antes de reiniciarlo
+// con restart_process_with
+#[derive(Debug, Serialize, Clone)]
+pub struct LaunchCommand {
+    pub pid: u32,
+    pub name: String,
+    pub exe: String,
+    pub args: Vec<String>,
+    pub cwd: Option<String>,
+    pub env: BTreeMap<String, String>, // Vacío si no se pudo leer; entonces se hereda el de la aplicación
+}
+
+// Resultado de `restart_process_with`, como el de `kill_process`
+#[derive(Debug, Serialize, Clone)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum RestartOutcome {
+    Restarted { pid: u32 }, // PID del proceso nuevo
+    UnsavedWork(UnsavedWorkWarning),
+}
+
+// En Windows hay variables ocultas que empiezan por "=" ("=C:=C:\\dir")
+fn parse_environ(environ: &[String]) -> BTreeMap<String, String> {
+    environ
+        .iter()
+        .filter_map(|entry| {
+            let split = entry.char_indices().skip(1).find(|(_, c)| *c == '=').map(|(index, _)| index)?;
+            Some((entry[..split].to_string(), entry[split + 1..].to_string()))
+        })
+        .collect()
+}
+
+fn launch_command(state: &AppState, pid: u32) -> Result<LaunchCommand, String> {
+    let killed = capture(state, pid)
+        .ok_or_else(|| format!("No se puede leer la línea de órdenes del proceso {}", pid))?;
+    let system = state.system.read().unwrap();
+    let env = system
+        .process(sysinfo::Pid::from_u32(pid))
+        .map(|process| parse_environ(process.environ()))
+        .unwrap_or_default();
+    Ok(LaunchCommand { pid, name: killed.name, exe: killed.exe, args: killed.args, cwd: killed.cwd, env })
+}
+
+fn wait_exit(pid: u32) -> bool {
+    #[cfg(target_os = "windows")]
+    {
+        crate::win::wait_for_exit(pid, EXIT_TIMEOUT.as_millis() as u64)
+    }
+
+    // Sin handle que esperar: se sondea con la señal 0
+    #[cfg(not(target_os = "windows"))]
+    {
+        let deadline = std::time::Instant::now() + EXIT_TIMEOUT;
+        while unsafe { libc::kill(pid as libc::pid_t, 0) } == 0 {
+            if std::time::Instant::now() >= deadline {
+                return false;
+            }
+            std::thread::sleep(Duration::from_millis(50));
+        }
+        true
+    }
+}
+
+pub fn remember(state: &AppState, mut killed: KilledProcess) {
+    killed.killed_at = crate::stats::now_millis();
+    let mut recent = state.recently_killed.lock().unwrap();
+    recent.retain(|previous| previous.pid != killed.pid);
+    if recent.len() == CAPACITY {
+        recent.pop_front();
+    }
+    recent.push_back(killed);
+}
+
+// Del más reciente al más antiguo
+#[tauri::command]
+pub fn get_recently_killed(state: State<AppState>) -> Vec<KilledProcess> {
+    state.recently_killed.lock().unwrap().iter().rev().cloned().collect()
+}
+
+// Relanza el último proceso terminado, o el del PID indicado, y lo quita de la
+// lista. Devuelve el PID del nuevo proceso
+#[tauri::command]
+pub fn relaunch_last_killed(state: State<AppState>, pid: Option<u32>) -> Result<u32, String> {
+    let killed = {
+        let mut recent = state.recently_killed.lock().unwrap();
+        let index = match pid {
+            Some(pid) => recent.iter().position(|killed| killed.pid == pid),
+            None => recent.len().checked_sub(1),
+        };
+        index
+            .and_then(|index| recent.remove(index))
+            .ok_or_else(|| "No hay ningún proceso terminado que relanzar".to_string())?
+    };
+
+    let mut command = std::process::Command::new(&killed.exe);
+    command.args(&killed.args);
+    if let Some(cwd) = killed.cwd.as_ref().filter(|cwd| std::path::Path::new(cwd).is_dir()) {
+        command.current_dir(cwd);
+    }
+    let result = command
+        .spawn()
+        .map(|child| child.id())
+        .map_err(|e| format!("Error al ejecutar {}: {}", killed.exe, e));
+    let new_pid = result.as_ref().ok().copied();
+    crate::audit::record(&state, AuditOrigin::User, "relaunch", &killed.name, new_pid, None, &result);
+    result
+}
+
+#[tauri::command]
+pub fn get_launch_command(state: State<AppState>, pid: u32) -> Result<LaunchCommand, String> {
+    launch_command(&state, pid)
+}
+
+// Termina el proceso y lo vuelve a lanzar con el mismo ejecutable y entorno.
+// `args` sustituye a los argumentos; en `env` un valor nulo quita la variable;
+// `cwd` cambia el directorio de trabajo. Sin `force` se respeta el aviso de
+// trabajo sin guardar, igual que en kill_process
+#[tauri::command]
+pub fn restart_process_with(
+    state: State<AppState>,
+    pid: u32,
+    args: Option<Vec<String>>,
+    env: Option<HashMap<String, Option<String>>>,
+    cwd: Option<String>,
+    force: Option<bool>,
+) -> Result<RestartOutcome, String> {
+    let force = force.unwrap_or(false);
+    let launch = launch_command(&state, pid)?;
+    crate::actions::run_once(&state, pid, &format!("restart:{}", force), || {
+        if let KillOutcome::UnsavedWork(warning) = crate::terminate_process(&state, pid, force)? {
+            return Ok(RestartOutcome::UnsavedWork(warning));
+        }
+        if !wait_exit(pid) {
+            return Err(format!("El proceso {} no terminó a tiempo; no se ha vuelto a lanzar", pid));
+        }
+        // Ya se relanza aquí; no debe quedar en la lista de terminados
+        state.recently_killed.lock().unwrap().retain(|killed| killed.pid != pid);
+
+        let mut command = std::process::Command::new(&launch.exe);
+        command.args(args.as_ref().unwrap_or(&launch.args));
+        if !launch.env.is_empty() {
+            command.env_clear().envs(&launch.env);
+        }
+        for (key, value) in env.iter().flatten() {
+            match value {
+                Some(value) => command.env(key, value),
+                None => command.env_remove(key),
+            };
+        }
+        let cwd = cwd.or(launch.cwd).filter(|cwd| std::path::Path::new(cwd).is_dir());
+        if let Some(cwd) = cwd {
+            command.current_dir(cwd);
+        }
+        command
+            .spawn()
+            .map(|child| RestartOutcome::Restarted { pid: child.id() })
+            .map_err(|e| format!("Proceso terminado, pero no se pudo volver a ejecutar {}: {}", launch.exe, e))
+    })
+}
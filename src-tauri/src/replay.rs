@@ -0,0 +1,273 @@
+// src-tauri/src/replay.rs
+// Grabación y reproducción de sesiones. La grabación guarda la instantánea de
+// cada refresco completo (una línea JSON por fotograma, con su instante
+// relativo) y la reproducción la vuelve a pasar por refresh_processes en lugar
+// del sistema real: árbol, reglas, alertas e historiales se comportan como en
+// el equipo del usuario, al ritmo original o acelerado
+//
+// Los comandos que leen sysinfo directamente (detalles, servicios...) siguen
+// mostrando el sistema local durante la reproducción
+
+use serde::{Serialize, Deserialize};
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::provider::{MockSystem, SystemProvider, SystemSnapshot};
+use crate::{AppState, ProcessTree};
+
+const MIN_SPEED: f64 = 0.1;
+const MAX_SPEED: f64 = 100.0;
+const MIN_FRAME_DELAY: Duration = Duration::from_millis(20); // Con velocidades altas
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedFrame {
+    elapsed_ms: u64, // Desde el inicio de la grabación
+    snapshot: SystemSnapshot,
+}
+
+struct Recorder {
+    path: PathBuf,
+    writer: BufWriter<File>,
+    started: Instant,
+    frames: usize,
+}
+
+struct Replay {
+    path: PathBuf,
+    frames: MockSystem,
+    timeline: Vec<u64>, // elapsed_ms de cada fotograma
+    speed: f64,
+}
+
+#[derive(Default)]
+pub struct ReplayState {
+    recorder: Option<Recorder>,
+    replay: Option<Replay>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct RecordingSummary {
+    pub path: PathBuf,
+    pub frames: usize,
+    pub duration_ms: u64,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ReplayStatus {
+    pub path: PathBuf,
+    pub frame: usize, // Último fotograma reproducido
+    pub frames: usize,
+    pub elapsed_ms: u64,
+    pub duration_ms: u64,
+    pub speed: f64,
+    pub finished: bool,
+}
+
+impl Replay {
+    fn status(&self) -> ReplayStatus {
+        let frame = self.frames.position().unwrap_or(0);
+        ReplayStatus {
+            path: self.path.clone(),
+            frame,
+            frames: self.timeline.len(),
+            elapsed_ms: self.timeline.get(frame).copied().unwrap_or(0),
+            duration_ms: self.timeline.last().copied().unwrap_or(0),
+            speed: self.speed,
+            finished: frame + 1 >= self.timeline.len(),
+        }
+    }
+}
+
+impl ReplayState {
+    // Fotograma que sustituye al refresco del sistema, si se está reproduciendo
+    pub fn next_frame(&mut self) -> Option<SystemSnapshot> {
+        let replay = self.replay.as_mut()?;
+        replay.frames.advance();
+        Some(replay.frames.snapshot())
+    }
+
+    // Guarda el refresco real; un error de escritura detiene la grabación
+    pub fn record(&mut self, snapshot: &SystemSnapshot) -> Result<(), String> {
+        let Some(recorder) = self.recorder.as_mut() else { return Ok(()) };
+        let elapsed_ms = recorder.started.elapsed().as_millis() as u64;
+        let frame = RecordedFrame { elapsed_ms, snapshot: snapshot.clone() };
+        let written = serde_json::to_writer(&mut recorder.writer, &frame)
+            .map_err(|e| e.to_string())
+            .and_then(|_| recorder.writer.write_all(b"\n").map_err(|e| e.to_string()));
+        match written {
+            Ok(()) => {
+                recorder.frames += 1;
+                Ok(())
+            }
+            Err(e) => {
+                let path = recorder.path.display().to_string();
+                self.recorder = None;
+                Err(format!("Grabación detenida, no se pudo escribir {}: {}", path, e))
+            }
+        }
+    }
+
+    // Espera hasta el siguiente fotograma según la grabación; None fuera de la
+    // reproducción o al llegar al final (se vuelve al intervalo normal)
+    pub fn next_delay(&self) -> Option<Duration> {
+        let replay = self.replay.as_ref()?;
+        let frame = replay.frames.position().unwrap_or(0);
+        let gap = replay.timeline.get(frame + 1)?.saturating_sub(replay.timeline[frame]);
+        Some(Duration::from_secs_f64(gap as f64 / 1000.0 / replay.speed).max(MIN_FRAME_DELAY))
+    }
+}
+
+fn recordings_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join("recordings"))
+        .map_err(|e| format!("No se pudo resolver el directorio de datos: {}", e))
+}
+
+fn check_speed(speed: f64) -> Result<f64, String> {
+    if !(MIN_SPEED..=MAX_SPEED).contains(&speed) {
+        return Err(format!("La velocidad debe estar entre {} y {}", MIN_SPEED, MAX_SPEED));
+    }
+    Ok(speed)
+}
+
+// Refresca con el fotograma actual y lo publica, como haría el muestreo
+fn emit_frame(app: &AppHandle, state: &AppState) -> ProcessTree {
+    let tree = crate::refresh_processes(app, state);
+    let _ = app.emit("process-update", &tree);
+    tree
+}
+
+// Empieza a grabar; sin ruta se crea recordings/recording-<ms>.jsonl en el
+// directorio de datos. Devuelve la ruta del archivo
+#[tauri::command]
+pub fn start_recording(app: AppHandle, state: State<AppState>, path: Option<String>) -> Result<PathBuf, String> {
+    let mut replay_state = state.replay.lock().unwrap();
+    if replay_state.recorder.is_some() {
+        return Err("Ya hay una grabación en curso".to_string());
+    }
+    if replay_state.replay.is_some() {
+        return Err("No se puede grabar durante una reproducción".to_string());
+    }
+    let path = match path.map(|path| path.trim().to_string()).filter(|path| !path.is_empty()) {
+        Some(path) => PathBuf::from(path),
+        None => {
+            let dir = recordings_dir(&app)?;
+            fs::create_dir_all(&dir).map_err(|e| format!("No se pudo crear {}: {}", dir.display(), e))?;
+            dir.join(format!("recording-{}.jsonl", crate::stats::now_millis()))
+        }
+    };
+    let file = File::create(&path).map_err(|e| format!("No se pudo crear {}: {}", path.display(), e))?;
+    replay_state.recorder = Some(Recorder {
+        path: path.clone(),
+        writer: BufWriter::new(file),
+        started: Instant::now(),
+        frames: 0,
+    });
+    Ok(path)
+}
+
+#[tauri::command]
+pub fn stop_recording(state: State<AppState>) -> Result<RecordingSummary, String> {
+    let mut recorder = state.replay.lock().unwrap().recorder.take().ok_or("No hay ninguna grabación en curso")?;
+    recorder.writer.flush().map_err(|e| format!("No se pudo guardar {}: {}", recorder.path.display(), e))?;
+    Ok(RecordingSummary {
+        path: recorder.path,
+        frames: recorder.frames,
+        duration_ms: recorder.started.elapsed().as_millis() as u64,
+    })
+}
+
+// Carga una grabación y sustituye al sistema real desde el siguiente refresco
+#[tauri::command]
+pub fn start_replay(
+    app: AppHandle,
+    state: State<AppState>,
+    path: String,
+    speed: Option<f64>,
+) -> Result<ReplayStatus, String> {
+    let speed = check_speed(speed.unwrap_or(1.0))?;
+    let path = PathBuf::from(path.trim());
+    let file = File::open(&path).map_err(|e| format!("No se pudo abrir {}: {}", path.display(), e))?;
+    let mut timeline = Vec::new();
+    let mut snapshots = Vec::new();
+    for (index, line) in BufReader::new(file).lines().enumerate() {
+        let line = line.map_err(|e| format!("No se pudo leer {}: {}", path.display(), e))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let mut frame: RecordedFrame = serde_json::from_str(&line)
+            .map_err(|e| format!("Fotograma {} no válido en {}: {}", index + 1, path.display(), e))?;
+        // Grabaciones anteriores al timestamp del fotograma
+        if frame.snapshot.timestamp == 0 {
+            frame.snapshot.timestamp = frame.elapsed_ms;
+        }
+        timeline.push(frame.elapsed_ms);
+        snapshots.push(frame.snapshot);
+    }
+    if snapshots.is_empty() {
+        return Err(format!("La grabación {} no tiene fotogramas", path.display()));
+    }
+
+    let status = {
+        let mut replay_state = state.replay.lock().unwrap();
+        if replay_state.recorder.is_some() {
+            return Err("Detén la grabación antes de reproducir".to_string());
+        }
+        let replay = Replay { path, frames: MockSystem::from_frames(snapshots), timeline, speed };
+        let status = replay.status();
+        replay_state.replay = Some(replay);
+        status
+    };
+    crate::sampler::wake();
+    let _ = app.emit("replay-started", &status);
+    Ok(status)
+}
+
+// Salta a un fotograma (empezando en 0) y lo publica enseguida, aunque las
+// actualizaciones estén en pausa: así se puede recorrer la grabación a mano
+#[tauri::command]
+pub fn seek_replay(app: AppHandle, state: State<AppState>, frame: usize) -> Result<ProcessTree, String> {
+    {
+        let mut replay_state = state.replay.lock().unwrap();
+        let replay = replay_state.replay.as_mut().ok_or("No hay ninguna reproducción en curso")?;
+        if frame >= replay.timeline.len() {
+            return Err(format!("La grabación tiene {} fotogramas", replay.timeline.len()));
+        }
+        // next_frame avanza antes de leer
+        replay.frames.seek(frame.checked_sub(1));
+    }
+    let tree = emit_frame(&app, &state);
+    crate::sampler::wake();
+    Ok(tree)
+}
+
+#[tauri::command]
+pub fn set_replay_speed(state: State<AppState>, speed: f64) -> Result<ReplayStatus, String> {
+    let speed = check_speed(speed)?;
+    let mut replay_state = state.replay.lock().unwrap();
+    let replay = replay_state.replay.as_mut().ok_or("No hay ninguna reproducción en curso")?;
+    replay.speed = speed;
+    let status = replay.status();
+    drop(replay_state);
+    crate::sampler::wake();
+    Ok(status)
+}
+
+#[tauri::command]
+pub fn get_replay_status(state: State<AppState>) -> Option<ReplayStatus> {
+    state.replay.lock().unwrap().replay.as_ref().map(Replay::status)
+}
+
+// Vuelve al sistema real y publica un refresco en vivo
+#[tauri::command]
+pub fn stop_replay(app: AppHandle, state: State<AppState>) -> Result<(), String> {
+    state.replay.lock().unwrap().replay.take().ok_or("No hay ninguna reproducción en curso")?;
+    let _ = app.emit("replay-stopped", ());
+    emit_frame(&app, &state);
+    crate::sampler::wake();
+    Ok(())
+}
@@ -0,0 +1,216 @@
+// src-tauri/src/report.rs
+// Informe HTML autocontenido con el estado de la máquina, para compartir con IT
+
+use std::fs;
+use sysinfo::{NetworkExt, SystemExt};
+use tauri::{AppHandle, Manager};
+
+use crate::connections::Connection;
+use crate::format::{format_bytes, FormatOptions};
+use crate::netinfo::NetworkInfo;
+use crate::provider::SystemProvider;
+use crate::stats::SystemStats;
+use crate::{AppState, ProcessInfo};
+
+const TOP_COUNT: usize = 10;
+const CONNECTION_COUNT: usize = 50; // Conexiones establecidas listadas como máximo
+
+pub fn html_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// Gráfico de línea en SVG en línea, sin dependencias externas
+fn svg_chart(title: &str, values: &[f64]) -> String {
+    const WIDTH: f64 = 600.0;
+    const HEIGHT: f64 = 120.0;
+
+    if values.len() < 2 {
+        return format!("<h3>{}</h3><p>Sin datos de historial suficientes.</p>", html_escape(title));
+    }
+    let max = values.iter().cloned().fold(f64::MIN, f64::max);
+    let min = values.iter().cloned().fold(f64::MAX, f64::min);
+    let range = if max > min { max - min } else { 1.0 };
+    let step = WIDTH / (values.len() - 1) as f64;
+    let points: Vec<String> = values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| format!("{:.1},{:.1}", i as f64 * step, HEIGHT - (value - min) / range * HEIGHT))
+        .collect();
+
+    format!(
+        "<h3>{} (mín. {:.0}, máx. {:.0})</h3>\
+         <svg width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\"><polyline fill=\"none\" stroke=\"#2563eb\" stroke-width=\"2\" points=\"{}\"/></svg>",
+        html_escape(title), min, max, WIDTH, HEIGHT, WIDTH, HEIGHT, points.join(" ")
+    )
+}
+
+fn process_table(title: &str, processes: &[&ProcessInfo], options: &FormatOptions) -> String {
+    let rows: String = processes
+        .iter()
+        .map(|process| {
+            format!(
+                "<tr><td>{}</td><td>{}</td><td>{} %</td><td>{}</td></tr>",
+                process.pid,
+                html_escape(&process.name),
+                options.locale().number(process.cpu_usage as f64, 1),
+                format_bytes(process.memory_usage as f64, options)
+            )
+        })
+        .collect();
+    format!(
+        "<h2>{}</h2><table><tr><th>PID</th><th>Nombre</th><th>CPU</th><th>Memoria</th></tr>{}</table>",
+        html_escape(title), rows
+    )
+}
+
+pub fn render_report(
+    system: &sysinfo::System,
+    processes: &[ProcessInfo],
+    history: &[SystemStats],
+    network: Option<&NetworkInfo>,
+    connections: &[Connection],
+    options: &FormatOptions,
+) -> String {
+    let mut html = String::from(
+        "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>Informe del sistema</title><style>\
+         body{font-family:sans-serif;margin:2em;color:#111}table{border-collapse:collapse;margin-bottom:1em}\
+         td,th{border:1px solid #ccc;padding:4px 8px;text-align:left}th{background:#f3f4f6}\
+         </style></head><body><h1>Informe del sistema</h1>",
+    );
+
+    // Resumen
+    html.push_str(&format!(
+        "<h2>Resumen</h2><table>\
+         <tr><th>Equipo</th><td>{}</td></tr><tr><th>Sistema</th><td>{}</td></tr>\
+         <tr><th>Tiempo activo</th><td>{} h</td></tr><tr><th>CPUs lógicas</th><td>{}</td></tr>\
+         <tr><th>Memoria</th><td>{} de {}</td></tr><tr><th>Procesos</th><td>{}</td></tr></table>",
+        html_escape(&system.host_name().unwrap_or_default()),
+        html_escape(&system.long_os_version().unwrap_or_default()),
+        system.uptime() / 3600,
+        system.cpus().len(),
+        format_bytes(system.used_memory() as f64, options),
+        format_bytes(system.total_memory() as f64, options),
+        processes.len()
+    ));
+
+    // Mayores consumidores
+    let mut by_cpu: Vec<&ProcessInfo> = processes.iter().collect();
+    by_cpu.sort_by(|a, b| b.cpu_usage.total_cmp(&a.cpu_usage));
+    by_cpu.truncate(TOP_COUNT);
+    html.push_str(&process_table("Mayor uso de CPU", &by_cpu, options));
+
+    let mut by_memory: Vec<&ProcessInfo> = processes.iter().collect();
+    by_memory.sort_by_key(|process| std::cmp::Reverse(process.memory_usage));
+    by_memory.truncate(TOP_COUNT);
+    html.push_str(&process_table("Mayor uso de memoria", &by_memory, options));
+
+    // Historial
+    html.push_str("<h2>Historial</h2>");
+    let process_counts: Vec<f64> = history.iter().map(|s| s.process_count as f64).collect();
+    html.push_str(&svg_chart("Procesos", &process_counts));
+    let thread_counts: Vec<f64> = history.iter().filter_map(|s| s.thread_count).map(|t| t as f64).collect();
+    html.push_str(&svg_chart("Hilos", &thread_counts));
+
+    // Red
+    html.push_str("<h2>Red</h2><table><tr><th>Interfaz</th><th>Recibido</th><th>Enviado</th></tr>");
+    for (name, network) in system.networks() {
+        html.push_str(&format!(
+            "<tr><td>{}</td><td>{}</td><td>{}</td></tr>",
+            html_escape(name),
+            format_bytes(network.total_received() as f64, options),
+            format_bytes(network.total_transmitted() as f64, options)
+        ));
+    }
+    html.push_str("</table>");
+
+    if let Some(network) = network {
+        let proxy = &network.proxy;
+        let server = if proxy.enabled { proxy.server.as_deref().unwrap_or("-") } else { "Desactivado" };
+        html.push_str(&format!(
+            "<table><tr><th>Proxy</th><td>{}</td></tr><tr><th>Script PAC</th><td>{}</td></tr></table>",
+            html_escape(server),
+            html_escape(proxy.auto_config_url.as_deref().unwrap_or("-"))
+        ));
+        if !network.vpn_adapters.is_empty() {
+            html.push_str(
+                "<h3>Adaptadores VPN</h3><table><tr><th>Nombre</th><th>Direcciones</th><th>Procesos</th></tr>",
+            );
+            for adapter in &network.vpn_adapters {
+                html.push_str(&format!(
+                    "<tr><td>{} ({})</td><td>{}</td><td>{}</td></tr>",
+                    html_escape(&adapter.name),
+                    html_escape(&adapter.description),
+                    html_escape(&adapter.addresses.join(", ")),
+                    adapter.pids.len()
+                ));
+            }
+            html.push_str("</table>");
+        }
+    }
+
+    // Conexiones activas, con el proceso propietario
+    let established: Vec<&Connection> =
+        connections.iter().filter(|connection| connection.state == "established").collect();
+    let udp_count = connections.iter().filter(|connection| connection.protocol == "udp").count();
+    html.push_str(&format!(
+        "<h3>Conexiones activas</h3><p>{} conexiones TCP establecidas, {} puertos UDP abiertos.</p>",
+        established.len(),
+        udp_count
+    ));
+    if !established.is_empty() {
+        html.push_str("<table><tr><th>PID</th><th>Proceso</th><th>Local</th><th>Remoto</th></tr>");
+        for connection in established.iter().take(CONNECTION_COUNT) {
+            let name = processes.iter().find(|process| process.pid == connection.pid).map_or("", |p| &p.name);
+            html.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td>{}:{}</td><td>{}:{}</td></tr>",
+                connection.pid,
+                html_escape(name),
+                html_escape(&connection.local_address),
+                connection.local_port,
+                html_escape(&connection.remote_address),
+                connection.remote_port
+            ));
+        }
+        html.push_str("</table>");
+    }
+
+    html.push_str(&format!(
+        "<p><small>Generado: {}</small></p></body></html>",
+        html_escape(&options.locale().timestamp(crate::stats::now_millis()))
+    ));
+    html
+}
+
+// Tarea en segundo plano; el resultado ("job-finished") es la ruta del informe
+#[tauri::command]
+pub fn generate_report(app: AppHandle, path: String) -> u64 {
+    crate::jobs::spawn(&app.clone(), "report", move |job| {
+        let state = app.state::<AppState>();
+        let options = state.settings.read().unwrap().format;
+        let history = state.history.read().unwrap().since(0);
+        job.progress(0.1, "Recopilando procesos");
+        job.check_cancelled()?;
+        job.progress(0.3, "Recopilando la red");
+        let network = crate::netinfo::get_network_info().ok();
+        let connections = crate::connections::get_connections(None).unwrap_or_default();
+        job.check_cancelled()?;
+        let html = {
+            // Como en get_processes, con el muestreo activo ya está al día
+            if !crate::sampler::is_active() {
+                state.refresh_schedule.lock().unwrap().refresh(&mut state.system.write().unwrap());
+            }
+            let system = state.system.read().unwrap();
+            let tree = crate::build_process_tree(&system.snapshot(), None);
+            job.progress(0.5, "Generando el informe");
+            render_report(&system, &tree.processes, &history, network.as_ref(), &connections, &options)
+        };
+        job.check_cancelled()?;
+        job.progress(0.9, "Guardando el informe");
+        fs::write(&path, html).map_err(|e| format!("No se pudo escribir el informe: {}", e))?;
+        Ok(path)
+    })
+}
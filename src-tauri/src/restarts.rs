@@ -0,0 +1,97 @@
+// src-tauri/src/restarts.rs
+// Detección de bucles de reinicio: se cuentan los arranques de cada
+// ejecutable y se marcan los que arrancan más de N veces en M minutos
+// (servicios o agentes que se caen y vuelven a lanzarse sin parar)
+
+use serde::Serialize;
+use std::collections::{HashMap, HashSet, VecDeque};
+use tauri::State;
+
+use crate::stats::now_millis;
+use crate::{AppState, ProcessInfo};
+
+#[derive(Debug, Serialize, Clone)]
+pub struct RestartLoop {
+    pub name: String,
+    pub starts: u32, // Arranques dentro de la ventana
+    pub window_minutes: u64,
+    pub last_start: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct RestartTracker {
+    known: HashSet<u32>,
+    starts: HashMap<String, VecDeque<u64>>, // Ejecutable -> instantes de arranque
+    flagged: HashSet<String>,
+    seeded: bool,
+}
+
+impl RestartTracker {
+    // Registra los procesos nuevos de este refresco y devuelve los ejecutables
+    // que acaban de entrar en bucle
+    pub fn record(&mut self, processes: &[ProcessInfo], threshold: u32, window_minutes: u64) -> Vec<RestartLoop> {
+        let now = now_millis();
+        let since = now.saturating_sub(window_minutes * 60_000);
+        let current: HashSet<u32> = processes.iter().map(|process| process.pid).collect();
+        // En el primer refresco todos los procesos son "nuevos": solo se toman de referencia
+        if self.seeded {
+            for process in processes.iter().filter(|process| !self.known.contains(&process.pid)) {
+                self.starts.entry(process.name.clone()).or_default().push_back(now);
+            }
+        }
+        self.known = current;
+        self.seeded = true;
+
+        let mut started = Vec::new();
+        self.starts.retain(|_, starts| {
+            while starts.front().is_some_and(|start| *start < since) {
+                starts.pop_front();
+            }
+            !starts.is_empty()
+        });
+        // Sale del bucle en cuanto los arranques de la ventana bajan del umbral
+        self.flagged
+            .retain(|name| self.starts.get(name).is_some_and(|starts| starts.len() as u32 >= threshold));
+        for (name, starts) in &self.starts {
+            if starts.len() as u32 >= threshold && self.flagged.insert(name.clone()) {
+                started.push(RestartLoop {
+                    name: name.clone(),
+                    starts: starts.len() as u32,
+                    window_minutes,
+                    last_start: starts.back().copied().unwrap_or(now),
+                });
+            }
+        }
+        started
+    }
+
+    pub fn annotate(&self, processes: &mut [ProcessInfo]) {
+        for process in processes {
+            process.restart_loop = self.flagged.contains(&process.name);
+        }
+    }
+
+    fn loops(&self, window_minutes: u64) -> Vec<RestartLoop> {
+        let mut loops: Vec<RestartLoop> = self
+            .flagged
+            .iter()
+            .filter_map(|name| {
+                let starts = self.starts.get(name)?;
+                Some(RestartLoop {
+                    name: name.clone(),
+                    starts: starts.len() as u32,
+                    window_minutes,
+                    last_start: starts.back().copied()?,
+                })
+            })
+            .collect();
+        loops.sort_by_key(|restart_loop| std::cmp::Reverse(restart_loop.starts));
+        loops
+    }
+}
+
+#[tauri::command]
+pub fn get_restart_loops(state: State<AppState>) -> Vec<RestartLoop> {
+    let window_minutes = state.settings.read().unwrap().restart_loop_window_minutes;
+    state.restarts.lock().unwrap().loops(window_minutes)
+}
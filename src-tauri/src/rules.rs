@@ -0,0 +1,299 @@
+// src-tauri/src/rules.rs
+// Reglas de alerta: una condición que pasa a cumplirse, un proceso que
+// arranca o termina o un presupuesto diario de CPU agotado dispara un evento "rule-triggered" y, opcionalmente, un
+// script del usuario con variables de entorno que describen el evento, o
+// termina o suspende el proceso. En modo simulación solo se anota lo que se
+// habría hecho, para revisarlo antes de confiar en la automatización
+
+use serde::{Serialize, Deserialize};
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, State};
+
+use crate::apphistory::AppHistory;
+use crate::audit::AuditOrigin;
+use crate::expressions::{Expr, MetricContext};
+use crate::{AppState, ProcessInfo};
+
+const LOG_CAPACITY: usize = 200;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RuleTrigger {
+    // Condición (`worker.exe.total_cpu > 40`); se dispara al pasar a cumplirse.
+    // Con `target`, el script y la acción reciben la instancia del ejecutable
+    // que más CPU usa
+    Expression {
+        expression: String,
+        #[serde(default)]
+        target: Option<String>,
+    },
+    ProcessStarted { name: String },
+    ProcessExited { name: String },
+    // Tiempo de CPU acumulado hoy por todas las instancias del ejecutable
+    CpuBudget { name: String, cpu_minutes: f64 },
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleAction {
+    Kill,
+    Suspend,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_cooldown() -> u64 {
+    60
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Rule {
+    pub name: String,
+    pub trigger: RuleTrigger,
+    #[serde(default)]
+    pub script: Option<String>, // Ejecutable o script a lanzar
+    #[serde(default)]
+    pub args: Vec<String>,
+    #[serde(default = "default_cooldown")]
+    pub cooldown_secs: u64,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    #[serde(default)]
+    pub min_idle_secs: Option<u64>, // Solo se dispara con el usuario inactivo al menos este tiempo
+    #[serde(default)]
+    pub action: Option<RuleAction>, // Sobre el proceso del evento; sin efecto en salidas y condiciones sin target
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RuleEvent {
+    pub timestamp: u64,
+    pub rule: String,
+    pub event: String, // expression, process_started, process_exited, cpu_budget
+    pub pid: Option<u32>,
+    pub name: Option<String>,
+    pub value: Option<f64>,
+    pub script_error: Option<String>,
+    #[serde(default)]
+    pub action: Option<RuleAction>, // Acción aplicada (o que se habría aplicado)
+    #[serde(default)]
+    pub action_error: Option<String>,
+    #[serde(default)]
+    pub simulated: bool, // Ni el script ni la acción se ejecutaron
+    #[serde(default)]
+    pub paused: bool, // Tampoco, por las horas de silencio
+}
+
+#[derive(Debug, Default)]
+pub struct RuleEngine {
+    active: HashMap<String, bool>,        // Último resultado de cada regla de expresión
+    known: Option<HashMap<u32, String>>,  // Procesos del refresco anterior
+    last_fired: HashMap<String, Instant>,
+    log: VecDeque<RuleEvent>,
+}
+
+// El script recibe el evento en variables TM_*
+fn run_script(rule: &Rule, event: &RuleEvent, process: Option<&ProcessInfo>) -> Result<(), String> {
+    let Some(script) = &rule.script else {
+        return Ok(());
+    };
+    let mut command = std::process::Command::new(script);
+    command
+        .args(&rule.args)
+        .env("TM_RULE", &event.rule)
+        .env("TM_EVENT", &event.event)
+        .env("TM_PID", event.pid.map(|pid| pid.to_string()).unwrap_or_default())
+        .env("TM_PROCESS_NAME", event.name.clone().unwrap_or_default())
+        .env("TM_VALUE", event.value.map(|value| value.to_string()).unwrap_or_default());
+    if let Some(process) = process {
+        command
+            .env("TM_CPU_USAGE", process.cpu_usage.to_string())
+            .env("TM_MEMORY_USAGE", process.memory_usage.to_string());
+    }
+    command
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("Error al ejecutar {}: {}", script, e))
+}
+
+pub fn validate(rule: &Rule) -> Result<(), String> {
+    if rule.name.trim().is_empty() {
+        return Err("La regla necesita un nombre".to_string());
+    }
+    if let RuleTrigger::Expression { expression, target } = &rule.trigger {
+        if !Expr::parse(expression)?.is_condition() {
+            return Err(format!("La regla {} necesita una condición, p. ej. `a > b`", rule.name));
+        }
+        if target.as_ref().is_some_and(|target| target.trim().is_empty()) {
+            return Err(format!("Falta el ejecutable de la regla {}", rule.name));
+        }
+    }
+    Ok(())
+}
+
+// Termina o suspende el proceso de una regla y lo anota en la auditoría
+pub fn apply_action(state: &AppState, rule: &str, action: RuleAction, pid: u32) -> Result<(), String> {
+    let target = crate::audit::process_name(state, pid);
+    let (name, result) = match action {
+        RuleAction::Kill => ("kill:true", crate::terminate_process(state, pid, true).map(|_| ())),
+        RuleAction::Suspend => ("suspend:false", crate::suspend::set_suspended(state, pid, true)),
+    };
+    let detail = Some(format!("Regla: {}", rule));
+    crate::audit::record(state, AuditOrigin::Automation, name, &target, Some(pid), detail, &result);
+    result
+}
+
+// Qué ejecuta una regla al dispararse
+pub struct Executor<'a> {
+    pub simulate: bool,
+    pub paused: bool, // Horas de silencio con la automatización en pausa
+    pub act: &'a mut dyn FnMut(&str, RuleAction, u32) -> Result<(), String>, // Regla, acción y PID
+}
+
+impl RuleEngine {
+    pub fn push_log(&mut self, event: RuleEvent) {
+        if self.log.len() == LOG_CAPACITY {
+            self.log.pop_front();
+        }
+        self.log.push_back(event);
+    }
+
+    // Respeta el enfriamiento de la regla; el evento aún no se ha ejecutado
+    fn fire(&mut self, rule: &Rule, event: &str, pid: Option<u32>, name: Option<String>, value: Option<f64>) -> Option<RuleEvent> {
+        let cooldown = Duration::from_secs(rule.cooldown_secs);
+        if self.last_fired.get(&rule.name).is_some_and(|at| at.elapsed() < cooldown) {
+            return None;
+        }
+        self.last_fired.insert(rule.name.clone(), Instant::now());
+        Some(RuleEvent {
+            timestamp: crate::stats::now_millis(),
+            rule: rule.name.clone(),
+            event: event.to_string(),
+            pid,
+            name,
+            value,
+            script_error: None,
+            // El PID de un proceso que ya terminó puede estar reutilizado
+            action: rule.action.filter(|_| pid.is_some() && event != "process_exited"),
+            action_error: None,
+            simulated: false,
+            paused: false,
+        })
+    }
+
+    // Lanza el script y la acción (salvo en simulación o en pausa) y lo anota en el historial
+    fn execute(&mut self, rule: &Rule, mut event: RuleEvent, process: Option<&ProcessInfo>, executor: &mut Executor) -> RuleEvent {
+        event.simulated = executor.simulate;
+        event.paused = executor.paused;
+        if !executor.simulate && !executor.paused {
+            event.script_error = run_script(rule, &event, process).err();
+            if let (Some(action), Some(pid)) = (event.action, event.pid) {
+                event.action_error = (executor.act)(&rule.name, action, pid).err();
+            }
+        }
+        self.push_log(event.clone());
+        event
+    }
+
+    // Evalúa las reglas con los datos de un refresco y devuelve las disparadas
+    pub fn evaluate(
+        &mut self,
+        rules: &[Rule],
+        context: &MetricContext,
+        processes: &[ProcessInfo],
+        history: &AppHistory,
+        executor: &mut Executor,
+    ) -> Vec<RuleEvent> {
+        let current: HashMap<u32, String> = processes.iter().map(|process| (process.pid, process.name.clone())).collect();
+        // En el primer refresco no hay con qué comparar
+        let previous = self.known.replace(current.clone()).unwrap_or_else(|| current.clone());
+        let started: Vec<&ProcessInfo> = processes.iter().filter(|process| !previous.contains_key(&process.pid)).collect();
+        let exited: Vec<(u32, &String)> = previous
+            .iter()
+            .filter(|(pid, _)| !current.contains_key(pid))
+            .map(|(pid, name)| (*pid, name))
+            .collect();
+
+        self.active.retain(|name, _| rules.iter().any(|rule| &rule.name == name));
+        let idle = crate::idle::idle_seconds();
+        let mut fired = Vec::new();
+        for rule in rules.iter().filter(|rule| rule.enabled) {
+            // Sin dato de inactividad la condición no se cumple
+            let idle_ok = rule.min_idle_secs.is_none_or(|min| idle.is_some_and(|idle| idle >= min));
+            match &rule.trigger {
+                RuleTrigger::Expression { expression, target } => {
+                    let value = Expr::parse(expression).and_then(|expr| expr.eval(&|name| context.get(name)));
+                    let Ok(value) = value else { continue };
+                    let active = value != 0.0 && idle_ok;
+                    let was_active = self.active.insert(rule.name.clone(), active).unwrap_or(false);
+                    if active && !was_active {
+                        let process = target.as_ref().and_then(|target| {
+                            processes
+                                .iter()
+                                .filter(|process| crate::text::same_name(&process.name, target))
+                                .max_by(|a, b| a.cpu_usage.total_cmp(&b.cpu_usage))
+                        });
+                        let pid = process.map(|process| process.pid);
+                        let name = process.map(|process| process.name.clone());
+                        if let Some(event) = self.fire(rule, "expression", pid, name, Some(value)) {
+                            fired.push(self.execute(rule, event, process, executor));
+                        }
+                    }
+                }
+                RuleTrigger::CpuBudget { name, cpu_minutes } => {
+                    // Al cambiar de día el acumulado vuelve a cero y la regla se rearma
+                    let used = history.today_usage(name).map_or(0.0, |usage| usage.cpu_seconds / 60.0);
+                    let active = used >= *cpu_minutes && idle_ok;
+                    let was_active = self.active.insert(rule.name.clone(), active).unwrap_or(false);
+                    if active && !was_active {
+                        let process = processes.iter().find(|process| crate::text::same_name(&process.name, name));
+                        let pid = process.map(|process| process.pid);
+                        if let Some(event) = self.fire(rule, "cpu_budget", pid, Some(name.clone()), Some(used)) {
+                            fired.push(self.execute(rule, event, process, executor));
+                        }
+                    }
+                }
+                RuleTrigger::ProcessStarted { .. } | RuleTrigger::ProcessExited { .. } if !idle_ok => {}
+                RuleTrigger::ProcessStarted { name } => {
+                    for process in started.iter().filter(|process| crate::text::same_name(&process.name, name)) {
+                        let name = Some(process.name.clone());
+                        if let Some(event) = self.fire(rule, "process_started", Some(process.pid), name, None) {
+                            fired.push(self.execute(rule, event, Some(process), executor));
+                        }
+                    }
+                }
+                RuleTrigger::ProcessExited { name } => {
+                    let matching = exited.iter().filter(|(_, exited_name)| crate::text::same_name(exited_name, name));
+                    for (pid, exited_name) in matching {
+                        let name = Some(exited_name.to_string());
+                        if let Some(event) = self.fire(rule, "process_exited", Some(*pid), name, None) {
+                            fired.push(self.execute(rule, event, None, executor));
+                        }
+                    }
+                }
+            }
+        }
+        fired
+    }
+}
+
+// Historial reciente de reglas disparadas
+#[tauri::command]
+pub fn get_rule_log(state: State<AppState>) -> Vec<RuleEvent> {
+    state.rules.lock().unwrap().log.iter().cloned().collect()
+}
+
+// Lo que las reglas y los límites habrían hecho en modo simulación
+#[tauri::command]
+pub fn get_simulated_actions(state: State<AppState>) -> Vec<RuleEvent> {
+    state.rules.lock().unwrap().log.iter().filter(|event| event.simulated).cloned().collect()
+}
+
+#[tauri::command]
+pub fn set_simulation_mode(app: AppHandle, state: State<AppState>, enabled: bool) -> Result<(), String> {
+    let mut settings = state.settings.write().unwrap();
+    settings.simulate_automation = enabled;
+    crate::settings::save(&app, &settings)
+}
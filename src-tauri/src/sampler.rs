@@ -0,0 +1,140 @@
+// src-tauri/src/sampler.rs
+// Muestreo de procesos en segundo plano: refresca al intervalo de la
+// configuración y envía el árbol en el evento "process-update", de modo que la
+// UI no tiene que sondear get_processes
+
+use std::panic::AssertUnwindSafe;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Condvar, Mutex};
+use std::time::{Duration, Instant};
+use sysinfo::{ProcessRefreshKind, System, SystemExt};
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::AppState;
+
+const MIN_INTERVAL_MS: u64 = 250; // Por debajo, sysinfo no llega a medir la CPU
+const MAX_INTERVAL_MS: u64 = 60_000;
+
+// Lo que cambia poco se refresca con menos frecuencia que los procesos
+const DISKS_EVERY: Duration = Duration::from_secs(10);
+const COMPONENTS_EVERY: Duration = Duration::from_secs(30);
+const NETWORKS_LIST_EVERY: Duration = Duration::from_secs(60); // Interfaces nuevas (VPN, adaptadores USB)
+
+static RUNNING: AtomicBool = AtomicBool::new(false);
+static PAUSED: AtomicBool = AtomicBool::new(false);
+// Despierta al hilo cuando cambia el intervalo o se reanuda
+static WAKE: (Mutex<()>, Condvar) = (Mutex::new(()), Condvar::new());
+
+// Sustituye a refresh_all en cada muestreo: de los procesos solo la CPU y la
+// E/S de disco (el usuario y demás detalles los refresca details.rs al pedirlos)
+#[derive(Debug, Default)]
+pub struct RefreshSchedule {
+    disks: Option<Instant>,
+    components: Option<Instant>,
+    networks_list: Option<Instant>,
+}
+
+fn due(last: &mut Option<Instant>, every: Duration) -> bool {
+    if last.is_some_and(|last| last.elapsed() < every) {
+        return false;
+    }
+    *last = Some(Instant::now());
+    true
+}
+
+impl RefreshSchedule {
+    pub fn refresh(&mut self, system: &mut System) {
+        system.refresh_cpu();
+        system.refresh_memory();
+        system.refresh_processes_specifics(ProcessRefreshKind::new().with_cpu().with_disk_usage());
+        // Ambas actualizan los contadores de red: los bytes desde el refresco
+        // anterior siguen siendo válidos para las tasas
+        if due(&mut self.networks_list, NETWORKS_LIST_EVERY) {
+            system.refresh_networks_list();
+        } else {
+            system.refresh_networks();
+        }
+        if due(&mut self.disks, DISKS_EVERY) {
+            system.refresh_disks();
+        }
+        if due(&mut self.components, COMPONENTS_EVERY) {
+            system.refresh_components();
+        }
+    }
+}
+
+pub fn is_active() -> bool {
+    RUNNING.load(Ordering::Relaxed) && !PAUSED.load(Ordering::Relaxed)
+}
+
+pub fn wake() {
+    WAKE.1.notify_all();
+}
+
+pub fn start(app: AppHandle) {
+    RUNNING.store(true, Ordering::Relaxed);
+    std::thread::spawn(move || loop {
+        let state = app.state::<AppState>();
+        if !PAUSED.load(Ordering::Relaxed) {
+            // Último recurso: un pánico fuera de los colectores pierde este
+            // refresco, pero no detiene el muestreo. Se anota en la salud de
+            // los colectores y se limpian los mutex que dejó envenenados
+            let refreshed = std::panic::catch_unwind(AssertUnwindSafe(|| crate::refresh_processes(&app, &state)));
+            match refreshed {
+                Ok(tree) => {
+                    let _ = app.emit("process-update", &tree);
+                    crate::tray::set_tooltip(&app, &crate::summary::describe(&state));
+                }
+                Err(panic) => {
+                    state.clear_poison();
+                    let message = crate::health::panic_message(panic.as_ref());
+                    state.collector_health.lock().unwrap().refresh_panicked(message);
+                }
+            }
+        }
+        // Mientras el usuario está ausente se refresca con menos frecuencia
+        let interval = {
+            let settings = state.settings.read().unwrap();
+            let idle = crate::idle::idle_seconds().is_some_and(|seconds| seconds >= settings.idle_threshold_secs);
+            crate::idle::refresh_interval(&settings, idle)
+        };
+        // Al reproducir una grabación se respeta el ritmo con que se grabó
+        let wait = match state.replay.lock().unwrap().next_delay() {
+            Some(delay) => delay,
+            None => Duration::from_millis(interval.max(MIN_INTERVAL_MS)),
+        };
+        let guard = WAKE.0.lock().unwrap();
+        let _ = WAKE.1.wait_timeout(guard, wait);
+    });
+}
+
+pub fn validate_interval(interval_ms: u64) -> Result<(), String> {
+    if !(MIN_INTERVAL_MS..=MAX_INTERVAL_MS).contains(&interval_ms) {
+        return Err(format!(
+            "El intervalo debe estar entre {} y {} ms",
+            MIN_INTERVAL_MS, MAX_INTERVAL_MS
+        ));
+    }
+    Ok(())
+}
+
+// Cambia y guarda el intervalo; se aplica de inmediato
+#[tauri::command]
+pub fn set_refresh_interval(app: AppHandle, state: State<AppState>, interval_ms: u64) -> Result<(), String> {
+    validate_interval(interval_ms)?;
+    let mut settings = state.settings.write().unwrap();
+    settings.refresh_interval_ms = interval_ms;
+    crate::settings::save(&app, &settings)?;
+    drop(settings);
+    wake();
+    Ok(())
+}
+
+// En pausa no se emiten eventos y get_processes vuelve a refrescar a demanda
+#[tauri::command]
+pub fn pause_updates(paused: bool) {
+    PAUSED.store(paused, Ordering::Relaxed);
+    if !paused {
+        wake();
+    }
+}
@@ -0,0 +1,39 @@
+// src-tauri/src/schema.rs
+// Versión del formato de ProcessTree y SystemStats, para que la interfaz, las
+// exportaciones y los agentes remotos evolucionen por separado. Al leer, los
+// campos que falten toman su valor por defecto; una carga sin
+// `schema_version` es anterior al versionado (versión 1)
+
+use serde::{Serialize, Deserialize};
+
+const CURRENT: u32 = 2;
+const LEGACY: u32 = 1;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(transparent)]
+pub struct SchemaVersion(pub u32);
+
+// Lo que se construye en este proceso lleva siempre la versión actual
+impl Default for SchemaVersion {
+    fn default() -> Self {
+        SchemaVersion(CURRENT)
+    }
+}
+
+impl SchemaVersion {
+    // Para `#[serde(default = ...)]`: el campo no existía en la versión 1
+    pub fn legacy() -> Self {
+        SchemaVersion(LEGACY)
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct SchemaInfo {
+    pub schema_version: u32,
+    pub min_supported: u32, // Las cargas desde esta versión se leen sin perder datos
+}
+
+#[tauri::command]
+pub fn get_schema_info() -> SchemaInfo {
+    SchemaInfo { schema_version: CURRENT, min_supported: LEGACY }
+}
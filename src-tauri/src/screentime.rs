@@ -0,0 +1,230 @@
+// src-tauri/src/screentime.rs
+// Tiempo de pantalla: qué proceso tiene la ventana en primer plano en cada
+// momento (SetWinEventHook) y desglose diario por aplicación
+
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager, State};
+
+use crate::AppState;
+
+const TIMELINE_CAPACITY: usize = 1000;
+const TICK: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ForegroundSpan {
+    pub pid: u32,
+    pub name: String,
+    pub start: u64, // Milisegundos desde UNIX_EPOCH
+    pub end: Option<u64>, // None mientras sigue en primer plano
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ScreenTimeEntry {
+    pub name: String,
+    pub seconds: f64,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ScreenTime {
+    pub day: String,
+    pub total_seconds: f64,
+    pub apps: Vec<ScreenTimeEntry>,
+}
+
+struct Tracker {
+    current: Option<(u32, String)>,
+    credited_at: Instant,
+    pending: HashMap<String, f64>, // Segundos aún no pasados al historial
+    timeline: VecDeque<ForegroundSpan>,
+}
+
+static TRACKER: Mutex<Option<Tracker>> = Mutex::new(None);
+
+fn with_tracker<T>(f: impl FnOnce(&mut Tracker) -> T) -> T {
+    let mut tracker = TRACKER.lock().unwrap();
+    f(tracker.get_or_insert_with(|| Tracker {
+        current: None,
+        credited_at: Instant::now(),
+        pending: HashMap::new(),
+        timeline: VecDeque::new(),
+    }))
+}
+
+impl Tracker {
+    fn credit_current(&mut self) {
+        let now = Instant::now();
+        let elapsed = (now - self.credited_at).as_secs_f64();
+        self.credited_at = now;
+        if let Some((_, name)) = &self.current {
+            *self.pending.entry(name.to_lowercase()).or_insert(0.0) += elapsed;
+        }
+    }
+}
+
+// Registra un cambio de primer plano (hook o sondeo)
+fn observe(pid: u32, name: String) {
+    with_tracker(|tracker| {
+        if tracker.current.as_ref().is_some_and(|(current, _)| *current == pid) {
+            return;
+        }
+        tracker.credit_current();
+        let now = crate::stats::now_millis();
+        if let Some(open) = tracker.timeline.back_mut().filter(|span| span.end.is_none()) {
+            open.end = Some(now);
+        }
+        if tracker.timeline.len() == TIMELINE_CAPACITY {
+            tracker.timeline.pop_front();
+        }
+        tracker.timeline.push_back(ForegroundSpan { pid, name: name.clone(), start: now, end: None });
+        tracker.current = Some((pid, name));
+    });
+}
+
+// Aplicación actual y segundos por ejecutable desde la última llamada
+fn take_elapsed() -> (Option<(u32, String)>, HashMap<String, f64>) {
+    with_tracker(|tracker| {
+        tracker.credit_current();
+        (tracker.current.clone(), std::mem::take(&mut tracker.pending))
+    })
+}
+
+// Proceso en primer plano y su ejecutable
+fn foreground_app() -> Option<(u32, String)> {
+    #[cfg(target_os = "windows")]
+    {
+        let pid = crate::win::foreground_pid()?;
+        crate::win::process_image_name(pid).map(|name| (pid, name))
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        None
+    }
+}
+
+#[cfg(target_os = "windows")]
+unsafe extern "system" fn on_foreground(
+    _hook: windows::Win32::UI::Accessibility::HWINEVENTHOOK,
+    _event: u32,
+    hwnd: windows::Win32::Foundation::HWND,
+    _object: i32,
+    _child: i32,
+    _thread: u32,
+    _time: u32,
+) {
+    use windows::Win32::UI::WindowsAndMessaging::GetWindowThreadProcessId;
+
+    let mut pid = 0u32;
+    GetWindowThreadProcessId(hwnd, Some(&mut pid));
+    if let Some(name) = crate::win::process_image_name(pid) {
+        observe(pid, name);
+    }
+}
+
+// Instala el hook en un hilo con bucle de mensajes; devuelve false si no se
+// pudo y hay que sondear el primer plano
+fn start_hook() -> bool {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::Foundation::{HMODULE, HWND};
+        use windows::Win32::UI::Accessibility::SetWinEventHook;
+        use windows::Win32::UI::WindowsAndMessaging::{
+            DispatchMessageW, GetMessageW, EVENT_SYSTEM_FOREGROUND, MSG, WINEVENT_OUTOFCONTEXT,
+        };
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            let hook = unsafe {
+                SetWinEventHook(
+                    EVENT_SYSTEM_FOREGROUND,
+                    EVENT_SYSTEM_FOREGROUND,
+                    HMODULE(0),
+                    Some(on_foreground),
+                    0,
+                    0,
+                    WINEVENT_OUTOFCONTEXT,
+                )
+            };
+            let _ = sender.send(hook.0 != 0);
+            if hook.0 == 0 {
+                return;
+            }
+            if let Some((pid, name)) = foreground_app() {
+                observe(pid, name);
+            }
+            // Los eventos fuera de contexto se entregan a través de la cola de mensajes
+            let mut msg = MSG::default();
+            while unsafe { GetMessageW(&mut msg, HWND(0), 0, 0) }.as_bool() {
+                unsafe { DispatchMessageW(&msg) };
+            }
+        });
+        receiver.recv().unwrap_or(false)
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        false
+    }
+}
+
+// Hilo propio: el tiempo cuenta aunque la interfaz no esté sondeando. Cada
+// segundo pasa el tiempo acumulado al historial y aplica los límites diarios
+pub fn start(app: AppHandle) {
+    std::thread::spawn(move || {
+        let hooked = start_hook();
+        let mut warned = HashMap::new();
+        loop {
+            std::thread::sleep(TICK);
+            if !hooked {
+                if let Some((pid, name)) = foreground_app() {
+                    observe(pid, name);
+                }
+            }
+            let (current, elapsed) = take_elapsed();
+            let state = app.state::<AppState>();
+            let used = {
+                let mut history = state.app_history.lock().unwrap();
+                for (name, seconds) in elapsed {
+                    history.usage_mut(&name).foreground_seconds += seconds;
+                }
+                history.flush_if_due();
+                current
+                    .as_ref()
+                    .and_then(|(_, name)| history.today_usage(name))
+                    .map_or(0.0, |usage| usage.foreground_seconds / 60.0)
+            };
+            if let Some((pid, name)) = current {
+                crate::limits::enforce(&app, pid, &name, used, &mut warned);
+            }
+        }
+    });
+}
+
+// Desglose de un día (hoy si no se indica), de mayor a menor tiempo
+#[tauri::command]
+pub fn get_screen_time(state: State<AppState>, day: Option<String>) -> ScreenTime {
+    let day = day.unwrap_or_else(crate::apphistory::today);
+    let mut apps: Vec<ScreenTimeEntry> = state
+        .app_history
+        .lock()
+        .unwrap()
+        .day(&day)
+        .filter(|(_, usage)| usage.foreground_seconds > 0.0)
+        .map(|(name, usage)| ScreenTimeEntry { name: name.clone(), seconds: usage.foreground_seconds })
+        .collect();
+    apps.sort_by(|a, b| b.seconds.total_cmp(&a.seconds));
+    ScreenTime {
+        day,
+        total_seconds: apps.iter().map(|app| app.seconds).sum(),
+        apps,
+    }
+}
+
+// Cambios de primer plano recientes, del más antiguo al más reciente
+#[tauri::command]
+pub fn get_foreground_timeline() -> Vec<ForegroundSpan> {
+    with_tracker(|tracker| tracker.timeline.iter().cloned().collect())
+}
@@ -0,0 +1,187 @@
+// src-tauri/src/security.rs
+// Información de seguridad de los procesos (tokens de acceso)
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TokenPrivilege {
+    pub name: String,              // p. ej. SeDebugPrivilege
+    pub enabled: bool,
+    pub enabled_by_default: bool,
+}
+
+#[cfg(target_os = "windows")]
+pub fn open_process_token(
+    pid: u32,
+    access: windows::Win32::Security::TOKEN_ACCESS_MASK,
+) -> Result<crate::win::OwnedHandle, String> {
+    use windows::Win32::Foundation::HANDLE;
+    use windows::Win32::System::Threading::{OpenProcessToken, PROCESS_QUERY_LIMITED_INFORMATION};
+
+    let process = crate::win::open_process(pid, PROCESS_QUERY_LIMITED_INFORMATION)?;
+    let mut token = HANDLE::default();
+    if !unsafe { OpenProcessToken(process.0, access, &mut token) }.as_bool() {
+        return Err(format!("No se pudo abrir el token del proceso {}: {}", pid, windows::core::Error::from_win32()));
+    }
+    Ok(crate::win::OwnedHandle(token))
+}
+
+// Lee una clase de información del token en un búfer alineado
+#[cfg(target_os = "windows")]
+pub fn token_information(
+    token: &crate::win::OwnedHandle,
+    class: windows::Win32::Security::TOKEN_INFORMATION_CLASS,
+) -> Result<Vec<u64>, String> {
+    use windows::Win32::Security::GetTokenInformation;
+
+    let mut size = 0u32;
+    unsafe { GetTokenInformation(token.0, class, None, 0, &mut size) };
+    if size == 0 {
+        return Err(format!("No se pudo consultar el token: {}", windows::core::Error::from_win32()));
+    }
+    let mut buffer = vec![0u64; (size as usize).div_ceil(8)];
+    if !unsafe { GetTokenInformation(token.0, class, Some(buffer.as_mut_ptr() as *mut _), size, &mut size) }.as_bool() {
+        return Err(format!("No se pudo consultar el token: {}", windows::core::Error::from_win32()));
+    }
+    Ok(buffer)
+}
+
+// Cuenta dueña del token ("DOMINIO\usuario") y su SID en texto
+#[cfg(target_os = "windows")]
+pub fn token_user(token: &crate::win::OwnedHandle) -> Result<(Option<String>, String), String> {
+    use windows::core::{PCWSTR, PWSTR};
+    use windows::Win32::Foundation::HLOCAL;
+    use windows::Win32::Security::Authorization::ConvertSidToStringSidW;
+    use windows::Win32::Security::{LookupAccountSidW, TokenUser, SID_NAME_USE, TOKEN_USER};
+    use windows::Win32::System::Memory::LocalFree;
+
+    let buffer = token_information(token, TokenUser)?;
+    let sid = unsafe { &*(buffer.as_ptr() as *const TOKEN_USER) }.User.Sid;
+
+    let mut text = PWSTR::null();
+    if !unsafe { ConvertSidToStringSidW(sid, &mut text) }.as_bool() {
+        return Err(format!("No se pudo convertir el SID: {}", windows::core::Error::from_win32()));
+    }
+    let sid_string = unsafe { text.to_string() }.unwrap_or_default();
+    let _ = unsafe { LocalFree(HLOCAL(text.0 as isize)) };
+
+    let (mut name, mut domain) = ([0u16; 256], [0u16; 256]);
+    let (mut name_len, mut domain_len) = (name.len() as u32, domain.len() as u32);
+    let mut kind = SID_NAME_USE::default();
+    let found = unsafe {
+        LookupAccountSidW(
+            PCWSTR::null(),
+            sid,
+            PWSTR(name.as_mut_ptr()),
+            &mut name_len,
+            PWSTR(domain.as_mut_ptr()),
+            &mut domain_len,
+            &mut kind,
+        )
+    }
+    .as_bool();
+    // Las cuentas eliminadas o de otro dominio pueden no resolverse
+    let account = found.then(|| {
+        let (name, domain) = (crate::win::from_wide(&name), crate::win::from_wide(&domain));
+        if domain.is_empty() { name } else { format!("{}\\{}", domain, name) }
+    });
+    Ok((account, sid_string))
+}
+
+// Nivel de integridad (low, medium, high, system...) y si el token está elevado
+#[cfg(target_os = "windows")]
+pub fn token_integrity(token: &crate::win::OwnedHandle) -> (Option<String>, Option<bool>) {
+    use windows::Win32::Security::{
+        GetSidSubAuthority, GetSidSubAuthorityCount, TokenElevation, TokenIntegrityLevel, TOKEN_ELEVATION,
+        TOKEN_MANDATORY_LABEL,
+    };
+
+    let level = token_information(token, TokenIntegrityLevel).ok().map(|buffer| {
+        let sid = unsafe { &*(buffer.as_ptr() as *const TOKEN_MANDATORY_LABEL) }.Label.Sid;
+        // El RID del nivel es la última subautoridad del SID de la etiqueta
+        let rid = unsafe { *GetSidSubAuthority(sid, (*GetSidSubAuthorityCount(sid) as u32).saturating_sub(1)) };
+        match rid {
+            0x0000..=0x0fff => "untrusted",
+            0x1000..=0x1fff => "low",
+            0x2000..=0x2fff => "medium",
+            0x3000..=0x3fff => "high",
+            0x4000..=0x4fff => "system",
+            _ => "protected",
+        }
+        .to_string()
+    });
+    let elevated = token_information(token, TokenElevation)
+        .ok()
+        .map(|buffer| unsafe { &*(buffer.as_ptr() as *const TOKEN_ELEVATION) }.TokenIsElevated != 0);
+    (level, elevated)
+}
+
+// Activa un privilegio (p. ej. SeDebugPrivilege) en el token del propio
+// proceso. Falla si la cuenta no lo tiene, normalmente por no ser administrador
+#[cfg(target_os = "windows")]
+pub fn enable_privilege(name: &str) -> Result<(), String> {
+    use windows::core::PCWSTR;
+    use windows::Win32::Foundation::{GetLastError, ERROR_NOT_ALL_ASSIGNED, LUID};
+    use windows::Win32::Security::{
+        AdjustTokenPrivileges, LookupPrivilegeValueW, LUID_AND_ATTRIBUTES, SE_PRIVILEGE_ENABLED,
+        TOKEN_ADJUST_PRIVILEGES, TOKEN_PRIVILEGES, TOKEN_QUERY,
+    };
+
+    let token = open_process_token(std::process::id(), TOKEN_ADJUST_PRIVILEGES | TOKEN_QUERY)?;
+    let wide = crate::win::to_wide(name);
+    let mut luid = LUID::default();
+    if !unsafe { LookupPrivilegeValueW(PCWSTR::null(), PCWSTR(wide.as_ptr()), &mut luid) }.as_bool() {
+        return Err(format!("Privilegio desconocido {}: {}", name, windows::core::Error::from_win32()));
+    }
+    let privileges = TOKEN_PRIVILEGES {
+        PrivilegeCount: 1,
+        Privileges: [LUID_AND_ATTRIBUTES { Luid: luid, Attributes: SE_PRIVILEGE_ENABLED }],
+    };
+    // Devuelve éxito aunque no se haya asignado: hay que mirar el último error
+    let adjusted = unsafe { AdjustTokenPrivileges(token.0, false, Some(&privileges), 0, None, None) }.as_bool();
+    if !adjusted || unsafe { GetLastError() } == ERROR_NOT_ALL_ASSIGNED {
+        return Err(format!("No se pudo activar {}; ejecuta la aplicación como administrador", name));
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_process_privileges(pid: u32) -> Result<Vec<TokenPrivilege>, String> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::core::{PCWSTR, PWSTR};
+        use windows::Win32::Security::{
+            LookupPrivilegeNameW, TokenPrivileges, SE_PRIVILEGE_ENABLED,
+            SE_PRIVILEGE_ENABLED_BY_DEFAULT, TOKEN_PRIVILEGES, TOKEN_QUERY,
+        };
+
+        let token = open_process_token(pid, TOKEN_QUERY)?;
+        let buffer = token_information(&token, TokenPrivileges)?;
+        let privileges = unsafe { &*(buffer.as_ptr() as *const TOKEN_PRIVILEGES) };
+        let entries = unsafe {
+            std::slice::from_raw_parts(privileges.Privileges.as_ptr(), privileges.PrivilegeCount as usize)
+        };
+
+        let mut result = Vec::with_capacity(entries.len());
+        for entry in entries {
+            let mut name = [0u16; 128];
+            let mut len = name.len() as u32;
+            let ok = unsafe { LookupPrivilegeNameW(PCWSTR::null(), &entry.Luid, PWSTR(name.as_mut_ptr()), &mut len) };
+            if !ok.as_bool() {
+                continue;
+            }
+            result.push(TokenPrivilege {
+                name: String::from_utf16_lossy(&name[..len as usize]),
+                enabled: entry.Attributes.0 & SE_PRIVILEGE_ENABLED.0 != 0,
+                enabled_by_default: entry.Attributes.0 & SE_PRIVILEGE_ENABLED_BY_DEFAULT.0 != 0,
+            });
+        }
+        Ok(result)
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = pid;
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
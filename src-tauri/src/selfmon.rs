@@ -0,0 +1,114 @@
+// src-tauri/src/selfmon.rs
+// Consumo del propio administrador de tareas: CPU, memoria, tamaño de la
+// respuesta de get_processes y tiempo invertido en cada colector
+
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::State;
+
+use crate::AppState;
+
+#[derive(Debug, Default)]
+struct Timing {
+    calls: u64,
+    total: Duration,
+    last: Duration,
+}
+
+#[derive(Debug, Default)]
+pub struct SelfMetrics {
+    timings: HashMap<&'static str, Timing>,
+}
+
+impl SelfMetrics {
+    pub fn record(&mut self, collector: &'static str, elapsed: Duration) {
+        let timing = self.timings.entry(collector).or_default();
+        timing.calls += 1;
+        timing.total += elapsed;
+        timing.last = elapsed;
+    }
+
+    // (colector, llamadas, tiempo total) desde que arrancó la aplicación
+    pub fn totals(&self) -> impl Iterator<Item = (&'static str, u64, Duration)> + '_ {
+        self.timings.iter().map(|(collector, timing)| (*collector, timing.calls, timing.total))
+    }
+}
+
+// Cronómetro por tramos para las fases de un refresco
+pub struct Laps {
+    last: Instant,
+    laps: Vec<(&'static str, Duration)>,
+}
+
+impl Laps {
+    pub fn start() -> Self {
+        Laps { last: Instant::now(), laps: Vec::new() }
+    }
+
+    pub fn lap(&mut self, collector: &'static str) {
+        let now = Instant::now();
+        self.laps.push((collector, now - self.last));
+        self.last = now;
+    }
+
+    pub fn finish(self, metrics: &Mutex<SelfMetrics>) {
+        let mut metrics = metrics.lock().unwrap();
+        for (collector, elapsed) in self.laps {
+            metrics.record(collector, elapsed);
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CollectorTiming {
+    pub collector: String,
+    pub calls: u64,
+    pub last_ms: f64,
+    pub average_ms: f64,
+    pub total_ms: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SelfMetricsReport {
+    pub pid: u32,
+    pub cpu_usage: Option<f32>,
+    pub memory_usage: Option<u64>,
+    pub process_list_payload_bytes: usize, // JSON de la última respuesta de get_processes
+    pub collectors: Vec<CollectorTiming>,
+}
+
+#[tauri::command]
+pub fn get_self_metrics(state: State<AppState>) -> SelfMetricsReport {
+    let pid = std::process::id();
+    let (own, payload) = {
+        let tree = state.processes.read().unwrap();
+        let own = tree.processes.iter().find(|process| process.pid == pid).cloned();
+        (own, serde_json::to_vec(&*tree).map(|json| json.len()).unwrap_or(0))
+    };
+
+    let mut collectors: Vec<CollectorTiming> = state
+        .self_metrics
+        .lock()
+        .unwrap()
+        .timings
+        .iter()
+        .map(|(collector, timing)| CollectorTiming {
+            collector: collector.to_string(),
+            calls: timing.calls,
+            last_ms: timing.last.as_secs_f64() * 1000.0,
+            average_ms: timing.total.as_secs_f64() * 1000.0 / timing.calls.max(1) as f64,
+            total_ms: timing.total.as_secs_f64() * 1000.0,
+        })
+        .collect();
+    collectors.sort_by(|a, b| b.total_ms.total_cmp(&a.total_ms));
+
+    SelfMetricsReport {
+        pid,
+        cpu_usage: own.as_ref().map(|process| process.cpu_usage),
+        memory_usage: own.as_ref().map(|process| process.memory_usage),
+        process_list_payload_bytes: payload,
+        collectors,
+    }
+}
@@ -0,0 +1,366 @@
+// src-tauri/src/services.rs
+// Servicios de Windows (Service Control Manager): lista con el proceso que
+// hospeda cada uno, inicio, parada y reinicio
+
+use serde::{Serialize, Deserialize};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager, State};
+
+use crate::audit::AuditOrigin;
+use crate::jobs::JobReporter;
+use crate::AppState;
+
+// Tiempo máximo que se espera a que un servicio se detenga al reiniciarlo
+const STOP_TIMEOUT: Duration = Duration::from_secs(30);
+const STOP_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ServiceState {
+    Stopped,
+    StartPending,
+    StopPending,
+    Running,
+    ContinuePending,
+    PausePending,
+    Paused,
+    Unknown,
+}
+
+impl ServiceState {
+    #[cfg(target_os = "windows")]
+    fn from_raw(value: u32) -> Self {
+        match value {
+            1 => ServiceState::Stopped,
+            2 => ServiceState::StartPending,
+            3 => ServiceState::StopPending,
+            4 => ServiceState::Running,
+            5 => ServiceState::ContinuePending,
+            6 => ServiceState::PausePending,
+            7 => ServiceState::Paused,
+            _ => ServiceState::Unknown,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ServiceInfo {
+    pub name: String,
+    pub display_name: String,
+    pub state: ServiceState,
+    pub pid: Option<u32>, // Proceso que hospeda el servicio, si está en ejecución
+}
+
+#[cfg(target_os = "windows")]
+pub mod scm {
+    use windows::Win32::Security::SC_HANDLE;
+    use windows::Win32::System::Services::{CloseServiceHandle, OpenSCManagerW};
+
+    // Handle del SCM o de un servicio que se cierra automáticamente
+    pub struct ScHandle(pub SC_HANDLE);
+
+    impl Drop for ScHandle {
+        fn drop(&mut self) {
+            unsafe { CloseServiceHandle(self.0) };
+        }
+    }
+
+    pub fn open_manager(access: u32) -> Result<ScHandle, String> {
+        unsafe { OpenSCManagerW(None, None, access) }
+            .map(ScHandle)
+            .map_err(|e| format!("No se pudo abrir el administrador de servicios: {}", e))
+    }
+}
+
+#[cfg(target_os = "windows")]
+pub fn enumerate_services() -> Result<Vec<ServiceInfo>, String> {
+    use windows::Win32::Foundation::{GetLastError, ERROR_MORE_DATA};
+    use windows::Win32::System::Services::{
+        EnumServicesStatusExW, ENUM_SERVICE_STATUS_PROCESSW, SC_ENUM_PROCESS_INFO,
+        SC_MANAGER_ENUMERATE_SERVICE, SERVICE_STATE_ALL, SERVICE_WIN32,
+    };
+
+    let manager = scm::open_manager(SC_MANAGER_ENUMERATE_SERVICE)?;
+    let mut services = Vec::new();
+    let mut resume = 0u32;
+    // Búfer de u64 para garantizar la alineación de las estructuras devueltas
+    let mut buffer = vec![0u64; 8 * 1024];
+
+    loop {
+        let mut needed = 0u32;
+        let mut returned = 0u32;
+        let bytes = unsafe { std::slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut u8, buffer.len() * 8) };
+        let ok = unsafe {
+            EnumServicesStatusExW(
+                manager.0,
+                SC_ENUM_PROCESS_INFO,
+                SERVICE_WIN32,
+                SERVICE_STATE_ALL,
+                Some(bytes),
+                &mut needed,
+                &mut returned,
+                Some(&mut resume),
+                None,
+            )
+        };
+        let more_data = !ok.as_bool() && unsafe { GetLastError() } == ERROR_MORE_DATA;
+        if !ok.as_bool() && !more_data {
+            return Err(format!("Error al enumerar servicios: {}", windows::core::Error::from_win32()));
+        }
+
+        let entries = unsafe {
+            std::slice::from_raw_parts(buffer.as_ptr() as *const ENUM_SERVICE_STATUS_PROCESSW, returned as usize)
+        };
+        for entry in entries {
+            let status = entry.ServiceStatusProcess;
+            services.push(ServiceInfo {
+                name: unsafe { entry.lpServiceName.to_string() }.unwrap_or_default(),
+                display_name: unsafe { entry.lpDisplayName.to_string() }.unwrap_or_default(),
+                state: ServiceState::from_raw(status.dwCurrentState.0),
+                pid: (status.dwProcessId != 0).then_some(status.dwProcessId),
+            });
+        }
+
+        if !more_data {
+            break;
+        }
+        if needed as usize > buffer.len() * 8 {
+            buffer.resize((needed as usize).div_ceil(8), 0);
+        }
+    }
+
+    Ok(services)
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn enumerate_services() -> Result<Vec<ServiceInfo>, String> {
+    Err("Esta función solo está disponible en Windows".to_string())
+}
+
+// Estado y código de salida (Win32 o específico del servicio) de un servicio;
+// un código distinto de 0 al detenerse indica que no se paró por petición
+pub fn query_status(name: &str) -> Result<(ServiceState, u32), String> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::core::PCWSTR;
+        use windows::Win32::System::Services::{
+            OpenServiceW, QueryServiceStatus, SC_MANAGER_CONNECT, SERVICE_QUERY_STATUS, SERVICE_STATUS,
+        };
+
+        // ERROR_SERVICE_SPECIFIC_ERROR: el código real está en el otro campo
+        const ERROR_SERVICE_SPECIFIC_ERROR: u32 = 1066;
+
+        let manager = scm::open_manager(SC_MANAGER_CONNECT)?;
+        let wide = crate::win::to_wide(name);
+        let service = unsafe { OpenServiceW(manager.0, PCWSTR(wide.as_ptr()), SERVICE_QUERY_STATUS) }
+            .map(scm::ScHandle)
+            .map_err(|e| format!("No se pudo abrir el servicio {}: {}", name, e))?;
+        let mut status = SERVICE_STATUS::default();
+        if !unsafe { QueryServiceStatus(service.0, &mut status) }.as_bool() {
+            return Err(format!("No se pudo consultar el servicio {}: {}", name, windows::core::Error::from_win32()));
+        }
+        let exit_code = match status.dwWin32ExitCode {
+            ERROR_SERVICE_SPECIFIC_ERROR => status.dwServiceSpecificExitCode,
+            code => code,
+        };
+        Ok((ServiceState::from_raw(status.dwCurrentState.0), exit_code))
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = name;
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
+
+// Servicios en ejecución que dependen de `name` (directa o indirectamente):
+// se detendrían o fallarían sin él. El SCM no da su proceso
+pub fn active_dependents(name: &str) -> Result<Vec<ServiceInfo>, String> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::core::PCWSTR;
+        use windows::Win32::Foundation::{GetLastError, ERROR_MORE_DATA};
+        use windows::Win32::System::Services::{
+            EnumDependentServicesW, OpenServiceW, ENUM_SERVICE_STATUSW, SC_MANAGER_CONNECT, SERVICE_ACTIVE,
+            SERVICE_ENUMERATE_DEPENDENTS,
+        };
+
+        let manager = scm::open_manager(SC_MANAGER_CONNECT)?;
+        let wide = crate::win::to_wide(name);
+        let service = unsafe { OpenServiceW(manager.0, PCWSTR(wide.as_ptr()), SERVICE_ENUMERATE_DEPENDENTS) }
+            .map(scm::ScHandle)
+            .map_err(|e| format!("No se pudo abrir el servicio {}: {}", name, e))?;
+        // Búfer de u64 para garantizar la alineación de las estructuras devueltas
+        let mut buffer = vec![0u64; 1024];
+        loop {
+            let (mut needed, mut returned) = (0u32, 0u32);
+            let ok = unsafe {
+                EnumDependentServicesW(
+                    service.0,
+                    SERVICE_ACTIVE,
+                    Some(buffer.as_mut_ptr() as *mut ENUM_SERVICE_STATUSW),
+                    (buffer.len() * 8) as u32,
+                    &mut needed,
+                    &mut returned,
+                )
+            };
+            if !ok.as_bool() && unsafe { GetLastError() } == ERROR_MORE_DATA {
+                buffer.resize((needed as usize).div_ceil(8), 0);
+                continue;
+            }
+            if !ok.as_bool() {
+                let error = windows::core::Error::from_win32();
+                return Err(format!("No se pudieron leer los dependientes de {}: {}", name, error));
+            }
+            let entries = unsafe {
+                std::slice::from_raw_parts(buffer.as_ptr() as *const ENUM_SERVICE_STATUSW, returned as usize)
+            };
+            return Ok(entries
+                .iter()
+                .map(|entry| ServiceInfo {
+                    name: unsafe { entry.lpServiceName.to_string() }.unwrap_or_default(),
+                    display_name: unsafe { entry.lpDisplayName.to_string() }.unwrap_or_default(),
+                    state: ServiceState::from_raw(entry.ServiceStatus.dwCurrentState.0),
+                    pid: None,
+                })
+                .collect());
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = name;
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
+
+// Requiere privilegios de administrador para la mayoría de servicios
+pub fn start(name: &str) -> Result<(), String> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::core::PCWSTR;
+        use windows::Win32::System::Services::{OpenServiceW, StartServiceW, SC_MANAGER_CONNECT, SERVICE_START};
+
+        let manager = scm::open_manager(SC_MANAGER_CONNECT)?;
+        let wide = crate::win::to_wide(name);
+        let service = unsafe { OpenServiceW(manager.0, PCWSTR(wide.as_ptr()), SERVICE_START) }
+            .map(scm::ScHandle)
+            .map_err(|e| format!("No se pudo abrir el servicio {}: {}", name, e))?;
+        if !unsafe { StartServiceW(service.0, None) }.as_bool() {
+            return Err(format!("No se pudo iniciar el servicio {}: {}", name, windows::core::Error::from_win32()));
+        }
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = name;
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
+
+// Pide la parada al servicio; devuelve sin esperar a que termine. Falla si
+// otros servicios en ejecución dependen de él
+pub fn stop(name: &str) -> Result<(), String> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::core::PCWSTR;
+        use windows::Win32::System::Services::{
+            ControlService, OpenServiceW, SC_MANAGER_CONNECT, SERVICE_CONTROL_STOP, SERVICE_STATUS, SERVICE_STOP,
+        };
+
+        let manager = scm::open_manager(SC_MANAGER_CONNECT)?;
+        let wide = crate::win::to_wide(name);
+        let service = unsafe { OpenServiceW(manager.0, PCWSTR(wide.as_ptr()), SERVICE_STOP) }
+            .map(scm::ScHandle)
+            .map_err(|e| format!("No se pudo abrir el servicio {}: {}", name, e))?;
+        let mut status = SERVICE_STATUS::default();
+        if !unsafe { ControlService(service.0, SERVICE_CONTROL_STOP, &mut status) }.as_bool() {
+            return Err(format!("No se pudo detener el servicio {}: {}", name, windows::core::Error::from_win32()));
+        }
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = name;
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
+
+#[tauri::command]
+pub fn list_services() -> Result<Vec<ServiceInfo>, String> {
+    let mut services = enumerate_services()?;
+    services.sort_by_cached_key(|service| service.display_name.to_lowercase());
+    Ok(services)
+}
+
+// Devuelven el estado tras enviar la orden (normalmente *_pending)
+#[tauri::command]
+pub fn start_service(state: State<AppState>, name: String) -> Result<ServiceState, String> {
+    let result = start(&name);
+    crate::audit::record(&state, AuditOrigin::User, "start_service", &name, None, None, &result);
+    result?;
+    Ok(query_status(&name)?.0)
+}
+
+#[tauri::command]
+pub fn stop_service(state: State<AppState>, name: String) -> Result<ServiceState, String> {
+    let result = stop(&name);
+    crate::audit::record(&state, AuditOrigin::User, "stop_service", &name, None, None, &result);
+    result?;
+    Ok(query_status(&name)?.0)
+}
+
+// Tarea en segundo plano: espera a que se detenga antes de iniciarlo otra vez;
+// el resultado ("job-finished") es el estado final
+#[tauri::command]
+pub fn restart_service(app: AppHandle, name: String) -> u64 {
+    let handle = app.clone();
+    crate::jobs::spawn(&app, "restart_service", move |job| {
+        let result = restart(job, &name);
+        let state = handle.state::<AppState>();
+        crate::audit::record(&state, AuditOrigin::User, "restart_service", &name, None, None, &result);
+        result
+    })
+}
+
+fn restart(job: &JobReporter, name: &str) -> Result<ServiceState, String> {
+    if query_status(name)?.0 != ServiceState::Stopped {
+        job.progress(0.1, "Deteniendo el servicio");
+        stop(name)?;
+        let started = Instant::now();
+        while query_status(name)?.0 != ServiceState::Stopped {
+            if job.is_cancelled() {
+                return Err(format!("Reinicio cancelado: el servicio {} no se volverá a iniciar", name));
+            }
+            if started.elapsed() >= STOP_TIMEOUT {
+                return Err(format!("El servicio {} no se detuvo en {} s", name, STOP_TIMEOUT.as_secs()));
+            }
+            std::thread::sleep(STOP_POLL_INTERVAL);
+        }
+    }
+    job.progress(0.6, "Iniciando el servicio");
+    start(name)?;
+    Ok(query_status(name)?.0)
+}
+
+// "Ir a detalles": proceso que hospeda un servicio
+#[tauri::command]
+pub fn get_process_for_service(name: String) -> Result<Option<u32>, String> {
+    let services = enumerate_services()?;
+    let service = services
+        .into_iter()
+        .find(|service| service.name.eq_ignore_ascii_case(&name))
+        .ok_or_else(|| format!("No existe el servicio {}", name))?;
+    Ok(service.pid)
+}
+
+// "Ir a servicios": servicios hospedados en un proceso (p. ej. un svchost.exe)
+#[tauri::command]
+pub fn get_services_for_process(pid: u32) -> Result<Vec<ServiceInfo>, String> {
+    Ok(enumerate_services()?
+        .into_iter()
+        .filter(|service| service.pid == Some(pid))
+        .collect())
+}
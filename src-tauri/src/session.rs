@@ -0,0 +1,267 @@
+// src-tauri/src/session.rs
+// Resumen de sesión: picos de CPU y memoria, mayores consumidores y alertas
+// disparadas, escrito en sessions/ al cerrar la aplicación o la sesión de Windows
+
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
+
+use crate::{AppState, ProcessInfo};
+
+const SUMMARIES_KEPT: usize = 20;
+const TOP_CONSUMERS: usize = 10;
+// Igual que en el historial de aplicaciones: los huecos largos no se imputan
+const MAX_SAMPLE_GAP: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Default)]
+struct Consumer {
+    cpu_seconds: f64,
+    peak_memory: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TopConsumer {
+    pub name: String,
+    pub cpu_seconds: f64,
+    pub peak_memory: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AlertCount {
+    pub name: String,
+    pub count: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SessionSummary {
+    pub started_at: u64, // Milisegundos desde UNIX_EPOCH
+    pub ended_at: u64,
+    pub reason: String, // exit, end_session
+    pub peak_cpu: f32,  // Uso global en porcentaje
+    pub peak_cpu_at: u64,
+    pub peak_memory: u64, // Memoria usada del sistema en bytes
+    pub peak_memory_at: u64,
+    pub top_cpu: Vec<TopConsumer>,
+    pub top_memory: Vec<TopConsumer>,
+    pub alerts: Vec<AlertCount>,
+}
+
+#[derive(Debug)]
+pub struct SessionTracker {
+    started_at: u64,
+    peak_cpu: (f32, u64),
+    peak_memory: (u64, u64),
+    consumers: HashMap<String, Consumer>,
+    alerts: HashMap<String, u32>,
+    last_sample: Option<Instant>,
+    written: bool,
+}
+
+impl Default for SessionTracker {
+    fn default() -> Self {
+        SessionTracker {
+            started_at: crate::stats::now_millis(),
+            peak_cpu: (0.0, 0),
+            peak_memory: (0, 0),
+            consumers: HashMap::new(),
+            alerts: HashMap::new(),
+            last_sample: None,
+            written: false,
+        }
+    }
+}
+
+impl SessionTracker {
+    pub fn record(&mut self, cpu_usage: f32, used_memory: u64, processes: &[ProcessInfo]) {
+        let now = crate::stats::now_millis();
+        if cpu_usage > self.peak_cpu.0 {
+            self.peak_cpu = (cpu_usage, now);
+        }
+        if used_memory > self.peak_memory.0 {
+            self.peak_memory = (used_memory, now);
+        }
+
+        let sampled_at = Instant::now();
+        let elapsed = self
+            .last_sample
+            .replace(sampled_at)
+            .map(|at| sampled_at - at)
+            .filter(|elapsed| *elapsed <= MAX_SAMPLE_GAP)
+            .map_or(0.0, |elapsed| elapsed.as_secs_f64());
+        for process in processes {
+            let consumer = self.consumers.entry(process.name.to_lowercase()).or_default();
+            consumer.cpu_seconds += process.cpu_usage as f64 / 100.0 * elapsed;
+            consumer.peak_memory = consumer.peak_memory.max(process.memory_usage);
+        }
+    }
+
+    pub fn alert(&mut self, name: &str) {
+        *self.alerts.entry(name.to_string()).or_insert(0) += 1;
+    }
+
+    fn top(&self, key: impl Fn(&Consumer) -> f64) -> Vec<TopConsumer> {
+        let mut consumers: Vec<(&String, &Consumer)> = self.consumers.iter().collect();
+        consumers.sort_by(|a, b| key(b.1).total_cmp(&key(a.1)));
+        consumers
+            .into_iter()
+            .take(TOP_CONSUMERS)
+            .map(|(name, consumer)| TopConsumer {
+                name: name.clone(),
+                cpu_seconds: consumer.cpu_seconds,
+                peak_memory: consumer.peak_memory,
+            })
+            .collect()
+    }
+
+    fn summary(&self, reason: &str) -> SessionSummary {
+        let mut alerts: Vec<AlertCount> = self
+            .alerts
+            .iter()
+            .map(|(name, count)| AlertCount { name: name.clone(), count: *count })
+            .collect();
+        alerts.sort_by_key(|alert| std::cmp::Reverse(alert.count));
+        SessionSummary {
+            started_at: self.started_at,
+            ended_at: crate::stats::now_millis(),
+            reason: reason.to_string(),
+            peak_cpu: self.peak_cpu.0,
+            peak_cpu_at: self.peak_cpu.1,
+            peak_memory: self.peak_memory.0,
+            peak_memory_at: self.peak_memory.1,
+            top_cpu: self.top(|consumer| consumer.cpu_seconds),
+            top_memory: self.top(|consumer| consumer.peak_memory as f64),
+            alerts,
+        }
+    }
+}
+
+fn sessions_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join("sessions"))
+        .map_err(|e| format!("No se pudo resolver el directorio de datos: {}", e))
+}
+
+// Escribe el resumen una sola vez, aunque lleguen a la vez el fin de sesión
+// de Windows y el cierre de la aplicación
+pub fn write_summary(app: &AppHandle, reason: &str) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let summary = {
+        let mut tracker = state.session.lock().unwrap();
+        if tracker.written {
+            return Ok(());
+        }
+        tracker.written = true;
+        tracker.summary(reason)
+    };
+
+    let dir = sessions_dir(app)?;
+    fs::create_dir_all(&dir).map_err(|e| format!("No se pudo crear {}: {}", dir.display(), e))?;
+    let content = serde_json::to_string_pretty(&summary)
+        .map_err(|e| format!("Error al serializar el resumen de sesión: {}", e))?;
+    let path = dir.join(format!("session-{}.json", summary.started_at));
+    fs::write(&path, content).map_err(|e| format!("No se pudo guardar {}: {}", path.display(), e))?;
+
+    // Solo se conservan los resúmenes más recientes
+    for old in summary_files(&dir).into_iter().skip(SUMMARIES_KEPT) {
+        let _ = fs::remove_file(old);
+    }
+    Ok(())
+}
+
+// Archivos de resumen, del más reciente al más antiguo
+fn summary_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    // El nombre lleva la marca de inicio, así que el orden alfabético inverso es el cronológico
+    files.sort_by(|a, b| b.cmp(a));
+    files
+}
+
+static APP: OnceLock<AppHandle> = OnceLock::new();
+
+#[cfg(target_os = "windows")]
+unsafe extern "system" fn end_session_proc(
+    hwnd: windows::Win32::Foundation::HWND,
+    msg: u32,
+    wparam: windows::Win32::Foundation::WPARAM,
+    lparam: windows::Win32::Foundation::LPARAM,
+) -> windows::Win32::Foundation::LRESULT {
+    use windows::Win32::UI::WindowsAndMessaging::{DefWindowProcW, WM_ENDSESSION};
+
+    if msg == WM_ENDSESSION && wparam.0 != 0 {
+        if let Some(app) = APP.get() {
+            let _ = write_summary(app, "end_session");
+        }
+    }
+    DefWindowProcW(hwnd, msg, wparam, lparam)
+}
+
+// WM_ENDSESSION solo llega a ventanas de nivel superior: se crea una oculta
+// con su propio bucle de mensajes
+pub fn watch_end_session(app: AppHandle) {
+    let _ = APP.set(app);
+
+    #[cfg(target_os = "windows")]
+    std::thread::spawn(|| {
+        use windows::core::PCWSTR;
+        use windows::Win32::Foundation::HWND;
+        use windows::Win32::UI::WindowsAndMessaging::{
+            CreateWindowExW, DispatchMessageW, GetMessageW, RegisterClassW, MSG, WINDOW_EX_STYLE, WNDCLASSW,
+            WS_OVERLAPPED,
+        };
+
+        let class_name = crate::win::to_wide("TaskManagerSessionWatcher");
+        let class = WNDCLASSW {
+            lpfnWndProc: Some(end_session_proc),
+            lpszClassName: PCWSTR(class_name.as_ptr()),
+            ..Default::default()
+        };
+        unsafe {
+            if RegisterClassW(&class) == 0 {
+                return;
+            }
+            let hwnd = CreateWindowExW(
+                WINDOW_EX_STYLE(0),
+                PCWSTR(class_name.as_ptr()),
+                PCWSTR(class_name.as_ptr()),
+                WS_OVERLAPPED,
+                0,
+                0,
+                0,
+                0,
+                None,
+                None,
+                None,
+                None,
+            );
+            if hwnd.0 == 0 {
+                return;
+            }
+            let mut msg = MSG::default();
+            while GetMessageW(&mut msg, HWND(0), 0, 0).as_bool() {
+                DispatchMessageW(&msg);
+            }
+        }
+    });
+}
+
+// Resúmenes de las últimas sesiones, del más reciente al más antiguo
+#[tauri::command]
+pub fn get_session_summaries(app: AppHandle, limit: Option<usize>) -> Result<Vec<SessionSummary>, String> {
+    let dir = sessions_dir(&app)?;
+    Ok(summary_files(&dir)
+        .into_iter()
+        .take(limit.unwrap_or(SUMMARIES_KEPT))
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .filter_map(|content| serde_json::from_str(&content).ok())
+        .collect())
+}
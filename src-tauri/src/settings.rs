@@ -0,0 +1,287 @@
+// src-tauri/src/settings.rs
+// Configuración persistente de la aplicación (settings.json en el directorio de config)
+
+use serde::{Serialize, Deserialize};
+use std::fs;
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager, State};
+
+use crate::alerts::AlertRule;
+use crate::expressions::{CustomColumn, WatchExpression};
+use crate::foreground::ForegroundBoostSettings;
+use crate::format::FormatOptions;
+use crate::impact::ImpactWeights;
+use crate::killswitch::KillSwitchSettings;
+use crate::layout::WindowLayout;
+use crate::limits::TimeLimit;
+use crate::profiles::Profile;
+use crate::quiethours::QuietHours;
+use crate::rules::Rule;
+use crate::watchlists::Watchlist;
+use crate::AppState;
+
+// Colectores que se pueden desactivar en equipos con pocos recursos; al
+// desactivarlos se detiene su origen de datos (sesión ETW, consulta PDH)
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(default)]
+pub struct CollectorSettings {
+    pub gpu: bool,
+    pub network: bool,
+    pub file_io: bool,
+    pub registry: bool,
+}
+
+impl Default for CollectorSettings {
+    fn default() -> Self {
+        CollectorSettings {
+            gpu: true,
+            network: true,
+            file_io: true,
+            registry: true,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct Settings {
+    pub format: FormatOptions,
+    pub watch_expressions: Vec<WatchExpression>,
+    pub debugger_command: Option<String>, // `{pid}` se sustituye por el PID
+    pub warn_unsaved_work: bool,          // Avisar antes de terminar editores con cambios
+    pub window_layout: Option<WindowLayout>,
+    pub selected_tab: Option<String>,
+    pub collectors: CollectorSettings,
+    pub rules: Vec<Rule>,
+    pub custom_columns: Vec<CustomColumn>,
+    pub refresh_interval_ms: u64, // Intervalo del muestreo de procesos
+    pub idle_threshold_secs: u64,      // Inactividad a partir de la cual se considera ausente al usuario
+    pub idle_refresh_interval_ms: u64, // Intervalo de sondeo mientras está ausente
+    pub profiles: Vec<Profile>,
+    pub active_profile: Option<String>,
+    pub time_limits_enabled: bool,
+    pub time_limits: Vec<TimeLimit>,
+    pub watched_services: Vec<String>, // Servicios que el vigilante reinicia si se caen
+    pub restart_loop_threshold: u32,      // Arranques del mismo ejecutable para considerarlo un bucle
+    pub restart_loop_window_minutes: u64,
+    pub check_for_updates: bool, // Permite consultar el servidor de actualizaciones
+    pub usage_stats: bool,       // Estadísticas de uso locales (nunca se envían)
+    pub simulate_automation: bool, // Reglas y límites solo anotan lo que harían
+    pub process_history_minutes: u64, // Ventana del historial por proceso (máximo 30)
+    pub alert_rules: Vec<AlertRule>,
+    pub last_alert_rule_id: u64, // Último asignado por add_alert_rule; no se reutiliza al borrar
+    pub impact_weights: ImpactWeights,
+    pub anomaly_detection: bool,
+    pub anomaly_sigma: f64, // Desviaciones sobre la línea base para avisar
+    pub clipboard_monitor: bool, // Avisa de qué proceso escribe en el portapapeles
+    pub watchlists: Vec<Watchlist>,
+    pub active_watchlist: Option<String>,
+    pub watchlist_hotkey: Option<String>, // Atajo global que pasa a la lista siguiente
+    pub kill_switch: KillSwitchSettings, // Atajo global que termina la aplicación en primer plano
+    pub quiet_hours: QuietHours,
+    pub foreground_boost: ForegroundBoostSettings, // Sube la prioridad de la aplicación en primer plano
+    pub growth_columns: bool, // Variación de CPU y crecimiento de memoria y handles por minuto
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            format: FormatOptions::default(),
+            watch_expressions: Vec::new(),
+            debugger_command: None,
+            warn_unsaved_work: true,
+            window_layout: None,
+            selected_tab: None,
+            collectors: CollectorSettings::default(),
+            rules: Vec::new(),
+            custom_columns: Vec::new(),
+            refresh_interval_ms: 1000,
+            idle_threshold_secs: 300,
+            idle_refresh_interval_ms: 5000,
+            profiles: Vec::new(),
+            active_profile: None,
+            time_limits_enabled: false,
+            time_limits: Vec::new(),
+            watched_services: Vec::new(),
+            restart_loop_threshold: 5,
+            restart_loop_window_minutes: 10,
+            check_for_updates: true,
+            usage_stats: true,
+            simulate_automation: false,
+            process_history_minutes: 5,
+            alert_rules: Vec::new(),
+            last_alert_rule_id: 0,
+            impact_weights: ImpactWeights::default(),
+            anomaly_detection: true,
+            anomaly_sigma: 4.0,
+            clipboard_monitor: false,
+            watchlists: Vec::new(),
+            active_watchlist: None,
+            watchlist_hotkey: None,
+            kill_switch: KillSwitchSettings::default(),
+            quiet_hours: QuietHours::default(),
+            foreground_boost: ForegroundBoostSettings::default(),
+            growth_columns: false,
+        }
+    }
+}
+
+fn settings_path(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_config_dir()
+        .map(|dir| dir.join("settings.json"))
+        .map_err(|e| format!("No se pudo resolver el directorio de configuración: {}", e))
+}
+
+// Si el archivo no existe o está corrupto se usan los valores por defecto
+pub fn load(app: &AppHandle) -> Settings {
+    settings_path(app)
+        .ok()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+pub fn save(app: &AppHandle, settings: &Settings) -> Result<(), String> {
+    let path = settings_path(app)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("No se pudo crear el directorio de configuración: {}", e))?;
+    }
+    let content = serde_json::to_string_pretty(settings)
+        .map_err(|e| format!("Error al serializar la configuración: {}", e))?;
+    fs::write(&path, content)
+        .map_err(|e| format!("No se pudo guardar la configuración: {}", e))
+}
+
+#[tauri::command]
+pub fn get_settings(state: State<AppState>) -> Settings {
+    state.settings.read().unwrap().clone()
+}
+
+// Comprobaciones previas a aplicar una configuración, sin efectos
+pub fn validate(settings: &Settings) -> Result<(), String> {
+    if settings.kill_switch.enabled {
+        crate::killswitch::parse_hotkey(&settings.kill_switch.hotkey)?;
+    }
+    if let Some(hotkey) = &settings.watchlist_hotkey {
+        crate::killswitch::parse_hotkey(hotkey)?;
+    }
+    for watchlist in &settings.watchlists {
+        crate::watchlists::validate(watchlist)?;
+    }
+    crate::sampler::validate_interval(settings.refresh_interval_ms)?;
+    crate::sampler::validate_interval(settings.idle_refresh_interval_ms)?;
+    settings.quiet_hours.validate()?;
+    for rule in &settings.rules {
+        crate::rules::validate(rule)?;
+    }
+    for profile in &settings.profiles {
+        crate::profiles::validate(profile)?;
+    }
+    for rule in &settings.alert_rules {
+        crate::alerts::validate_rule(rule)?;
+    }
+    if settings.foreground_boost.enabled {
+        crate::foreground::validate(&settings.foreground_boost)?;
+    }
+    Ok(())
+}
+
+// Cambia la configuración con el bloqueo de escritura tomado mientras se
+// valida y se guarda, para no perder lo que otro comando guarde a la vez, y
+// después aplica lo que haya cambiado. Devuelve la configuración anterior
+pub fn modify<F>(app: &AppHandle, change: F) -> Result<Settings, String>
+where
+    F: FnOnce(&mut Settings) -> Result<(), String>,
+{
+    let state = app.state::<AppState>();
+    let mut current = state.settings.write().unwrap();
+    let mut settings = current.clone();
+    change(&mut settings)?;
+    validate(&settings)?;
+    save(app, &settings)?;
+    let previous = std::mem::replace(&mut *current, settings.clone());
+    drop(current);
+    apply_changes(app, &state, &previous, &settings)?;
+    Ok(previous)
+}
+
+fn apply_changes(app: &AppHandle, state: &AppState, previous: &Settings, settings: &Settings) -> Result<(), String> {
+    if previous.profiles != settings.profiles || previous.watchlists != settings.watchlists {
+        crate::tray::refresh(app);
+    }
+    if previous.collectors != settings.collectors {
+        crate::start_collectors(state);
+    }
+    if (previous.refresh_interval_ms, previous.idle_refresh_interval_ms, previous.idle_threshold_secs)
+        != (settings.refresh_interval_ms, settings.idle_refresh_interval_ms, settings.idle_threshold_secs)
+    {
+        crate::sampler::wake();
+    }
+    if previous.clipboard_monitor != settings.clipboard_monitor {
+        crate::clipboard::apply(app, settings.clipboard_monitor);
+    }
+    // Guardada igualmente: el atajo puede quedar libre más adelante
+    if previous.kill_switch.registration() != settings.kill_switch.registration() {
+        crate::killswitch::apply(app, &settings.kill_switch)?;
+    }
+    if previous.watchlist_hotkey != settings.watchlist_hotkey {
+        crate::watchlists::apply_hotkey(app, settings.watchlist_hotkey.as_deref())?;
+    }
+    if previous.foreground_boost != settings.foreground_boost {
+        crate::foreground::apply(app, &settings.foreground_boost)?;
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn update_settings(app: AppHandle, settings: Settings) -> Result<(), String> {
+    modify(&app, |current| {
+        *current = settings;
+        Ok(())
+    })?;
+    Ok(())
+}
+
+// Archivo de exportación: la configuración completa salvo la disposición de
+// la ventana, que depende de los monitores de cada equipo
+#[derive(Debug, Serialize, Deserialize)]
+struct ConfigExport {
+    version: u32,
+    exported_at: u64,
+    settings: Settings,
+}
+
+const CONFIG_EXPORT_VERSION: u32 = 1;
+
+#[tauri::command]
+pub fn export_config(state: State<AppState>, path: String) -> Result<(), String> {
+    let mut settings = state.settings.read().unwrap().clone();
+    settings.window_layout = None;
+    let export = ConfigExport {
+        version: CONFIG_EXPORT_VERSION,
+        exported_at: crate::stats::now_millis(),
+        settings,
+    };
+    let content = serde_json::to_string_pretty(&export)
+        .map_err(|e| format!("Error al serializar la configuración: {}", e))?;
+    fs::write(&path, content).map_err(|e| format!("No se pudo escribir {}: {}", path, e))
+}
+
+#[tauri::command]
+pub fn import_config(app: AppHandle, path: String) -> Result<Settings, String> {
+    let content = fs::read_to_string(&path).map_err(|e| format!("No se pudo leer {}: {}", path, e))?;
+    let export: ConfigExport = serde_json::from_str(&content)
+        .map_err(|e| format!("El archivo no es una configuración válida: {}", e))?;
+    if export.version > CONFIG_EXPORT_VERSION {
+        return Err(format!("La configuración es de una versión más reciente ({})", export.version));
+    }
+    let mut imported = export.settings;
+    modify(&app, |settings| {
+        imported.window_layout = settings.window_layout;
+        *settings = imported.clone();
+        Ok(())
+    })?;
+    Ok(imported)
+}
@@ -0,0 +1,299 @@
+// src-tauri/src/signature.rs
+// Firma Authenticode del ejecutable de un proceso (WinVerifyTrust): estado,
+// editor y cadena de certificados, para señalar los procesos sin firmar. Los
+// binarios del sistema suelen estar firmados por catálogo y no en el archivo
+
+use serde::Serialize;
+use tauri::State;
+
+use crate::AppState;
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SignatureStatus {
+    Valid,
+    Unsigned,
+    Expired,
+    Untrusted, // Cadena que no termina en una raíz de confianza
+    Revoked,
+    Distrusted, // Certificado bloqueado explícitamente
+    Invalid,    // Firma dañada o archivo modificado tras firmarlo
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct CertificateSummary {
+    pub subject: String,
+    pub issuer: String,
+    pub valid_until: u64, // Milisegundos desde 1970
+    pub trusted_root: bool,
+    pub self_signed: bool,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ProcessSignature {
+    pub path: String,
+    pub status: SignatureStatus,
+    pub catalog_signed: bool,
+    pub publisher: Option<String>, // Sujeto del certificado firmante
+    pub issuer: Option<String>,
+    pub chain: Vec<CertificateSummary>, // Del firmante a la raíz
+    pub error_code: Option<String>,     // HRESULT de WinVerifyTrust si no es válida
+}
+
+#[cfg(target_os = "windows")]
+mod windows_trust {
+    use std::collections::{HashMap, VecDeque};
+    use std::sync::Mutex;
+    use std::time::SystemTime;
+    use windows::core::{GUID, PCWSTR};
+    use windows::Win32::Foundation::{
+        CERT_E_CHAINING, CERT_E_EXPIRED, CERT_E_REVOKED, CERT_E_UNTRUSTEDROOT, CRYPT_E_SECURITY_SETTINGS, HANDLE,
+        HWND, TRUST_E_EXPLICIT_DISTRUST, TRUST_E_NOSIGNATURE, TRUST_E_PROVIDER_UNKNOWN,
+        TRUST_E_SUBJECT_FORM_UNKNOWN, TRUST_E_SUBJECT_NOT_TRUSTED,
+    };
+    use windows::Win32::Security::Cryptography::Catalog::{
+        CryptCATAdminAcquireContext2, CryptCATAdminCalcHashFromFileHandle2, CryptCATAdminEnumCatalogFromHash,
+        CryptCATAdminReleaseCatalogContext, CryptCATAdminReleaseContext, CryptCATCatalogInfoFromContext,
+        CATALOG_INFO,
+    };
+    use windows::Win32::Security::Cryptography::{
+        CertGetNameStringW, CERT_CONTEXT, CERT_NAME_ISSUER_FLAG, CERT_NAME_SIMPLE_DISPLAY_TYPE,
+    };
+    use windows::Win32::Security::WinTrust::{
+        WTHelperGetProvSignerFromChain, WTHelperProvDataFromStateData, WinVerifyTrust, WINTRUST_ACTION_GENERIC_VERIFY_V2,
+        WINTRUST_CATALOG_INFO, WINTRUST_DATA, WINTRUST_DATA_0, WINTRUST_FILE_INFO, WTD_CACHE_ONLY_URL_RETRIEVAL,
+        WTD_CHOICE_CATALOG, WTD_CHOICE_FILE, WTD_REVOCATION_CHECK_NONE, WTD_REVOKE_NONE, WTD_STATEACTION_CLOSE,
+        WTD_STATEACTION_VERIFY, WTD_UI_NONE,
+    };
+    use windows::Win32::Storage::FileSystem::{
+        CreateFileW, FILE_FLAGS_AND_ATTRIBUTES, FILE_GENERIC_READ, FILE_SHARE_DELETE, FILE_SHARE_READ,
+        FILE_SHARE_WRITE, OPEN_EXISTING,
+    };
+
+    use super::{CertificateSummary, ProcessSignature, SignatureStatus};
+    use crate::win::{from_wide, to_wide, OwnedHandle};
+
+    const CACHE_CAPACITY: usize = 512;
+
+    // Ruta -> (fecha de modificación, resultado): verificar cuesta decenas de
+    // ms y la interfaz lo pide para toda la lista. Con la capacidad llena se
+    // descarta la usada hace más tiempo
+    #[derive(Default)]
+    struct Cache {
+        entries: HashMap<String, (Option<SystemTime>, ProcessSignature)>,
+        order: VecDeque<String>, // De menos a más reciente
+    }
+
+    impl Cache {
+        fn touch(&mut self, path: &str) {
+            if let Some(position) = self.order.iter().position(|entry| entry == path) {
+                self.order.remove(position);
+            }
+            self.order.push_back(path.to_string());
+        }
+
+        fn get(&mut self, path: &str, modified: Option<SystemTime>) -> Option<ProcessSignature> {
+            let signature = self.entries.get(path).filter(|(at, _)| *at == modified)?.1.clone();
+            self.touch(path);
+            Some(signature)
+        }
+
+        fn insert(&mut self, path: String, modified: Option<SystemTime>, signature: ProcessSignature) {
+            if !self.entries.contains_key(&path) && self.entries.len() == CACHE_CAPACITY {
+                if let Some(oldest) = self.order.pop_front() {
+                    self.entries.remove(&oldest);
+                }
+            }
+            self.touch(&path);
+            self.entries.insert(path, (modified, signature));
+        }
+    }
+
+    static CACHE: Mutex<Option<Cache>> = Mutex::new(None);
+
+    fn status(code: i32) -> SignatureStatus {
+        match windows::core::HRESULT(code) {
+            windows::core::HRESULT(0) => SignatureStatus::Valid,
+            TRUST_E_NOSIGNATURE | TRUST_E_SUBJECT_FORM_UNKNOWN | TRUST_E_PROVIDER_UNKNOWN => SignatureStatus::Unsigned,
+            CERT_E_EXPIRED => SignatureStatus::Expired,
+            CERT_E_UNTRUSTEDROOT | CERT_E_CHAINING | TRUST_E_SUBJECT_NOT_TRUSTED | CRYPT_E_SECURITY_SETTINGS => {
+                SignatureStatus::Untrusted
+            }
+            CERT_E_REVOKED => SignatureStatus::Revoked,
+            TRUST_E_EXPLICIT_DISTRUST => SignatureStatus::Distrusted,
+            _ => SignatureStatus::Invalid, // TRUST_E_BAD_DIGEST y demás
+        }
+    }
+
+    fn cert_name(cert: *const CERT_CONTEXT, flags: u32) -> String {
+        let mut buffer = [0u16; 256];
+        let len = unsafe { CertGetNameStringW(cert, CERT_NAME_SIMPLE_DISPLAY_TYPE, flags, None, Some(&mut buffer)) };
+        // La longitud incluye el terminador nulo
+        from_wide(&buffer[..(len as usize).saturating_sub(1)])
+    }
+
+    // Cadena del primer firmante a partir del estado que deja WTD_STATEACTION_VERIFY
+    fn signer_chain(state: HANDLE) -> Vec<CertificateSummary> {
+        let provider = unsafe { WTHelperProvDataFromStateData(state) };
+        if provider.is_null() {
+            return Vec::new();
+        }
+        let signer = unsafe { WTHelperGetProvSignerFromChain(provider, 0, false, 0) };
+        if signer.is_null() {
+            return Vec::new();
+        }
+        let signer = unsafe { &*signer };
+        let certificates = unsafe { std::slice::from_raw_parts(signer.pasCertChain, signer.csCertChain as usize) };
+        certificates
+            .iter()
+            .filter(|certificate| !certificate.pCert.is_null())
+            .map(|certificate| {
+                let info = unsafe { &*(*certificate.pCert).pCertInfo };
+                let ticks = ((info.NotAfter.dwHighDateTime as u64) << 32) | info.NotAfter.dwLowDateTime as u64;
+                CertificateSummary {
+                    subject: cert_name(certificate.pCert, 0),
+                    issuer: cert_name(certificate.pCert, CERT_NAME_ISSUER_FLAG),
+                    // FILETIME cuenta intervalos de 100 ns desde 1601
+                    valid_until: (ticks / 10_000).saturating_sub(11_644_473_600_000),
+                    trusted_root: certificate.fTrustedRoot.as_bool(),
+                    self_signed: certificate.fSelfSigned.as_bool(),
+                }
+            })
+            .collect()
+    }
+
+    // Sin comprobar revocación ni descargar nada: la verificación no depende de la red
+    fn run(choice: WINTRUST_DATA_0, catalog: bool) -> (i32, Vec<CertificateSummary>) {
+        let mut data = WINTRUST_DATA {
+            cbStruct: std::mem::size_of::<WINTRUST_DATA>() as u32,
+            dwUIChoice: WTD_UI_NONE,
+            fdwRevocationChecks: WTD_REVOKE_NONE,
+            dwUnionChoice: if catalog { WTD_CHOICE_CATALOG } else { WTD_CHOICE_FILE },
+            Anonymous: choice,
+            dwStateAction: WTD_STATEACTION_VERIFY,
+            dwProvFlags: WTD_CACHE_ONLY_URL_RETRIEVAL | WTD_REVOCATION_CHECK_NONE,
+            ..Default::default()
+        };
+        let mut action: GUID = WINTRUST_ACTION_GENERIC_VERIFY_V2;
+        let code = unsafe { WinVerifyTrust(HWND(-1), &mut action, &mut data as *mut WINTRUST_DATA as *mut _) };
+        let chain = signer_chain(data.hWVTStateData);
+        // Libera el estado que se conservó para leer la cadena
+        data.dwStateAction = WTD_STATEACTION_CLOSE;
+        unsafe { WinVerifyTrust(HWND(-1), &mut action, &mut data as *mut WINTRUST_DATA as *mut _) };
+        (code, chain)
+    }
+
+    // Firma en un catálogo del sistema, buscada por el hash del archivo
+    fn verify_catalog(path: &[u16]) -> Option<(i32, Vec<CertificateSummary>)> {
+        let file = unsafe {
+            CreateFileW(
+                PCWSTR(path.as_ptr()),
+                FILE_GENERIC_READ.0,
+                FILE_SHARE_READ | FILE_SHARE_WRITE | FILE_SHARE_DELETE,
+                None,
+                OPEN_EXISTING,
+                FILE_FLAGS_AND_ATTRIBUTES(0),
+                None,
+            )
+        }
+        .ok()
+        .map(OwnedHandle)?;
+
+        let mut admin = 0isize;
+        let algorithm = to_wide("SHA256");
+        if !unsafe { CryptCATAdminAcquireContext2(&mut admin, None, PCWSTR(algorithm.as_ptr()), None, 0) }.as_bool() {
+            return None;
+        }
+        let result = (|| {
+            let mut size = 0u32;
+            unsafe { CryptCATAdminCalcHashFromFileHandle2(admin, file.0, &mut size, None, 0) };
+            let mut hash = vec![0u8; size as usize];
+            if size == 0
+                || !unsafe { CryptCATAdminCalcHashFromFileHandle2(admin, file.0, &mut size, Some(hash.as_mut_ptr()), 0) }
+                    .as_bool()
+            {
+                return None;
+            }
+            let catalog = unsafe { CryptCATAdminEnumCatalogFromHash(admin, &hash, 0, None) };
+            if catalog == 0 {
+                return None;
+            }
+            let mut info = CATALOG_INFO { cbStruct: std::mem::size_of::<CATALOG_INFO>() as u32, ..Default::default() };
+            let found = unsafe { CryptCATCatalogInfoFromContext(catalog, &mut info, 0) }.as_bool();
+            let result = found.then(|| {
+                let tag: String = hash.iter().map(|byte| format!("{:02X}", byte)).collect();
+                let tag = to_wide(&tag);
+                let mut member = WINTRUST_CATALOG_INFO {
+                    cbStruct: std::mem::size_of::<WINTRUST_CATALOG_INFO>() as u32,
+                    pcwszCatalogFilePath: PCWSTR(info.wszCatalogFile.as_ptr()),
+                    pcwszMemberTag: PCWSTR(tag.as_ptr()),
+                    pcwszMemberFilePath: PCWSTR(path.as_ptr()),
+                    hMemberFile: file.0,
+                    pbCalculatedFileHash: hash.as_mut_ptr(),
+                    cbCalculatedFileHash: hash.len() as u32,
+                    hCatAdmin: admin,
+                    ..Default::default()
+                };
+                run(WINTRUST_DATA_0 { pCatalog: &mut member }, true)
+            });
+            unsafe { CryptCATAdminReleaseCatalogContext(admin, catalog, 0) };
+            result
+        })();
+        unsafe { CryptCATAdminReleaseContext(admin, 0) };
+        result
+    }
+
+    fn verify(path: &str) -> ProcessSignature {
+        let wide = to_wide(path);
+        let mut file = WINTRUST_FILE_INFO {
+            cbStruct: std::mem::size_of::<WINTRUST_FILE_INFO>() as u32,
+            pcwszFilePath: PCWSTR(wide.as_ptr()),
+            ..Default::default()
+        };
+        let (mut code, mut chain) = run(WINTRUST_DATA_0 { pFile: &mut file }, false);
+        let mut catalog_signed = false;
+        if status(code) == SignatureStatus::Unsigned {
+            if let Some((catalog_code, catalog_chain)) = verify_catalog(&wide) {
+                (code, chain, catalog_signed) = (catalog_code, catalog_chain, true);
+            }
+        }
+        let status = status(code);
+        ProcessSignature {
+            path: path.to_string(),
+            status,
+            catalog_signed,
+            publisher: chain.first().map(|certificate| certificate.subject.clone()),
+            issuer: chain.first().map(|certificate| certificate.issuer.clone()),
+            chain,
+            error_code: (code != 0).then(|| format!("0x{:08X}", code as u32)),
+        }
+    }
+
+    pub fn verify_cached(path: String) -> ProcessSignature {
+        let modified = std::fs::metadata(&path).and_then(|metadata| metadata.modified()).ok();
+        if let Some(signature) = CACHE.lock().unwrap().get_or_insert_with(Cache::default).get(&path, modified) {
+            return signature;
+        }
+        // Sin el bloqueo: la verificación de un archivo grande puede tardar
+        let signature = verify(&path);
+        CACHE.lock().unwrap().get_or_insert_with(Cache::default).insert(path, modified, signature.clone());
+        signature
+    }
+}
+
+#[tauri::command]
+pub fn verify_process_signature(state: State<AppState>, pid: u32) -> Result<ProcessSignature, String> {
+    #[cfg(target_os = "windows")]
+    {
+        let path = crate::win::process_image_path(pid)
+            .or_else(|| crate::relaunch::capture(&state, pid).map(|process| process.exe))
+            .ok_or_else(|| format!("No se pudo obtener la ruta del proceso {}", pid))?;
+        Ok(windows_trust::verify_cached(path))
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = (state, pid);
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
@@ -0,0 +1,322 @@
+// src-tauri/src/startup.rs
+// Programas de inicio: claves Run/RunOnce, carpetas Inicio y tareas
+// programadas que arrancan al iniciar sesión. Se habilitan y deshabilitan
+// como lo hace el Administrador de tareas (StartupApproved), sin borrar nada
+
+use serde::{Serialize, Deserialize};
+use tauri::State;
+
+use crate::audit::AuditOrigin;
+use crate::AppState;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StartupSource {
+    Run,
+    RunOnce,
+    StartupFolder,
+    ScheduledTask,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StartupScope {
+    User,    // Solo el usuario actual (HKCU, carpeta del perfil)
+    Machine, // Todos los usuarios; cambiarlo requiere administrador
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct StartupItem {
+    pub id: String, // Para enable_startup_item / disable_startup_item
+    pub name: String,
+    pub source: StartupSource,
+    pub scope: StartupScope,
+    pub command: String,
+    pub path: Option<String>, // Ejecutable, si se pudo deducir de la orden
+    pub publisher: Option<String>,
+    pub enabled: bool,
+    pub can_toggle: bool, // RunOnce no admite deshabilitarse
+}
+
+#[cfg(target_os = "windows")]
+mod windows_startup {
+    use super::{StartupItem, StartupScope, StartupSource};
+    use windows::Win32::System::Registry::{HKEY, HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE};
+
+    const APPROVED_KEY: &str = r"Software\Microsoft\Windows\CurrentVersion\Explorer\StartupApproved";
+
+    // (prefijo del id, ámbito, origen, clave, subclave de StartupApproved o None si no se puede deshabilitar)
+    pub const REGISTRY_LOCATIONS: [(&str, StartupScope, StartupSource, &str, Option<&str>); 5] = [
+        ("run:user", StartupScope::User, StartupSource::Run, r"Software\Microsoft\Windows\CurrentVersion\Run", Some("Run")),
+        ("run:machine", StartupScope::Machine, StartupSource::Run, r"SOFTWARE\Microsoft\Windows\CurrentVersion\Run", Some("Run")),
+        (
+            "run32:machine",
+            StartupScope::Machine,
+            StartupSource::Run,
+            r"SOFTWARE\WOW6432Node\Microsoft\Windows\CurrentVersion\Run",
+            Some("Run32"),
+        ),
+        ("runonce:user", StartupScope::User, StartupSource::RunOnce, r"Software\Microsoft\Windows\CurrentVersion\RunOnce", None),
+        (
+            "runonce:machine",
+            StartupScope::Machine,
+            StartupSource::RunOnce,
+            r"SOFTWARE\Microsoft\Windows\CurrentVersion\RunOnce",
+            None,
+        ),
+    ];
+
+    pub fn root(scope: StartupScope) -> HKEY {
+        match scope {
+            StartupScope::User => HKEY_CURRENT_USER,
+            StartupScope::Machine => HKEY_LOCAL_MACHINE,
+        }
+    }
+
+    pub fn startup_folder(scope: StartupScope) -> Option<std::path::PathBuf> {
+        let (var, relative) = match scope {
+            StartupScope::User => ("APPDATA", r"Microsoft\Windows\Start Menu\Programs\Startup"),
+            StartupScope::Machine => ("ProgramData", r"Microsoft\Windows\Start Menu\Programs\StartUp"),
+        };
+        std::env::var(var).ok().map(|base| std::path::Path::new(&base).join(relative))
+    }
+
+    // Primer byte par = habilitado; sin valor también cuenta como habilitado
+    pub fn is_approved(scope: StartupScope, approved: &str, name: &str) -> bool {
+        let key = format!(r"{}\{}", APPROVED_KEY, approved);
+        crate::win::read_registry_binary(root(scope), &key, name)
+            .and_then(|data| data.first().copied())
+            .is_none_or(|flag| flag & 1 == 0)
+    }
+
+    // El Administrador de tareas guarda 0x03 y la fecha al deshabilitar, 0x02 al habilitar
+    pub fn set_approved(scope: StartupScope, approved: &str, name: &str, enabled: bool) -> Result<(), String> {
+        let mut data = [0u8; 12];
+        if enabled {
+            data[0] = 0x02;
+        } else {
+            data[0] = 0x03;
+            let filetime = (crate::stats::now_millis() + 11_644_473_600_000) * 10_000;
+            data[4..].copy_from_slice(&filetime.to_le_bytes());
+        }
+        let key = format!(r"{}\{}", APPROVED_KEY, approved);
+        crate::win::write_registry_binary(root(scope), &key, name, &data)
+    }
+
+    // Expande %VARIABLE% con el entorno del proceso
+    pub fn expand_env(value: &str) -> String {
+        let mut output = String::new();
+        let mut rest = value;
+        while let Some(start) = rest.find('%') {
+            let Some(len) = rest[start + 1..].find('%') else { break };
+            let name = &rest[start + 1..start + 1 + len];
+            output.push_str(&rest[..start]);
+            match std::env::var(name) {
+                Ok(expanded) if !name.is_empty() => output.push_str(&expanded),
+                _ => output.push_str(&rest[start..start + len + 2]),
+            }
+            rest = &rest[start + len + 2..];
+        }
+        output + rest
+    }
+
+    // Ejecutable de una orden: entre comillas o hasta el primer ".exe"
+    pub fn executable(command: &str) -> Option<String> {
+        let command = expand_env(command.trim());
+        let program = match command.to_lowercase().find(".exe") {
+            Some(end) if !command.starts_with('"') => command[..end + 4].to_string(),
+            _ => crate::debugger::split_program(&command).0,
+        };
+        (!program.is_empty()).then_some(program)
+    }
+
+    pub fn item(
+        id: String,
+        name: String,
+        source: StartupSource,
+        scope: StartupScope,
+        command: String,
+        path: Option<String>,
+        enabled: bool,
+    ) -> StartupItem {
+        let publisher = path.as_deref().and_then(crate::win::file_company_name);
+        StartupItem { id, name, source, scope, command, path, publisher, enabled, can_toggle: source != StartupSource::RunOnce }
+    }
+
+    fn xml_unescape(value: &str) -> String {
+        value
+            .replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&quot;", "\"")
+            .replace("&apos;", "'")
+            .replace("&amp;", "&")
+    }
+
+    fn xml_text<'a>(xml: &'a str, tag: &str) -> Option<&'a str> {
+        let open = format!("<{}>", tag);
+        let start = xml.find(&open)? + open.len();
+        let end = xml[start..].find(&format!("</{}>", tag))? + start;
+        Some(&xml[start..end])
+    }
+
+    // Tareas con desencadenador de inicio de sesión o de arranque, salvo las de
+    // Windows (\Microsoft\). `schtasks /query /xml ONE` da todas en un documento,
+    // cada una precedida de un comentario con su ruta
+    pub fn scheduled_tasks() -> Result<Vec<StartupItem>, String> {
+        let output = std::process::Command::new("schtasks")
+            .args(["/Query", "/XML", "ONE"])
+            .output()
+            .map_err(|e| format!("Error al ejecutar schtasks: {}", e))?;
+        if !output.status.success() {
+            return Err(format!(
+                "No se pudieron consultar las tareas programadas: {}",
+                crate::win::decode_console_output(&output.stderr).trim()
+            ));
+        }
+        let xml = crate::win::decode_console_output(&output.stdout);
+
+        let mut items = Vec::new();
+        for chunk in xml.split("<!-- ").skip(1) {
+            let Some((path, task)) = chunk.split_once(" -->") else { continue };
+            let path = path.trim();
+            if path.starts_with(r"\Microsoft\") || !(task.contains("<LogonTrigger") || task.contains("<BootTrigger")) {
+                continue;
+            }
+            let Some(exec) = xml_text(task, "Exec") else { continue };
+            let Some(program) = xml_text(exec, "Command").map(xml_unescape) else { continue };
+            let arguments = xml_text(exec, "Arguments").map(xml_unescape).unwrap_or_default();
+            // El <Enabled> de <Settings> es el de la tarea; los desencadenadores tienen el suyo
+            let enabled = task
+                .find("<Settings>")
+                .and_then(|start| xml_text(&task[start..], "Enabled"))
+                .is_none_or(|value| value.trim() != "false");
+            let program = expand_env(program.trim_matches('"'));
+            let command = format!("{} {}", program, arguments).trim().to_string();
+            let scope = if task.contains("<UserId>S-1-5-18</UserId>") { StartupScope::Machine } else { StartupScope::User };
+            let name = path.rsplit('\\').next().unwrap_or(path).to_string();
+            items.push(item(
+                format!("task:{}", path),
+                name,
+                StartupSource::ScheduledTask,
+                scope,
+                command,
+                Some(program),
+                enabled,
+            ));
+        }
+        Ok(items)
+    }
+}
+
+#[tauri::command]
+pub fn get_startup_items() -> Result<Vec<StartupItem>, String> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows_startup::*;
+
+        let mut items = Vec::new();
+        for (prefix, scope, source, key, approved) in REGISTRY_LOCATIONS {
+            for name in crate::win::registry_value_names(root(scope), key) {
+                let Some(command) = crate::win::read_registry_string(root(scope), key, &name) else { continue };
+                let enabled = approved.is_none_or(|approved| is_approved(scope, approved, &name));
+                let path = executable(&command);
+                items.push(item(format!("{}:{}", prefix, name), name, source, scope, command, path, enabled));
+            }
+        }
+        for scope in [StartupScope::User, StartupScope::Machine] {
+            let Some(entries) = startup_folder(scope).and_then(|folder| std::fs::read_dir(folder).ok()) else { continue };
+            for entry in entries.flatten() {
+                let file = entry.file_name().to_string_lossy().to_string();
+                if file.eq_ignore_ascii_case("desktop.ini") {
+                    continue;
+                }
+                let path = entry.path().to_string_lossy().to_string();
+                let scope_id = if scope == StartupScope::User { "user" } else { "machine" };
+                let enabled = is_approved(scope, "StartupFolder", &file);
+                // Los accesos directos no se resuelven: la ruta es la del .lnk
+                let name = file.strip_suffix(".lnk").unwrap_or(&file).to_string();
+                let executable = path.to_lowercase().ends_with(".exe").then(|| path.clone());
+                items.push(item(
+                    format!("folder:{}:{}", scope_id, file),
+                    name,
+                    StartupSource::StartupFolder,
+                    scope,
+                    path,
+                    executable,
+                    enabled,
+                ));
+            }
+        }
+        // Sin permisos para consultar las tareas se devuelve el resto
+        items.extend(scheduled_tasks().unwrap_or_default());
+        items.sort_by_cached_key(|item| item.name.to_lowercase());
+        Ok(items)
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
+
+fn set_enabled(id: &str, enabled: bool) -> Result<(), String> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows_startup::*;
+
+        if let Some(path) = id.strip_prefix("task:") {
+            let flag = if enabled { "/ENABLE" } else { "/DISABLE" };
+            let output = std::process::Command::new("schtasks")
+                .args(["/Change", "/TN", path, flag])
+                .output()
+                .map_err(|e| format!("Error al ejecutar schtasks: {}", e))?;
+            if !output.status.success() {
+                return Err(format!(
+                    "No se pudo cambiar la tarea {}: {}",
+                    path,
+                    crate::win::decode_console_output(&output.stderr).trim()
+                ));
+            }
+            return Ok(());
+        }
+        if let Some(rest) = id.strip_prefix("folder:") {
+            let (scope, file) = match rest.split_once(':') {
+                Some(("user", file)) => (StartupScope::User, file),
+                Some(("machine", file)) => (StartupScope::Machine, file),
+                _ => return Err(format!("Elemento de inicio no válido: {}", id)),
+            };
+            return set_approved(scope, "StartupFolder", file, enabled);
+        }
+        let location = REGISTRY_LOCATIONS
+            .iter()
+            .find_map(|(prefix, scope, _, _, approved)| {
+                let name = id.strip_prefix(prefix)?.strip_prefix(':')?;
+                Some((*scope, *approved, name))
+            })
+            .ok_or_else(|| format!("Elemento de inicio no válido: {}", id))?;
+        match location {
+            (scope, Some(approved), name) => set_approved(scope, approved, name, enabled),
+            (_, None, _) => Err("Las entradas RunOnce no se pueden deshabilitar".to_string()),
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = (id, enabled);
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
+
+#[tauri::command]
+pub fn enable_startup_item(state: State<AppState>, id: String) -> Result<(), String> {
+    let result = set_enabled(&id, true);
+    crate::audit::record(&state, AuditOrigin::User, "enable_startup_item", &id, None, None, &result);
+    result
+}
+
+#[tauri::command]
+pub fn disable_startup_item(state: State<AppState>, id: String) -> Result<(), String> {
+    let result = set_enabled(&id, false);
+    crate::audit::record(&state, AuditOrigin::User, "disable_startup_item", &id, None, None, &result);
+    result
+}
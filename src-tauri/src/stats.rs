@@ -0,0 +1,154 @@
+// src-tauri/src/stats.rs
+// Estadísticas globales del sistema (equivalente a la pestaña Rendimiento:
+// CPU por núcleo, memoria, intercambio, tiempo activo y totales de GPU)
+
+use serde::{Serialize, Deserialize};
+use std::collections::VecDeque;
+use std::time::{SystemTime, UNIX_EPOCH};
+use sysinfo::{CpuExt, ProcessRefreshKind, System, SystemExt};
+use tauri::State;
+
+use crate::gpu::GpuAdapterUsage;
+use crate::paging::{paginate, Page};
+use crate::schema::SchemaVersion;
+use crate::AppState;
+
+// Muestras guardadas en el historial (p. ej. 10 minutos a una muestra por segundo)
+pub const HISTORY_CAPACITY: usize = 600;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct SystemStats {
+    #[serde(default = "SchemaVersion::legacy")]
+    pub schema_version: SchemaVersion,
+    pub timestamp: u64,         // Milisegundos desde UNIX_EPOCH
+    pub process_count: u32,
+    pub thread_count: Option<u32>,
+    pub handle_count: Option<u32>,
+    pub cpu_usage: f32,       // Porcentaje global
+    pub core_usage: Vec<f32>, // Porcentaje por procesador lógico
+    pub cpu_frequency_mhz: u64,
+    pub cpu_model: String,
+    pub total_memory: u64, // En bytes
+    pub used_memory: u64,
+    pub available_memory: u64,
+    pub total_swap: u64,
+    pub used_swap: u64,
+    pub uptime_secs: u64,
+    pub load_average: Option<[f64; 3]>, // 1, 5 y 15 minutos; Windows no la tiene
+    #[serde(default)]
+    pub gpu_usage: Option<f32>, // El adaptador más ocupado; None sin colector de GPU
+    #[serde(default)]
+    pub gpus: Vec<GpuAdapterUsage>, // Del último refresco de procesos
+}
+
+#[derive(Debug, Default)]
+pub struct SystemHistory {
+    samples: VecDeque<SystemStats>,
+}
+
+impl SystemHistory {
+    pub fn push(&mut self, stats: SystemStats) {
+        if self.samples.len() == HISTORY_CAPACITY {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(stats);
+    }
+
+    pub fn since(&self, timestamp: u64) -> Vec<SystemStats> {
+        self.samples
+            .iter()
+            .filter(|sample| sample.timestamp >= timestamp)
+            .cloned()
+            .collect()
+    }
+}
+
+pub fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+#[cfg(target_os = "windows")]
+fn object_counts(system: &System) -> (u32, Option<u32>, Option<u32>) {
+    use windows::Win32::System::ProcessStatus::{GetPerformanceInfo, PERFORMANCE_INFORMATION};
+
+    let mut info = PERFORMANCE_INFORMATION::default();
+    let size = std::mem::size_of::<PERFORMANCE_INFORMATION>() as u32;
+    if unsafe { GetPerformanceInfo(&mut info, size) }.as_bool() {
+        (info.ProcessCount, Some(info.ThreadCount), Some(info.HandleCount))
+    } else {
+        (system.processes().len() as u32, None, None)
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+fn object_counts(system: &System) -> (u32, Option<u32>, Option<u32>) {
+    (system.processes().len() as u32, None, None)
+}
+
+pub fn collect(system: &System) -> SystemStats {
+    let (process_count, thread_count, handle_count) = object_counts(system);
+    let load = system.load_average();
+    SystemStats {
+        schema_version: SchemaVersion::default(),
+        timestamp: now_millis(),
+        process_count,
+        thread_count,
+        handle_count,
+        cpu_usage: system.global_cpu_info().cpu_usage(),
+        core_usage: system.cpus().iter().map(|cpu| cpu.cpu_usage()).collect(),
+        cpu_frequency_mhz: system.cpus().iter().map(|cpu| cpu.frequency()).max().unwrap_or(0),
+        cpu_model: system.cpus().first().map(|cpu| cpu.brand().trim().to_string()).unwrap_or_default(),
+        total_memory: system.total_memory(),
+        used_memory: system.used_memory(),
+        available_memory: system.available_memory(),
+        total_swap: system.total_swap(),
+        used_swap: system.used_swap(),
+        uptime_secs: system.uptime(),
+        load_average: (!cfg!(target_os = "windows")).then_some([load.one, load.five, load.fifteen]),
+        gpu_usage: None,
+        gpus: Vec::new(),
+    }
+}
+
+#[tauri::command]
+pub fn get_system_stats(state: State<AppState>) -> SystemStats {
+    // Con el muestreo activo CPU, memoria y procesos ya están al día; refrescar
+    // la CPU aquí acortaría el intervalo sobre el que se calcula el uso
+    let mut stats = if crate::sampler::is_active() {
+        collect(&state.system.read().unwrap())
+    } else {
+        let mut system = state.system.write().unwrap();
+        system.refresh_processes_specifics(ProcessRefreshKind::new());
+        system.refresh_memory();
+        system.refresh_cpu();
+        collect(&system)
+    };
+    stats.gpus = state.gpu.lock().unwrap().adapters();
+    stats.gpu_usage = stats.gpus.iter().map(|gpu| gpu.utilization).reduce(f32::max);
+    state.history.write().unwrap().push(stats.clone());
+    stats
+}
+
+// Devuelve las muestras de los últimos `duration_secs` segundos
+#[tauri::command]
+pub fn get_system_history(state: State<AppState>, duration_secs: u64) -> Vec<SystemStats> {
+    let since = now_millis().saturating_sub(duration_secs * 1000);
+    state.history.read().unwrap().since(since)
+}
+
+#[tauri::command]
+pub fn get_system_history_page(
+    state: State<AppState>,
+    duration_secs: u64,
+    cursor: Option<String>,
+    limit: Option<usize>,
+) -> Result<Page<SystemStats>, String> {
+    let since = now_millis().saturating_sub(duration_secs * 1000);
+    let history = state.history.read().unwrap();
+    let samples = history.samples.iter().filter(|sample| sample.timestamp >= since);
+    paginate(samples, |sample| sample.timestamp, cursor.as_deref(), limit)
+}
@@ -0,0 +1,60 @@
+// src-tauri/src/status.rs
+// Estado de cada proceso como en la columna "Estado" del Administrador de
+// tareas: una aplicación con alguna ventana colgada aparece como "No responde"
+
+use serde::{Serialize, Deserialize};
+use std::collections::HashSet;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ProcessStatus {
+    #[default]
+    Running,
+    Suspended,
+    NotResponding,
+}
+
+impl ProcessStatus {
+    // Un proceso suspendido tampoco atiende sus ventanas: prevalece la suspensión
+    pub fn resolve(suspended: bool, not_responding: bool) -> Self {
+        match (suspended, not_responding) {
+            (true, _) => ProcessStatus::Suspended,
+            (false, true) => ProcessStatus::NotResponding,
+            (false, false) => ProcessStatus::Running,
+        }
+    }
+}
+
+// PIDs con alguna ventana visible de nivel superior colgada. IsHungAppWindow
+// no envía mensajes (no bloquea el refresco): Windows la considera colgada
+// cuando lleva 5 s sin atender su cola de mensajes
+pub fn not_responding_pids() -> HashSet<u32> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::Foundation::{BOOL, HWND, LPARAM};
+        use windows::Win32::UI::WindowsAndMessaging::{
+            EnumWindows, GetWindowThreadProcessId, IsHungAppWindow, IsWindowVisible,
+        };
+
+        unsafe extern "system" fn visit(hwnd: HWND, lparam: LPARAM) -> BOOL {
+            let hung = &mut *(lparam.0 as *mut HashSet<u32>);
+            if IsWindowVisible(hwnd).as_bool() && IsHungAppWindow(hwnd).as_bool() {
+                let mut owner = 0u32;
+                GetWindowThreadProcessId(hwnd, Some(&mut owner));
+                if owner != 0 {
+                    hung.insert(owner);
+                }
+            }
+            true.into()
+        }
+
+        let mut hung = HashSet::new();
+        unsafe { EnumWindows(Some(visit), LPARAM(&mut hung as *mut HashSet<u32> as isize)) };
+        hung
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        HashSet::new()
+    }
+}
@@ -0,0 +1,179 @@
+// src-tauri/src/stress.rs
+// Generadores de carga para comprobar columnas y alertas. Se ejecutan como
+// procesos hijos (el propio ejecutable con `--stress ...`) ligados al job de
+// limpieza, de modo que terminan al cerrar la aplicación
+
+use serde::{Serialize, Deserialize};
+use std::io::Write;
+use std::time::{Duration, Instant};
+use tauri::State;
+
+use crate::AppState;
+
+// Límite de duración de cualquier prueba
+const MAX_DURATION_SECS: u64 = 600;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum StressKind {
+    Cpu { threads: u32 },
+    Memory { megabytes: u64 },
+    Disk { megabytes: u64 },
+}
+
+impl StressKind {
+    fn to_args(self) -> Vec<String> {
+        let (kind, amount) = match self {
+            StressKind::Cpu { threads } => ("cpu", threads as u64),
+            StressKind::Memory { megabytes } => ("memory", megabytes),
+            StressKind::Disk { megabytes } => ("disk", megabytes),
+        };
+        vec![kind.to_string(), amount.to_string()]
+    }
+
+    fn from_args(kind: &str, amount: u64) -> Option<Self> {
+        Some(match kind {
+            "cpu" => StressKind::Cpu { threads: amount.max(1) as u32 },
+            "memory" => StressKind::Memory { megabytes: amount },
+            "disk" => StressKind::Disk { megabytes: amount },
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StressTask {
+    pub pid: u32,
+    pub stress: StressKind,
+    pub started: u64,
+    pub duration_secs: u64,
+}
+
+fn run_cpu(threads: u32, deadline: Instant) {
+    let workers: Vec<_> = (0..threads)
+        .map(|_| {
+            std::thread::spawn(move || {
+                let mut value = 0u64;
+                while Instant::now() < deadline {
+                    for _ in 0..100_000 {
+                        value = std::hint::black_box(value.wrapping_mul(6364136223846793005).wrapping_add(1));
+                    }
+                }
+            })
+        })
+        .collect();
+    for worker in workers {
+        let _ = worker.join();
+    }
+}
+
+// Se escribe en cada página para que la memoria quede realmente comprometida
+fn run_memory(megabytes: u64, deadline: Instant) {
+    let mut block = vec![0u8; (megabytes * 1024 * 1024) as usize];
+    for page in block.chunks_mut(4096) {
+        page[0] = 1;
+    }
+    std::hint::black_box(&block);
+    std::thread::sleep(deadline.saturating_duration_since(Instant::now()));
+}
+
+// Reescribe un archivo temporal de `megabytes` hasta el final de la prueba
+fn run_disk(megabytes: u64, deadline: Instant) {
+    let path = std::env::temp_dir().join(format!("task-manager-stress-{}.tmp", std::process::id()));
+    let chunk = vec![0xA5u8; 1024 * 1024];
+    while Instant::now() < deadline {
+        let Ok(mut file) = std::fs::File::create(&path) else {
+            break;
+        };
+        for _ in 0..megabytes.max(1) {
+            if Instant::now() >= deadline || file.write_all(&chunk).is_err() {
+                break;
+            }
+        }
+        let _ = file.sync_all();
+    }
+    let _ = std::fs::remove_file(&path);
+}
+
+// Punto de entrada del proceso hijo: `--stress <tipo> <cantidad> <segundos>`.
+// Devuelve false si el proceso no se lanzó como generador de carga
+pub fn run_from_args() -> bool {
+    let args: Vec<String> = std::env::args().collect();
+    let Some(position) = args.iter().position(|arg| arg == "--stress") else {
+        return false;
+    };
+    let amount = args.get(position + 2).and_then(|value| value.parse().ok()).unwrap_or(1);
+    let seconds = args.get(position + 3).and_then(|value| value.parse().ok()).unwrap_or(30u64);
+    let deadline = Instant::now() + Duration::from_secs(seconds.min(MAX_DURATION_SECS));
+    match args.get(position + 1).and_then(|kind| StressKind::from_args(kind, amount)) {
+        Some(StressKind::Cpu { threads }) => run_cpu(threads, deadline),
+        Some(StressKind::Memory { megabytes }) => run_memory(megabytes, deadline),
+        Some(StressKind::Disk { megabytes }) => run_disk(megabytes, deadline),
+        None => {}
+    }
+    true
+}
+
+fn prune_finished(tasks: &mut Vec<StressTask>) {
+    let now = crate::stats::now_millis();
+    tasks.retain(|task| task.started + task.duration_secs * 1000 > now);
+}
+
+#[tauri::command]
+pub fn start_stress(state: State<AppState>, stress: StressKind, duration_secs: u64) -> Result<StressTask, String> {
+    let exe = std::env::current_exe()
+        .map_err(|e| format!("No se pudo localizar el ejecutable: {}", e))?;
+    let duration_secs = duration_secs.clamp(1, MAX_DURATION_SECS);
+    let mut args = vec!["--stress".to_string()];
+    args.extend(stress.to_args());
+    args.push(duration_secs.to_string());
+
+    let pid = crate::launch::spawn_task(&state, &exe.to_string_lossy(), &args, true)?;
+    let task = StressTask {
+        pid,
+        stress,
+        started: crate::stats::now_millis(),
+        duration_secs,
+    };
+    let mut tasks = state.stress_tasks.lock().unwrap();
+    prune_finished(&mut tasks);
+    tasks.push(task.clone());
+    Ok(task)
+}
+
+// Pruebas en curso
+#[tauri::command]
+pub fn get_stress_tasks(state: State<AppState>) -> Vec<StressTask> {
+    let mut tasks = state.stress_tasks.lock().unwrap();
+    prune_finished(&mut tasks);
+    tasks.clone()
+}
+
+#[tauri::command]
+pub fn stop_stress(state: State<AppState>, pid: u32) -> Result<(), String> {
+    let mut tasks = state.stress_tasks.lock().unwrap();
+    let Some(index) = tasks.iter().position(|task| task.pid == pid) else {
+        return Err(format!("El proceso {} no es una prueba de carga", pid));
+    };
+    tasks.remove(index);
+
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::System::Threading::{TerminateProcess, PROCESS_TERMINATE};
+
+        let process = crate::win::open_process(pid, PROCESS_TERMINATE)?;
+        if !unsafe { TerminateProcess(process.0, 0) }.as_bool() {
+            return Err(format!("No se pudo detener la prueba: {}", windows::core::Error::from_win32()));
+        }
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        std::process::Command::new("kill")
+            .arg(pid.to_string())
+            .status()
+            .map(|_| ())
+            .map_err(|e| format!("No se pudo detener la prueba: {}", e))
+    }
+}
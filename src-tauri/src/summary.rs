@@ -0,0 +1,67 @@
+// src-tauri/src/summary.rs
+// Resumen en lenguaje natural del estado del sistema ("CPU 82 %, sobre todo
+// chrome.exe; memoria 91 % en uso") para lectores de pantalla y el tooltip
+// de la bandeja
+
+use std::collections::HashMap;
+use sysinfo::{CpuExt, SystemExt};
+use tauri::State;
+
+use crate::AppState;
+
+// Un proceso solo se nombra si supone al menos esta parte del total
+const MIN_SHARE: f64 = 0.25;
+// Por debajo de este uso no tiene sentido señalar a nadie
+const MIN_CPU: f64 = 10.0;
+
+// Mayor consumidor agrupando los procesos por nombre (varios chrome.exe cuentan juntos)
+fn top_by_name(values: impl Iterator<Item = (String, f64)>) -> Option<(String, f64)> {
+    let mut totals: HashMap<String, f64> = HashMap::new();
+    for (name, value) in values {
+        *totals.entry(name).or_default() += value;
+    }
+    totals.into_iter().max_by(|a, b| a.1.total_cmp(&b.1))
+}
+
+pub fn describe(state: &AppState) -> String {
+    let (cpu, cores, used_memory, total_memory) = {
+        let system = state.system.read().unwrap();
+        (
+            system.global_cpu_info().cpu_usage() as f64,
+            system.cpus().len().max(1) as f64,
+            system.used_memory() as f64,
+            system.total_memory() as f64,
+        )
+    };
+    let tree = state.processes.read().unwrap();
+    if tree.processes.is_empty() || total_memory == 0.0 {
+        return "Sin datos del sistema todavía".to_string();
+    }
+
+    let mut summary = format!("CPU {:.0} %", cpu);
+    // El uso de CPU de un proceso es por núcleo: se pasa a porcentaje del equipo
+    let top_cpu = top_by_name(
+        tree.processes
+            .iter()
+            .map(|process| (process.name.clone(), process.cpu_usage as f64 / cores)),
+    );
+    if let Some((name, _)) = top_cpu.filter(|(_, usage)| cpu >= MIN_CPU && *usage >= cpu * MIN_SHARE) {
+        summary += &format!(", sobre todo {}", name);
+    }
+
+    summary += &format!("; memoria {:.0} % en uso", used_memory / total_memory * 100.0);
+    let top_memory = top_by_name(
+        tree.processes
+            .iter()
+            .map(|process| (process.name.clone(), process.memory_usage as f64)),
+    );
+    if let Some((name, _)) = top_memory.filter(|(_, memory)| *memory >= used_memory * MIN_SHARE) {
+        summary += &format!(", sobre todo {}", name);
+    }
+    summary
+}
+
+#[tauri::command]
+pub fn describe_system_state(state: State<AppState>) -> String {
+    describe(&state)
+}
@@ -0,0 +1,123 @@
+// src-tauri/src/suspend.rs
+// Suspender y reanudar procesos, solos o con todos sus descendientes (un
+// navegador con sus procesos auxiliares) para liberar CPU temporalmente.
+// En Linux y macOS se usan SIGSTOP y SIGCONT
+
+use std::collections::HashSet;
+use std::sync::Mutex;
+use tauri::State;
+
+use crate::AppState;
+
+// Procesos suspendidos desde la aplicación; Windows no expone el estado de
+// suspensión de un proceso sin recorrer todos sus hilos
+static SUSPENDED: Mutex<Option<HashSet<u32>>> = Mutex::new(None);
+
+pub fn is_suspended(pid: u32) -> bool {
+    SUSPENDED.lock().unwrap().as_ref().is_some_and(|suspended| suspended.contains(&pid))
+}
+
+// Olvida los PIDs que ya no existen, para que un PID reutilizado no herede el estado
+pub fn retain_alive(alive: &HashSet<u32>) {
+    if let Some(suspended) = SUSPENDED.lock().unwrap().as_mut() {
+        suspended.retain(|pid| alive.contains(pid));
+    }
+}
+
+#[cfg(target_os = "windows")]
+#[link(name = "ntdll")]
+extern "system" {
+    fn NtSuspendProcess(process: windows::Win32::Foundation::HANDLE) -> i32;
+    fn NtResumeProcess(process: windows::Win32::Foundation::HANDLE) -> i32;
+}
+
+pub fn set_suspended(state: &AppState, pid: u32, suspended: bool) -> Result<(), String> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::System::Threading::PROCESS_SUSPEND_RESUME;
+
+        let _ = state;
+        let process = crate::win::open_process(pid, PROCESS_SUSPEND_RESUME)?;
+        let status = unsafe {
+            if suspended {
+                NtSuspendProcess(process.0)
+            } else {
+                NtResumeProcess(process.0)
+            }
+        };
+        if status < 0 {
+            return Err(format!("NTSTATUS 0x{:08X} en el proceso {}", status, pid));
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        use sysinfo::{PidExt, ProcessExt, Signal, SystemExt};
+
+        let signal = if suspended { Signal::Stop } else { Signal::Continue };
+        let system = state.system.read().unwrap();
+        let process = system
+            .process(sysinfo::Pid::from_u32(pid))
+            .ok_or_else(|| format!("El proceso {} ya no está en ejecución", pid))?;
+        if process.kill_with(signal) != Some(true) {
+            return Err(format!("No se pudo enviar la señal al proceso {}", pid));
+        }
+    }
+
+    let mut set = SUSPENDED.lock().unwrap();
+    let set = set.get_or_insert_with(HashSet::new);
+    if suspended {
+        set.insert(pid);
+    } else {
+        set.remove(&pid);
+    }
+    Ok(())
+}
+
+fn targets(state: &AppState, pid: u32, tree: bool) -> Vec<u32> {
+    if tree {
+        crate::tree_order(&state.processes.read().unwrap(), pid)
+    } else {
+        vec![pid]
+    }
+}
+
+// Todo o nada: si un proceso falla se reanudan los ya suspendidos.
+// Devuelve los PIDs suspendidos
+#[tauri::command]
+pub fn suspend_process(state: State<AppState>, pid: u32, tree: Option<bool>) -> Result<Vec<u32>, String> {
+    let tree = tree.unwrap_or(false);
+    crate::actions::run_once(&state, pid, &format!("suspend:{}", tree), || {
+        let targets = targets(&state, pid, tree);
+        for (index, target) in targets.iter().enumerate() {
+            if let Err(e) = set_suspended(&state, *target, true) {
+                for done in targets[..index].iter().rev() {
+                    let _ = set_suspended(&state, *done, false);
+                }
+                return Err(format!("No se pudo suspender el proceso {}: {}", target, e));
+            }
+        }
+        Ok(targets)
+    })
+}
+
+// Del raíz a las hojas; se reanudan todos los posibles aunque alguno falle
+#[tauri::command]
+pub fn resume_process(state: State<AppState>, pid: u32, tree: Option<bool>) -> Result<Vec<u32>, String> {
+    let tree = tree.unwrap_or(false);
+    crate::actions::run_once(&state, pid, &format!("resume:{}", tree), || {
+        let mut resumed = Vec::new();
+        let mut errors = Vec::new();
+        for target in targets(&state, pid, tree).into_iter().rev() {
+            match set_suspended(&state, target, false) {
+                Ok(()) => resumed.push(target),
+                Err(e) => errors.push(e),
+            }
+        }
+        if errors.is_empty() {
+            Ok(resumed)
+        } else {
+            Err(format!("No se pudieron reanudar todos los procesos: {}", errors.join("; ")))
+        }
+    })
+}
@@ -0,0 +1,73 @@
+// src-tauri/src/text.rs
+// Comparación y búsqueda de nombres con caracteres no ASCII (cirílico, CJK,
+// acentos). Los nombres de archivo de Windows no distinguen mayúsculas en
+// ningún alfabeto, así que "ПРОГРАММА.exe" y "программа.exe" son el mismo
+// ejecutable; eq_ignore_ascii_case solo pliega el ASCII
+
+use serde::{Serialize, Deserialize};
+
+// Plegados opcionales de la búsqueda; las mayúsculas se pliegan siempre
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(default)]
+pub struct SearchNormalization {
+    pub fold_diacritics: bool, // "cafe" encuentra "café", "ёлка" y "елка" coinciden
+    pub fold_width: bool,      // Letras y dígitos de ancho completo (ＡＢＣ１２３) como ASCII
+}
+
+// Letras con diacríticos más habituales (latín, griego y la ё cirílica), ya
+// en minúsculas, y su letra base
+const DIACRITICS: &[(&str, char)] = &[
+    ("àáâãäåāăą", 'a'),
+    ("çćĉċč", 'c'),
+    ("ďđ", 'd'),
+    ("èéêëēĕėęě", 'e'),
+    ("ĝğġģ", 'g'),
+    ("ĥħ", 'h'),
+    ("ìíîïĩīĭįı", 'i'),
+    ("ĵ", 'j'),
+    ("ķ", 'k'),
+    ("ĺļľŀł", 'l'),
+    ("ñńņňŉ", 'n'),
+    ("òóôõöøōŏő", 'o'),
+    ("ŕŗř", 'r'),
+    ("śŝşš", 's'),
+    ("ţťŧ", 't'),
+    ("ùúûüũūŭůűų", 'u'),
+    ("ŵ", 'w'),
+    ("ýÿŷ", 'y'),
+    ("źżž", 'z'),
+    ("ё", 'е'),
+    ("ά", 'α'),
+    ("έ", 'ε'),
+    ("ή", 'η'),
+    ("ίϊΐ", 'ι'),
+    ("ό", 'ο'),
+    ("ύϋΰ", 'υ'),
+    ("ώ", 'ω'),
+];
+
+fn fold_diacritic(c: char) -> char {
+    DIACRITICS.iter().find(|(accented, _)| accented.contains(c)).map_or(c, |(_, base)| *base)
+}
+
+// Formas de ancho completo (U+FF01-FF5E) y el espacio ideográfico
+fn fold_width(c: char) -> char {
+    match c {
+        '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+        '\u{3000}' => ' ',
+        _ => c,
+    }
+}
+
+pub fn normalize(text: &str, options: SearchNormalization) -> String {
+    text.chars()
+        .map(|c| if options.fold_width { fold_width(c) } else { c })
+        .flat_map(char::to_lowercase)
+        .map(|c| if options.fold_diacritics { fold_diacritic(c) } else { c })
+        .collect()
+}
+
+// Mismo ejecutable o servicio sin distinguir mayúsculas en ningún alfabeto
+pub fn same_name(a: &str, b: &str) -> bool {
+    a.eq_ignore_ascii_case(b) || a.chars().flat_map(char::to_lowercase).eq(b.chars().flat_map(char::to_lowercase))
+}
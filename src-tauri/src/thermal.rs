@@ -0,0 +1,249 @@
+// src-tauri/src/thermal.rs
+// Detección de estrangulamiento térmico: caídas de frecuencia con carga
+// sostenida, límite de rendimiento y temperatura de las zonas térmicas.
+// Los episodios quedan registrados con su inicio y fin
+
+use serde::{Serialize, Deserialize};
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+use tauri::State;
+
+use crate::AppState;
+
+const EVENT_CAPACITY: usize = 200;
+const LOAD_THRESHOLD: f64 = 50.0; // Uso (%) a partir del que se considera carga
+const SUSTAINED_SAMPLES: u32 = 3;
+const FREQUENCY_DROP: f64 = 0.8; // Fracción de la frecuencia máxima observada con carga
+const PERFORMANCE_LIMIT: f64 = 95.0;
+const HOT_CELSIUS: f64 = 90.0;
+const GPU_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ThermalReading {
+    pub cpu_load: Option<f64>,
+    pub cpu_frequency_mhz: Option<f64>,
+    pub cpu_performance_limit: Option<f64>, // 100 = sin límite
+    pub temperature_celsius: Option<f64>,   // Zona térmica más caliente
+    pub gpu_temperature_celsius: Option<f64>,
+    pub gpu_thermal_slowdown: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ThrottleEvent {
+    pub component: String, // cpu, gpu
+    pub reason: String,    // temperature, performance_limit, frequency_drop, thermal_slowdown
+    pub started_at: u64,   // Milisegundos desde UNIX_EPOCH
+    pub ended_at: Option<u64>,
+    pub min_frequency_mhz: Option<f64>,
+    pub max_temperature_celsius: Option<f64>,
+}
+
+#[derive(Default)]
+pub struct ThermalCollector {
+    #[cfg(target_os = "windows")]
+    query: Option<crate::pdh::PdhQuery>,
+    #[cfg(target_os = "windows")]
+    zones: Option<usize>, // Índice del contador de zonas térmicas, si existe
+    reading: ThermalReading,
+    loaded_samples: u32,
+    peak_frequency: f64, // Máxima frecuencia vista con carga
+    cpu_event: Option<usize>, // Índice del episodio en curso en `events`
+    gpu_event: Option<usize>,
+    gpu_checked: Option<Instant>,
+    events: VecDeque<ThrottleEvent>,
+    pub error: Option<String>,
+}
+
+// Temperatura y bajada de reloj térmica de la NVIDIA más caliente, por NVML
+fn read_gpu() -> Option<(f64, bool)> {
+    use nvml_wrapper::bitmasks::device::ThrottleReasons;
+    use nvml_wrapper::enum_wrappers::device::TemperatureSensor;
+
+    let nvml = crate::gpu::nvml()?;
+    let thermal = ThrottleReasons::HW_THERMAL_SLOWDOWN | ThrottleReasons::SW_THERMAL_SLOWDOWN;
+    (0..nvml.device_count().ok()?)
+        .filter_map(|index| nvml.device_by_index(index).ok())
+        .filter_map(|device| {
+            let temperature = device.temperature(TemperatureSensor::Gpu).ok()? as f64;
+            let slowdown = device.current_throttle_reasons().is_ok_and(|reasons| reasons.intersects(thermal));
+            Some((temperature, slowdown))
+        })
+        .reduce(|a, b| (a.0.max(b.0), a.1 || b.1))
+}
+
+impl ThermalCollector {
+    #[cfg(target_os = "windows")]
+    fn read_cpu(&mut self) -> Result<ThermalReading, String> {
+        if self.query.is_none() {
+            let mut query = crate::pdh::PdhQuery::open()?;
+            query.add_counter(r"\Processor Information(_Total)\% Processor Utility")?;
+            query.add_counter(r"\Processor Information(_Total)\Processor Frequency")?;
+            query.add_counter(r"\Processor Information(_Total)\% Processor Performance")?;
+            query.add_counter(r"\Processor Information(_Total)\% Performance Limit")?;
+            // Puede no existir en equipos sin zonas ACPI expuestas
+            self.zones = query.add_counter(r"\Thermal Zone Information(*)\Temperature").ok();
+            query.collect()?;
+            self.query = Some(query);
+        }
+        let query = self.query.as_ref().unwrap();
+        query.collect()?;
+
+        let total = |index: usize| query.values(index).ok().and_then(|values| values.first().map(|(_, value)| *value));
+        // La frecuencia nominal se escala con el rendimiento para reflejar el turbo
+        let frequency = match (total(1), total(2)) {
+            (Some(nominal), Some(performance)) => Some(nominal * performance / 100.0),
+            (nominal, _) => nominal,
+        };
+        let temperature = self
+            .zones
+            .and_then(|index| query.values(index).ok())
+            .and_then(|zones| zones.iter().map(|(_, kelvin)| kelvin - 273.15).reduce(f64::max));
+        Ok(ThermalReading {
+            cpu_load: total(0).map(|load| load.min(100.0)),
+            cpu_frequency_mhz: frequency,
+            cpu_performance_limit: total(3),
+            temperature_celsius: temperature,
+            ..Default::default()
+        })
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    fn read_cpu(&mut self) -> Result<ThermalReading, String> {
+        Err("La detección de estrangulamiento de CPU solo está disponible en Windows".to_string())
+    }
+
+    fn open_event(&mut self, component: &str, reason: &str, frequency: Option<f64>, temperature: Option<f64>) -> usize {
+        if self.events.len() == EVENT_CAPACITY {
+            self.events.pop_front();
+            // Los índices de los episodios abiertos se desplazan
+            self.cpu_event = self.cpu_event.and_then(|index| index.checked_sub(1));
+            self.gpu_event = self.gpu_event.and_then(|index| index.checked_sub(1));
+        }
+        self.events.push_back(ThrottleEvent {
+            component: component.to_string(),
+            reason: reason.to_string(),
+            started_at: crate::stats::now_millis(),
+            ended_at: None,
+            min_frequency_mhz: frequency,
+            max_temperature_celsius: temperature,
+        });
+        self.events.len() - 1
+    }
+
+    fn update_event(&mut self, index: usize, frequency: Option<f64>, temperature: Option<f64>) {
+        if let Some(event) = self.events.get_mut(index) {
+            if let Some(frequency) = frequency {
+                event.min_frequency_mhz = Some(event.min_frequency_mhz.map_or(frequency, |min| min.min(frequency)));
+            }
+            if let Some(temperature) = temperature {
+                event.max_temperature_celsius =
+                    Some(event.max_temperature_celsius.map_or(temperature, |max| max.max(temperature)));
+            }
+        }
+    }
+
+    fn close_event(&mut self, index: Option<usize>) {
+        if let Some(event) = index.and_then(|index| self.events.get_mut(index)) {
+            event.ended_at = Some(crate::stats::now_millis());
+        }
+    }
+
+    // Sin los contadores de rendimiento en este equipo (o fuera de Windows),
+    // que no es un fallo
+    pub fn is_unavailable(&self) -> bool {
+        #[cfg(target_os = "windows")]
+        return self.query.is_none();
+        #[cfg(not(target_os = "windows"))]
+        true
+    }
+
+    // Toma una muestra y devuelve los episodios que acaban de empezar
+    pub fn sample(&mut self) -> Vec<ThrottleEvent> {
+        let mut started = Vec::new();
+        let mut reading = match self.read_cpu() {
+            Ok(reading) => {
+                self.error = None;
+                reading
+            }
+            Err(e) => {
+                self.error = Some(e);
+                ThermalReading::default()
+            }
+        };
+        // La GPU se consulta con menos frecuencia: se conserva la última lectura
+        reading.gpu_temperature_celsius = self.reading.gpu_temperature_celsius;
+        reading.gpu_thermal_slowdown = self.reading.gpu_thermal_slowdown;
+        self.reading = reading.clone();
+
+        // CPU: solo cuenta con carga sostenida, para no confundir el ahorro en reposo
+        let loaded = reading.cpu_load.is_some_and(|load| load >= LOAD_THRESHOLD);
+        self.loaded_samples = if loaded { self.loaded_samples + 1 } else { 0 };
+        if loaded {
+            if let Some(frequency) = reading.cpu_frequency_mhz {
+                self.peak_frequency = self.peak_frequency.max(frequency);
+            }
+        }
+        let reason = if self.loaded_samples < SUSTAINED_SAMPLES {
+            None
+        } else if reading.temperature_celsius.is_some_and(|celsius| celsius >= HOT_CELSIUS) {
+            Some("temperature")
+        } else if reading.cpu_performance_limit.is_some_and(|limit| limit < PERFORMANCE_LIMIT) {
+            Some("performance_limit")
+        } else if reading.cpu_frequency_mhz.is_some_and(|frequency| frequency < self.peak_frequency * FREQUENCY_DROP) {
+            Some("frequency_drop")
+        } else {
+            None
+        };
+        match (reason, self.cpu_event) {
+            (Some(reason), None) => {
+                let index = self.open_event("cpu", reason, reading.cpu_frequency_mhz, reading.temperature_celsius);
+                self.cpu_event = Some(index);
+                started.push(self.events[index].clone());
+            }
+            (Some(_), Some(index)) => self.update_event(index, reading.cpu_frequency_mhz, reading.temperature_celsius),
+            (None, Some(_)) => {
+                let index = self.cpu_event.take();
+                self.close_event(index);
+            }
+            (None, None) => {}
+        }
+
+        // GPU (NVIDIA): el propio controlador informa de la bajada térmica
+        if self.gpu_checked.is_none_or(|at| at.elapsed() >= GPU_CHECK_INTERVAL) {
+            self.gpu_checked = Some(Instant::now());
+            let gpu = read_gpu();
+            self.reading.gpu_temperature_celsius = gpu.map(|(temperature, _)| temperature);
+            self.reading.gpu_thermal_slowdown = gpu.is_some_and(|(_, slowdown)| slowdown);
+            let temperature = self.reading.gpu_temperature_celsius;
+            match (self.reading.gpu_thermal_slowdown, self.gpu_event) {
+                (true, None) => {
+                    let index = self.open_event("gpu", "thermal_slowdown", None, temperature);
+                    self.gpu_event = Some(index);
+                    started.push(self.events[index].clone());
+                }
+                (true, Some(index)) => self.update_event(index, None, temperature),
+                (false, Some(_)) => {
+                    let index = self.gpu_event.take();
+                    self.close_event(index);
+                }
+                (false, None) => {}
+            }
+        }
+        started
+    }
+}
+
+#[tauri::command]
+pub fn get_thermal_status(state: State<AppState>) -> Result<ThermalReading, String> {
+    let thermal = state.thermal.lock().unwrap();
+    match &thermal.error {
+        Some(error) if thermal.reading.gpu_temperature_celsius.is_none() => Err(error.clone()),
+        _ => Ok(thermal.reading.clone()),
+    }
+}
+
+// Episodios registrados, del más antiguo al más reciente
+#[tauri::command]
+pub fn get_throttle_events(state: State<AppState>) -> Vec<ThrottleEvent> {
+    state.thermal.lock().unwrap().events.iter().cloned().collect()
+}
@@ -0,0 +1,115 @@
+// src-tauri/src/tray.rs
+// Icono de bandeja (mostrar, cambio de perfil y de lista de vigilancia, salir)
+// y arranque minimizado
+// (`--minimized` / `--tray`), pensado para el inicio automático con la sesión
+
+use tauri::menu::{Menu, MenuItem, Submenu};
+use tauri::tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent};
+use tauri::{App, AppHandle, Manager, Wry};
+
+use crate::AppState;
+
+const PROFILE_PREFIX: &str = "profile:";
+const WATCHLIST_PREFIX: &str = "watchlist:";
+const WATCHLIST_OFF: &str = "watchlist-off";
+const TOOLTIP: &str = "Administrador de tareas";
+
+// Argumentos de línea de comandos que inician la aplicación solo en la bandeja
+pub fn start_minimized() -> bool {
+    std::env::args().skip(1).any(|arg| arg == "--minimized" || arg == "--tray")
+}
+
+pub fn show_main_window(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.unminimize();
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+}
+
+// Resumen del estado actual bajo el nombre de la aplicación
+pub fn set_tooltip(app: &AppHandle, summary: &str) {
+    if let Some(tray) = app.tray_by_id("main") {
+        let _ = tray.set_tooltip(Some(format!("{}\n{}", TOOLTIP, summary)));
+    }
+}
+
+// Perfiles y listas de vigilancia de la configuración actual
+fn menu(app: &AppHandle) -> tauri::Result<Menu<Wry>> {
+    let show = MenuItem::with_id(app, "show", "Mostrar", true, None::<&str>)?;
+    let quit = MenuItem::with_id(app, "quit", "Salir", true, None::<&str>)?;
+    let menu = Menu::with_items(app, &[&show])?;
+
+    let state = app.state::<AppState>();
+    let (profiles, watchlists): (Vec<String>, Vec<String>) = {
+        let settings = state.settings.read().unwrap();
+        (
+            settings.profiles.iter().map(|profile| profile.name.clone()).collect(),
+            settings.watchlists.iter().map(|watchlist| watchlist.name.clone()).collect(),
+        )
+    };
+    if !profiles.is_empty() {
+        let submenu = Submenu::with_id(app, "profiles", "Perfil", true)?;
+        for name in &profiles {
+            let id = format!("{}{}", PROFILE_PREFIX, name);
+            submenu.append(&MenuItem::with_id(app, id, name, true, None::<&str>)?)?;
+        }
+        menu.append(&submenu)?;
+    }
+    if !watchlists.is_empty() {
+        let submenu = Submenu::with_id(app, "watchlists", "Lista de vigilancia", true)?;
+        submenu.append(&MenuItem::with_id(app, WATCHLIST_OFF, "Todos los procesos", true, None::<&str>)?)?;
+        for name in &watchlists {
+            let id = format!("{}{}", WATCHLIST_PREFIX, name);
+            submenu.append(&MenuItem::with_id(app, id, name, true, None::<&str>)?)?;
+        }
+        menu.append(&submenu)?;
+    }
+    menu.append(&quit)?;
+    Ok(menu)
+}
+
+// Rehace el menú tras cambiar los perfiles o las listas de vigilancia
+pub fn refresh(app: &AppHandle) {
+    if let (Some(tray), Ok(menu)) = (app.tray_by_id("main"), menu(app)) {
+        let _ = tray.set_menu(Some(menu));
+    }
+}
+
+pub fn create(app: &App) -> tauri::Result<()> {
+    let menu = menu(app.handle())?;
+
+    let mut builder = TrayIconBuilder::with_id("main")
+        .tooltip(TOOLTIP)
+        .menu(&menu)
+        .show_menu_on_left_click(false)
+        .on_menu_event(|app, event| match event.id().as_ref() {
+            "show" => show_main_window(app),
+            "quit" => app.exit(0),
+            WATCHLIST_OFF => {
+                let _ = crate::watchlists::switch(app, None);
+            }
+            id => {
+                if let Some(name) = id.strip_prefix(PROFILE_PREFIX) {
+                    let _ = crate::profiles::switch(app, name);
+                } else if let Some(name) = id.strip_prefix(WATCHLIST_PREFIX) {
+                    let _ = crate::watchlists::switch(app, Some(name));
+                }
+            }
+        })
+        .on_tray_icon_event(|tray, event| {
+            if let TrayIconEvent::Click {
+                button: MouseButton::Left,
+                button_state: MouseButtonState::Up,
+                ..
+            } = event
+            {
+                show_main_window(tray.app_handle());
+            }
+        });
+    if let Some(icon) = app.default_window_icon() {
+        builder = builder.icon(icon.clone());
+    }
+    builder.build(app)?;
+    Ok(())
+}
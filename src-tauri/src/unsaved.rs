@@ -0,0 +1,71 @@
+// src-tauri/src/unsaved.rs
+// Heurísticas para avisar antes de terminar un proceso que podría tener
+// trabajo sin guardar (títulos con marca de modificado o editores conocidos)
+
+use serde::{Serialize, Deserialize};
+
+// Ejecutables de editores y suites ofimáticas (en minúsculas)
+const KNOWN_EDITORS: &[&str] = &[
+    "notepad.exe",
+    "notepad++.exe",
+    "wordpad.exe",
+    "winword.exe",
+    "excel.exe",
+    "powerpnt.exe",
+    "onenote.exe",
+    "outlook.exe",
+    "soffice.bin",
+    "code.exe",
+    "devenv.exe",
+    "sublime_text.exe",
+    "photoshop.exe",
+    "illustrator.exe",
+    "mspaint.exe",
+    "blender.exe",
+];
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct UnsavedWorkWarning {
+    pub pid: u32,
+    pub name: String,
+    pub known_editor: bool,
+    pub modified_windows: Vec<String>, // Títulos con marca de documento modificado
+}
+
+// Marcas habituales: "*documento", "documento*", "● archivo", "[Modified]"
+#[cfg(target_os = "windows")]
+fn has_modified_marker(title: &str) -> bool {
+    let title = title.trim();
+    let lower = title.to_lowercase();
+    title.starts_with('*')
+        || title.ends_with('*')
+        || title.contains(" *")
+        || title.contains('●')
+        || title.contains('•')
+        || lower.contains("[modified]")
+        || lower.contains("(modified)")
+        || lower.contains("(modificado)")
+}
+
+// `None` si no hay indicios de trabajo sin guardar
+pub fn check(pid: u32, name: &str) -> Option<UnsavedWorkWarning> {
+    let known_editor = KNOWN_EDITORS.contains(&name.to_lowercase().as_str());
+
+    #[cfg(target_os = "windows")]
+    let modified_windows: Vec<String> = crate::win::visible_window_titles(pid)
+        .into_iter()
+        .filter(|title| has_modified_marker(title))
+        .collect();
+    #[cfg(not(target_os = "windows"))]
+    let modified_windows: Vec<String> = Vec::new();
+
+    if !known_editor && modified_windows.is_empty() {
+        return None;
+    }
+    Some(UnsavedWorkWarning {
+        pid,
+        name: name.to_string(),
+        known_editor,
+        modified_windows,
+    })
+}
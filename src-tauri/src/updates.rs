@@ -0,0 +1,55 @@
+// src-tauri/src/updates.rs
+// Comprobación de actualizaciones con el actualizador de Tauri; devuelve la
+// versión disponible y sus notas sin descargar nada. Se puede desactivar
+// desde la configuración, y queda desactivada mientras tauri.conf.json no
+// tenga la clave pública con la que se firman las versiones
+
+use serde::Serialize;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_updater::UpdaterExt;
+
+use crate::AppState;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct UpdateInfo {
+    pub current_version: String,
+    pub available: bool,
+    pub version: Option<String>,   // Versión nueva, si la hay
+    pub changelog: Option<String>, // Notas de la versión tal como las publica el servidor
+    pub date: Option<String>,      // Fecha de publicación (RFC 3339)
+}
+
+// Sin clave no se puede comprobar la firma de lo que se descargue
+fn has_public_key(app: &AppHandle) -> bool {
+    let config = &app.config().plugins.0;
+    let pubkey = config.get("updater").and_then(|updater| updater.get("pubkey"));
+    pubkey.and_then(|pubkey| pubkey.as_str()).is_some_and(|pubkey| !pubkey.trim().is_empty())
+}
+
+#[tauri::command]
+pub async fn check_for_updates(app: AppHandle) -> Result<UpdateInfo, String> {
+    if !app.state::<AppState>().settings.read().unwrap().check_for_updates {
+        return Err("La comprobación de actualizaciones está desactivada".to_string());
+    }
+    if !has_public_key(&app) {
+        return Err("Esta compilación no tiene configurada la clave del actualizador".to_string());
+    }
+    let current_version = app.package_info().version.to_string();
+    let updater = app
+        .updater()
+        .map_err(|e| format!("No se pudo iniciar el actualizador: {}", e))?;
+    let update = updater
+        .check()
+        .await
+        .map_err(|e| format!("No se pudo comprobar si hay actualizaciones: {}", e))?;
+    Ok(match update {
+        Some(update) => UpdateInfo {
+            current_version,
+            available: true,
+            version: Some(update.version),
+            changelog: update.body,
+            date: update.date.map(|date| date.to_string()),
+        },
+        None => UpdateInfo { current_version, available: false, version: None, changelog: None, date: None },
+    })
+}
@@ -0,0 +1,188 @@
+// src-tauri/src/usagestats.rs
+// Estadísticas de uso locales: cuántas veces se usa cada función y el coste
+// medio del propio administrador, para ajustar la configuración. Se guardan en
+// usage_stats.json y nunca se envían fuera del equipo
+
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager, State};
+
+use crate::selfmon::SelfMetrics;
+use crate::stats::now_millis;
+use crate::AppState;
+
+const SAVE_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct FeatureUsage {
+    pub uses: u64,
+    pub first_used: u64, // Milisegundos desde UNIX_EPOCH
+    pub last_used: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+struct CollectorTotals {
+    calls: u64,
+    total_ms: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+struct StoredStats {
+    since: u64,
+    sessions: u64,
+    features: BTreeMap<String, FeatureUsage>, // Comando -> usos
+    samples: u64,                             // Refrescos con el consumo propio medido
+    cpu_total: f64,
+    memory_total: f64,
+    collectors: BTreeMap<String, CollectorTotals>, // Sesiones anteriores
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct CollectorOverhead {
+    pub collector: String,
+    pub calls: u64,
+    pub average_ms: f64,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct FeatureStats {
+    pub feature: String,
+    #[serde(flatten)]
+    pub usage: FeatureUsage,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct UsageStatsReport {
+    pub since: u64,
+    pub sessions: u64,
+    pub features: Vec<FeatureStats>, // De más a menos usada
+    pub average_cpu_usage: Option<f64>,
+    pub average_memory_usage: Option<u64>,
+    pub collectors: Vec<CollectorOverhead>, // Incluye la sesión actual
+    pub path: Option<String>,              // Dónde se guardan, para poder revisarlas
+}
+
+#[derive(Debug, Default)]
+pub struct UsageStats {
+    stored: StoredStats,
+    path: Option<PathBuf>,
+    last_save: Option<Instant>,
+}
+
+impl UsageStats {
+    // Un archivo ausente o corrupto empieza de cero; cada carga es una sesión
+    pub fn load(path: &Path) -> Self {
+        let mut stored: StoredStats = fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        if stored.since == 0 {
+            stored.since = now_millis();
+        }
+        stored.sessions += 1;
+        UsageStats { stored, path: Some(path.to_path_buf()), ..Default::default() }
+    }
+
+    // Tiempos de las sesiones anteriores más los de la actual
+    fn collectors(&self, session: &SelfMetrics) -> BTreeMap<String, CollectorTotals> {
+        let mut collectors = self.stored.collectors.clone();
+        for (collector, calls, total) in session.totals() {
+            let totals = collectors.entry(collector.to_string()).or_default();
+            totals.calls += calls;
+            totals.total_ms += total.as_secs_f64() * 1000.0;
+        }
+        collectors
+    }
+
+    pub fn save(&mut self, session: &SelfMetrics) -> Result<(), String> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("No se pudo crear {}: {}", dir.display(), e))?;
+        }
+        let stored = StoredStats { collectors: self.collectors(session), ..self.stored.clone() };
+        let content = serde_json::to_string(&stored)
+            .map_err(|e| format!("Error al serializar las estadísticas de uso: {}", e))?;
+        fs::write(path, content).map_err(|e| format!("No se pudo guardar {}: {}", path.display(), e))?;
+        self.last_save = Some(Instant::now());
+        Ok(())
+    }
+
+    pub fn record_feature(&mut self, feature: &str) {
+        let now = now_millis();
+        let usage = self.stored.features.entry(feature.to_string()).or_default();
+        if usage.uses == 0 {
+            usage.first_used = now;
+        }
+        usage.uses += 1;
+        usage.last_used = now;
+    }
+
+    // Consumo propio en un refresco; guarda a intervalos
+    pub fn record_overhead(&mut self, cpu_usage: f32, memory_usage: u64, session: &SelfMetrics) {
+        self.stored.samples += 1;
+        self.stored.cpu_total += cpu_usage as f64;
+        self.stored.memory_total += memory_usage as f64;
+        if self.last_save.is_none_or(|at| at.elapsed() >= SAVE_INTERVAL) {
+            let _ = self.save(session);
+        }
+    }
+}
+
+// Cada comando invocado desde la interfaz cuenta como un uso de esa función
+pub fn record_command(app: &AppHandle, command: &str) {
+    let state = app.state::<AppState>();
+    if state.settings.read().unwrap().usage_stats {
+        state.usage_stats.lock().unwrap().record_feature(command);
+    }
+}
+
+#[tauri::command]
+pub fn get_usage_stats(state: State<AppState>) -> UsageStatsReport {
+    let stats = state.usage_stats.lock().unwrap();
+    let stored = &stats.stored;
+    let mut features: Vec<FeatureStats> = stored
+        .features
+        .iter()
+        .map(|(feature, usage)| FeatureStats { feature: feature.clone(), usage: usage.clone() })
+        .collect();
+    features.sort_by_key(|feature| std::cmp::Reverse(feature.usage.uses));
+
+    let samples = (stored.samples > 0).then_some(stored.samples as f64);
+    let collectors = stats
+        .collectors(&state.self_metrics.lock().unwrap())
+        .into_iter()
+        .map(|(collector, totals)| CollectorOverhead {
+            collector,
+            calls: totals.calls,
+            average_ms: totals.total_ms / totals.calls.max(1) as f64,
+        })
+        .collect();
+
+    UsageStatsReport {
+        since: stored.since,
+        sessions: stored.sessions,
+        features,
+        average_cpu_usage: samples.map(|samples| stored.cpu_total / samples),
+        average_memory_usage: samples.map(|samples| (stored.memory_total / samples) as u64),
+        collectors,
+        path: stats.path.as_ref().map(|path| path.display().to_string()),
+    }
+}
+
+// Borra lo acumulado, también del disco y los tiempos de esta sesión
+#[tauri::command]
+pub fn clear_usage_stats(state: State<AppState>) -> Result<(), String> {
+    let mut stats = state.usage_stats.lock().unwrap();
+    stats.stored = StoredStats { since: now_millis(), sessions: 1, ..Default::default() };
+    let mut session = state.self_metrics.lock().unwrap();
+    *session = SelfMetrics::default();
+    stats.save(&session)
+}
@@ -0,0 +1,211 @@
+// src-tauri/src/usersessions.rs
+// Pestaña Usuarios: sesiones de Terminal Services (consola, escritorio
+// remoto...) con su usuario, estado y el consumo sumado de sus procesos, y las
+// acciones de desconectar o cerrar la sesión
+
+use serde::Serialize;
+use tauri::State;
+
+use crate::AppState;
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionState {
+    Active,
+    Connected,
+    Disconnected, // Sigue abierta, con sus programas, pero sin nadie conectado
+    Idle,
+    Listening, // Sesión de escucha de RDP, sin usuario
+    Other,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct UserSession {
+    pub session_id: u32,
+    pub station: String, // "Console", "RDP-Tcp#3"...
+    pub user: Option<String>, // None en la sesión 0 de los servicios
+    pub domain: Option<String>,
+    pub state: SessionState,
+    pub logon_time: Option<u64>, // Milisegundos Unix
+    pub current: bool,           // La sesión en la que se ejecuta el administrador de tareas
+    pub process_count: usize,
+    pub cpu_usage: f32,
+    pub memory_usage: u64,
+    pub pids: Vec<u32>,
+}
+
+#[cfg(target_os = "windows")]
+mod wts {
+    use super::SessionState;
+    use windows::Win32::System::RemoteDesktop::*;
+
+    pub struct SessionDetails {
+        pub session_id: u32,
+        pub station: String,
+        pub user: String,
+        pub domain: String,
+        pub state: SessionState,
+        pub logon_time: i64, // FILETIME, 0 si no hay usuario
+    }
+
+    pub fn state(state: WTS_CONNECTSTATE_CLASS) -> SessionState {
+        let states = [
+            (WTSActive, SessionState::Active),
+            (WTSConnected, SessionState::Connected),
+            (WTSDisconnected, SessionState::Disconnected),
+            (WTSIdle, SessionState::Idle),
+            (WTSListen, SessionState::Listening),
+        ];
+        states.iter().find(|(class, _)| *class == state).map_or(SessionState::Other, |&(_, state)| state)
+    }
+
+    fn details(session: &WTS_SESSION_INFOW) -> SessionDetails {
+        let station = unsafe { session.pWinStationName.to_string() }.unwrap_or_default();
+        let mut details = SessionDetails {
+            session_id: session.SessionId,
+            station,
+            user: String::new(),
+            domain: String::new(),
+            state: state(session.State),
+            logon_time: 0,
+        };
+        let mut buffer = windows::core::PWSTR::null();
+        let mut size = 0u32;
+        let ok = unsafe {
+            WTSQuerySessionInformationW(WTS_CURRENT_SERVER_HANDLE, session.SessionId, WTSSessionInfo, &mut buffer, &mut size)
+        };
+        if ok.as_bool() && !buffer.is_null() {
+            if size as usize >= std::mem::size_of::<WTSINFOW>() {
+                let info = unsafe { &*(buffer.0 as *const WTSINFOW) };
+                details.user = crate::win::from_wide(&info.UserName);
+                details.domain = crate::win::from_wide(&info.Domain);
+                details.logon_time = info.LogonTime;
+            }
+            unsafe { WTSFreeMemory(buffer.0 as *mut _) };
+        }
+        details
+    }
+
+    pub fn sessions() -> Result<Vec<SessionDetails>, String> {
+        let mut list: *mut WTS_SESSION_INFOW = std::ptr::null_mut();
+        let mut count = 0u32;
+        if !unsafe { WTSEnumerateSessionsW(WTS_CURRENT_SERVER_HANDLE, 0, 1, &mut list, &mut count) }.as_bool() {
+            return Err(format!("No se pudieron enumerar las sesiones: {}", windows::core::Error::from_win32()));
+        }
+        let sessions = unsafe { std::slice::from_raw_parts(list, count as usize) }.iter().map(details).collect();
+        unsafe { WTSFreeMemory(list as *mut _) };
+        Ok(sessions)
+    }
+
+    pub fn session_of(pid: u32) -> Option<u32> {
+        let mut session = 0u32;
+        unsafe { ProcessIdToSessionId(pid, &mut session) }.as_bool().then_some(session)
+    }
+}
+
+// FILETIME (intervalos de 100 ns desde 1601) a milisegundos Unix
+#[cfg(target_os = "windows")]
+fn filetime_to_millis(filetime: i64) -> Option<u64> {
+    const UNIX_EPOCH: i64 = 116_444_736_000_000_000;
+    (filetime > UNIX_EPOCH).then(|| ((filetime - UNIX_EPOCH) / 10_000) as u64)
+}
+
+// Los procesos se reparten con los datos del último refresco completo
+#[tauri::command]
+pub fn get_user_sessions(state: State<AppState>) -> Result<Vec<UserSession>, String> {
+    #[cfg(target_os = "windows")]
+    {
+        let current = wts::session_of(std::process::id());
+        let mut sessions: Vec<UserSession> = wts::sessions()?
+            .into_iter()
+            .map(|details| UserSession {
+                session_id: details.session_id,
+                station: details.station,
+                user: Some(details.user).filter(|user| !user.is_empty()),
+                domain: Some(details.domain).filter(|domain| !domain.is_empty()),
+                state: details.state,
+                logon_time: filetime_to_millis(details.logon_time),
+                current: current == Some(details.session_id),
+                process_count: 0,
+                cpu_usage: 0.0,
+                memory_usage: 0,
+                pids: Vec::new(),
+            })
+            .collect();
+
+        let processes = state.processes.read().unwrap();
+        for process in &processes.processes {
+            let Some(id) = wts::session_of(process.pid) else { continue };
+            let Some(session) = sessions.iter_mut().find(|session| session.session_id == id) else { continue };
+            session.process_count += 1;
+            session.cpu_usage += process.cpu_usage;
+            session.memory_usage += process.memory_usage;
+            session.pids.push(process.pid);
+        }
+        // Las de escucha no tienen procesos ni se pueden cerrar
+        sessions.retain(|session| session.state != SessionState::Listening);
+        Ok(sessions)
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = state;
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
+
+#[derive(Clone, Copy)]
+enum SessionAction {
+    Logoff,
+    Disconnect,
+}
+
+fn session_action(state: &AppState, session_id: u32, action: SessionAction) -> Result<(), String> {
+    #[cfg(target_os = "windows")]
+    {
+        use crate::audit::AuditOrigin;
+        use windows::Win32::Foundation::BOOL;
+        use windows::Win32::System::RemoteDesktop::{WTSDisconnectSession, WTSLogoffSession, WTS_CURRENT_SERVER_HANDLE};
+
+        let user = wts::sessions()?
+            .into_iter()
+            .find(|session| session.session_id == session_id)
+            .map(|session| session.user)
+            .ok_or_else(|| format!("No existe la sesión {}", session_id))?;
+        // Sin esperar: cerrar la sesión puede tardar mientras las aplicaciones terminan
+        let (ok, name) = match action {
+            SessionAction::Logoff => {
+                (unsafe { WTSLogoffSession(WTS_CURRENT_SERVER_HANDLE, session_id, BOOL(0)) }, "logoff_session")
+            }
+            SessionAction::Disconnect => {
+                (unsafe { WTSDisconnectSession(WTS_CURRENT_SERVER_HANDLE, session_id, BOOL(0)) }, "disconnect_session")
+            }
+        };
+        let result = if ok.as_bool() {
+            Ok(())
+        } else {
+            Err(format!("No se pudo actuar sobre la sesión {}: {}", session_id, windows::core::Error::from_win32()))
+        };
+        let target = if user.is_empty() { format!("sesión {}", session_id) } else { user };
+        crate::audit::record(state, AuditOrigin::User, name, &target, None, None, &result);
+        result
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = (state, session_id, action);
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
+
+// Cierra la sesión y todos sus programas; el trabajo sin guardar se pierde
+#[tauri::command]
+pub fn logoff_session(state: State<AppState>, session_id: u32) -> Result<(), String> {
+    session_action(&state, session_id, SessionAction::Logoff)
+}
+
+// La sesión sigue abierta y se puede volver a conectar a ella
+#[tauri::command]
+pub fn disconnect_session(state: State<AppState>, session_id: u32) -> Result<(), String> {
+    session_action(&state, session_id, SessionAction::Disconnect)
+}
@@ -0,0 +1,183 @@
+// src-tauri/src/watchdog.rs
+// Vigilante de servicios: si un servicio vigilado se detiene con un código de
+// error (caída, no parada manual) se reinicia por el SCM y se avisa. Los
+// reintentos se espacian cada vez más para no entrar en un bucle de reinicios
+
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::services::{self, ServiceState};
+use crate::AppState;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+const INITIAL_BACKOFF: Duration = Duration::from_secs(10);
+const MAX_BACKOFF: Duration = Duration::from_secs(30 * 60);
+// Tras este tiempo en ejecución se olvidan los fallos anteriores
+const STABLE_AFTER: Duration = Duration::from_secs(10 * 60);
+const LOG_CAPACITY: usize = 200;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ServiceRestartEvent {
+    pub service: String,
+    pub exit_code: u32,
+    pub attempt: u32, // Reinicios seguidos sin llegar a estabilizarse
+    pub restarted: bool,
+    pub error: Option<String>,
+    pub next_retry_secs: u64, // Espera mínima antes del siguiente reinicio
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct WatchedService {
+    pub service: String,
+    pub state: Option<ServiceState>, // None si no se pudo consultar
+    pub failures: u32,
+    pub retry_in_secs: Option<u64>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Default)]
+struct ServiceWatch {
+    state: Option<ServiceState>,
+    running_since: Option<Instant>,
+    failures: u32,
+    next_attempt: Option<Instant>,
+    error: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct ServiceWatchdog {
+    services: HashMap<String, ServiceWatch>,
+    log: VecDeque<ServiceRestartEvent>,
+}
+
+// 10 s, 20 s, 40 s... hasta MAX_BACKOFF
+fn backoff(failures: u32) -> Duration {
+    INITIAL_BACKOFF.saturating_mul(1 << failures.saturating_sub(1).min(16)).min(MAX_BACKOFF)
+}
+
+impl ServiceWatchdog {
+    // Comprueba los servicios vigilados y devuelve los reinicios intentados
+    fn poll(&mut self, watched: &[String]) -> Vec<ServiceRestartEvent> {
+        self.services.retain(|name, _| watched.contains(name));
+        let mut events = Vec::new();
+        for name in watched {
+            let watch = self.services.entry(name.clone()).or_default();
+            let (state, exit_code) = match services::query_status(name) {
+                Ok(status) => status,
+                Err(e) => {
+                    watch.state = None;
+                    watch.error = Some(e);
+                    continue;
+                }
+            };
+            watch.state = Some(state);
+            match state {
+                ServiceState::Running => {
+                    let since = *watch.running_since.get_or_insert_with(Instant::now);
+                    if since.elapsed() >= STABLE_AFTER {
+                        watch.failures = 0;
+                        watch.next_attempt = None;
+                    }
+                    watch.error = None;
+                }
+                // Con código 0 se detuvo a petición: no se toca
+                ServiceState::Stopped if exit_code != 0 => {
+                    watch.running_since = None;
+                    if watch.next_attempt.is_some_and(|at| Instant::now() < at) {
+                        continue;
+                    }
+                    watch.failures += 1;
+                    let result = services::start(name);
+                    let wait = backoff(watch.failures);
+                    watch.next_attempt = Some(Instant::now() + wait);
+                    watch.error = result.as_ref().err().cloned();
+                    events.push(ServiceRestartEvent {
+                        service: name.clone(),
+                        exit_code,
+                        attempt: watch.failures,
+                        restarted: result.is_ok(),
+                        error: result.err(),
+                        next_retry_secs: wait.as_secs(),
+                        timestamp: crate::stats::now_millis(),
+                    });
+                }
+                ServiceState::Stopped => {
+                    watch.running_since = None;
+                    watch.failures = 0;
+                    watch.next_attempt = None;
+                }
+                _ => {}
+            }
+        }
+        for event in &events {
+            if self.log.len() == LOG_CAPACITY {
+                self.log.pop_front();
+            }
+            self.log.push_back(event.clone());
+        }
+        events
+    }
+}
+
+pub fn start(app: AppHandle) {
+    std::thread::spawn(move || loop {
+        std::thread::sleep(POLL_INTERVAL);
+        let state = app.state::<AppState>();
+        let watched = state.settings.read().unwrap().watched_services.clone();
+        if watched.is_empty() {
+            continue;
+        }
+        let events = state.service_watchdog.lock().unwrap().poll(&watched);
+        for event in events {
+            state.session.lock().unwrap().alert(&format!("servicio:{}", event.service));
+            let name = if event.restarted { "service-restarted" } else { "service-restart-failed" };
+            let _ = app.emit(name, event);
+        }
+    });
+}
+
+// Añade o quita un servicio de la lista vigilada y guarda la configuración
+#[tauri::command]
+pub fn set_service_watched(app: AppHandle, state: State<AppState>, name: String, watched: bool) -> Result<(), String> {
+    if watched {
+        services::query_status(&name)?;
+    }
+    let mut settings = state.settings.write().unwrap();
+    settings.watched_services.retain(|service| !service.eq_ignore_ascii_case(&name));
+    if watched {
+        settings.watched_services.push(name);
+    }
+    crate::settings::save(&app, &settings)
+}
+
+#[tauri::command]
+pub fn get_watched_services(state: State<AppState>) -> Vec<WatchedService> {
+    let watched = state.settings.read().unwrap().watched_services.clone();
+    let watchdog = state.service_watchdog.lock().unwrap();
+    watched
+        .into_iter()
+        .map(|service| match watchdog.services.get(&service) {
+            Some(watch) => WatchedService {
+                state: watch.state,
+                failures: watch.failures,
+                retry_in_secs: watch
+                    .next_attempt
+                    .map(|at| at.saturating_duration_since(Instant::now()).as_secs())
+                    .filter(|_| watch.state == Some(ServiceState::Stopped)),
+                error: watch.error.clone(),
+                service,
+            },
+            // Aún sin consultar
+            None => WatchedService { service, state: None, failures: 0, retry_in_secs: None, error: None },
+        })
+        .collect()
+}
+
+// Reinicios recientes, del más antiguo al más reciente
+#[tauri::command]
+pub fn get_service_restart_log(state: State<AppState>) -> Vec<ServiceRestartEvent> {
+    state.service_watchdog.lock().unwrap().log.iter().cloned().collect()
+}
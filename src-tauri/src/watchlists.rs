@@ -0,0 +1,235 @@
+// src-tauri/src/watchlists.rs
+// Listas de vigilancia con nombre: ejecutables y filtros que, al activarse,
+// limitan la vista de query_processes a esos procesos y añaden sus alertas a
+// las de la configuración. Se guardan en la configuración y se cambian desde
+// la bandeja, con activate_watchlist, con cycle_watchlist o con el atajo
+// global de la configuración, que pasa a la lista siguiente
+
+use serde::{Serialize, Deserialize};
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::alerts::{AlertMetric, AlertRule, AlertTarget};
+use crate::settings::Settings;
+use crate::{AppState, ProcessInfo};
+
+fn default_true() -> bool {
+    true
+}
+
+// Se aplica a cada ejecutable de la lista por separado
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct WatchlistAlert {
+    pub metric: AlertMetric,
+    pub threshold: f64,
+    #[serde(default)]
+    pub duration_secs: u64,
+    #[serde(default = "default_true")]
+    pub notify: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Watchlist {
+    pub name: String,
+    #[serde(default)]
+    pub executables: Vec<String>, // Nombre exacto, sin distinguir mayúsculas
+    #[serde(default)]
+    pub filters: Vec<String>, // Fragmentos del nombre; no generan alertas
+    #[serde(default)]
+    pub alerts: Vec<WatchlistAlert>,
+}
+
+impl Watchlist {
+    pub fn matches(&self, process: &ProcessInfo) -> bool {
+        let name = process.name.to_lowercase();
+        self.executables.iter().any(|executable| crate::text::same_name(executable, &process.name))
+            || self.filters.iter().any(|filter| name.contains(&filter.to_lowercase()))
+    }
+
+    // Una regla por ejecutable y alerta. El identificador es un FNV-1a de la
+    // lista, el ejecutable y la alerta (con el bit alto puesto para no chocar
+    // con los de add_alert_rule): no cambia entre refrescos ni entre versiones
+    // de Rust, y el historial de alertas lo guarda
+    pub fn alert_rules(&self) -> Vec<AlertRule> {
+        let mut rules = Vec::new();
+        for executable in &self.executables {
+            for (index, alert) in self.alerts.iter().enumerate() {
+                let key = format!("{}\0{}\0{}", self.name, executable.to_lowercase(), index);
+                rules.push(AlertRule {
+                    id: (1 << 63) | (fnv1a(key.as_bytes()) >> 1),
+                    target: AlertTarget::Process(executable.clone()),
+                    metric: alert.metric,
+                    threshold: alert.threshold,
+                    duration_secs: alert.duration_secs,
+                    notify: alert.notify,
+                });
+            }
+        }
+        rules
+    }
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, &byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3))
+}
+
+pub fn active(settings: &Settings) -> Option<&Watchlist> {
+    let name = settings.active_watchlist.as_ref()?;
+    settings.watchlists.iter().find(|watchlist| &watchlist.name == name)
+}
+
+// Alertas de la configuración más las de la lista activa
+pub fn alert_rules(settings: &Settings) -> Vec<AlertRule> {
+    let mut rules = settings.alert_rules.clone();
+    if let Some(watchlist) = active(settings) {
+        rules.extend(watchlist.alert_rules());
+    }
+    rules
+}
+
+pub fn validate(watchlist: &Watchlist) -> Result<(), String> {
+    if watchlist.name.trim().is_empty() {
+        return Err("Falta el nombre de la lista".to_string());
+    }
+    let blank = |value: &String| value.trim().is_empty();
+    if watchlist.executables.iter().any(blank) || watchlist.filters.iter().any(blank) {
+        return Err("La lista contiene ejecutables o filtros vacíos".to_string());
+    }
+    if watchlist.executables.is_empty() && watchlist.filters.is_empty() {
+        return Err("La lista necesita al menos un ejecutable o un filtro".to_string());
+    }
+    // Las mismas comprobaciones que una alerta suelta, por cada ejecutable
+    for executable in &watchlist.executables {
+        for alert in &watchlist.alerts {
+            crate::alerts::validate_rule(&AlertRule {
+                id: 0,
+                target: AlertTarget::Process(executable.clone()),
+                metric: alert.metric,
+                threshold: alert.threshold,
+                duration_secs: alert.duration_secs,
+                notify: alert.notify,
+            })?;
+        }
+    }
+    Ok(())
+}
+
+// None desactiva la lista y vuelve a mostrar todos los procesos
+pub fn switch(app: &AppHandle, name: Option<&str>) -> Result<Option<Watchlist>, String> {
+    let state = app.state::<AppState>();
+    let mut settings = state.settings.write().unwrap();
+    let watchlist = match name {
+        Some(name) => Some(
+            settings
+                .watchlists
+                .iter()
+                .find(|watchlist| watchlist.name == name)
+                .cloned()
+                .ok_or_else(|| format!("No existe la lista de vigilancia {}", name))?,
+        ),
+        None => None,
+    };
+    settings.active_watchlist = watchlist.as_ref().map(|watchlist| watchlist.name.clone());
+    crate::settings::save(app, &settings)?;
+    drop(settings);
+    let _ = app.emit("watchlist-changed", &watchlist);
+    Ok(watchlist)
+}
+
+#[tauri::command]
+pub fn list_watchlists(state: State<AppState>) -> Vec<Watchlist> {
+    state.settings.read().unwrap().watchlists.clone()
+}
+
+// Crea la lista o sustituye a la que tenga el mismo nombre
+#[tauri::command]
+pub fn save_watchlist(app: AppHandle, state: State<AppState>, watchlist: Watchlist) -> Result<Watchlist, String> {
+    validate(&watchlist)?;
+    let watchlist = Watchlist { name: watchlist.name.trim().to_string(), ..watchlist };
+    let mut settings = state.settings.write().unwrap();
+    match settings.watchlists.iter_mut().find(|existing| existing.name == watchlist.name) {
+        Some(existing) => *existing = watchlist.clone(),
+        None => settings.watchlists.push(watchlist.clone()),
+    }
+    crate::settings::save(&app, &settings)?;
+    drop(settings);
+    crate::tray::refresh(&app);
+    Ok(watchlist)
+}
+
+#[tauri::command]
+pub fn remove_watchlist(app: AppHandle, state: State<AppState>, name: String) -> Result<(), String> {
+    let mut settings = state.settings.write().unwrap();
+    let count = settings.watchlists.len();
+    settings.watchlists.retain(|watchlist| watchlist.name != name);
+    if settings.watchlists.len() == count {
+        return Err(format!("No existe la lista de vigilancia {}", name));
+    }
+    if settings.active_watchlist.as_ref() == Some(&name) {
+        settings.active_watchlist = None;
+        let _ = app.emit("watchlist-changed", None::<Watchlist>);
+    }
+    crate::settings::save(&app, &settings)?;
+    drop(settings);
+    crate::tray::refresh(&app);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn activate_watchlist(app: AppHandle, name: Option<String>) -> Result<Option<Watchlist>, String> {
+    switch(&app, name.as_deref())
+}
+
+// Pasa a la lista siguiente; tras la última, a ninguna
+pub fn cycle(app: &AppHandle) -> Result<Option<Watchlist>, String> {
+    let state = app.state::<AppState>();
+    let next = {
+        let settings = state.settings.read().unwrap();
+        let position = settings
+            .active_watchlist
+            .as_ref()
+            .and_then(|name| settings.watchlists.iter().position(|watchlist| &watchlist.name == name));
+        let next = position.map_or(0, |position| position + 1);
+        settings.watchlists.get(next).map(|watchlist| watchlist.name.clone())
+    };
+    switch(app, next.as_deref())
+}
+
+#[tauri::command]
+pub fn cycle_watchlist(app: AppHandle) -> Result<Option<Watchlist>, String> {
+    cycle(&app)
+}
+
+// Hilo que tiene registrado el atajo de cambio de lista
+#[derive(Debug, Default)]
+pub struct WatchlistHotkey {
+    #[cfg(target_os = "windows")]
+    listener: Option<crate::win::MessageLoop>,
+}
+
+// Registra (o retira) el atajo; se vuelve a llamar al cambiar la configuración
+pub fn apply_hotkey(app: &AppHandle, hotkey: Option<&str>) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    #[cfg(target_os = "windows")]
+    if let Some(listener) = state.watchlist_hotkey.lock().unwrap().listener.take() {
+        listener.stop();
+    }
+    let Some(hotkey) = hotkey else {
+        return Ok(());
+    };
+    let hotkey = crate::killswitch::parse_hotkey(hotkey)?;
+
+    #[cfg(target_os = "windows")]
+    {
+        let listener = crate::killswitch::register_hotkey(app, "watchlist-hotkey", hotkey, |app| {
+            let _ = cycle(app);
+        })?;
+        state.watchlist_hotkey.lock().unwrap().listener = Some(listener);
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = (state, hotkey);
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
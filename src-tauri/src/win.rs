@@ -0,0 +1,539 @@
+// src-tauri/src/win.rs
+// Utilidades compartidas para las llamadas a la API Win32
+
+use std::ffi::OsStr;
+use std::os::windows::ffi::OsStrExt;
+
+// Cadena UTF-16 terminada en nulo para los parámetros PCWSTR/PWSTR
+pub fn to_wide(value: &str) -> Vec<u16> {
+    OsStr::new(value).encode_wide().chain(std::iter::once(0)).collect()
+}
+
+// Convierte un búfer UTF-16 (posiblemente terminado en nulo) a String. Si el
+// búfer se quedó corto a mitad de un par suplente (emoji, CJK poco común) se
+// descarta la mitad suelta en lugar de acabar en U+FFFD
+pub fn from_wide(buffer: &[u16]) -> String {
+    let mut len = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+    if len > 0 && (0xD800..=0xDBFF).contains(&buffer[len - 1]) {
+        len -= 1;
+    }
+    String::from_utf16_lossy(&buffer[..len])
+}
+
+// Salida de las herramientas de consola (taskkill, schtasks, ipconfig...):
+// escriben en la página de códigos OEM, no en UTF-8 (CP866 en ruso, CP936 en
+// chino...), y from_utf8_lossy dejaría los nombres ilegibles
+pub fn decode_console_output(bytes: &[u8]) -> String {
+    use windows::Win32::Globalization::{GetOEMCP, MultiByteToWideChar, MULTI_BYTE_TO_WIDE_CHAR_FLAGS};
+
+    if let Ok(text) = std::str::from_utf8(bytes) {
+        return text.to_string();
+    }
+    let flags = MULTI_BYTE_TO_WIDE_CHAR_FLAGS(0);
+    let codepage = unsafe { GetOEMCP() };
+    let len = unsafe { MultiByteToWideChar(codepage, flags, bytes, None) };
+    if len <= 0 {
+        return String::from_utf8_lossy(bytes).to_string();
+    }
+    let mut wide = vec![0u16; len as usize];
+    let written = unsafe { MultiByteToWideChar(codepage, flags, bytes, Some(&mut wide)) }.max(0) as usize;
+    String::from_utf16_lossy(&wide[..written])
+}
+
+// Construye una línea de comandos con las reglas de comillas de CommandLineToArgvW
+pub fn build_command_line(program: &str, args: &[String]) -> String {
+    std::iter::once(program)
+        .chain(args.iter().map(|arg| arg.as_str()))
+        .map(quote_arg)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// Solo los argumentos, p. ej. para lpParameters de ShellExecuteExW
+pub fn quote_args(args: &[String]) -> String {
+    args.iter().map(|arg| quote_arg(arg)).collect::<Vec<_>>().join(" ")
+}
+
+fn quote_arg(arg: &str) -> String {
+    if !arg.is_empty() && !arg.contains([' ', '\t', '"']) {
+        return arg.to_string();
+    }
+    let mut quoted = String::from("\"");
+    let mut backslashes = 0;
+    for c in arg.chars() {
+        match c {
+            '\\' => backslashes += 1,
+            '"' => {
+                quoted.push_str(&"\\".repeat(backslashes * 2 + 1));
+                quoted.push('"');
+                backslashes = 0;
+            }
+            _ => {
+                quoted.push_str(&"\\".repeat(backslashes));
+                quoted.push(c);
+                backslashes = 0;
+            }
+        }
+    }
+    quoted.push_str(&"\\".repeat(backslashes * 2));
+    quoted.push('"');
+    quoted
+}
+
+// Variables de entorno (%SystemRoot%...) de una cadena REG_EXPAND_SZ
+pub fn expand_environment(text: &str) -> String {
+    use windows::core::PCWSTR;
+    use windows::Win32::System::Environment::ExpandEnvironmentStringsW;
+
+    let source = to_wide(text);
+    let size = unsafe { ExpandEnvironmentStringsW(PCWSTR(source.as_ptr()), None) };
+    if size == 0 {
+        return text.to_string();
+    }
+    let mut buffer = vec![0u16; size as usize];
+    if unsafe { ExpandEnvironmentStringsW(PCWSTR(source.as_ptr()), Some(&mut buffer)) } == 0 {
+        return text.to_string();
+    }
+    from_wide(&buffer)
+}
+
+// Lee un valor REG_SZ/REG_EXPAND_SZ del registro (expandido). RegGetValueW
+// rechaza pedir ambos tipos sin RRF_NOEXPAND, así que se expande aquí
+pub fn read_registry_string(
+    root: windows::Win32::System::Registry::HKEY,
+    subkey: &str,
+    value: &str,
+) -> Option<String> {
+    use windows::core::PCWSTR;
+    use windows::Win32::System::Registry::{
+        RegGetValueW, REG_EXPAND_SZ, REG_VALUE_TYPE, RRF_NOEXPAND, RRF_RT_REG_EXPAND_SZ, RRF_RT_REG_SZ,
+    };
+
+    let subkey = to_wide(subkey);
+    let value = to_wide(value);
+    let flags = RRF_RT_REG_SZ | RRF_RT_REG_EXPAND_SZ | RRF_NOEXPAND;
+    let mut size = 0u32;
+    let status = unsafe {
+        RegGetValueW(root, PCWSTR(subkey.as_ptr()), PCWSTR(value.as_ptr()), flags, None, None, Some(&mut size))
+    };
+    if status.is_err() || size == 0 {
+        return None;
+    }
+
+    let mut buffer = vec![0u16; (size as usize).div_ceil(2)];
+    let mut kind = REG_VALUE_TYPE::default();
+    let status = unsafe {
+        RegGetValueW(
+            root,
+            PCWSTR(subkey.as_ptr()),
+            PCWSTR(value.as_ptr()),
+            flags,
+            Some(&mut kind),
+            Some(buffer.as_mut_ptr() as *mut _),
+            Some(&mut size),
+        )
+    };
+    if status.is_err() {
+        return None;
+    }
+    let text = from_wide(&buffer);
+    Some(if kind == REG_EXPAND_SZ { expand_environment(&text) } else { text })
+}
+
+// Lee un valor REG_DWORD del registro
+pub fn read_registry_dword(
+    root: windows::Win32::System::Registry::HKEY,
+    subkey: &str,
+    value: &str,
+) -> Option<u32> {
+    use windows::core::PCWSTR;
+    use windows::Win32::System::Registry::{RegGetValueW, RRF_RT_REG_DWORD};
+
+    let subkey = to_wide(subkey);
+    let value = to_wide(value);
+    let mut data = 0u32;
+    let mut size = std::mem::size_of::<u32>() as u32;
+    let status = unsafe {
+        RegGetValueW(
+            root,
+            PCWSTR(subkey.as_ptr()),
+            PCWSTR(value.as_ptr()),
+            RRF_RT_REG_DWORD,
+            None,
+            Some(&mut data as *mut u32 as *mut _),
+            Some(&mut size),
+        )
+    };
+    status.is_ok().then_some(data)
+}
+
+// Lee un valor REG_BINARY del registro
+pub fn read_registry_binary(
+    root: windows::Win32::System::Registry::HKEY,
+    subkey: &str,
+    value: &str,
+) -> Option<Vec<u8>> {
+    use windows::core::PCWSTR;
+    use windows::Win32::System::Registry::{RegGetValueW, RRF_RT_REG_BINARY};
+
+    let subkey = to_wide(subkey);
+    let value = to_wide(value);
+    let mut buffer = [0u8; 256];
+    let mut size = buffer.len() as u32;
+    let status = unsafe {
+        RegGetValueW(
+            root,
+            PCWSTR(subkey.as_ptr()),
+            PCWSTR(value.as_ptr()),
+            RRF_RT_REG_BINARY,
+            None,
+            Some(buffer.as_mut_ptr() as *mut _),
+            Some(&mut size),
+        )
+    };
+    status.is_ok().then(|| buffer[..size as usize].to_vec())
+}
+
+// Escribe un valor REG_BINARY creando la clave si no existe
+pub fn write_registry_binary(
+    root: windows::Win32::System::Registry::HKEY,
+    subkey: &str,
+    value: &str,
+    data: &[u8],
+) -> Result<(), String> {
+    use windows::core::PCWSTR;
+    use windows::Win32::System::Registry::{
+        RegCloseKey, RegCreateKeyExW, RegSetValueExW, HKEY, KEY_SET_VALUE, REG_BINARY, REG_OPTION_NON_VOLATILE,
+    };
+
+    let wide_subkey = to_wide(subkey);
+    let wide_value = to_wide(value);
+    let mut key = HKEY::default();
+    let status = unsafe {
+        RegCreateKeyExW(
+            root,
+            PCWSTR(wide_subkey.as_ptr()),
+            0,
+            PCWSTR::null(),
+            REG_OPTION_NON_VOLATILE,
+            KEY_SET_VALUE,
+            None,
+            &mut key,
+            None,
+        )
+    };
+    if status.is_err() {
+        return Err(format!("No se pudo abrir {}: {}", subkey, windows::core::Error::from(status)));
+    }
+    let status = unsafe { RegSetValueExW(key, PCWSTR(wide_value.as_ptr()), 0, REG_BINARY, Some(data)) };
+    unsafe { RegCloseKey(key) };
+    if status.is_err() {
+        return Err(format!("No se pudo escribir {}\\{}: {}", subkey, value, windows::core::Error::from(status)));
+    }
+    Ok(())
+}
+
+// Nombres de los valores de una clave; vacío si no existe
+pub fn registry_value_names(root: windows::Win32::System::Registry::HKEY, subkey: &str) -> Vec<String> {
+    use windows::core::{PCWSTR, PWSTR};
+    use windows::Win32::System::Registry::{RegCloseKey, RegEnumValueW, RegOpenKeyExW, HKEY, KEY_QUERY_VALUE};
+
+    let wide_subkey = to_wide(subkey);
+    let mut key = HKEY::default();
+    if unsafe { RegOpenKeyExW(root, PCWSTR(wide_subkey.as_ptr()), 0, KEY_QUERY_VALUE, &mut key) }.is_err() {
+        return Vec::new();
+    }
+    let mut names = Vec::new();
+    for index in 0.. {
+        // Los nombres de valor tienen como máximo 16383 caracteres
+        let mut buffer = vec![0u16; 16384];
+        let mut len = buffer.len() as u32;
+        let status =
+            unsafe { RegEnumValueW(key, index, PWSTR(buffer.as_mut_ptr()), &mut len, None, None, None, None) };
+        if status.is_err() {
+            break;
+        }
+        names.push(from_wide(&buffer[..len as usize]));
+    }
+    unsafe { RegCloseKey(key) };
+    names
+}
+
+// Empresa (CompanyName) de la información de versión de un ejecutable
+pub fn file_company_name(path: &str) -> Option<String> {
+    use windows::core::PCWSTR;
+    use windows::Win32::Storage::FileSystem::{GetFileVersionInfoSizeW, GetFileVersionInfoW, VerQueryValueW};
+
+    let wide_path = to_wide(path);
+    let size = unsafe { GetFileVersionInfoSizeW(PCWSTR(wide_path.as_ptr()), None) };
+    if size == 0 {
+        return None;
+    }
+    let mut data = vec![0u8; size as usize];
+    if !unsafe { GetFileVersionInfoW(PCWSTR(wide_path.as_ptr()), 0, size, data.as_mut_ptr() as *mut _) }.as_bool() {
+        return None;
+    }
+
+    let query = |sub_block: &str| -> Option<(*const u8, u32)> {
+        let wide = to_wide(sub_block);
+        let mut pointer = std::ptr::null_mut();
+        let mut len = 0u32;
+        let ok = unsafe { VerQueryValueW(data.as_ptr() as *const _, PCWSTR(wide.as_ptr()), &mut pointer, &mut len) };
+        (ok.as_bool() && len > 0).then_some((pointer as *const u8, len))
+    };
+    // Primera traducción (idioma + página de códigos); si no hay, inglés Unicode
+    let translation = query(r"\VarFileInfo\Translation")
+        .map(|(pointer, _)| unsafe { (*(pointer as *const u16), *(pointer as *const u16).add(1)) })
+        .unwrap_or((0x0409, 0x04B0));
+    let (pointer, len) = query(&format!(r"\StringFileInfo\{:04x}{:04x}\CompanyName", translation.0, translation.1))?;
+    let text = unsafe { std::slice::from_raw_parts(pointer as *const u16, len as usize) };
+    Some(from_wide(text).trim().to_string()).filter(|company| !company.is_empty())
+}
+
+// Handle (proceso, token...) que se cierra automáticamente
+#[derive(Debug)]
+pub struct OwnedHandle(pub windows::Win32::Foundation::HANDLE);
+
+impl Drop for OwnedHandle {
+    fn drop(&mut self) {
+        unsafe { windows::Win32::Foundation::CloseHandle(self.0) };
+    }
+}
+
+pub fn open_process(
+    pid: u32,
+    access: windows::Win32::System::Threading::PROCESS_ACCESS_RIGHTS,
+) -> Result<OwnedHandle, String> {
+    unsafe { windows::Win32::System::Threading::OpenProcess(access, false, pid) }
+        .map(OwnedHandle)
+        .map_err(|e| format!("No se pudo abrir el proceso {}: {}", pid, e))
+}
+
+pub fn open_thread(
+    tid: u32,
+    access: windows::Win32::System::Threading::THREAD_ACCESS_RIGHTS,
+) -> Result<OwnedHandle, String> {
+    unsafe { windows::Win32::System::Threading::OpenThread(access, false, tid) }
+        .map(OwnedHandle)
+        .map_err(|e| format!("No se pudo abrir el hilo {}: {}", tid, e))
+}
+
+// Espera a que el proceso termine; false si sigue vivo pasado `timeout_ms`
+pub fn wait_for_exit(pid: u32, timeout_ms: u64) -> bool {
+    use windows::Win32::Foundation::WAIT_OBJECT_0;
+    use windows::Win32::System::Threading::{WaitForSingleObject, PROCESS_SYNCHRONIZE};
+
+    // Si ya no se puede abrir es que terminó
+    let Ok(handle) = open_process(pid, PROCESS_SYNCHRONIZE) else { return true };
+    let waited = unsafe { WaitForSingleObject(handle.0, timeout_ms.min(u32::MAX as u64) as u32) };
+    waited == WAIT_OBJECT_0
+}
+
+// Identificadores de los hilos de un proceso (instantánea de Toolhelp)
+pub fn thread_ids(pid: u32) -> Result<Vec<u32>, String> {
+    use windows::Win32::System::Diagnostics::ToolHelp::{
+        CreateToolhelp32Snapshot, Thread32First, Thread32Next, TH32CS_SNAPTHREAD, THREADENTRY32,
+    };
+
+    // La instantánea de hilos siempre incluye todo el sistema
+    let snapshot = unsafe { CreateToolhelp32Snapshot(TH32CS_SNAPTHREAD, 0) }
+        .map(OwnedHandle)
+        .map_err(|e| format!("No se pudieron enumerar los hilos: {}", e))?;
+    let mut entry = THREADENTRY32 {
+        dwSize: std::mem::size_of::<THREADENTRY32>() as u32,
+        ..Default::default()
+    };
+    let mut ids = Vec::new();
+    let mut more = unsafe { Thread32First(snapshot.0, &mut entry) }.as_bool();
+    while more {
+        if entry.th32OwnerProcessID == pid {
+            ids.push(entry.th32ThreadID);
+        }
+        more = unsafe { Thread32Next(snapshot.0, &mut entry) }.as_bool();
+    }
+    Ok(ids)
+}
+
+// Títulos de las ventanas visibles de nivel superior de un proceso
+pub fn visible_window_titles(pid: u32) -> Vec<String> {
+    use windows::Win32::Foundation::{BOOL, HWND, LPARAM};
+    use windows::Win32::UI::WindowsAndMessaging::{
+        EnumWindows, GetWindowTextW, GetWindowThreadProcessId, IsWindowVisible,
+    };
+
+    struct Search {
+        pid: u32,
+        titles: Vec<String>,
+    }
+
+    unsafe extern "system" fn visit(hwnd: HWND, lparam: LPARAM) -> BOOL {
+        let search = &mut *(lparam.0 as *mut Search);
+        let mut owner = 0u32;
+        GetWindowThreadProcessId(hwnd, Some(&mut owner));
+        if owner == search.pid && IsWindowVisible(hwnd).as_bool() {
+            let mut buffer = [0u16; 512];
+            let len = GetWindowTextW(hwnd, &mut buffer);
+            if len > 0 {
+                search.titles.push(from_wide(&buffer[..len as usize]));
+            }
+        }
+        true.into()
+    }
+
+    let mut search = Search { pid, titles: Vec::new() };
+    unsafe { EnumWindows(Some(visit), LPARAM(&mut search as *mut Search as isize)) };
+    search.titles
+}
+
+// PID del proceso dueño de la ventana en primer plano
+pub fn foreground_pid() -> Option<u32> {
+    use windows::Win32::UI::WindowsAndMessaging::{GetForegroundWindow, GetWindowThreadProcessId};
+
+    let hwnd = unsafe { GetForegroundWindow() };
+    if hwnd.0 == 0 {
+        return None;
+    }
+    let mut pid = 0u32;
+    unsafe { GetWindowThreadProcessId(hwnd, Some(&mut pid)) };
+    (pid != 0).then_some(pid)
+}
+
+// Ruta completa del ejecutable de un proceso
+pub fn process_image_path(pid: u32) -> Option<String> {
+    use windows::Win32::System::Threading::{
+        QueryFullProcessImageNameW, PROCESS_NAME_WIN32, PROCESS_QUERY_LIMITED_INFORMATION,
+    };
+
+    let process = open_process(pid, PROCESS_QUERY_LIMITED_INFORMATION).ok()?;
+    let mut buffer = [0u16; 1024];
+    let mut len = buffer.len() as u32;
+    let ok = unsafe {
+        QueryFullProcessImageNameW(process.0, PROCESS_NAME_WIN32, windows::core::PWSTR(buffer.as_mut_ptr()), &mut len)
+    };
+    if !ok.as_bool() {
+        return None;
+    }
+    Some(from_wide(&buffer[..len as usize]))
+}
+
+// Nombre del ejecutable (sin ruta) de un proceso
+pub fn process_image_name(pid: u32) -> Option<String> {
+    process_image_path(pid)?.rsplit('\\').next().map(str::to_string)
+}
+
+// Handles abiertos por un proceso
+pub fn process_handle_count(pid: u32) -> Option<u32> {
+    use windows::Win32::System::Threading::{GetProcessHandleCount, PROCESS_QUERY_LIMITED_INFORMATION};
+
+    let process = open_process(pid, PROCESS_QUERY_LIMITED_INFORMATION).ok()?;
+    let mut handles = 0u32;
+    unsafe { GetProcessHandleCount(process.0, &mut handles) }.as_bool().then_some(handles)
+}
+
+// Código de salida; None si sigue en ejecución (STILL_ACTIVE) o sin acceso
+pub fn exit_code(process: &OwnedHandle) -> Option<u32> {
+    use windows::Win32::System::Threading::GetExitCodeProcess;
+
+    const STILL_ACTIVE: u32 = 259;
+    let mut code = 0u32;
+    let ok = unsafe { GetExitCodeProcess(process.0, &mut code) }.as_bool();
+    (ok && code != STILL_ACTIVE).then_some(code)
+}
+
+// (CPU consumida, tiempo en ejecución) en ms; el handle sigue dando los
+// tiempos después de que el proceso termine
+pub fn process_times(process: &OwnedHandle) -> Option<(u64, u64)> {
+    use windows::Win32::Foundation::FILETIME;
+    use windows::Win32::System::Threading::GetProcessTimes;
+
+    let ticks = |time: &FILETIME| ((time.dwHighDateTime as u64) << 32) | time.dwLowDateTime as u64;
+    let (mut created, mut exited, mut kernel, mut user) = Default::default();
+    if !unsafe { GetProcessTimes(process.0, &mut created, &mut exited, &mut kernel, &mut user) }.as_bool() {
+        return None;
+    }
+    // Intervalos de 100 ns; sin hora de salida el proceso sigue vivo
+    let end = match ticks(&exited) {
+        0 => (crate::stats::now_millis() + 11_644_473_600_000) * 10_000,
+        exited => exited,
+    };
+    Some(((ticks(&kernel) + ticks(&user)) / 10_000, end.saturating_sub(ticks(&created)) / 10_000))
+}
+
+thread_local! {
+    static LOOP_APP: std::cell::RefCell<Option<tauri::AppHandle>> = const { std::cell::RefCell::new(None) };
+}
+
+// AppHandle del hilo de MessageLoop en curso. Los callbacks de hooks y
+// ventanas no reciben contexto, pero se ejecutan en ese hilo
+pub fn message_loop_app() -> Option<tauri::AppHandle> {
+    LOOP_APP.with(|app| app.borrow().clone())
+}
+
+// Hilo con su propio bucle de mensajes, para hooks, atajos globales y ventanas
+// de solo mensajes. `setup` se ejecuta en el hilo antes del bucle y devuelve
+// qué hacer con cada mensaje y cómo deshacer lo registrado al terminar
+#[derive(Debug)]
+pub struct MessageLoop {
+    thread: u32,
+    handle: std::thread::JoinHandle<()>,
+}
+
+impl MessageLoop {
+    pub fn start<S, M, C>(app: &tauri::AppHandle, name: &str, setup: S) -> Result<MessageLoop, String>
+    where
+        S: FnOnce() -> Result<(M, C), String> + Send + 'static,
+        M: FnMut(&windows::Win32::UI::WindowsAndMessaging::MSG),
+        C: FnOnce(),
+    {
+        use windows::Win32::Foundation::HWND;
+        use windows::Win32::System::Threading::GetCurrentThreadId;
+        use windows::Win32::UI::WindowsAndMessaging::{DispatchMessageW, GetMessageW, PeekMessageW, MSG, PM_NOREMOVE};
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let app = app.clone();
+        let spawned = std::thread::Builder::new().name(name.to_string()).spawn(move || {
+            LOOP_APP.with(|slot| *slot.borrow_mut() = Some(app));
+            // La cola del hilo se crea antes de avisar: sin ella se perdería un
+            // WM_QUIT de stop enviado justo después
+            let mut msg = MSG::default();
+            unsafe { PeekMessageW(&mut msg, HWND(0), 0, 0, PM_NOREMOVE) };
+            let (mut on_message, cleanup) = match setup() {
+                Ok(handlers) => handlers,
+                Err(e) => {
+                    let _ = sender.send(Err(e));
+                    return;
+                }
+            };
+            let _ = sender.send(Ok(unsafe { GetCurrentThreadId() }));
+
+            // WM_QUIT (de stop) hace que GetMessageW devuelva false
+            while unsafe { GetMessageW(&mut msg, HWND(0), 0, 0) }.as_bool() {
+                on_message(&msg);
+                unsafe { DispatchMessageW(&msg) };
+            }
+            cleanup();
+        });
+        let handle = spawned.map_err(|e| format!("No se pudo crear el hilo {}: {}", name, e))?;
+        match receiver.recv() {
+            Ok(Ok(thread)) => Ok(MessageLoop { thread, handle }),
+            Ok(Err(e)) => {
+                let _ = handle.join();
+                Err(e)
+            }
+            Err(_) => {
+                let _ = handle.join();
+                Err(format!("El hilo {} terminó al iniciarse", name))
+            }
+        }
+    }
+
+    // Espera a que el hilo deshaga lo registrado: registrarlo de nuevo antes
+    // fallaría. No se llama con un bloqueo que tomen los callbacks del hilo
+    pub fn stop(self) {
+        use windows::Win32::Foundation::{LPARAM, WPARAM};
+        use windows::Win32::UI::WindowsAndMessaging::{PostThreadMessageW, WM_QUIT};
+
+        unsafe { PostThreadMessageW(self.thread, WM_QUIT, WPARAM(0), LPARAM(0)) };
+        let _ = self.handle.join();
+    }
+}
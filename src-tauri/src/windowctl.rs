@@ -0,0 +1,147 @@
+// src-tauri/src/windowctl.rs
+// Ventanas de nivel superior de un proceso y acciones sobre ellas: cambiar a
+// la ventana, minimizar, maximizar o pedirle que se cierre (WM_CLOSE), como
+// "Finalizar tarea" antes de recurrir a taskkill /F
+
+use serde::{Serialize, Deserialize};
+use tauri::State;
+
+use crate::AppState;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ProcessWindow {
+    pub hwnd: isize,
+    pub title: String,
+    pub class_name: String,
+    pub minimized: bool,
+    pub maximized: bool,
+    pub not_responding: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WindowAction {
+    SwitchTo, // Restaura si está minimizada y la trae al frente
+    Minimize,
+    Maximize,
+    Restore,
+    Close, // WM_CLOSE: la aplicación puede pedir confirmación o negarse
+}
+
+// Ventanas visibles con título, las mismas que lista la pestaña Aplicaciones
+#[tauri::command]
+pub fn list_process_windows(pid: u32) -> Result<Vec<ProcessWindow>, String> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::Foundation::{BOOL, HWND, LPARAM};
+        use windows::Win32::UI::WindowsAndMessaging::{
+            EnumWindows, GetClassNameW, GetWindowTextW, GetWindowThreadProcessId, IsHungAppWindow, IsIconic,
+            IsWindowVisible, IsZoomed,
+        };
+
+        struct Search {
+            pid: u32,
+            windows: Vec<ProcessWindow>,
+        }
+
+        unsafe extern "system" fn visit(hwnd: HWND, lparam: LPARAM) -> BOOL {
+            let search = &mut *(lparam.0 as *mut Search);
+            let mut owner = 0u32;
+            GetWindowThreadProcessId(hwnd, Some(&mut owner));
+            if owner != search.pid || !IsWindowVisible(hwnd).as_bool() {
+                return true.into();
+            }
+            let mut title = [0u16; 512];
+            let len = GetWindowTextW(hwnd, &mut title);
+            if len > 0 {
+                let mut class_name = [0u16; 256];
+                let class_len = GetClassNameW(hwnd, &mut class_name).max(0);
+                search.windows.push(ProcessWindow {
+                    hwnd: hwnd.0,
+                    title: crate::win::from_wide(&title[..len as usize]),
+                    class_name: crate::win::from_wide(&class_name[..class_len as usize]),
+                    minimized: IsIconic(hwnd).as_bool(),
+                    maximized: IsZoomed(hwnd).as_bool(),
+                    not_responding: IsHungAppWindow(hwnd).as_bool(),
+                });
+            }
+            true.into()
+        }
+
+        let mut search = Search { pid, windows: Vec::new() };
+        unsafe { EnumWindows(Some(visit), LPARAM(&mut search as *mut Search as isize)) };
+        Ok(search.windows)
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = pid;
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn apply(hwnd: windows::Win32::Foundation::HWND, action: WindowAction) -> Result<(), String> {
+    use windows::Win32::Foundation::{LPARAM, WPARAM};
+    use windows::Win32::UI::WindowsAndMessaging::{
+        IsIconic, PostMessageW, SetForegroundWindow, ShowWindow, SW_MAXIMIZE, SW_MINIMIZE, SW_RESTORE, WM_CLOSE,
+    };
+
+    // ShowWindow devuelve el estado de visibilidad anterior, no un error
+    match action {
+        WindowAction::SwitchTo => {
+            if unsafe { IsIconic(hwnd) }.as_bool() {
+                unsafe { ShowWindow(hwnd, SW_RESTORE) };
+            }
+            // Windows puede negarlo si otra aplicación tiene el foco bloqueado
+            if !unsafe { SetForegroundWindow(hwnd) }.as_bool() {
+                return Err("Windows no permitió traer la ventana al frente".to_string());
+            }
+        }
+        WindowAction::Minimize => {
+            unsafe { ShowWindow(hwnd, SW_MINIMIZE) };
+        }
+        WindowAction::Maximize => {
+            unsafe { ShowWindow(hwnd, SW_MAXIMIZE) };
+        }
+        WindowAction::Restore => {
+            unsafe { ShowWindow(hwnd, SW_RESTORE) };
+        }
+        WindowAction::Close => {
+            if !unsafe { PostMessageW(hwnd, WM_CLOSE, WPARAM(0), LPARAM(0)) }.as_bool() {
+                return Err(format!("No se pudo enviar WM_CLOSE: {}", windows::core::Error::from_win32()));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn window_action(state: State<AppState>, hwnd: isize, action: WindowAction) -> Result<(), String> {
+    #[cfg(target_os = "windows")]
+    {
+        use crate::audit::AuditOrigin;
+        use windows::Win32::Foundation::HWND;
+        use windows::Win32::UI::WindowsAndMessaging::{GetWindowThreadProcessId, IsWindow};
+
+        let hwnd = HWND(hwnd);
+        if !unsafe { IsWindow(hwnd) }.as_bool() {
+            return Err("La ventana ya no existe".to_string());
+        }
+        let mut pid = 0u32;
+        unsafe { GetWindowThreadProcessId(hwnd, Some(&mut pid)) };
+        let result = apply(hwnd, action);
+        // Cerrar una ventana puede terminar la aplicación: queda en el registro
+        if action == WindowAction::Close {
+            let name = crate::audit::process_name(&state, pid);
+            crate::audit::record(&state, AuditOrigin::User, "close_window", &name, Some(pid), None, &result);
+        }
+        result
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = (state, hwnd, action);
+        Err("Esta función solo está disponible en Windows".to_string())
+    }
+}
@@ -0,0 +1,146 @@
+// src-tauri/src/wmi.rs
+// Envoltorio mínimo de las consultas WQL a WMI (root\cimv2 salvo que se pida
+// otro espacio de nombres, como el del Centro de seguridad). Cada consulta
+// corre en un hilo propio inicializado como MTA: el hilo de los comandos puede
+// tener ya COM en modo STA por el WebView
+
+use windows::core::{BSTR, PCWSTR};
+use windows::Win32::System::Com::{
+    CoCreateInstance, CoInitializeEx, CoSetProxyBlanket, CoUninitialize, CLSCTX_INPROC_SERVER, COINIT_MULTITHREADED,
+    EOAC_NONE, RPC_C_AUTHN_LEVEL_CALL, RPC_C_IMP_LEVEL_IMPERSONATE, VARIANT, VT_BOOL, VT_BSTR, VT_I1, VT_I2, VT_I4,
+    VT_I8, VT_UI1, VT_UI2, VT_UI4, VT_UI8,
+};
+use windows::Win32::System::Ole::VariantClear;
+use windows::Win32::System::Rpc::{RPC_C_AUTHN_WINNT, RPC_C_AUTHZ_NONE};
+use windows::Win32::System::Wmi::{
+    IWbemClassObject, IWbemLocator, WbemLocator, WBEM_FLAG_FORWARD_ONLY, WBEM_FLAG_RETURN_IMMEDIATELY, WBEM_INFINITE,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum WmiValue {
+    Null, // Propiedad vacía o de un tipo que no se convierte
+    Bool(bool),
+    Int(i64),
+    Text(String),
+}
+
+impl WmiValue {
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            WmiValue::Int(value) => u64::try_from(*value).ok(),
+            // Los uint64 llegan como texto
+            WmiValue::Text(text) => text.parse().ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            WmiValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn into_text(self) -> Option<String> {
+        match self {
+            WmiValue::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+}
+
+struct ComGuard;
+
+impl Drop for ComGuard {
+    fn drop(&mut self) {
+        unsafe { CoUninitialize() };
+    }
+}
+
+fn value(variant: &VARIANT) -> WmiValue {
+    let inner = unsafe { &variant.Anonymous.Anonymous };
+    unsafe {
+        match inner.vt {
+            VT_BSTR => WmiValue::Text(inner.Anonymous.bstrVal.to_string()),
+            VT_BOOL => WmiValue::Bool(inner.Anonymous.boolVal.as_bool()),
+            VT_I1 | VT_UI1 => WmiValue::Int(inner.Anonymous.bVal as i64),
+            VT_I2 => WmiValue::Int(inner.Anonymous.iVal as i64),
+            VT_UI2 => WmiValue::Int(inner.Anonymous.uiVal as i64),
+            VT_I4 => WmiValue::Int(inner.Anonymous.lVal as i64),
+            VT_UI4 => WmiValue::Int(inner.Anonymous.ulVal as i64),
+            VT_I8 => WmiValue::Int(inner.Anonymous.llVal),
+            VT_UI8 => WmiValue::Int(inner.Anonymous.ullVal as i64),
+            _ => WmiValue::Null,
+        }
+    }
+}
+
+fn property(object: &IWbemClassObject, name: &str) -> WmiValue {
+    let wide = crate::win::to_wide(name);
+    let mut variant = VARIANT::default();
+    if unsafe { object.Get(PCWSTR(wide.as_ptr()), 0, &mut variant, None, None) }.is_err() {
+        return WmiValue::Null;
+    }
+    let result = value(&variant);
+    let _ = unsafe { VariantClear(&mut variant) };
+    result
+}
+
+fn run(namespace: &str, wql: &str, properties: &[&str]) -> Result<Vec<Vec<WmiValue>>, String> {
+    unsafe { CoInitializeEx(None, COINIT_MULTITHREADED) }.map_err(|e| format!("No se pudo inicializar COM: {}", e))?;
+    let _guard = ComGuard;
+
+    let locator: IWbemLocator = unsafe { CoCreateInstance(&WbemLocator, None, CLSCTX_INPROC_SERVER) }
+        .map_err(|e| format!("No se pudo crear el localizador WMI: {}", e))?;
+    let services = unsafe {
+        locator.ConnectServer(&BSTR::from(namespace), &BSTR::new(), &BSTR::new(), &BSTR::new(), 0, &BSTR::new(), None)
+    }
+    .map_err(|e| format!("No se pudo conectar con WMI ({}): {}", namespace, e))?;
+    unsafe {
+        CoSetProxyBlanket(
+            &services,
+            RPC_C_AUTHN_WINNT,
+            RPC_C_AUTHZ_NONE,
+            PCWSTR::null(),
+            RPC_C_AUTHN_LEVEL_CALL,
+            RPC_C_IMP_LEVEL_IMPERSONATE,
+            None,
+            EOAC_NONE,
+        )
+    }
+    .map_err(|e| format!("No se pudo configurar la seguridad de WMI: {}", e))?;
+
+    let flags = WBEM_FLAG_FORWARD_ONLY | WBEM_FLAG_RETURN_IMMEDIATELY;
+    let objects = unsafe { services.ExecQuery(&BSTR::from("WQL"), &BSTR::from(wql), flags, None) }
+        .map_err(|e| format!("Error en la consulta WMI \"{}\": {}", wql, e))?;
+
+    let mut rows = Vec::new();
+    loop {
+        let mut row = [None];
+        let mut returned = 0u32;
+        // WBEM_S_FALSE (un código de éxito) marca el final con 0 objetos
+        unsafe { objects.Next(WBEM_INFINITE, &mut row, &mut returned) }
+            .ok()
+            .map_err(|e| format!("Error al leer los resultados de WMI: {}", e))?;
+        let Some(object) = row[0].take().filter(|_| returned > 0) else { break };
+        rows.push(properties.iter().map(|name| property(&object, name)).collect());
+    }
+    Ok(rows)
+}
+
+// Una fila por objeto con el valor de cada propiedad pedida, en el mismo orden
+pub fn query(wql: &str, properties: &[&str]) -> Result<Vec<Vec<WmiValue>>, String> {
+    query_namespace("ROOT\\CIMV2", wql, properties)
+}
+
+pub fn query_namespace(namespace: &str, wql: &str, properties: &[&str]) -> Result<Vec<Vec<WmiValue>>, String> {
+    let namespace = namespace.to_string();
+    let wql = wql.to_string();
+    let properties: Vec<String> = properties.iter().map(|name| name.to_string()).collect();
+    std::thread::spawn(move || {
+        let properties: Vec<&str> = properties.iter().map(String::as_str).collect();
+        run(&namespace, &wql, &properties)
+    })
+    .join()
+    .map_err(|_| "La consulta WMI terminó de forma inesperada".to_string())?
+}
@@ -42,6 +42,11 @@ function Process() {
     has_children: boolean;
   }
 
+  // Respuesta de kill_process (KillOutcome en main.rs)
+  type KillOutcome =
+    | { status: 'killed' }
+    | { status: 'unsaved_work'; pid: number; name: string; known_editor: boolean; modified_windows: string[] };
+
   const [notification, setNotification] = useState<Notification | null>(null);
   const [confirmKill, setConfirmKill] = useState<ProcessInfo | null>(null);
 
@@ -76,18 +81,29 @@ function Process() {
     setSortConfig({ key, direction });
   };
 
-  const handleKillProcess = async (pid: any) => {
+  const handleKillProcess = async (pid: any, force = false) => {
     setConfirmKill(null);
     try {
       setLoading(true);
-      const result = await invoke('kill_process', { pid });
-      if (result) {
+      const outcome = await invoke<KillOutcome>('kill_process', { pid, force });
+      if (outcome.status === 'killed') {
         setNotification({
           type: 'success',
           message: `Proceso con PID ${pid} terminado correctamente.`
         });
         // Recargar la lista de procesos
         fetchProcesses();
+      } else if (outcome.status === 'unsaved_work') {
+        // Ventanas con cambios sin guardar: se pide confirmación y se fuerza
+        const windows = outcome.modified_windows.length > 0 ? `\n\n${outcome.modified_windows.join('\n')}` : '';
+        if (window.confirm(`${outcome.name} puede tener trabajo sin guardar. ¿Terminarlo igualmente?${windows}`)) {
+          await handleKillProcess(pid, true);
+        } else {
+          setNotification({
+            type: 'error',
+            message: `No se terminó ${outcome.name} (PID ${pid}): tiene trabajo sin guardar.`
+          });
+        }
       }
     } catch (error) {
       setNotification({